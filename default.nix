@@ -88,6 +88,7 @@ let commonBuildInputs = pkgs:
     pkgs.ocamlPackages.bisect_ppx
     pkgs.ocamlPackages.obelisk
     pkgs.ocamlPackages.uucp
+    pkgs.ocamlPackages.uunf
     pkgs.perl
     pkgs.removeReferencesTo
   ]; in
@@ -366,6 +367,21 @@ rec {
           EXTRA_MOC_ARGS = "--sanity-checks --compacting-gc";
       });
 
+    generational_gc_subdir = dir: deps:
+      (test_subdir dir deps).overrideAttrs (args: {
+          EXTRA_MOC_ARGS = "--sanity-checks --generational-gc";
+      });
+
+    incremental_gc_subdir = dir: deps:
+      (test_subdir dir deps).overrideAttrs (args: {
+          EXTRA_MOC_ARGS = "--sanity-checks --incremental-gc";
+      });
+
+    marksweep_gc_subdir = dir: deps:
+      (test_subdir dir deps).overrideAttrs (args: {
+          EXTRA_MOC_ARGS = "--sanity-checks --marksweep-gc";
+      });
+
     perf_subdir = dir: deps:
       (test_subdir dir deps).overrideAttrs (args: {
         checkPhase = ''
@@ -478,9 +494,15 @@ rec {
       run-dbg    = snty_subdir "run"        [ moc ] ;
       ic-ref-run = test_subdir "run-drun"   [ moc ic-ref-run ];
       ic-ref-run-compacting-gc = compacting_gc_subdir "run-drun" [ moc ic-ref-run ] ;
+      ic-ref-run-generational-gc = generational_gc_subdir "run-drun" [ moc ic-ref-run ] ;
+      ic-ref-run-incremental-gc = incremental_gc_subdir "run-drun" [ moc ic-ref-run ] ;
+      ic-ref-run-marksweep-gc = marksweep_gc_subdir "run-drun" [ moc ic-ref-run ] ;
       drun       = test_subdir "run-drun"   [ moc nixpkgs.drun ];
       drun-dbg   = snty_subdir "run-drun"   [ moc nixpkgs.drun ];
       drun-compacting-gc = compacting_gc_subdir "run-drun" [ moc nixpkgs.drun ] ;
+      drun-generational-gc = generational_gc_subdir "run-drun" [ moc nixpkgs.drun ] ;
+      drun-incremental-gc = incremental_gc_subdir "run-drun" [ moc nixpkgs.drun ] ;
+      drun-marksweep-gc = marksweep_gc_subdir "run-drun" [ moc nixpkgs.drun ] ;
       fail       = test_subdir "fail"       [ moc ];
       repl       = test_subdir "repl"       [ moc ];
       ld         = test_subdir "ld"         ([ mo-ld ] ++ ldTestDeps);
@@ -600,6 +622,7 @@ rec {
       echo "If this fails, run `make -C rts format`"
       cargo fmt --verbose --manifest-path motoko-rts/Cargo.toml -- --check
       cargo fmt --verbose --manifest-path motoko-rts-tests/Cargo.toml -- --check
+      cargo fmt --verbose --manifest-path heap-snapshot/Cargo.toml -- --check
     '';
     installPhase = "touch $out";
   };
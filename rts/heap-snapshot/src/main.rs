@@ -0,0 +1,353 @@
+use std::fmt::Write as _;
+use std::io::{Read, Write};
+
+static USAGE: &str = "
+Usage: heap-snapshot [SNAPSHOT [OUTPUT]]
+
+Converts a heap snapshot taken with `Prim.rts_heap_snapshot()` to the V8 `.heapsnapshot` format,
+which can be loaded in the memory tab of Chrome DevTools and other heap snapshot viewers. Reads
+from stdin and writes to stdout when the paths are not given.
+
+See `rts/motoko-rts/src/heap_snapshot.rs` for the snapshot format.
+";
+
+// Keep these in sync with `rts/motoko-rts/src/heap_snapshot.rs`. Roots other than the
+// continuation table are static roots.
+const SNAPSHOT_MAGIC: u32 = 0x5348_4f4d;
+const SNAPSHOT_VERSION: u32 = 1;
+const ROOT_CONTINUATION_TABLE: u32 = 1;
+
+// Keep these in sync with `rts/motoko-rts/src/types.rs`
+const TAG_NAMES: [(u32, &str); 17] = [
+    (1, "Object"),
+    (3, "ObjInd"),
+    (5, "Array"),
+    (7, "Bits64"),
+    (9, "MutBox"),
+    (11, "Closure"),
+    (13, "Some"),
+    (15, "Variant"),
+    (17, "Blob"),
+    (19, "FwdPtr"),
+    (21, "Bits32"),
+    (23, "BigInt"),
+    (25, "Concat"),
+    (27, "Null"),
+    (29, "OneWordFiller"),
+    (31, "FreeSpace"),
+    (33, "WeakRef"),
+];
+
+// Node and edge types of the V8 format, see `NODE_TYPES` and `EDGE_TYPES` below
+const NODE_HIDDEN: u32 = 0;
+const NODE_ARRAY: u32 = 1;
+const NODE_STRING: u32 = 2;
+const NODE_OBJECT: u32 = 3;
+const NODE_CLOSURE: u32 = 5;
+const NODE_NUMBER: u32 = 7;
+const NODE_SYNTHETIC: u32 = 9;
+const NODE_CONCATENATED_STRING: u32 = 10;
+
+const EDGE_ELEMENT: u32 = 1;
+const EDGE_PROPERTY: u32 = 2;
+
+const NODE_TYPES: &str = r#"["hidden","array","string","object","code","closure","regexp","number","native","synthetic","concatenated string","sliced string"]"#;
+const EDGE_TYPES: &str =
+    r#"["context","element","property","internal","hidden","shortcut","weak"]"#;
+
+/// Number of fields of a node in the V8 format: type, name, id, self_size, edge_count,
+/// trace_node_id
+const NODE_FIELDS: usize = 6;
+
+struct Root {
+    kind: u32,
+    index: u32,
+    addr: u32,
+}
+
+struct Object {
+    addr: u32,
+    tag: u32,
+    size: u32,
+    /// Field index and address of the pointed object
+    pointers: Vec<(u32, u32)>,
+}
+
+struct Snapshot {
+    roots: Vec<Root>,
+    objects: Vec<Object>,
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    if args.len() > 2 || args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        eprintln!("{}", USAGE.trim());
+        std::process::exit(1);
+    }
+
+    let mut input = vec![];
+    match args.first() {
+        Some(path) => input = std::fs::read(path).unwrap_or_else(|err| fail(&err.to_string())),
+        None => {
+            std::io::stdin()
+                .read_to_end(&mut input)
+                .unwrap_or_else(|err| fail(&err.to_string()));
+        }
+    }
+
+    let snapshot = parse_snapshot(&input).unwrap_or_else(|err| fail(&err));
+    let output = to_v8_json(&snapshot);
+
+    match args.get(1) {
+        Some(path) => std::fs::write(path, output).unwrap_or_else(|err| fail(&err.to_string())),
+        None => std::io::stdout()
+            .write_all(output.as_bytes())
+            .unwrap_or_else(|err| fail(&err.to_string())),
+    }
+}
+
+fn fail(msg: &str) -> ! {
+    eprintln!("heap-snapshot: {}", msg);
+    std::process::exit(1);
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn read(&mut self) -> Result<u32, String> {
+        let word = self
+            .bytes
+            .get(self.offset..self.offset + 4)
+            .ok_or_else(|| format!("unexpected end of snapshot at offset {}", self.offset))?;
+        self.offset += 4;
+        Ok(u32::from_le_bytes([word[0], word[1], word[2], word[3]]))
+    }
+}
+
+fn parse_snapshot(bytes: &[u8]) -> Result<Snapshot, String> {
+    let mut reader = Reader { bytes, offset: 0 };
+
+    if reader.read()? != SNAPSHOT_MAGIC {
+        return Err("not a heap snapshot".to_string());
+    }
+
+    let version = reader.read()?;
+    if version != SNAPSHOT_VERSION {
+        return Err(format!("unsupported snapshot version {}", version));
+    }
+
+    let _heap_base = reader.read()?;
+    let _heap_end = reader.read()?;
+    let n_roots = reader.read()?;
+    let n_objects = reader.read()?;
+
+    let mut roots = vec![];
+    for _ in 0..n_roots {
+        roots.push(Root {
+            kind: reader.read()?,
+            index: reader.read()?,
+            addr: reader.read()?,
+        });
+    }
+
+    let mut objects = vec![];
+    for _ in 0..n_objects {
+        let addr = reader.read()?;
+        let tag = reader.read()?;
+        let size = reader.read()?;
+        let n_pointers = reader.read()?;
+        let mut pointers = vec![];
+        for _ in 0..n_pointers {
+            pointers.push((reader.read()?, reader.read()?));
+        }
+        objects.push(Object {
+            addr,
+            tag,
+            size,
+            pointers,
+        });
+    }
+
+    Ok(Snapshot { roots, objects })
+}
+
+fn tag_name(tag: u32) -> String {
+    match TAG_NAMES.iter().find(|(t, _)| *t == tag) {
+        Some((_, name)) => name.to_string(),
+        None => format!("Tag {:#x}", tag),
+    }
+}
+
+fn node_type(tag: u32) -> u32 {
+    match tag_name(tag).as_str() {
+        "Object" => NODE_OBJECT,
+        "Array" => NODE_ARRAY,
+        "Closure" => NODE_CLOSURE,
+        "Blob" => NODE_STRING,
+        "Concat" => NODE_CONCATENATED_STRING,
+        "Bits64" | "Bits32" | "BigInt" => NODE_NUMBER,
+        _ => NODE_HIDDEN,
+    }
+}
+
+/// Interned strings of the V8 format
+#[derive(Default)]
+struct Strings {
+    strings: Vec<String>,
+    indices: std::collections::HashMap<String, usize>,
+}
+
+impl Strings {
+    fn intern(&mut self, s: &str) -> usize {
+        if let Some(idx) = self.indices.get(s) {
+            return *idx;
+        }
+        let idx = self.strings.len();
+        self.strings.push(s.to_string());
+        self.indices.insert(s.to_string(), idx);
+        idx
+    }
+}
+
+/// Converts the snapshot to the V8 `.heapsnapshot` format. The first node is a synthetic root
+/// node, with edges to the objects pointed by the static roots and the continuation table.
+/// Object addresses are used as node ids.
+fn to_v8_json(snapshot: &Snapshot) -> String {
+    let mut strings = Strings::default();
+
+    // Node index of each object, the root node is index 0
+    let node_indices: std::collections::HashMap<u32, usize> = snapshot
+        .objects
+        .iter()
+        .enumerate()
+        .map(|(i, obj)| (obj.addr, i + 1))
+        .collect();
+
+    let mut nodes: Vec<u64> = vec![];
+    let mut edges: Vec<u64> = vec![];
+
+    let root_edges: Vec<&Root> = snapshot
+        .roots
+        .iter()
+        .filter(|root| node_indices.contains_key(&root.addr))
+        .collect();
+
+    nodes.extend_from_slice(&[
+        u64::from(NODE_SYNTHETIC),
+        strings.intern("(GC roots)") as u64,
+        0,
+        0,
+        root_edges.len() as u64,
+        0,
+    ]);
+
+    for root in root_edges {
+        // Static roots are elements numbered by their index in the static roots array, the
+        // continuation table is a named property
+        let (edge_type, name_or_index) = match root.kind {
+            ROOT_CONTINUATION_TABLE => {
+                (EDGE_PROPERTY, strings.intern("(continuation table)") as u64)
+            }
+            _ => (EDGE_ELEMENT, u64::from(root.index)),
+        };
+        edges.extend_from_slice(&[
+            u64::from(edge_type),
+            name_or_index,
+            (node_indices[&root.addr] * NODE_FIELDS) as u64,
+        ]);
+    }
+
+    for obj in &snapshot.objects {
+        let pointers: Vec<&(u32, u32)> = obj
+            .pointers
+            .iter()
+            .filter(|(_, addr)| node_indices.contains_key(addr))
+            .collect();
+
+        let name = format!("{} @{:#x}", tag_name(obj.tag), obj.addr);
+
+        nodes.extend_from_slice(&[
+            u64::from(node_type(obj.tag)),
+            strings.intern(&name) as u64,
+            u64::from(obj.addr),
+            u64::from(obj.size),
+            pointers.len() as u64,
+            0,
+        ]);
+
+        for (field_idx, addr) in pointers {
+            edges.extend_from_slice(&[
+                u64::from(EDGE_ELEMENT),
+                u64::from(*field_idx),
+                (node_indices[addr] * NODE_FIELDS) as u64,
+            ]);
+        }
+    }
+
+    let mut out = String::new();
+
+    let _ = write!(
+        out,
+        concat!(
+            r#"{{"snapshot":{{"meta":{{"#,
+            r#""node_fields":["type","name","id","self_size","edge_count","trace_node_id"],"#,
+            r#""node_types":[{},"string","number","number","number","number"],"#,
+            r#""edge_fields":["type","name_or_index","to_node"],"#,
+            r#""edge_types":[{},"string_or_number","node"],"#,
+            r#""trace_function_info_fields":[],"trace_node_fields":[],"#,
+            r#""sample_fields":[],"location_fields":[]}},"#,
+            r#""node_count":{},"edge_count":{},"trace_function_count":0}},"#
+        ),
+        NODE_TYPES,
+        EDGE_TYPES,
+        nodes.len() / NODE_FIELDS,
+        edges.len() / 3,
+    );
+
+    out.push_str("\n\"nodes\":[");
+    write_numbers(&mut out, &nodes, NODE_FIELDS);
+    out.push_str("],\n\"edges\":[");
+    write_numbers(&mut out, &edges, 3);
+    out.push_str("],\n\"trace_function_infos\":[],\"trace_tree\":[],\"samples\":[],\"locations\":[],\n\"strings\":[");
+    for (i, s) in strings.strings.iter().enumerate() {
+        if i != 0 {
+            out.push_str(",\n");
+        }
+        write_json_string(&mut out, s);
+    }
+    out.push_str("]}\n");
+
+    out
+}
+
+/// Writes comma-separated numbers, with a line break after each `per_line` numbers
+fn write_numbers(out: &mut String, numbers: &[u64], per_line: usize) {
+    for (i, n) in numbers.iter().enumerate() {
+        if i != 0 {
+            out.push(',');
+            if i % per_line == 0 {
+                out.push('\n');
+            }
+        }
+        let _ = write!(out, "{}", n);
+    }
+}
+
+fn write_json_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
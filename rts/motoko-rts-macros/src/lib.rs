@@ -25,7 +25,7 @@ use quote::quote;
 /// #[cfg(feature = "ic")]
 /// #[export_name = "text_concat"]
 /// unsafe extern "C" fn ic_text_concat(s1: SkewedPtr, s2: SkewedPtr) -> SkewedPtr {
-///     text_concat(crate::memory::ic::IcMemory, s1, s2)
+///     text_concat(&mut crate::memory::ic::IcMemory::current(), s1, s2)
 /// }
 /// ```
 ///
@@ -49,11 +49,18 @@ use quote::quote;
 /// #[cfg(feature = "ic")]
 /// #[export_name = "text_concat"]
 /// unsafe extern "C" fn ic_my_function() {
-///     my_function(crate::memory::ic::IcMemory)
+///     my_function(&mut crate::memory::ic::IcMemory::current())
 /// }
 /// ```
 ///
-/// This is useful when the function won't be used when compiling the RTS for testing.
+/// This is useful when the function won't be used when compiling the RTS for testing. Such
+/// functions can also take `&mut IcMemory` instead of a generic `Memory`, to access the allocator
+/// state of the heap:
+///
+/// ```
+/// #[ic_mem_fn(ic_only)]
+/// fn my_function(mem: &mut IcMemory) { ... }
+/// ```
 #[proc_macro_attribute]
 pub fn ic_mem_fn(attr: TokenStream, input: TokenStream) -> TokenStream {
     let ic_only = if attr.is_empty() {
@@ -69,10 +76,10 @@ pub fn ic_mem_fn(attr: TokenStream, input: TokenStream) -> TokenStream {
 
     // Some sanity checks
     assert!(fun_sig.asyncness.is_none(), "IC functions cannot be async");
-    assert_eq!(
-        fun_sig.generics.params.len(),
-        1,
-        "IC memory functions should have one generic argument for the memory implementation"
+    assert!(
+        fun_sig.generics.params.len() == 1 || (ic_only && fun_sig.generics.params.is_empty()),
+        "IC memory functions should have one generic argument for the memory implementation, \
+         or take `IcMemory` when `ic_only`"
     );
     assert!(
         fun_sig.abi.is_none(),
@@ -131,7 +138,7 @@ pub fn ic_mem_fn(attr: TokenStream, input: TokenStream) -> TokenStream {
         #[cfg(feature = "ic")]
         #[export_name = #fn_name]
         unsafe extern "C" fn #fn_wrapper_ident(#(#wrapper_params_syn,)*) #wrapper_ret {
-            #fn_ident(&mut crate::memory::ic::IcMemory, #(#wrapper_args_syn,)*)
+            #fn_ident(&mut crate::memory::ic::IcMemory::current(), #(#wrapper_args_syn,)*)
         }
     )
     .into()
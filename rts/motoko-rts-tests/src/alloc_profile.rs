@@ -0,0 +1,104 @@
+use crate::memory::TestMemory;
+
+use motoko_rts::alloc_profile::{
+    alloc_profile, note_allocation, set_alloc_sampling, set_alloc_site, NO_SITE, N_SAMPLES,
+};
+use motoko_rts::memory::{alloc_array, alloc_blob, Context, Memory, ScratchRegion};
+use motoko_rts::types::*;
+
+pub unsafe fn test() {
+    println!("Testing allocation profiler ...");
+
+    let mut mem = TestMemory::new(Words(1024 * 1024));
+
+    test_sampling(&mut mem);
+    test_ring_buffer(&mut mem);
+
+    set_alloc_sampling(0);
+}
+
+unsafe fn test_sampling(mem: &mut TestMemory) {
+    println!("  Testing sampling");
+
+    set_alloc_sampling(100);
+
+    // 48 bytes per blob, every third blob is sampled
+    set_alloc_site(1);
+    for _ in 0..10 {
+        let blob = alloc_blob(mem, Bytes(40));
+        note_allocation(blob.get_ptr() as u32, Bytes(48));
+    }
+
+    // 128 bytes per array, every array is sampled
+    set_alloc_site(2);
+    for _ in 0..2 {
+        let array = alloc_array(mem, 30);
+        note_allocation(array.get_ptr() as u32, Bytes(128));
+    }
+
+    // Not sampled
+    set_alloc_site(NO_SITE);
+    for _ in 0..10 {
+        let array = alloc_array(mem, 30);
+        note_allocation(array.get_ptr() as u32, Bytes(128));
+    }
+
+    assert_eq!(
+        profile_words(mem),
+        vec![100, 5, 2, 1, TAG_BLOB, 3, 144, 2, TAG_ARRAY, 2, 256]
+    );
+}
+
+unsafe fn test_ring_buffer(mem: &mut TestMemory) {
+    println!("  Testing ring buffer");
+
+    set_alloc_sampling(1);
+    set_alloc_site(3);
+
+    for _ in 0..N_SAMPLES + 10 {
+        let blob = alloc_blob(mem, Bytes(0));
+        note_allocation(blob.get_ptr() as u32, Bytes(8));
+    }
+
+    let n_samples = N_SAMPLES as u32;
+    let expected = vec![1, n_samples + 10, 1, 3, TAG_BLOB, n_samples, n_samples * 8];
+
+    // The profile itself is not sampled, and doesn't overwrite the samples in the full buffer
+    assert_eq!(profile_words(&mut SampledMemory(mem)), expected);
+    assert_eq!(profile_words(&mut SampledMemory(mem)), expected);
+
+    set_alloc_site(NO_SITE);
+
+    assert_eq!(profile_words(mem), expected);
+}
+
+/// Calls `note_allocation` on allocations, like `IcMemory`
+struct SampledMemory<'a>(&'a mut TestMemory);
+
+impl<'a> Memory for SampledMemory<'a> {
+    unsafe fn alloc_words(&mut self, n: Words<u32>) -> Value {
+        let value = self.0.alloc_words(n);
+        note_allocation(value.get_ptr() as u32, n.to_bytes());
+        value
+    }
+
+    unsafe fn context(&mut self) -> &mut Context {
+        self.0.context()
+    }
+
+    unsafe fn begin_scratch(&mut self) -> ScratchRegion {
+        self.0.begin_scratch()
+    }
+
+    unsafe fn release_scratch(&mut self, region: ScratchRegion) {
+        self.0.release_scratch(region)
+    }
+}
+
+unsafe fn profile_words<M: Memory>(mem: &mut M) -> Vec<u32> {
+    let blob = alloc_profile(mem).as_blob();
+    let words = blob.payload_const() as *const u32;
+    (0..blob.len().as_u32() / 4)
+        .map(|i| *words.add(i as usize))
+        .collect()
+}
@@ -8,27 +8,32 @@ use motoko_rts::types::{Value, Words};
 pub unsafe fn test() {
     println!("Testing continuation table ...");
 
-    assert_eq!(continuation_count(), 0);
+    test_remember_recall();
+    test_independent_heaps();
+}
 
+unsafe fn test_remember_recall() {
     const N: usize = 2000; // >256, to exercise `double_continuation_table`
 
     // Array will be doubled 3 times, so 256 + 512 + 1024 + 2048 = 3840 words, plus each array will
     // have 2 word header.
     let mut heap = TestMemory::new(Words(3848));
 
+    assert_eq!(continuation_count(&mut heap), 0);
+
     let mut references: [u32; N] = [0; N];
     for i in 0..N {
         references[i] = remember_continuation(
             &mut heap,
             Value::from_raw(((i as u32) << 2).wrapping_sub(1)),
         );
-        assert_eq!(continuation_count(), (i + 1) as u32);
+        assert_eq!(continuation_count(&mut heap), (i + 1) as u32);
     }
 
     for i in 0..N / 2 {
-        let c = recall_continuation(references[i]);
+        let c = recall_continuation(&mut heap, references[i]);
         assert_eq!(c.get_raw(), (i << 2).wrapping_sub(1) as u32);
-        assert_eq!(continuation_count(), (N - i - 1) as u32);
+        assert_eq!(continuation_count(&mut heap), (N - i - 1) as u32);
     }
 
     for i in 0..N / 2 {
@@ -36,14 +41,46 @@ pub unsafe fn test() {
             &mut heap,
             Value::from_raw(((i as u32) << 2).wrapping_sub(1)),
         );
-        assert_eq!(continuation_count(), (N / 2 + i + 1) as u32);
+        assert_eq!(continuation_count(&mut heap), (N / 2 + i + 1) as u32);
     }
 
     for i in (0..N).rev() {
         assert_eq!(
-            recall_continuation(references[i]).get_raw(),
+            recall_continuation(&mut heap, references[i]).get_raw(),
             (i << 2).wrapping_sub(1) as u32,
         );
-        assert_eq!(continuation_count(), i as u32);
+        assert_eq!(continuation_count(&mut heap), i as u32);
     }
 }
+
+/// Each heap has its own continuation table
+unsafe fn test_independent_heaps() {
+    let mut heap1 = TestMemory::new(Words(1024));
+    let mut heap2 = TestMemory::new(Words(1024));
+
+    let c1 = Value::from_raw(3);
+    let c2 = Value::from_raw(7);
+
+    let idx1 = remember_continuation(&mut heap1, c1);
+    let idx2 = remember_continuation(&mut heap2, c2);
+
+    // Both tables are new, so the first slots are used
+    assert_eq!(idx1, 0);
+    assert_eq!(idx2, 0);
+
+    assert_eq!(continuation_count(&mut heap1), 1);
+    assert_eq!(continuation_count(&mut heap2), 1);
+
+    assert_eq!(
+        recall_continuation(&mut heap1, idx1).get_raw(),
+        c1.get_raw()
+    );
+    assert_eq!(continuation_count(&mut heap1), 0);
+    assert_eq!(continuation_count(&mut heap2), 1);
+
+    assert_eq!(
+        recall_continuation(&mut heap2, idx2).get_raw(),
+        c2.get_raw()
+    );
+    assert_eq!(continuation_count(&mut heap2), 0);
+}
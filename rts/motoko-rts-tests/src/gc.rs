@@ -5,15 +5,31 @@
 //
 // To convert an offset into an address, add heap array's address to the offset.
 
+mod census;
+mod copying;
+mod dedup;
+mod generational;
 mod heap;
+mod heap_check;
+mod heap_snapshot;
+mod incremental;
+mod large_object;
+mod mark_sweep;
+mod policy;
 mod random;
+mod retainer_path;
+mod stats;
 mod utils;
+mod weak_ref;
 
 use heap::MotokoHeap;
 use utils::{get_scalar_value, read_word, unskew_pointer, ObjectIdx, GC, GC_IMPLS, WORD_SIZE};
 
 use motoko_rts::gc::copying::copying_gc_internal;
+use motoko_rts::gc::generational::{generational_gc_internal, Strategy};
+use motoko_rts::gc::incremental::incremental_gc_internal;
 use motoko_rts::gc::mark_compact::compacting_gc_internal;
+use motoko_rts::gc::mark_sweep::marksweep_gc_internal;
 use motoko_rts::types::*;
 
 use std::fmt::Write;
@@ -36,6 +52,20 @@ pub fn test() {
         test_random_heap(seed, 180);
     }
     print!("\r");
+
+    census::test();
+    copying::test();
+    dedup::test();
+    generational::test();
+    heap_check::test();
+    heap_snapshot::test();
+    incremental::test();
+    large_object::test();
+    mark_sweep::test();
+    policy::test();
+    retainer_path::test();
+    stats::test();
+    weak_ref::test();
 }
 
 fn test_heaps() -> Vec<TestHeap> {
@@ -188,6 +218,15 @@ fn check_dynamic_heap(
         let tag = read_word(heap, offset);
         offset += WORD_SIZE;
 
+        // Free space left by the mark-sweep GC
+        if tag == TAG_ONE_WORD_FILLER {
+            continue;
+        }
+        if tag == TAG_FREE_SPACE {
+            offset += read_word(heap, offset) as usize * WORD_SIZE;
+            continue;
+        }
+
         assert_eq!(tag, TAG_ARRAY);
 
         let n_fields = read_word(heap, offset);
@@ -374,6 +413,77 @@ impl GC {
                     );
                 }
             }
+
+            GC::MarkSweep => {
+                unsafe {
+                    marksweep_gc_internal(
+                        &mut heap,
+                        heap_base,
+                        // get_hp
+                        || heap_1.heap_ptr_address(),
+                        // set_hp
+                        move |hp| heap_2.set_heap_ptr_address(hp as usize),
+                        static_roots,
+                        continuation_table_ptr_address,
+                        // note_live_size
+                        |_live_size| {},
+                        // note_reclaimed
+                        |_reclaimed| {},
+                    );
+                }
+            }
+
+            GC::Generational => {
+                let last_hp = heap.last_heap_ptr_address() as u32;
+                unsafe {
+                    generational_gc_internal(
+                        &mut heap,
+                        heap_base,
+                        last_hp,
+                        Strategy::Young,
+                        // get_hp
+                        || heap_1.heap_ptr_address(),
+                        // set_hp
+                        move |hp| heap_2.set_heap_ptr_address(hp as usize),
+                        static_roots,
+                        continuation_table_ptr_address,
+                        // note_live_size
+                        |_live_size| {},
+                        // note_reclaimed
+                        |_reclaimed| {},
+                    );
+                }
+                heap.set_last_heap_ptr_address(heap.heap_ptr_address());
+            }
+
+            GC::Incremental => {
+                // Use a small budget to test resuming the collection in a new increment
+                let budget = 3;
+                loop {
+                    let heap_1 = heap.clone();
+                    let heap_2 = heap.clone();
+                    let done = unsafe {
+                        incremental_gc_internal(
+                            &mut heap,
+                            heap_base,
+                            budget,
+                            // get_hp
+                            || heap_1.heap_ptr_address(),
+                            // set_hp
+                            move |hp| heap_2.set_heap_ptr_address(hp as usize),
+                            static_roots,
+                            continuation_table_ptr_address,
+                            // note_live_size
+                            |_live_size| {},
+                            // note_reclaimed
+                            |_reclaimed| {},
+                        )
+                    };
+                    if done {
+                        break;
+                    }
+                }
+            }
         }
     }
 }
@@ -0,0 +1,91 @@
+//! Tests for the heap census
+
+use super::heap::MotokoHeap;
+use super::utils::{UnalignedHeap, GC};
+use crate::memory::TestMemory;
+
+use motoko_rts::census::{heap_census_internal, Census};
+use motoko_rts::memory::{alloc_array, alloc_blob};
+use motoko_rts::types::*;
+
+pub fn test() {
+    println!("  Testing heap census...");
+
+    // Object 0 is a root and points to objects 1 and 2, object 3 is garbage. Objects are arrays
+    // with the object index in the first field.
+    let mut heap = MotokoHeap::new(
+        &[(0, vec![1, 2]), (1, vec![]), (2, vec![]), (3, vec![1])],
+        &[0],
+        &[],
+        GC::MarkCompact,
+    );
+
+    let heap_base = heap.heap_base_address() as u32;
+    let heap_end = heap.heap_ptr_address() as u32;
+    let static_roots = Value::from_ptr(heap.static_root_array_address());
+    let continuation_table_ptr_address = heap.continuation_table_ptr_address() as *mut Value;
+
+    let census = unsafe {
+        heap_census_internal(
+            &mut heap,
+            heap_base,
+            heap_end,
+            static_roots,
+            continuation_table_ptr_address,
+        )
+    };
+
+    // Objects 0, 1, 2, and the empty continuation table
+    let obj_0_size = size_of::<Array>() + Words(3);
+    let obj_1_size = size_of::<Array>() + Words(1);
+    let table_size = size_of::<Array>();
+
+    let arrays = census.tag(TAG_ARRAY);
+    assert_eq!(arrays.count, 4);
+    assert_eq!(
+        arrays.bytes,
+        (obj_0_size + obj_1_size * 2 + table_size).to_bytes()
+    );
+
+    for tag in (TAG_OBJECT..=TAG_FREE_SPACE).step_by(2) {
+        if tag != TAG_ARRAY {
+            assert_eq!(census.tag(tag).count, 0);
+        }
+    }
+
+    // Table is 2 words, objects 1 and 2 are 3 words, object 0 is 5 words
+    assert_eq!(Census::size_class(table_size), 1);
+    assert_eq!(Census::size_class(obj_1_size), 1);
+    assert_eq!(Census::size_class(obj_0_size), 2);
+    assert_eq!(census.by_size_class[1].count, 3);
+    assert_eq!(census.by_size_class[2].count, 1);
+
+    let total: u32 = census.by_size_class.iter().map(|entry| entry.count).sum();
+    assert_eq!(total, 4);
+
+    unsafe { test_unaligned_heap_base() };
+}
+
+/// Objects between the unaligned heap base and the next 32-byte aligned address are counted
+unsafe fn test_unaligned_heap_base() {
+    let mut mem = TestMemory::new(Words(1024));
+    let heap = UnalignedHeap::new(&mut mem);
+
+    // The blob is before the first aligned address, and only reachable from the array
+    let blob = alloc_blob(&mut mem, Bytes(4));
+    let array = alloc_array(&mut mem, 1);
+    array.as_array().set(0, blob);
+    (*heap.root).field = array;
+    let heap_end = mem.heap_pointer() as u32;
+
+    let census = heap_census_internal(
+        &mut mem,
+        heap.heap_base,
+        heap_end,
+        heap.static_roots,
+        heap.continuation_table_loc,
+    );
+
+    assert_eq!(census.tag(TAG_BLOB).count, 1);
+    assert_eq!(census.tag(TAG_ARRAY).count, 1);
+}
@@ -0,0 +1,37 @@
+//! Tests for the placement of to-space in the copying GC
+
+use super::heap::MotokoHeap;
+use super::utils::{find_object, heap_objects, GC};
+
+pub fn test() {
+    println!("  Testing copying GC to-space...");
+
+    unsafe {
+        test_to_space();
+    }
+}
+
+unsafe fn test_to_space() {
+    // Object 0 is a root and points to object 1, object 2 is garbage
+    let heap = MotokoHeap::new(
+        &[(0, vec![1]), (1, vec![]), (2, vec![])],
+        &[0],
+        &[],
+        GC::Copying,
+    );
+
+    let heap_base = heap.heap_base_address();
+    let heap_ptr = heap.heap_ptr_address();
+
+    // From-space starts at the heap base, so objects are evacuated after the heap pointer. The
+    // roots are evacuated first.
+    GC::Copying.run(heap.clone());
+    assert_eq!(find_object(&heap, 0) as usize, heap_ptr);
+    assert_eq!(heap_objects(&heap), vec![0, 1]);
+
+    // The old from-space is now free, and large enough for the new from-space
+    GC::Copying.run(heap.clone());
+    assert_eq!(find_object(&heap, 0) as usize, heap_base);
+    assert_eq!(heap_objects(&heap), vec![0, 1]);
+    assert!(heap.heap_ptr_address() < heap_ptr);
+}
@@ -0,0 +1,110 @@
+//! Tests for the blob deduplication pass of the mark-compact GC
+
+use super::heap::MotokoHeap;
+use super::utils::{find_object, heap_objects, GC};
+
+use motoko_rts::gc::generational::remembered_set;
+use motoko_rts::gc::generational::write_barrier::YOUNG_GENERATION_START;
+use motoko_rts::gc::mark_compact::dedup::{dedup_requested, request_blob_dedup};
+use motoko_rts::gc::stats::{last, set_gc_stats_freed_by_tag};
+use motoko_rts::types::*;
+
+pub fn test() {
+    println!("  Testing blob deduplication...");
+
+    unsafe {
+        test_young_collection();
+        test_dedup();
+    }
+}
+
+/// Object 0 is a root and points to objects 1 to 4. Objects 1 and 3 are blobs with the same
+/// contents, object 2 is a blob with different contents, and object 4 is an array pointing to
+/// object 3. Object 5 is a dead blob with the same contents as object 1.
+unsafe fn make_heap(gc: GC) -> MotokoHeap {
+    let heap = MotokoHeap::new(
+        &[
+            (0, vec![1, 2, 3, 4]),
+            (1, vec![]),
+            (2, vec![]),
+            (3, vec![]),
+            (4, vec![3]),
+            (5, vec![]),
+        ],
+        &[0],
+        &[],
+        gc,
+    );
+
+    // Objects without fields are arrays of one word, same size as a blob of 4 bytes
+    make_blob(&heap, 1, b"abcd");
+    make_blob(&heap, 2, b"abce");
+    make_blob(&heap, 3, b"abcd");
+    make_blob(&heap, 5, b"abcd");
+
+    heap
+}
+
+unsafe fn make_blob(heap: &MotokoHeap, idx: u32, contents: &[u8; 4]) {
+    let blob = find_object(heap, idx) as *mut Blob;
+    (*blob).header.tag = TAG_BLOB;
+    (*blob).len = Bytes(4);
+    core::ptr::copy_nonoverlapping(contents.as_ptr(), blob.payload_addr(), 4);
+}
+
+/// Young generation collections don't deduplicate, the request is kept for the next full
+/// collection
+unsafe fn test_young_collection() {
+    let heap = make_heap(GC::Generational);
+
+    request_blob_dedup();
+    GC::Generational.run(heap.clone());
+
+    assert!(dedup_requested());
+    assert_eq!(last().bytes_deduplicated, Bytes(0));
+
+    let obj_0 = find_object(&heap, 0);
+    assert_ne!(obj_0.get(1).get_ptr(), obj_0.get(3).get_ptr());
+
+    // Reset the barrier so that it doesn't record locations in other tests
+    YOUNG_GENERATION_START = 0;
+    remembered_set::clear();
+}
+
+unsafe fn test_dedup() {
+    let heap = make_heap(GC::MarkCompact);
+
+    let blob_size = (size_of::<Blob>() + Words(1)).to_bytes();
+
+    set_gc_stats_freed_by_tag(true);
+    request_blob_dedup();
+    GC::MarkCompact.run(heap.clone());
+    set_gc_stats_freed_by_tag(false);
+
+    assert!(!dedup_requested());
+
+    let stats = last();
+    assert_eq!(stats.bytes_deduplicated, blob_size);
+    // The duplicate and the dead blob
+    assert_eq!(stats.bytes_freed(TAG_BLOB), blob_size + blob_size);
+
+    assert_eq!(heap_objects(&heap), vec![0, 4]);
+
+    let obj_0 = find_object(&heap, 0);
+    let obj_4 = find_object(&heap, 4);
+    let blob_1 = obj_0.get(1);
+    assert_eq!(obj_0.get(3).get_ptr(), blob_1.get_ptr());
+    assert_eq!(obj_4.get(1).get_ptr(), blob_1.get_ptr());
+    assert_ne!(obj_0.get(2).get_ptr(), blob_1.get_ptr());
+
+    let blob_1 = blob_1.as_blob();
+    assert_eq!(blob_1.len(), Bytes(4));
+    assert_eq!(
+        core::slice::from_raw_parts(blob_1.payload_const(), 4),
+        b"abcd"
+    );
+
+    // Nothing to deduplicate without a request
+    GC::MarkCompact.run(heap.clone());
+    assert_eq!(last().bytes_deduplicated, Bytes(0));
+}
@@ -0,0 +1,99 @@
+//! Tests for young generation collections, with old generation objects pointing to young objects
+
+use super::heap::MotokoHeap;
+use super::utils::{alloc_object, find_object, heap_objects, GC};
+
+use motoko_rts::gc::generational::write_barrier::YOUNG_GENERATION_START;
+use motoko_rts::gc::generational::{generational_gc_internal, remembered_set, Strategy};
+use motoko_rts::gc::write_with_barrier;
+use motoko_rts::types::*;
+
+pub fn test() {
+    println!("  Testing young generation collections...");
+
+    unsafe {
+        test_remembered_set();
+
+        // Reset the barrier so that it doesn't record locations in other tests
+        YOUNG_GENERATION_START = 0;
+        remembered_set::clear();
+    }
+}
+
+unsafe fn test_remembered_set() {
+    // Object 0 is a root pointing to object 1, object 2 is garbage
+    let mut heap = MotokoHeap::new(
+        &[(0, vec![1]), (1, vec![]), (2, vec![])],
+        &[0],
+        &[],
+        GC::Generational,
+    );
+
+    run(&mut heap, Strategy::Young);
+    assert_eq!(heap_objects(&heap), vec![0, 1]);
+
+    // Allocate two young objects, one of them will be referenced by an old object
+    let young_obj = alloc_object(&mut heap, 3);
+    alloc_object(&mut heap, 4);
+
+    let old_obj = find_object(&heap, 0);
+    let field_addr = old_obj.payload_addr().add(1);
+
+    // Storing scalars and pointers to the old generation should not be recorded
+    write_with_barrier(&mut heap, field_addr, Value::from_scalar(123));
+    write_with_barrier(&mut heap, field_addr, Value::from_ptr(old_obj as usize));
+    assert_eq!(remembered_set::len(), 0);
+
+    // Storing a pointer to the young generation should be recorded once
+    write_with_barrier(&mut heap, field_addr, young_obj);
+    write_with_barrier(&mut heap, field_addr, young_obj);
+    assert_eq!(remembered_set::len(), 1);
+    assert!(remembered_set::contains(field_addr));
+
+    // Object 1 is now garbage in the old generation, which is not collected by a young generation
+    // collection. Object 3 is only reachable via the remembered set.
+    run(&mut heap, Strategy::Young);
+    assert_eq!(heap_objects(&heap), vec![0, 1, 3]);
+    assert_eq!(remembered_set::len(), 0);
+
+    let old_obj = find_object(&heap, 0);
+    let young_obj = find_object(&heap, 3);
+    assert_eq!(old_obj.get(1).get_ptr(), young_obj as usize);
+
+    // A full collection reclaims the old generation garbage
+    run(&mut heap, Strategy::Full);
+    assert_eq!(heap_objects(&heap), vec![0, 3]);
+
+    let old_obj = find_object(&heap, 0);
+    let young_obj = find_object(&heap, 3);
+    assert_eq!(old_obj.get(1).get_ptr(), young_obj as usize);
+}
+
+unsafe fn run(heap: &mut MotokoHeap, strategy: Strategy) {
+    let heap_base = heap.heap_base_address() as u32;
+    let last_hp = heap.last_heap_ptr_address() as u32;
+    let static_roots = Value::from_ptr(heap.static_root_array_address());
+    let continuation_table_ptr_address = heap.continuation_table_ptr_address() as *mut Value;
+
+    let heap_1 = heap.clone();
+    let heap_2 = heap.clone();
+
+    generational_gc_internal(
+        heap,
+        heap_base,
+        last_hp,
+        strategy,
+        // get_hp
+        || heap_1.heap_ptr_address(),
+        // set_hp
+        move |hp| heap_2.set_heap_ptr_address(hp as usize),
+        static_roots,
+        continuation_table_ptr_address,
+        // note_live_size
+        |_live_size| {},
+        // note_reclaimed
+        |_reclaimed| {},
+    );
+
+    heap.set_last_heap_ptr_address(heap.heap_ptr_address());
+}
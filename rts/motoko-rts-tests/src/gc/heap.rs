@@ -2,8 +2,10 @@ use super::utils::{
     make_pointer, make_scalar, write_word, ObjectIdx, GC, MAX_MARK_STACK_SIZE, WORD_SIZE,
 };
 
+use motoko_rts::gc::generational::remembered_set::INIT_CAPACITY;
 use motoko_rts::gc::mark_compact::mark_stack::INIT_STACK_SIZE;
-use motoko_rts::memory::Memory;
+use motoko_rts::gc::mark_sweep::free_list;
+use motoko_rts::memory::{Context, Memory, ScratchRegion};
 use motoko_rts::types::*;
 
 use std::cell::{Ref, RefCell};
@@ -23,6 +25,19 @@ impl Memory for MotokoHeap {
     unsafe fn alloc_words(&mut self, n: Words<u32>) -> Value {
         self.inner.borrow_mut().alloc_words(n)
     }
+
+    unsafe fn context(&mut self) -> &mut Context {
+        &mut (*self.inner.as_ptr()).context
+    }
+
+    unsafe fn begin_scratch(&mut self) -> ScratchRegion {
+        ScratchRegion(self.heap_ptr_address())
+    }
+
+    unsafe fn release_scratch(&mut self, region: ScratchRegion) {
+        assert!(region.0 <= self.heap_ptr_address());
+        self.set_heap_ptr_address(region.0);
+    }
 }
 
 impl MotokoHeap {
@@ -38,6 +53,10 @@ impl MotokoHeap {
         continuation_table: &[ObjectIdx],
         gc: GC,
     ) -> MotokoHeap {
+        // The free lists are global, and can still hold the free space of the heap of an earlier
+        // test
+        unsafe { free_list::clear() };
+
         MotokoHeap {
             inner: Rc::new(RefCell::new(MotokoHeapInner::new(
                 map,
@@ -69,6 +88,17 @@ impl MotokoHeap {
         self.inner.borrow_mut().set_heap_ptr_address(address)
     }
 
+    /// Get the heap pointer after the last GC (i.e. start of the young generation), as address in
+    /// the current process.
+    pub fn last_heap_ptr_address(&self) -> usize {
+        self.inner.borrow().last_heap_ptr_address()
+    }
+
+    /// Update the heap pointer after the last GC, given as an address in the current process.
+    pub fn set_last_heap_ptr_address(&self, address: usize) {
+        self.inner.borrow_mut().set_last_heap_ptr_address(address)
+    }
+
     /// Get the beginning of dynamic heap, as an address in the current process
     pub fn heap_base_address(&self) -> usize {
         self.inner.borrow().heap_base_address()
@@ -119,6 +149,10 @@ struct MotokoHeapInner {
     /// Where the dynamic heap ends, i.e. the heap pointer
     heap_ptr_offset: usize,
 
+    /// Where the dynamic heap ended after the last GC. Objects between this and `heap_ptr_offset`
+    /// are in the young generation.
+    last_heap_ptr_offset: usize,
+
     /// Offset of the static root array: an array of pointers below `heap_base`
     static_root_array_offset: usize,
 
@@ -127,6 +161,9 @@ struct MotokoHeapInner {
     /// Reminder: this location is in static heap and will have pointer to an array in dynamic
     /// heap.
     continuation_table_ptr_offset: usize,
+
+    /// RTS state of the heap, see `Memory::context`
+    context: Context,
 }
 
 impl MotokoHeapInner {
@@ -153,6 +190,16 @@ impl MotokoHeapInner {
         self.heap_ptr_offset = self.address_to_offset(address);
     }
 
+    /// Get the heap pointer after the last GC in the process's address space
+    fn last_heap_ptr_address(&self) -> usize {
+        self.offset_to_address(self.last_heap_ptr_offset)
+    }
+
+    /// Set heap pointer after the last GC
+    fn set_last_heap_ptr_address(&mut self, address: usize) {
+        self.last_heap_ptr_offset = self.address_to_offset(address);
+    }
+
     /// Get static root array address in the process's address space
     fn static_root_array_address(&self) -> usize {
         self.offset_to_address(self.static_root_array_offset)
@@ -211,7 +258,9 @@ impl MotokoHeapInner {
         // MarkCompact assumes that the dynamic heap starts at a 32-byte multiple
         let realign = match gc {
             GC::Copying => 0,
-            GC::MarkCompact => (32 - (heap.as_ptr() as usize + static_heap_size_bytes) % 32) % 32,
+            GC::MarkCompact | GC::Generational | GC::Incremental | GC::MarkSweep => {
+                (32 - (heap.as_ptr() as usize + static_heap_size_bytes) % 32) % 32
+            }
         };
         assert_eq!(realign % 4, 0);
 
@@ -236,8 +285,10 @@ impl MotokoHeapInner {
             heap: heap.into_boxed_slice(),
             heap_base_offset: static_heap_size_bytes + realign,
             heap_ptr_offset: total_heap_size_bytes + realign,
+            last_heap_ptr_offset: static_heap_size_bytes + realign,
             static_root_array_offset: realign,
             continuation_table_ptr_offset: continuation_table_ptr_offset + realign,
+            context: Context::new(),
         }
     }
 
@@ -281,28 +332,68 @@ fn heap_size_for_gc(
             total_heap_size_bytes + to_space_bytes
         }
         GC::MarkCompact => {
-            let bitmap_size_bytes = {
-                let dynamic_heap_bytes = Bytes(dynamic_heap_size_bytes as u32);
-                // `...to_words().to_bytes()` below effectively rounds up heap size to word size
-                // then gets the bytes
-                let dynamic_heap_words = dynamic_heap_bytes.to_words();
-                let mark_bit_bytes = dynamic_heap_words.to_bytes();
-
-                // The bitmap implementation rounds up to 64-bits to be able to read as many
-                // bits as possible in one instruction and potentially skip 64 words in the
-                // heap with single 64-bit comparison
-                (((mark_bit_bytes.as_u32() + 7) / 8) * 8) + size_of::<Blob>().to_bytes().as_u32()
-            };
-            // In the worst case the entire heap will be pushed to the mark stack, but in tests
-            // we limit the size
-            let mark_stack_words = n_objects.clamp(INIT_STACK_SIZE.as_usize(), MAX_MARK_STACK_SIZE)
-                + size_of::<Blob>().as_usize();
-
-            total_heap_size_bytes + bitmap_size_bytes as usize + (mark_stack_words * WORD_SIZE)
+            mark_compact_heap_size(static_heap_size_bytes, dynamic_heap_size_bytes, n_objects)
+                + dedup_table_size(n_objects)
+        }
+        GC::MarkSweep => {
+            mark_compact_heap_size(static_heap_size_bytes, dynamic_heap_size_bytes, n_objects)
+        }
+        GC::Generational => {
+            // Allow allocating young objects as large as the initial dynamic heap, and a
+            // remembered set
+            let young_generation_bytes = dynamic_heap_size_bytes;
+            let remembered_set_bytes = (size_of::<Blob>() + Words(INIT_CAPACITY))
+                .to_bytes()
+                .as_usize();
+            mark_compact_heap_size(static_heap_size_bytes, dynamic_heap_size_bytes, n_objects)
+                + young_generation_bytes
+                + remembered_set_bytes
+                + dedup_table_size(n_objects)
+        }
+        GC::Incremental => {
+            // Allow allocating as much as the initial dynamic heap during a collection cycle
+            let allocation_bytes = dynamic_heap_size_bytes;
+            mark_compact_heap_size(static_heap_size_bytes, dynamic_heap_size_bytes, n_objects)
+                + allocation_bytes
         }
     }
 }
 
+/// Size of the hash table of the blob deduplication pass of the mark-compact GC, when all objects
+/// are blobs: at least two slots of two words per blob.
+fn dedup_table_size(n_objects: usize) -> usize {
+    (size_of::<Blob>() + Words(4 * n_objects.next_power_of_two() as u32))
+        .to_bytes()
+        .as_usize()
+}
+
+/// Heap size for the mark-compact GC: the heap, the bitmap, and the mark stack.
+fn mark_compact_heap_size(
+    static_heap_size_bytes: usize,
+    dynamic_heap_size_bytes: usize,
+    n_objects: usize,
+) -> usize {
+    let total_heap_size_bytes = static_heap_size_bytes + dynamic_heap_size_bytes;
+    let bitmap_size_bytes = {
+        let dynamic_heap_bytes = Bytes(dynamic_heap_size_bytes as u32);
+        // `...to_words().to_bytes()` below effectively rounds up heap size to word size
+        // then gets the bytes
+        let dynamic_heap_words = dynamic_heap_bytes.to_words();
+        let mark_bit_bytes = dynamic_heap_words.to_bytes();
+
+        // The bitmap implementation rounds up to 64-bits to be able to read as many
+        // bits as possible in one instruction and potentially skip 64 words in the
+        // heap with single 64-bit comparison
+        (((mark_bit_bytes.as_u32() + 7) / 8) * 8) + size_of::<Blob>().to_bytes().as_u32()
+    };
+    // In the worst case the entire heap will be pushed to the mark stack, but in tests
+    // we limit the size
+    let mark_stack_words = n_objects.clamp(INIT_STACK_SIZE.as_usize(), MAX_MARK_STACK_SIZE)
+        + size_of::<Blob>().as_usize();
+
+    total_heap_size_bytes + bitmap_size_bytes as usize + (mark_stack_words * WORD_SIZE)
+}
+
 /// Given a heap description (as a map from objects to objects), and the dynamic part of the heap
 /// (as an array), initialize the dynamic heap with objects.
 ///
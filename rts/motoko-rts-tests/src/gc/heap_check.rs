@@ -0,0 +1,162 @@
+//! Tests for the heap integrity checker
+
+use super::heap::MotokoHeap;
+use super::utils::{find_object, GC, WORD_SIZE};
+use crate::memory::TestMemory;
+
+use motoko_rts::heap_check::{check_heap_internal, HeapError, HeapReport};
+use motoko_rts::memory::{alloc_array, alloc_blob, Memory};
+use motoko_rts::types::*;
+
+pub fn test() {
+    println!("  Testing heap checker...");
+
+    unsafe {
+        // Valid heap, object 3 is garbage
+        let mut heap = new_heap();
+        assert_eq!(
+            check(&mut heap),
+            Ok(HeapReport {
+                objects: 5,  // objects 0 to 3, and the continuation table
+                pointers: 6, // root, continuation table, and the object fields
+            })
+        );
+
+        // Pointer to the middle of an object
+        let mut heap = new_heap();
+        let obj_0_field = find_object(&heap, 0).payload_addr().add(1);
+        let obj_1 = find_object(&heap, 1) as u32;
+        *obj_0_field = Value::from_ptr(obj_1 as usize + 4);
+        assert_eq!(
+            check(&mut heap),
+            Err(HeapError::PointerNotToObject {
+                field: obj_0_field as u32,
+                ptr: obj_1 + 4,
+            })
+        );
+
+        // Invalid tag
+        let mut heap = new_heap();
+        let obj_2 = find_object(&heap, 2);
+        (*obj_2).header.tag = 0;
+        assert_eq!(
+            check(&mut heap),
+            Err(HeapError::InvalidTag {
+                obj: obj_2 as u32,
+                tag: 0,
+            })
+        );
+
+        // Forwarding pointer left in the heap
+        let mut heap = new_heap();
+        let obj_2 = find_object(&heap, 2);
+        (*obj_2).header.tag = TAG_FWD_PTR;
+        assert_eq!(
+            check(&mut heap),
+            Err(HeapError::ForwardingPointer { obj: obj_2 as u32 })
+        );
+
+        // Object larger than the heap
+        let mut heap = new_heap();
+        let obj_3 = find_object(&heap, 3);
+        (*obj_3).len = 1000;
+        assert_eq!(
+            check(&mut heap),
+            Err(HeapError::ObjectPastHeapEnd {
+                obj: obj_3 as u32,
+                size: 1002,
+            })
+        );
+
+        // Object size overflowing 32 bits
+        let mut heap = new_heap();
+        let obj_3 = find_object(&heap, 3);
+        (*obj_3).len = u32::MAX;
+        assert_eq!(
+            check(&mut heap),
+            Err(HeapError::ObjectPastHeapEnd {
+                obj: obj_3 as u32,
+                size: 0x1_0000_0001,
+            })
+        );
+
+        test_aligned_heap_start();
+    }
+}
+
+/// With the non-copying GCs the dynamic heap starts at the heap base aligned to 32 bytes, and the
+/// words between the heap base and the heap start are not objects
+unsafe fn test_aligned_heap_start() {
+    let mut mem = TestMemory::new(Words(1024));
+
+    // Static heap: the static roots and the continuation table location
+    let static_roots = alloc_array(&mut mem, 0);
+    let continuation_table_loc = mem.alloc_words(Words(1)).get_ptr() as *mut Value;
+
+    // Leave a gap before the heap start
+    if mem.heap_pointer() % 32 == 0 {
+        mem.alloc_words(Words(1));
+    }
+    let heap_base = mem.heap_pointer();
+    let heap_start = (heap_base + 31) / 32 * 32;
+    mem.alloc_words(Words(((heap_start - heap_base) / WORD_SIZE) as u32));
+
+    let blob = alloc_blob(&mut mem, Bytes(10));
+    let array = alloc_array(&mut mem, 1);
+    array.as_array().set(0, blob);
+    *continuation_table_loc = array;
+    let heap_end = mem.heap_pointer();
+
+    assert_eq!(
+        check_heap_internal(
+            &mut mem,
+            heap_start as u32,
+            heap_end as u32,
+            static_roots,
+            continuation_table_loc,
+        ),
+        Ok(HeapReport {
+            objects: 2,
+            pointers: 2,
+        })
+    );
+
+    // The gap is zeroed, which is not a valid object header
+    assert_eq!(
+        check_heap_internal(
+            &mut mem,
+            heap_base as u32,
+            heap_end as u32,
+            static_roots,
+            continuation_table_loc,
+        ),
+        Err(HeapError::InvalidTag {
+            obj: heap_base as u32,
+            tag: 0,
+        })
+    );
+}
+
+fn new_heap() -> MotokoHeap {
+    MotokoHeap::new(
+        &[(0, vec![1]), (1, vec![2]), (2, vec![]), (3, vec![0, 1])],
+        &[0],
+        &[],
+        GC::MarkCompact,
+    )
+}
+
+unsafe fn check(heap: &mut MotokoHeap) -> Result<HeapReport, HeapError> {
+    let heap_base = heap.heap_base_address() as u32;
+    let heap_end = heap.heap_ptr_address() as u32;
+    let static_roots = Value::from_ptr(heap.static_root_array_address());
+    let continuation_table_ptr_address = heap.continuation_table_ptr_address() as *mut Value;
+
+    check_heap_internal(
+        heap,
+        heap_base,
+        heap_end,
+        static_roots,
+        continuation_table_ptr_address,
+    )
+}
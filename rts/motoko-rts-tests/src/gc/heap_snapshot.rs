@@ -0,0 +1,81 @@
+//! Tests for heap snapshots
+
+use super::heap::MotokoHeap;
+use super::utils::{find_object, GC};
+
+use motoko_rts::heap_snapshot::{
+    heap_snapshot_internal, ROOT_CONTINUATION_TABLE, ROOT_STATIC, SNAPSHOT_MAGIC, SNAPSHOT_VERSION,
+};
+use motoko_rts::types::*;
+
+pub fn test() {
+    println!("  Testing heap snapshots...");
+
+    // Object 0 is a root, object 3 is garbage but still in the snapshot
+    let mut heap = MotokoHeap::new(
+        &[(0, vec![1, 2]), (1, vec![]), (2, vec![1]), (3, vec![0])],
+        &[0],
+        &[1],
+        GC::MarkCompact,
+    );
+
+    let heap_base = heap.heap_base_address() as u32;
+    let heap_end = heap.heap_ptr_address() as u32;
+    let static_roots = Value::from_ptr(heap.static_root_array_address());
+    let continuation_table_ptr_address = heap.continuation_table_ptr_address() as *mut Value;
+
+    unsafe {
+        let snapshot = heap_snapshot_internal(
+            &mut heap,
+            heap_base,
+            heap_end,
+            static_roots,
+            continuation_table_ptr_address,
+        );
+
+        let snapshot = snapshot.as_blob();
+        let words = std::slice::from_raw_parts(
+            snapshot.payload_const() as *const u32,
+            snapshot.len().as_usize() / 4,
+        );
+
+        let obj = |idx| find_object(&heap, idx) as u32;
+        let continuation_table = (*continuation_table_ptr_address).get_ptr() as u32;
+
+        assert_eq!(
+            &words[0..6],
+            &[SNAPSHOT_MAGIC, SNAPSHOT_VERSION, heap_base, heap_end, 2, 5]
+        );
+
+        // Roots
+        assert_eq!(&words[6..9], &[ROOT_STATIC, 0, obj(0)]);
+        assert_eq!(
+            &words[9..12],
+            &[ROOT_CONTINUATION_TABLE, 0, continuation_table]
+        );
+
+        // Objects, in heap order. Field 2 of an array is the first element after the object index.
+        let mut expected: Vec<(u32, Vec<u32>)> = vec![
+            (obj(0), vec![3, obj(1), 4, obj(2)]),
+            (obj(1), vec![]),
+            (obj(2), vec![3, obj(1)]),
+            (obj(3), vec![3, obj(0)]),
+            (continuation_table, vec![2, obj(1)]),
+        ];
+        expected.sort_by_key(|(addr, _)| *addr);
+
+        let mut offset = 12;
+        for (addr, pointers) in expected {
+            let size = (*(addr as *mut Array)).len * 4 + 8;
+            assert_eq!(
+                &words[offset..offset + 4],
+                &[addr, TAG_ARRAY, size, pointers.len() as u32 / 2]
+            );
+            offset += 4;
+            assert_eq!(&words[offset..offset + pointers.len()], pointers.as_slice());
+            offset += pointers.len();
+        }
+
+        assert_eq!(offset, words.len());
+    }
+}
@@ -0,0 +1,122 @@
+//! Tests for the incremental GC, with the mutator running between the increments
+
+use super::heap::MotokoHeap;
+use super::utils::{alloc_object, find_object, heap_objects, GC};
+
+use motoko_rts::gc::incremental::{incremental_gc_internal, phase, Phase, UNLIMITED_BUDGET};
+use motoko_rts::gc::write_with_barrier;
+use motoko_rts::types::*;
+use motoko_rts::weak_ref::{weak_ref_get, weak_ref_is_live, weak_ref_new};
+
+pub fn test() {
+    println!("  Testing incremental collections...");
+
+    unsafe {
+        test_write_barrier();
+        test_weak_ref_read_barrier();
+    }
+}
+
+unsafe fn test_write_barrier() {
+    // Object 0 is a root, 0 -> 1 -> 2, object 3 is garbage
+    let mut heap = MotokoHeap::new(
+        &[(0, vec![1]), (1, vec![2]), (2, vec![]), (3, vec![])],
+        &[0],
+        &[],
+        GC::Incremental,
+    );
+
+    // Starts the cycle and marks the roots, but doesn't finish marking
+    assert!(!run(&mut heap, 1));
+    assert_eq!(phase(), Phase::Mark);
+
+    // Objects allocated during marking are live in this cycle
+    let new_obj = alloc_object(&mut heap, 4);
+    let new_obj_field = new_obj.as_array().payload_addr().add(1);
+
+    // Move the pointer to object 2 from object 1 to the new object. Object 2 is not marked yet,
+    // the barrier needs to mark it when we overwrite the field of object 1.
+    let obj_1_field = find_object(&heap, 1).payload_addr().add(1);
+    *new_obj_field = *obj_1_field;
+    write_with_barrier(&mut heap, obj_1_field, Value::from_scalar(0));
+
+    // Make object 1 unreachable. It's already marked, so it will be collected in the next cycle.
+    let obj_0_field = find_object(&heap, 0).payload_addr().add(1);
+    write_with_barrier(&mut heap, obj_0_field, new_obj);
+
+    assert!(run(&mut heap, UNLIMITED_BUDGET));
+    assert_eq!(phase(), Phase::Idle);
+    assert_eq!(heap_objects(&heap), vec![0, 1, 2, 4]);
+    check_field(&heap, 0, 4);
+    check_field(&heap, 4, 2);
+
+    // A new cycle collects object 1
+    assert!(run(&mut heap, UNLIMITED_BUDGET));
+    assert_eq!(heap_objects(&heap), vec![0, 2, 4]);
+    check_field(&heap, 0, 4);
+    check_field(&heap, 4, 2);
+}
+
+unsafe fn test_weak_ref_read_barrier() {
+    // Object 0 is a root and points to object 1 only with a weak reference
+    let mut heap = MotokoHeap::new(&[(0, vec![1, 1]), (1, vec![])], &[0], &[], GC::Incremental);
+
+    let obj_1 = Value::from_ptr(find_object(&heap, 1) as usize);
+    let weak = weak_ref_new(&mut heap, obj_1);
+    let obj_0 = find_object(&heap, 0);
+    obj_0.set(1, weak);
+    obj_0.set(2, Value::from_scalar(0));
+
+    assert!(!run(&mut heap, 1));
+    assert_eq!(phase(), Phase::Mark);
+
+    // Store the target in an object allocated during marking, which is not scanned. The read
+    // barrier needs to mark the target.
+    let new_obj = alloc_object(&mut heap, 2);
+    *new_obj.as_array().payload_addr().add(1) = weak_ref_get(&mut heap, weak);
+    write_with_barrier(&mut heap, obj_0.payload_addr().add(2), new_obj);
+
+    assert!(run(&mut heap, UNLIMITED_BUDGET));
+    assert_eq!(heap_objects(&heap), vec![0, 1, 2]);
+    check_field(&heap, 2, 1);
+
+    let weak = find_object(&heap, 0).get(1);
+    assert!(weak_ref_is_live(weak));
+    assert_eq!(
+        weak_ref_get(&mut heap, weak).get_ptr(),
+        find_object(&heap, 1) as usize
+    );
+}
+
+/// Runs an increment with the given budget. Returns whether the collection cycle is finished.
+unsafe fn run(heap: &mut MotokoHeap, budget: u32) -> bool {
+    let heap_base = heap.heap_base_address() as u32;
+    let static_roots = Value::from_ptr(heap.static_root_array_address());
+    let continuation_table_ptr_address = heap.continuation_table_ptr_address() as *mut Value;
+
+    let heap_1 = heap.clone();
+    let heap_2 = heap.clone();
+
+    incremental_gc_internal(
+        heap,
+        heap_base,
+        budget,
+        // get_hp
+        || heap_1.heap_ptr_address(),
+        // set_hp
+        move |hp| heap_2.set_heap_ptr_address(hp as usize),
+        static_roots,
+        continuation_table_ptr_address,
+        // note_live_size
+        |_live_size| {},
+        // note_reclaimed
+        |_reclaimed| {},
+    )
+}
+
+/// Checks that the pointer field of object `idx` points to object `pointee_idx`
+unsafe fn check_field(heap: &MotokoHeap, idx: u32, pointee_idx: u32) {
+    let obj = find_object(heap, idx);
+    let pointee = find_object(heap, pointee_idx);
+    assert_eq!(obj.get(1).get_ptr(), pointee as usize);
+}
@@ -0,0 +1,217 @@
+//! Tests for the large object space: the collectors don't move large objects, and the space left
+//! before them is reused
+
+use super::heap::MotokoHeap;
+use super::utils::{find_object, heap_objects, GC};
+
+use motoko_rts::gc::generational::remembered_set;
+use motoko_rts::gc::generational::write_barrier::YOUNG_GENERATION_START;
+use motoko_rts::gc::large_object::LARGE_OBJECT_THRESHOLD;
+use motoko_rts::gc::mark_sweep::free_list::{self, alloc, list_len, size_class};
+use motoko_rts::types::*;
+use motoko_rts::weak_ref::{weak_ref_get, weak_ref_is_live, weak_ref_new};
+
+pub fn test() {
+    println!("  Testing large objects...");
+
+    unsafe {
+        for gc in &[GC::MarkCompact, GC::Generational, GC::Incremental] {
+            test_large_object(*gc);
+            test_dead_large_object(*gc);
+        }
+        test_copying_large_object();
+        test_copying_dead_large_object();
+
+        // Reset the barrier so that it doesn't record locations in other tests
+        YOUNG_GENERATION_START = 0;
+        remembered_set::clear();
+        free_list::clear();
+    }
+}
+
+unsafe fn test_large_object(gc: GC) {
+    // Object 0 is a root and points to the large object 2, which points to object 4. Objects 1
+    // and 3 are garbage. The array of a test object has the object index as the first element.
+    let large_len = LARGE_OBJECT_THRESHOLD.as_usize() - size_of::<Array>().as_usize() - 1;
+    let heap = MotokoHeap::new(
+        &[
+            (0, vec![2]),
+            (1, vec![]),
+            (2, vec![4; large_len]),
+            (3, vec![]),
+            (4, vec![]),
+        ],
+        &[0],
+        &[],
+        gc,
+    );
+
+    let large_object = find_object(&heap, 2) as usize;
+    let obj_1 = find_object(&heap, 1) as usize;
+
+    // Run twice to also test compacting with the free space left before the large object
+    for _ in 0..2 {
+        gc.run(heap.clone());
+
+        assert_eq!(heap_objects(&heap), vec![0, 2, 4], "{:?}", gc);
+
+        // The large object is not moved, the objects after it are compacted to its end
+        let obj_2 = find_object(&heap, 2);
+        assert_eq!(obj_2 as usize, large_object, "{:?}", gc);
+        assert_eq!(
+            find_object(&heap, 0).get(1).get_ptr(),
+            large_object,
+            "{:?}",
+            gc
+        );
+
+        let obj_4 = find_object(&heap, 4) as usize;
+        let large_object_size = (size_of::<Array>() + Words(obj_2.len())).to_bytes();
+        assert_eq!(
+            obj_4,
+            large_object + large_object_size.as_usize(),
+            "{:?}",
+            gc
+        );
+        assert_eq!(obj_2.get(1).get_ptr(), obj_4, "{:?}", gc);
+    }
+    // The space left by object 1 is reused, except by the generational GC
+    let gap_size = size_of::<Array>() + Words(1);
+    if matches!(gc, GC::Generational) {
+        assert_eq!(list_len(size_class(gap_size)), 0);
+    } else {
+        assert_eq!(list_len(size_class(gap_size)), 1, "{:?}", gc);
+        assert_eq!(alloc(gap_size), Some(obj_1 as u32), "{:?}", gc);
+    }
+}
+
+unsafe fn test_dead_large_object(gc: GC) {
+    // Object 0 is a root and points to the large object 2 and to object 4. Objects 1 and 3 are
+    // garbage.
+    let large_len = LARGE_OBJECT_THRESHOLD.as_usize() - size_of::<Array>().as_usize() - 1;
+    let heap = MotokoHeap::new(
+        &[
+            (0, vec![2, 4]),
+            (1, vec![]),
+            (2, vec![4; large_len]),
+            (3, vec![]),
+            (4, vec![]),
+        ],
+        &[0],
+        &[],
+        gc,
+    );
+
+    let obj_1 = find_object(&heap, 1) as usize;
+
+    gc.run(heap.clone());
+
+    assert_eq!(heap_objects(&heap), vec![0, 2, 4], "{:?}", gc);
+
+    // Drop the large object. The objects after it are compacted over it and over the free space
+    // before it.
+    find_object(&heap, 0).set(1, Value::from_scalar(0));
+
+    // The test runs young collections of the generational GC, make the young generation the whole
+    // heap so that the large object is collected
+    heap.set_last_heap_ptr_address(heap.heap_base_address());
+
+    gc.run(heap.clone());
+
+    assert_eq!(heap_objects(&heap), vec![0, 4], "{:?}", gc);
+    assert_eq!(find_object(&heap, 4) as usize, obj_1, "{:?}", gc);
+    assert_eq!(
+        list_len(size_class(size_of::<Array>() + Words(1))),
+        0,
+        "{:?}",
+        gc
+    );
+}
+
+unsafe fn test_copying_large_object() {
+    // Object 0 is a root and points to the large object 2, which points to object 4. Objects 1
+    // and 3 are garbage. The last field of object 0 is for a weak reference to the large object.
+    let large_len = LARGE_OBJECT_THRESHOLD.as_usize() - size_of::<Array>().as_usize() - 1;
+    let mut heap = MotokoHeap::new(
+        &[
+            (0, vec![2, 0]),
+            (1, vec![]),
+            (2, vec![4; large_len]),
+            (3, vec![]),
+            (4, vec![]),
+        ],
+        &[0],
+        &[],
+        GC::Copying,
+    );
+
+    let large_object = find_object(&heap, 2) as usize;
+    let weak_ref = weak_ref_new(&mut heap, Value::from_ptr(large_object));
+    find_object(&heap, 0).set(2, weak_ref);
+
+    let mut heap_ptr = 0;
+    for i in 0..6 {
+        GC::Copying.run(heap.clone());
+
+        // The large object is not moved, the other objects are evacuated around it
+        assert_eq!(find_object(&heap, 2) as usize, large_object);
+        let mut objects = heap_objects(&heap);
+        objects.sort();
+        assert_eq!(objects, vec![0, 2, 4]);
+
+        let obj_0 = find_object(&heap, 0);
+        let obj_4 = find_object(&heap, 4) as usize;
+        assert_eq!(obj_0.get(1).get_ptr(), large_object);
+        assert_eq!((large_object as *mut Array).get(1).get_ptr(), obj_4);
+
+        let weak_ref = obj_0.get(2);
+        assert!(weak_ref_is_live(weak_ref));
+        assert_eq!(weak_ref_get(&mut heap, weak_ref).get_ptr(), large_object);
+
+        // The objects are evacuated into the free space around the large object, so the heap
+        // doesn't grow after the second collection
+        if i == 1 {
+            heap_ptr = heap.heap_ptr_address();
+        } else if i > 1 {
+            assert!(heap.heap_ptr_address() <= heap_ptr);
+        }
+    }
+}
+
+unsafe fn test_copying_dead_large_object() {
+    // Object 0 is a root and points to the large object 2 and to object 4. Objects 1 and 3 are
+    // garbage.
+    let large_len = LARGE_OBJECT_THRESHOLD.as_usize() - size_of::<Array>().as_usize() - 1;
+    let heap = MotokoHeap::new(
+        &[
+            (0, vec![2, 4]),
+            (1, vec![]),
+            (2, vec![4; large_len]),
+            (3, vec![]),
+            (4, vec![]),
+        ],
+        &[0],
+        &[],
+        GC::Copying,
+    );
+
+    let large_object = find_object(&heap, 2) as usize;
+
+    GC::Copying.run(heap.clone());
+
+    assert_eq!(find_object(&heap, 2) as usize, large_object);
+
+    // Drop the large object. Its space is free after the next collection.
+    find_object(&heap, 0).set(1, Value::from_scalar(0));
+
+    for _ in 0..2 {
+        GC::Copying.run(heap.clone());
+
+        let mut objects = heap_objects(&heap);
+        objects.sort();
+        assert_eq!(objects, vec![0, 4]);
+    }
+
+    // The live objects fit into the space of the large object
+    assert!(heap.heap_ptr_address() < large_object + LARGE_OBJECT_THRESHOLD.to_bytes().as_usize());
+}
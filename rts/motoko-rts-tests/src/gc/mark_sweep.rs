@@ -0,0 +1,61 @@
+//! Tests for the free lists of the mark-sweep GC
+
+use super::heap::MotokoHeap;
+use super::utils::{find_object, heap_objects, GC};
+
+use motoko_rts::gc::mark_sweep::free_list::{alloc, list_len, size_class};
+use motoko_rts::types::*;
+
+pub fn test() {
+    println!("  Testing mark-sweep free lists...");
+
+    unsafe {
+        test_free_lists();
+    }
+}
+
+unsafe fn test_free_lists() {
+    // Object 0 is a root and points to object 3, objects 1 and 2 are garbage
+    let heap = MotokoHeap::new(
+        &[(0, vec![3]), (1, vec![]), (2, vec![]), (3, vec![])],
+        &[0],
+        &[],
+        GC::MarkSweep,
+    );
+
+    let heap_ptr = heap.heap_ptr_address();
+    let obj_1 = find_object(&heap, 1) as usize;
+
+    GC::MarkSweep.run(heap.clone());
+
+    // Objects 1 and 2 (3 words each) are merged into one free block. The heap pointer does not
+    // move as the continuation table is at the end of the heap.
+    assert_eq!(heap.heap_ptr_address(), heap_ptr);
+    assert_eq!(heap_objects(&heap), vec![0, 3]);
+    assert_eq!(list_len(size_class(Words(6))), 1);
+
+    // The block is too small
+    assert_eq!(alloc(Words(7)), None);
+
+    // Allocate an object with no pointers in the block, the rest of the block goes back to the
+    // free lists
+    let obj_4 = alloc(size_of::<Array>() + Words(1)).unwrap() as usize;
+    assert_eq!(obj_4, obj_1);
+    assert_eq!(list_len(size_class(Words(6))), 0);
+    assert_eq!(list_len(size_class(Words(3))), 1);
+
+    let array = obj_4 as *mut Array;
+    (*array).header.tag = TAG_ARRAY;
+    (*array).len = 1;
+    array.set(0, Value::from_scalar(4));
+
+    // Replace the pointer to object 3 with a pointer to the new object
+    find_object(&heap, 0).set(1, Value::from_ptr(obj_4));
+
+    GC::MarkSweep.run(heap.clone());
+
+    // Object 3 is merged with the rest of the first block
+    assert_eq!(heap_objects(&heap), vec![0, 4]);
+    assert_eq!(list_len(size_class(Words(3))), 0);
+    assert_eq!(list_len(size_class(Words(6))), 1);
+}
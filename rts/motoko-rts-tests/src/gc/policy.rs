@@ -0,0 +1,36 @@
+//! Tests for the GC scheduling policies
+
+use motoko_rts::gc::policy::{get_policy, set_gc_policy, set_policy, Policy, DEFAULT_POLICY};
+use motoko_rts::types::Bytes;
+
+pub fn test() {
+    println!("  Testing scheduling policies...");
+
+    let max_live = Bytes(u64::from(u32::MAX));
+
+    let growth = Policy::GrowthFactor(150);
+    assert!(!growth.should_collect(1000, 1499, max_live));
+    assert!(growth.should_collect(1000, 1500, max_live));
+
+    let budget = Policy::AllocationBudget(100);
+    assert!(!budget.should_collect(1000, 1099, max_live));
+    assert!(budget.should_collect(1000, 1100, max_live));
+
+    assert!(Policy::Always.should_collect(1000, 1000, max_live));
+
+    // All policies collect when the heap gets close to the maximum live data
+    assert!(Policy::GrowthFactor(1000).should_collect(1000, 1500, Bytes(2000)));
+    assert!(Policy::AllocationBudget(u32::MAX).should_collect(1000, 1500, Bytes(2000)));
+
+    unsafe {
+        assert_eq!(get_policy(), DEFAULT_POLICY);
+
+        set_gc_policy(1, 4096);
+        assert_eq!(get_policy(), Policy::AllocationBudget(4096));
+
+        set_gc_policy(2, 0);
+        assert_eq!(get_policy(), Policy::Always);
+
+        set_policy(DEFAULT_POLICY);
+    }
+}
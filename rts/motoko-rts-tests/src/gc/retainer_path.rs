@@ -0,0 +1,97 @@
+//! Tests for retainer path queries
+
+use super::heap::MotokoHeap;
+use super::utils::{find_object, UnalignedHeap, GC};
+use crate::memory::TestMemory;
+
+use motoko_rts::memory::{alloc_array, alloc_blob};
+use motoko_rts::retainer_path::{retainer_path_internal, ROOT_CONTINUATION_TABLE, ROOT_STATIC};
+use motoko_rts::types::*;
+
+pub fn test() {
+    println!("  Testing retainer paths...");
+
+    // Object 3 is reachable from the root 0 in two ways, the shortest is 0 -> 4 -> 3. Object 5 is
+    // in the continuation table, object 6 is garbage.
+    let heap = MotokoHeap::new(
+        &[
+            (0, vec![1, 4]),
+            (1, vec![2]),
+            (2, vec![3]),
+            (3, vec![]),
+            (4, vec![3]),
+            (5, vec![]),
+            (6, vec![3]),
+        ],
+        &[0],
+        &[5],
+        GC::MarkCompact,
+    );
+
+    // Field 2 of an array is the first element, which is the object index. Pointers start at
+    // field 3. The continuation table has no object index.
+    assert_eq!(
+        path(&heap, 3),
+        vec![ROOT_STATIC, 0, TAG_ARRAY, 4, TAG_ARRAY, 3]
+    );
+    assert_eq!(
+        path(&heap, 5),
+        vec![ROOT_CONTINUATION_TABLE, 0, TAG_ARRAY, 2]
+    );
+    assert_eq!(path(&heap, 0), vec![ROOT_STATIC, 0]);
+    assert_eq!(path(&heap, 6), vec![]);
+
+    unsafe { test_unaligned_heap_base() };
+}
+
+/// Paths through objects between the unaligned heap base and the next 32-byte aligned address are
+/// found
+unsafe fn test_unaligned_heap_base() {
+    let mut mem = TestMemory::new(Words(1024));
+    let heap = UnalignedHeap::new(&mut mem);
+
+    // The array is before the first aligned address, the blob is only reachable from the array
+    let array = alloc_array(&mut mem, 1);
+    let blob = alloc_blob(&mut mem, Bytes(40));
+    array.as_array().set(0, blob);
+    (*heap.root).field = array;
+    let heap_end = mem.heap_pointer() as u32;
+
+    let path = retainer_path_internal(
+        &mut mem,
+        heap.heap_base,
+        heap_end,
+        heap.static_roots,
+        heap.continuation_table_loc,
+        blob,
+    )
+    .as_array();
+
+    let path: Vec<u32> = (0..path.len()).map(|i| path.get(i).get_scalar()).collect();
+    assert_eq!(path, vec![ROOT_STATIC, 0, TAG_ARRAY, 2]);
+}
+
+fn path(heap: &MotokoHeap, idx: u32) -> Vec<u32> {
+    let mut heap = heap.clone();
+
+    let heap_base = heap.heap_base_address() as u32;
+    let heap_end = heap.heap_ptr_address() as u32;
+    let static_roots = Value::from_ptr(heap.static_root_array_address());
+    let continuation_table_ptr_address = heap.continuation_table_ptr_address() as *mut Value;
+
+    unsafe {
+        let target = Value::from_ptr(find_object(&heap, idx) as usize);
+
+        let path = retainer_path_internal(
+            &mut heap,
+            heap_base,
+            heap_end,
+            static_roots,
+            continuation_table_ptr_address,
+            target,
+        )
+        .as_array();
+
+        (0..path.len()).map(|i| path.get(i).get_scalar()).collect()
+    }
+}
@@ -0,0 +1,82 @@
+//! Tests for the per-collection GC statistics
+
+use super::heap::MotokoHeap;
+use super::utils::{GC, GC_IMPLS};
+
+use motoko_rts::gc::stats::{last, set_gc_stats_freed_by_tag};
+use motoko_rts::types::*;
+
+pub fn test() {
+    println!("  Testing GC stats...");
+
+    unsafe { set_gc_stats_freed_by_tag(true) };
+
+    for gc in &GC_IMPLS {
+        test_stats(*gc);
+    }
+
+    unsafe { set_gc_stats_freed_by_tag(false) };
+
+    for gc in &GC_IMPLS {
+        test_freed_by_tag_disabled(*gc);
+    }
+}
+
+fn test_stats(gc: GC) {
+    // Object 0 is a root and points to object 2, objects 1 and 3 are garbage. Objects are arrays
+    // with the object index in the first field. The mark-sweep GC turns object 1 into free space.
+    let heap = MotokoHeap::new(
+        &[(0, vec![2]), (1, vec![]), (2, vec![]), (3, vec![])],
+        &[0],
+        &[],
+        gc,
+    );
+
+    let garbage_size = (size_of::<Array>() * 2 + Words(2)).to_bytes();
+
+    // Objects 0 and 2, and the empty continuation table
+    let live_size = (size_of::<Array>() * 3 + Words(3)).to_bytes();
+
+    gc.run(heap.clone());
+
+    let stats = unsafe { last() };
+
+    // Objects 0 and 2, and the continuation table
+    assert_eq!(stats.objects_marked, 3, "{:?}", gc);
+    assert_eq!(stats.bytes_freed(TAG_ARRAY), garbage_size, "{:?}", gc);
+
+    match gc {
+        GC::Copying => {
+            assert_eq!(stats.bitmap_size, Bytes(0));
+            assert_eq!(stats.mark_stack_high_water_mark, Bytes(0));
+        }
+        GC::MarkCompact | GC::Generational | GC::Incremental | GC::MarkSweep => {
+            assert!(stats.bitmap_size > Bytes(0), "{:?}", gc);
+            assert!(stats.mark_stack_high_water_mark > Bytes(0), "{:?}", gc);
+        }
+    }
+
+    // Nothing to free in the next collection. Free space left by the first collection is not
+    // counted again.
+    gc.run(heap);
+
+    let stats = unsafe { last() };
+    assert_eq!(stats.total_bytes_freed(), Bytes(0), "{:?}", gc);
+
+    // The copying GC moves all live objects, the compacting GCs don't need to move anything
+    if let GC::Copying = gc {
+        assert_eq!(stats.bytes_moved, live_size);
+    } else {
+        assert_eq!(stats.bytes_moved, Bytes(0), "{:?}", gc);
+    }
+}
+
+fn test_freed_by_tag_disabled(gc: GC) {
+    let heap = MotokoHeap::new(&[(0, vec![]), (1, vec![])], &[0], &[], gc);
+
+    gc.run(heap);
+
+    let stats = unsafe { last() };
+    assert_eq!(stats.objects_marked, 2, "{:?}", gc);
+    assert_eq!(stats.total_bytes_freed(), Bytes(0), "{:?}", gc);
+}
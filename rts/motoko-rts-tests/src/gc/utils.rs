@@ -1,3 +1,9 @@
+use super::heap::MotokoHeap;
+use crate::memory::TestMemory;
+
+use motoko_rts::memory::{alloc_array, Memory};
+use motoko_rts::types::*;
+
 use byteorder::{ReadBytesExt, WriteBytesExt, LE};
 
 /// A unique object index, used in heap descriptions.
@@ -18,9 +24,18 @@ pub const MAX_MARK_STACK_SIZE: usize = 100;
 pub enum GC {
     Copying,
     MarkCompact,
+    Generational,
+    Incremental,
+    MarkSweep,
 }
 
-pub static GC_IMPLS: [GC; 2] = [GC::Copying, GC::MarkCompact];
+pub static GC_IMPLS: [GC; 5] = [
+    GC::Copying,
+    GC::MarkCompact,
+    GC::Generational,
+    GC::Incremental,
+    GC::MarkSweep,
+];
 
 /// Read a little-endian (Wasm) word from given offset
 pub fn read_word(heap: &[u8], offset: usize) -> u32 {
@@ -54,3 +69,120 @@ pub fn make_pointer(addr: u32) -> u32 {
 pub fn unskew_pointer(skewed_ptr: u32) -> u32 {
     skewed_ptr.wrapping_add(1)
 }
+
+/// Allocate an object in the same format as the test heaps: an array with the object index as
+/// the first element, and one pointer field initialized to a scalar
+pub unsafe fn alloc_object(heap: &mut MotokoHeap, idx: ObjectIdx) -> Value {
+    let value = heap.alloc_words(size_of::<Array>() + Words(2));
+    let array = value.get_ptr() as *mut Array;
+    (*array).header.tag = TAG_ARRAY;
+    (*array).len = 2;
+    array.set(0, Value::from_scalar(idx));
+    array.set(1, Value::from_scalar(0));
+    value
+}
+
+/// Indices of the objects in the dynamic heap, in heap order. Skips the continuation table, free
+/// space, weak references, and blobs.
+pub unsafe fn heap_objects(heap: &MotokoHeap) -> Vec<ObjectIdx> {
+    let continuation_table = *(heap.continuation_table_ptr_address() as *const Value);
+
+    let mut objects = vec![];
+    let mut p = heap.heap_base_address();
+    while p < heap.heap_ptr_address() {
+        let array = p as *mut Array;
+        if !is_free_space(p) && !is_weak_ref(p) && !is_blob(p) {
+            assert_eq!((*array).header.tag, TAG_ARRAY);
+            if p != continuation_table.get_ptr() {
+                objects.push(get_scalar_value(array.get(0).get_raw()));
+            }
+        }
+        p += object_size(p).to_bytes().as_usize();
+    }
+    objects
+}
+
+/// Finds the object with the given index in the dynamic heap
+pub unsafe fn find_object(heap: &MotokoHeap, idx: ObjectIdx) -> *mut Array {
+    let mut p = heap.heap_base_address();
+    while p < heap.heap_ptr_address() {
+        let array = p as *mut Array;
+        if !is_free_space(p)
+            && !is_weak_ref(p)
+            && !is_blob(p)
+            && array.len() != 0
+            && array.get(0).get_raw() == Value::from_scalar(idx).get_raw()
+        {
+            return array;
+        }
+        p += object_size(p).to_bytes().as_usize();
+    }
+    panic!("Object {} not in heap", idx);
+}
+
+/// Whether the object at the given address is free space left by the mark-sweep GC
+unsafe fn is_free_space(p: usize) -> bool {
+    let tag = (*(p as *mut Obj)).tag;
+    tag == TAG_ONE_WORD_FILLER || tag == TAG_FREE_SPACE
+}
+
+/// Whether the object at the given address is a weak reference
+unsafe fn is_weak_ref(p: usize) -> bool {
+    (*(p as *mut Obj)).tag == TAG_WEAK_REF
+}
+
+/// Whether the object at the given address is a blob
+unsafe fn is_blob(p: usize) -> bool {
+    (*(p as *mut Obj)).tag == TAG_BLOB
+}
+
+/// Size of an object in a test heap: an array, free space, a weak reference, or a blob
+unsafe fn object_size(p: usize) -> Words<u32> {
+    match (*(p as *mut Obj)).tag {
+        TAG_ONE_WORD_FILLER => size_of::<OneWordFiller>(),
+        TAG_FREE_SPACE => (p as *mut FreeSpace).size(),
+        TAG_WEAK_REF => size_of::<WeakRef>(),
+        TAG_BLOB => size_of::<Blob>() + (p as *mut Blob).len().to_words(),
+        _ => size_of::<Array>() + Words((p as *mut Array).len()),
+    }
+}
+
+/// Static heap of a test heap in a `TestMemory`, where the dynamic heap starts at the unaligned
+/// heap base like with the copying GC
+pub struct UnalignedHeap {
+    pub static_roots: Value,
+    /// The only root, initially a scalar
+    pub root: *mut MutBox,
+    /// Initially a scalar
+    pub continuation_table_loc: *mut Value,
+    /// 4 bytes past a 32-byte aligned address
+    pub heap_base: u32,
+}
+
+impl UnalignedHeap {
+    pub unsafe fn new(mem: &mut TestMemory) -> UnalignedHeap {
+        let root = mem.alloc_words(size_of::<MutBox>()).get_ptr() as *mut MutBox;
+        (*root).header.tag = TAG_MUTBOX;
+        (*root).field = Value::from_scalar(0);
+
+        let static_roots = alloc_array(mem, 1);
+        static_roots
+            .as_array()
+            .set(0, Value::from_ptr(root as usize));
+
+        let continuation_table_loc = mem.alloc_words(Words(1)).get_ptr() as *mut Value;
+        *continuation_table_loc = Value::from_scalar(0);
+
+        // Unused static data up to the heap base
+        while mem.heap_pointer() % 32 != 4 {
+            mem.alloc_words(Words(1));
+        }
+
+        UnalignedHeap {
+            static_roots,
+            root,
+            continuation_table_loc,
+            heap_base: mem.heap_pointer() as u32,
+        }
+    }
+}
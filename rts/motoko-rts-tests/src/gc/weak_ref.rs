@@ -0,0 +1,98 @@
+//! Tests for weak references: the collectors clear the weak references whose targets died, and
+//! update the others when the targets are moved
+
+use super::heap::MotokoHeap;
+use super::utils::{alloc_object, find_object, heap_objects, GC, GC_IMPLS};
+
+use motoko_rts::gc::generational::remembered_set;
+use motoko_rts::gc::generational::write_barrier::YOUNG_GENERATION_START;
+use motoko_rts::types::*;
+use motoko_rts::weak_ref::{weak_ref_get, weak_ref_is_live, weak_ref_new, WEAK_REF_CLEARED};
+
+pub fn test() {
+    println!("  Testing weak references...");
+
+    unsafe {
+        for gc in &GC_IMPLS {
+            test_weak_refs(*gc);
+        }
+
+        // Reset the barrier so that it doesn't record locations in other tests
+        YOUNG_GENERATION_START = 0;
+        remembered_set::clear();
+    }
+}
+
+unsafe fn test_weak_refs(gc: GC) {
+    // Object 0 is a root. After updating its fields below, object 1 is garbage (large enough to
+    // leave space for the objects allocated below), object 2 is live, and object 3 is only
+    // pointed by a weak reference.
+    let mut heap = MotokoHeap::new(
+        &[
+            (0, vec![1, 2, 3, 0, 0]),
+            (1, vec![1; 20]),
+            (2, vec![]),
+            (3, vec![]),
+        ],
+        &[0],
+        &[],
+        gc,
+    );
+
+    let obj_0 = find_object(&heap, 0);
+
+    // A weak reference to a live object before it, and to an object that dies
+    let obj_2 = Value::from_ptr(find_object(&heap, 2) as usize);
+    let obj_3 = Value::from_ptr(find_object(&heap, 3) as usize);
+    let weak_bwd = weak_ref_new(&mut heap, obj_2);
+    let weak_dead = weak_ref_new(&mut heap, obj_3);
+
+    // A weak reference to a live object after it. The target of a new weak reference is always
+    // before it, so update the target after allocating the object.
+    let weak_fwd = weak_ref_new(&mut heap, obj_2);
+    let obj_4 = alloc_object(&mut heap, 4);
+    (*weak_fwd.as_weak_ref()).target = obj_4;
+
+    obj_0.set(1, weak_bwd);
+    obj_0.set(3, weak_dead);
+    obj_0.set(4, weak_fwd);
+    obj_0.set(5, obj_4);
+
+    // Run twice to also test weak references that were moved in the previous collection
+    for _ in 0..2 {
+        gc.run(heap.clone());
+
+        assert_eq!(heap_objects(&heap), vec![0, 2, 4], "{:?}", gc);
+
+        let obj_0 = find_object(&heap, 0);
+        let obj_2 = find_object(&heap, 2) as usize;
+        let obj_4 = find_object(&heap, 4) as usize;
+        assert_eq!(obj_0.get(2).get_ptr(), obj_2, "{:?}", gc);
+
+        let weak_bwd = obj_0.get(1);
+        assert!(weak_ref_is_live(weak_bwd), "{:?}", gc);
+        assert_eq!(
+            weak_ref_get(&mut heap, weak_bwd).get_ptr(),
+            obj_2,
+            "{:?}",
+            gc
+        );
+
+        let weak_dead = obj_0.get(3);
+        assert!(!weak_ref_is_live(weak_dead), "{:?}", gc);
+        assert!(
+            weak_ref_get(&mut heap, weak_dead) == WEAK_REF_CLEARED,
+            "{:?}",
+            gc
+        );
+
+        let weak_fwd = obj_0.get(4);
+        assert!(weak_ref_is_live(weak_fwd), "{:?}", gc);
+        assert_eq!(
+            weak_ref_get(&mut heap, weak_fwd).get_ptr(),
+            obj_4,
+            "{:?}",
+            gc
+        );
+    }
+}
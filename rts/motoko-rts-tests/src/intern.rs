@@ -0,0 +1,120 @@
+use crate::memory::TestMemory;
+
+use motoko_rts::intern::{intern, INIT_CAPACITY};
+use motoko_rts::text::text_of_ptr_size;
+use motoko_rts::types::*;
+use motoko_rts::weak_ref::WEAK_REF_CLEARED;
+
+pub unsafe fn test() {
+    println!("Testing blob interning ...");
+
+    let mut mem = TestMemory::new(Words(1024 * 1024));
+
+    test_dedup(&mut mem);
+    test_resize(&mut mem);
+    test_dead_entries(&mut mem);
+}
+
+unsafe fn test_dedup(mem: &mut TestMemory) {
+    println!("  Testing deduplication");
+
+    let mut table = Value::from_scalar(0);
+
+    let abc = blob_of_bytes(mem, b"abc");
+    assert_eq!(intern(mem, &mut table, abc).get_ptr(), abc.get_ptr());
+
+    let abc_copy = blob_of_bytes(mem, b"abc");
+    assert_eq!(intern(mem, &mut table, abc_copy).get_ptr(), abc.get_ptr());
+
+    let abd = blob_of_bytes(mem, b"abd");
+    assert_eq!(intern(mem, &mut table, abd).get_ptr(), abd.get_ptr());
+
+    let empty = blob_of_bytes(mem, b"");
+    assert_eq!(intern(mem, &mut table, empty).get_ptr(), empty.get_ptr());
+    let empty_copy = blob_of_bytes(mem, b"");
+    assert_eq!(
+        intern(mem, &mut table, empty_copy).get_ptr(),
+        empty.get_ptr()
+    );
+}
+
+unsafe fn test_resize(mem: &mut TestMemory) {
+    println!("  Testing resizing");
+
+    let mut table = Value::from_scalar(0);
+
+    let blobs: Vec<Value> = (0..1000u32)
+        .map(|i| {
+            let blob = blob_of_bytes(mem, &i.to_le_bytes());
+            assert_eq!(intern(mem, &mut table, blob).get_ptr(), blob.get_ptr());
+            blob
+        })
+        .collect();
+
+    assert!(capacity(table) > INIT_CAPACITY);
+
+    for (i, blob) in blobs.iter().enumerate() {
+        let copy = blob_of_bytes(mem, &(i as u32).to_le_bytes());
+        assert_eq!(intern(mem, &mut table, copy).get_ptr(), blob.get_ptr());
+    }
+}
+
+unsafe fn test_dead_entries(mem: &mut TestMemory) {
+    println!("  Testing entries of dead blobs");
+
+    let mut table = Value::from_scalar(0);
+
+    let abc = blob_of_bytes(mem, b"abc");
+    intern(mem, &mut table, abc);
+
+    // Clear the weak references, as the collectors do when the blobs die
+    clear_weak_refs(table);
+
+    // The slot of the dead blob is reused
+    let abc_copy = blob_of_bytes(mem, b"abc");
+    assert_eq!(
+        intern(mem, &mut table, abc_copy).get_ptr(),
+        abc_copy.get_ptr()
+    );
+    assert_eq!(n_used(table), 1);
+
+    // Slots of dead blobs are reused or dropped when resizing, so the table doesn't grow when the
+    // blobs die
+    for i in 0..1000u32 {
+        let blob = blob_of_bytes(mem, &i.to_le_bytes());
+        intern(mem, &mut table, blob);
+    }
+    let capacity_1000 = capacity(table);
+
+    clear_weak_refs(table);
+
+    for i in 1000..2000u32 {
+        let blob = blob_of_bytes(mem, &i.to_le_bytes());
+        intern(mem, &mut table, blob);
+    }
+    assert_eq!(capacity(table), capacity_1000);
+}
+
+unsafe fn blob_of_bytes(mem: &mut TestMemory, bytes: &[u8]) -> Value {
+    text_of_ptr_size(mem, bytes.as_ptr(), Bytes(bytes.len() as u32))
+}
+
+// The layout of the table, see `intern.rs`
+
+unsafe fn n_used(table: Value) -> u32 {
+    table.as_array().get(0).get_scalar()
+}
+
+unsafe fn capacity(table: Value) -> u32 {
+    (table.as_array().len() - 1) / 2
+}
+
+unsafe fn clear_weak_refs(table: Value) {
+    let array = table.as_array();
+    for slot in 0..capacity(table) {
+        let weak_ref = array.get(1 + 2 * slot);
+        if weak_ref.is_ptr() {
+            (*weak_ref.as_weak_ref()).target = WEAK_REF_CLEARED;
+        }
+    }
+}
@@ -1,16 +1,19 @@
 #![feature(map_first_last)]
 
+mod alloc_profile;
 mod bigint;
 mod bitmap;
 mod continuation_table;
 mod crc32;
 mod gc;
+mod intern;
 mod leb128;
 mod mark_stack;
 mod memory;
 mod principal_id;
 mod stream;
 mod text;
+mod unicode;
 mod utf8;
 
 use motoko_rts::types::Bytes;
@@ -22,16 +25,20 @@ fn main() {
     }
 
     unsafe {
+        alloc_profile::test();
         bigint::test();
         bitmap::test();
         continuation_table::test();
         crc32::test();
         gc::test();
+        intern::test();
         leb128::test();
         mark_stack::test();
+        memory::test();
         principal_id::test();
         stream::test();
         text::test();
+        unicode::test();
         utf8::test();
     }
 }
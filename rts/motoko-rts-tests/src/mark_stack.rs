@@ -1,8 +1,8 @@
 use crate::memory::TestMemory;
 
 use motoko_rts::gc::mark_compact::mark_stack::{
-    alloc_mark_stack, free_mark_stack, grow_stack, pop_mark_stack, push_mark_stack,
-    INIT_STACK_SIZE, STACK_BASE, STACK_PTR, STACK_TOP,
+    alloc_mark_stack, free_mark_stack, grow_stack, mark_stack_blob, pop_mark_stack,
+    push_mark_stack, INIT_STACK_SIZE,
 };
 use motoko_rts::memory::Memory;
 use motoko_rts::types::*;
@@ -14,6 +14,7 @@ pub unsafe fn test() {
 
     test_push_pop();
     test_grow_stack();
+    test_grow_stack_after_allocation();
 }
 
 fn test_push_pop() {
@@ -61,9 +62,9 @@ fn test_<M: Memory>(mem: &mut M, n_objs: u32) -> TestCaseResult {
         }
 
         for obj in objs.iter().copied().rev() {
-            let popped = pop_mark_stack();
+            let popped = pop_mark_stack(mem);
             if popped != Some((obj as usize, TAGS[(obj as usize) % TAGS.len()])) {
-                free_mark_stack();
+                free_mark_stack(mem);
                 return Err(TestCaseError::Fail(
                     format!(
                         "Unexpected object popped, expected={:?}, popped={:?}",
@@ -74,7 +75,7 @@ fn test_<M: Memory>(mem: &mut M, n_objs: u32) -> TestCaseResult {
             }
         }
 
-        free_mark_stack();
+        free_mark_stack(mem);
     }
 
     Ok(())
@@ -91,16 +92,62 @@ unsafe fn test_grow_stack() {
     alloc_mark_stack(&mut mem);
 
     let mut current_size = INIT_STACK_SIZE.as_usize();
-    assert_eq!(STACK_BASE.add(current_size), STACK_TOP);
-    assert_eq!(STACK_BASE, STACK_PTR);
+    let (base, top, ptr) = stack_pointers(&mut mem);
+    assert_eq!(base.add(current_size), top);
+    assert_eq!(base, ptr);
 
     grow_stack(&mut mem);
     current_size *= 2;
-    assert_eq!(STACK_BASE.add(current_size), STACK_TOP);
-    assert_eq!(STACK_BASE, STACK_PTR);
+    let (base, top, ptr) = stack_pointers(&mut mem);
+    assert_eq!(base.add(current_size), top);
+    assert_eq!(base, ptr);
 
     grow_stack(&mut mem);
     current_size *= 2;
-    assert_eq!(STACK_BASE.add(current_size), STACK_TOP);
-    assert_eq!(STACK_BASE, STACK_PTR);
+    let (base, top, ptr) = stack_pointers(&mut mem);
+    assert_eq!(base.add(current_size), top);
+    assert_eq!(base, ptr);
+}
+
+unsafe fn test_grow_stack_after_allocation() {
+    println!("  Testing grow_stack after allocation");
+
+    // The stack, the allocated word, the space allocated for growing in place, and the new stack
+    let mut mem = TestMemory::new(
+        size_of::<Blob>()
+            + INIT_STACK_SIZE
+            + Words(1)
+            + INIT_STACK_SIZE
+            + size_of::<Blob>()
+            + INIT_STACK_SIZE * 2,
+    );
+
+    alloc_mark_stack(&mut mem);
+
+    for i in 0..INIT_STACK_SIZE.as_u32() / 2 {
+        push_mark_stack(&mut mem, i as usize, TAG_ARRAY);
+    }
+
+    // The stack cannot be grown in place after this
+    mem.alloc_words(Words(1));
+
+    let old_blob = mark_stack_blob(&mut mem);
+    push_mark_stack(&mut mem, 1234, TAG_BLOB);
+    assert_ne!(mark_stack_blob(&mut mem), old_blob);
+    let (base, top, _) = stack_pointers(&mut mem);
+    assert_eq!(base.add(INIT_STACK_SIZE.as_usize() * 2), top);
+
+    assert_eq!(pop_mark_stack(&mut mem), Some((1234, TAG_BLOB)));
+    for i in (0..INIT_STACK_SIZE.as_u32() / 2).rev() {
+        assert_eq!(pop_mark_stack(&mut mem), Some((i as usize, TAG_ARRAY)));
+    }
+    assert_eq!(pop_mark_stack(&mut mem), None);
+
+    free_mark_stack(&mut mem);
+}
+
+/// Bottom, top, and the next free slot of the mark stack of `mem`
+unsafe fn stack_pointers(mem: &mut TestMemory) -> (*mut usize, *mut usize, *mut usize) {
+    let stack = &mem.context().mark_stack;
+    (stack.base, stack.top, stack.ptr)
 }
@@ -1,9 +1,10 @@
-use motoko_rts::memory::Memory;
-use motoko_rts::types::{Value, Words};
+use motoko_rts::memory::{with_scratch, Context, Memory, ScratchRegion};
+use motoko_rts::types::*;
 
 pub struct TestMemory {
     heap: Box<[u8]>,
     hp: usize,
+    context: Context,
 }
 
 impl TestMemory {
@@ -11,7 +12,16 @@ impl TestMemory {
         let bytes = size.to_bytes().as_usize();
         let heap = vec![0u8; bytes].into_boxed_slice();
         let hp = heap.as_ptr() as usize;
-        TestMemory { heap, hp }
+        TestMemory {
+            heap,
+            hp,
+            context: Context::new(),
+        }
+    }
+
+    /// Address of the next allocation
+    pub fn heap_pointer(&self) -> usize {
+        self.hp
     }
 
     unsafe fn grow_memory(&mut self, ptr: usize) {
@@ -40,4 +50,51 @@ impl Memory for TestMemory {
 
         Value::from_ptr(old_hp)
     }
+
+    unsafe fn context(&mut self) -> &mut Context {
+        &mut self.context
+    }
+
+    unsafe fn begin_scratch(&mut self) -> ScratchRegion {
+        ScratchRegion(self.hp)
+    }
+
+    unsafe fn release_scratch(&mut self, region: ScratchRegion) {
+        assert!(region.0 <= self.hp);
+        self.hp = region.0;
+    }
+}
+
+pub unsafe fn test() {
+    test_scratch_regions();
+}
+
+unsafe fn test_scratch_regions() {
+    println!("Testing scratch regions ...");
+
+    let mut mem = TestMemory::new(Words(1024));
+
+    let hp = mem.hp;
+    let kept = mem.alloc_words(Words(2));
+
+    // Nested regions
+    let outer = mem.begin_scratch();
+    mem.alloc_words(Words(10));
+    let inner = mem.begin_scratch();
+    mem.alloc_words(Words(20));
+    mem.release_scratch(inner);
+    assert_eq!(mem.hp, hp + 12 * 4);
+    mem.release_scratch(outer);
+    assert_eq!(mem.hp, hp + 2 * 4);
+
+    // Results that are not scratch data are returned
+    let n = with_scratch(&mut mem, |mem| {
+        mem.alloc_words(Words(100));
+        42
+    });
+    assert_eq!(n, 42);
+    assert_eq!(mem.hp, hp + 2 * 4);
+
+    // Allocation continues from the start of the released region
+    assert_eq!(mem.alloc_words(Words(1)).get_ptr(), kept.get_ptr() + 2 * 4);
 }
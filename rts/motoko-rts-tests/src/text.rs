@@ -4,10 +4,11 @@ use crate::memory::TestMemory;
 
 use motoko_rts::memory::Memory;
 use motoko_rts::text::{
-    blob_of_text, decode_code_point, text_compare, text_concat, text_len, text_of_str,
-    text_singleton, text_size,
+    blob_of_text, decode_code_point, text_char_at, text_compare, text_concat, text_depth, text_len,
+    text_of_str, text_singleton, text_size, text_slice,
 };
 use motoko_rts::text_iter::{text_iter, text_iter_done, text_iter_next};
+use motoko_rts::text_search::{text_find, text_replace, text_split};
 use motoko_rts::types::{Bytes, Value, Words, TAG_BLOB};
 
 use std::convert::TryFrom;
@@ -78,6 +79,21 @@ pub unsafe fn test() {
 
     drop(mem);
 
+    println!("  Testing slicing");
+    let mut mem = TestMemory::new(Words(1024 * 1024));
+    slice_and_char_at(&mut mem);
+    drop(mem);
+
+    println!("  Testing search, split and replace");
+    let mut mem = TestMemory::new(Words(1024 * 1024));
+    search(&mut mem);
+    drop(mem);
+
+    println!("  Testing balancing of concatenations");
+    append_loop(&mut TestMemory::new(Words(16 * 1024 * 1024)));
+    prepend_loop(&mut TestMemory::new(Words(4 * 1024 * 1024)));
+    random_concats(&mut TestMemory::new(Words(4 * 1024 * 1024)));
+
     let mut proptest_runner = TestRunner::new(Config {
         cases: 1_000,
         failure_persistence: None,
@@ -93,6 +109,23 @@ pub unsafe fn test() {
             },
         )
         .unwrap();
+
+    proptest_runner
+        .run(
+            &(
+                proptest::collection::vec(
+                    proptest::string::string_regex("[aöb]{0,8}").unwrap(),
+                    1..10,
+                ),
+                proptest::string::string_regex("[aöb]{0,3}").unwrap(),
+                proptest::string::string_regex("[xy]{0,3}").unwrap(),
+            ),
+            |(strs, pattern, replacement)| {
+                let mut mem = TestMemory::new(Words(1024 * 1024));
+                search_prop(&mut mem, strs, &pattern, &replacement)
+            },
+        )
+        .unwrap();
 }
 
 unsafe fn concat1<M: Memory>(mem: &mut M) {
@@ -133,6 +166,248 @@ unsafe fn concat1<M: Memory>(mem: &mut M) {
     assert_eq!(TextIter::from_text(mem, obj).collect::<String>(), expected);
 }
 
+unsafe fn slice_and_char_at<M: Memory>(mem: &mut M) {
+    let strs = [
+        "a",
+        "öabcdef",
+        "y",
+        "ÿ€𐍈 abcdefghi",
+        "",
+        "jklmnopqrstuvwxyz",
+        "ö",
+    ];
+
+    let mut text = text_of_str(mem, "");
+    for str in &strs {
+        let str_obj = text_of_str(mem, str);
+        text = text_concat(mem, text, str_obj);
+    }
+
+    let chars: Vec<char> = strs.concat().chars().collect();
+
+    for (i, char) in chars.iter().enumerate() {
+        assert_eq!(text_char_at(text, i as u32), *char as u32);
+    }
+
+    for start in 0..=chars.len() {
+        for len in 0..=chars.len() - start {
+            let slice = text_slice(mem, text, start as u32, len as u32);
+            let expected: String = chars[start..start + len].iter().collect();
+            check_contents(mem, slice, &expected);
+            check_depth(slice);
+        }
+    }
+
+    // The whole text and the subtrees in the range are shared
+    let n_chars = chars.len() as u32;
+    assert_eq!(text_slice(mem, text, 0, n_chars).get_ptr(), text.get_ptr());
+
+    let left = text_of_str(mem, "abcdefghijk");
+    let right = text_of_str(mem, "lmnopqrstuv");
+    let text = text_concat(mem, left, right);
+    assert_eq!(text_slice(mem, text, 0, 11).get_ptr(), left.get_ptr());
+    assert_eq!(text_slice(mem, text, 11, 11).get_ptr(), right.get_ptr());
+
+    let slice = text_slice(mem, text, 1, 21).as_concat();
+    assert_eq!((*slice).text2.get_ptr(), right.get_ptr());
+}
+
+unsafe fn text_of_strs<M: Memory>(mem: &mut M, strs: &[&str]) -> Value {
+    let mut text = text_of_str(mem, "");
+    for str in strs {
+        let str_obj = text_of_str(mem, str);
+        text = text_concat(mem, text, str_obj);
+    }
+    text
+}
+
+unsafe fn search<M: Memory>(mem: &mut M) {
+    // Occurrences of "abc" span blobs
+    let text = text_of_strs(mem, &["xxabcab", "€€abXab", "cab", "cy€abc"]);
+    let expected = "xxabcab€€abXabcabcy€abc";
+
+    let abc = text_of_str(mem, "abc");
+    let xyz = text_of_str(mem, "xyz");
+    let euro = text_of_str(mem, "€");
+    let empty = text_of_str(mem, "");
+
+    assert_eq!(text_find(mem, text, abc), 2);
+    assert_eq!(text_find(mem, text, euro), 7);
+    assert_eq!(text_find(mem, text, xyz), -1);
+    assert_eq!(text_find(mem, text, empty), 0);
+    assert_eq!(text_find(mem, empty, abc), -1);
+
+    let parts = text_split(mem, text, abc).as_array();
+    let expected_parts: Vec<&str> = expected.split("abc").collect();
+    assert_eq!(parts.len(), expected_parts.len() as u32);
+    for (i, part) in expected_parts.iter().enumerate() {
+        check_contents(mem, parts.get(i as u32), part);
+    }
+
+    let replaced = text_replace(mem, text, abc, euro);
+    check_contents(mem, replaced, &expected.replace("abc", "€"));
+
+    // Texts without occurrences are not copied
+    assert_eq!(text_replace(mem, text, xyz, euro).get_ptr(), text.get_ptr());
+    let parts = text_split(mem, text, xyz).as_array();
+    assert_eq!(parts.len(), 1);
+    assert_eq!(parts.get(0).get_ptr(), text.get_ptr());
+
+    // Parts share the blobs of the text
+    let left = text_of_str(mem, "abcdefghij,");
+    let right = text_of_str(mem, "klmnopqrstu");
+    let text = text_concat(mem, left, right);
+    let comma = text_of_str(mem, ",");
+    let parts = text_split(mem, text, comma).as_array();
+    assert_eq!(parts.len(), 2);
+    check_contents(mem, parts.get(0), "abcdefghij");
+    assert_eq!(parts.get(1).get_ptr(), right.get_ptr());
+}
+
+fn search_prop<M: Memory>(
+    mem: &mut M,
+    strs: Vec<String>,
+    pattern: &str,
+    replacement: &str,
+) -> TestCaseResult {
+    unsafe {
+        let strs: Vec<&str> = strs.iter().map(|str| str.as_str()).collect();
+        let text = text_of_strs(mem, &strs);
+        let expected = strs.concat();
+
+        let pattern_text = text_of_str(mem, pattern);
+        let replacement_text = text_of_str(mem, replacement);
+
+        let expected_find = match expected.find(pattern) {
+            Some(offset) => expected[..offset].chars().count() as i32,
+            None => -1,
+        };
+        if text_find(mem, text, pattern_text) != expected_find {
+            return Err(TestCaseError::Fail("text_find".into()));
+        }
+
+        let expected_parts: Vec<&str> = if pattern.is_empty() {
+            vec![&expected]
+        } else {
+            expected.split(pattern).collect()
+        };
+        let parts = text_split(mem, text, pattern_text).as_array();
+        if parts.len() != expected_parts.len() as u32 {
+            return Err(TestCaseError::Fail("text_split length".into()));
+        }
+        for (i, part) in expected_parts.iter().enumerate() {
+            let part_text = text_of_str(mem, part);
+            if text_compare(parts.get(i as u32), part_text) != 0 {
+                return Err(TestCaseError::Fail("text_split part".into()));
+            }
+        }
+
+        let expected_replaced = if pattern.is_empty() {
+            expected.clone()
+        } else {
+            expected.replace(pattern, replacement)
+        };
+        let expected_replaced_text = text_of_str(mem, &expected_replaced);
+        let replaced = text_replace(mem, text, pattern_text, replacement_text);
+        if text_compare(replaced, expected_replaced_text) != 0 {
+            return Err(TestCaseError::Fail("text_replace".into()));
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks that the text is not deeper than allowed by `text_concat`: `2 * log2(size) + 8`
+unsafe fn check_depth(text: Value) {
+    let size = text_size(text).as_u32();
+    if size == 0 {
+        return;
+    }
+    let log2_size = 31 - size.leading_zeros();
+    assert!(text_depth(text) <= 2 * log2_size + 8);
+}
+
+unsafe fn check_contents<M: Memory>(mem: &mut M, text: Value, expected: &str) {
+    assert_eq!(text_size(text), Bytes(expected.len() as u32));
+    assert_eq!(text_len(text), expected.chars().count() as u32);
+    let expected_text = text_of_str(mem, expected);
+    assert_eq!(text_compare(text, expected_text), 0);
+    assert_eq!(text_compare(blob_of_text(mem, text), expected_text), 0);
+}
+
+unsafe fn append_loop<M: Memory>(mem: &mut M) {
+    let leaf_str = "öabcdefgh";
+    let leaf = text_of_str(mem, leaf_str);
+
+    let mut text = leaf;
+    for _ in 1..1_000_000 {
+        text = text_concat(mem, text, leaf);
+    }
+
+    check_depth(text);
+    check_contents(mem, text, &leaf_str.repeat(1_000_000));
+}
+
+unsafe fn prepend_loop<M: Memory>(mem: &mut M) {
+    let mut text = text_of_str(mem, "");
+    let mut expected = String::new();
+    for i in 0..100_000 {
+        let leaf_str = &STR[0..i % 8 + 1];
+        let leaf = text_of_str(mem, leaf_str);
+        text = text_concat(mem, leaf, text);
+        expected.insert_str(0, leaf_str);
+    }
+
+    check_depth(text);
+    check_contents(mem, text, &expected);
+}
+
+/// Concatenates random pairs of texts from a pool, mixing appends, prepends and concatenations of
+/// deep texts
+unsafe fn random_concats<M: Memory>(mem: &mut M) {
+    let leaf_strs = ["a", "öabcdef", "yz", "abcdefghijklmnop"];
+
+    let mut pool: Vec<(Value, String)> = vec![];
+    for i in 0..16 {
+        let leaf_str = leaf_strs[i % leaf_strs.len()];
+        pool.push((text_of_str(mem, leaf_str), leaf_str.to_string()));
+    }
+
+    // xorshift32, for a deterministic sequence
+    let mut rng_state: u32 = 0x2545f491;
+    let mut rng = || {
+        rng_state ^= rng_state << 13;
+        rng_state ^= rng_state >> 17;
+        rng_state ^= rng_state << 5;
+        rng_state as usize % 16
+    };
+
+    for step in 0..2_000 {
+        let i = rng();
+        let j = rng();
+
+        let text = text_concat(mem, pool[i].0, pool[j].0);
+        let expected = pool[i].1.clone() + &pool[j].1;
+        check_depth(text);
+
+        if step % 100 == 0 {
+            check_contents(mem, text, &expected);
+        }
+
+        // Keep the texts small enough to compare them often
+        if expected.len() > 64 * 1024 {
+            let leaf_str = leaf_strs[step % leaf_strs.len()];
+            pool[i] = (text_of_str(mem, leaf_str), leaf_str.to_string());
+        } else {
+            pool[i] = (text, expected);
+        }
+    }
+
+    for (text, expected) in &pool {
+        check_contents(mem, *text, expected);
+    }
+}
+
 fn concat_prop<M: Memory>(mem: &mut M, strs: Vec<String>) -> TestCaseResult {
     unsafe {
         let mut obj = text_of_str(mem, "");
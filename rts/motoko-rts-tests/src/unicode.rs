@@ -0,0 +1,275 @@
+use crate::memory::TestMemory;
+
+use motoko_rts::memory::Memory;
+use motoko_rts::text::{text_compare, text_concat, text_of_str};
+use motoko_rts::text_iter::{text_iter, text_iter_done, text_iter_next_grapheme};
+use motoko_rts::types::{Value, Words};
+use motoko_rts::unicode::{text_nfc, text_nfd, text_nfkc, text_to_lower, text_to_upper};
+
+pub unsafe fn test() {
+    println!("Testing case mapping, normalization, and grapheme clusters ...");
+
+    let mut mem = TestMemory::new(Words(1024 * 1024));
+
+    test_case_mapping(&mut mem);
+    test_normalization(&mut mem);
+    test_graphemes(&mut mem);
+}
+
+/// Input and expected output of the functions. Inputs are split in the middle and concatenated, to
+/// also test texts that are not blobs.
+type Case<'a> = (&'a str, &'a str);
+
+unsafe fn check<M: Memory>(
+    mem: &mut M,
+    name: &str,
+    f: unsafe fn(&mut M, Value) -> Value,
+    cases: &[Case],
+) {
+    for (input, expected) in cases {
+        let mid = input
+            .char_indices()
+            .nth(input.chars().count() / 2)
+            .map_or(0, |(i, _)| i);
+        let left = text_of_str(mem, &input[..mid]);
+        let right = text_of_str(mem, &input[mid..]);
+        let text = text_concat(mem, left, right);
+
+        let result = f(mem, text);
+        let expected_text = text_of_str(mem, expected);
+        assert_eq!(
+            text_compare(result, expected_text),
+            0,
+            "{} of {:?} should be {:?}",
+            name,
+            input,
+            expected
+        );
+    }
+}
+
+unsafe fn test_case_mapping(mem: &mut TestMemory) {
+    println!("  Testing case mapping");
+
+    check(
+        mem,
+        "text_to_upper",
+        text_to_upper,
+        &[
+            ("", ""),
+            ("abc XYZ 123", "ABC XYZ 123"),
+            ("straße", "STRASSE"),
+            ("ﬁne", "FINE"),
+            ("ǆemal", "ǄEMAL"),
+            ("ὀδυσσεύς", "ὈΔΥΣΣΕΎΣ"),
+        ],
+    );
+
+    check(
+        mem,
+        "text_to_lower",
+        text_to_lower,
+        &[
+            ("", ""),
+            ("ABC xyz 123", "abc xyz 123"),
+            ("İ", "i\u{307}"),
+            // Final sigma
+            ("ὈΔΥΣΣΕΎΣ", "ὀδυσσεύς"),
+            ("ΣΑΣ ΣΑΣ.", "σας σας."),
+            ("Σ", "σ"),
+            ("ΑΣ'", "ας'"),
+            ("ΑΣΑ", "ασα"),
+            // Titlecase letters are cased
+            ("ǅΣ", "ǆς"),
+            // Modifier letters are both cased and case-ignorable
+            ("ʰΣ", "ʰς"),
+            ("ΑΣʰ", "ασʰ"),
+            // Soft hyphen and zero width joiner are case-ignorable
+            ("ΑΣ\u{AD}", "ας\u{AD}"),
+            ("ΑΣ\u{AD}Α", "ασ\u{AD}α"),
+            ("Α\u{200D}Σ", "α\u{200D}ς"),
+        ],
+    );
+}
+
+unsafe fn test_normalization(mem: &mut TestMemory) {
+    println!("  Testing normalization");
+
+    // ASCII texts are returned unchanged
+    let ascii = text_of_str(mem, "abc");
+    assert_eq!(text_nfc(mem, ascii).get_ptr(), ascii.get_ptr());
+    assert_eq!(text_nfkc(mem, ascii).get_ptr(), ascii.get_ptr());
+
+    check(
+        mem,
+        "text_nfc",
+        text_nfc,
+        &[
+            ("café", "café"),
+            ("cafe\u{301}", "café"),
+            ("\u{1E0A}\u{323}", "\u{1E0C}\u{307}"),
+            ("D\u{307}\u{323}", "\u{1E0C}\u{307}"),
+            ("\u{212B}", "\u{C5}"),
+            ("A\u{30A}\u{301}", "\u{1FA}"),
+            ("\u{1100}\u{1161}\u{11A8}", "\u{AC01}"),
+            ("\u{AC00}\u{11A8}", "\u{AC01}"),
+            ("x\u{301}\u{327}y", "x\u{327}\u{301}y"),
+            ("\u{FB01}", "\u{FB01}"),
+            // Composition exclusion
+            ("\u{1D15E}", "\u{1D157}\u{1D165}"),
+        ],
+    );
+
+    check(
+        mem,
+        "text_nfd",
+        text_nfd,
+        &[
+            ("café", "cafe\u{301}"),
+            ("\u{1E0A}\u{323}", "D\u{323}\u{307}"),
+            ("\u{212B}", "A\u{30A}"),
+            ("\u{AC01}", "\u{1100}\u{1161}\u{11A8}"),
+            ("\u{1E9B}\u{323}", "\u{17F}\u{323}\u{307}"),
+            ("\u{FB01}", "\u{FB01}"),
+        ],
+    );
+
+    check(
+        mem,
+        "text_nfkc",
+        text_nfkc,
+        &[
+            ("cafe\u{301}", "café"),
+            ("\u{FB01}", "fi"),
+            ("\u{2075}", "5"),
+            ("\u{1E9B}\u{323}", "\u{1E69}"),
+            ("\u{1C4}", "D\u{17D}"),
+            ("\u{AC00}\u{11A8}", "\u{AC01}"),
+        ],
+    );
+}
+
+unsafe fn test_graphemes(mem: &mut TestMemory) {
+    println!("  Testing grapheme clusters");
+
+    check_graphemes(mem, &[""], &[]);
+    check_graphemes(mem, &["abc"], &["a", "b", "c"]);
+    check_graphemes(mem, &["a\r\nb\n\r"], &["a", "\r\n", "b", "\n", "\r"]);
+    check_graphemes(
+        mem,
+        &["\u{1F1E9}\u{1F1EA}\u{1F1EB}\u{1F1F7}\u{1F1EE}"],
+        &["\u{1F1E9}\u{1F1EA}", "\u{1F1EB}\u{1F1F7}", "\u{1F1EE}"],
+    );
+    check_graphemes(
+        mem,
+        &["\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{1F44D}\u{1F3FD}a\u{200D}\u{1F469}"],
+        &[
+            "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}",
+            "\u{1F44D}\u{1F3FD}",
+            "a\u{200D}",
+            "\u{1F469}",
+        ],
+    );
+    check_graphemes(
+        mem,
+        &["\u{1100}\u{1161}\u{11A8}\u{AC00}\u{11A8}\u{AC01}\u{1161}"],
+        &[
+            "\u{1100}\u{1161}\u{11A8}",
+            "\u{AC00}\u{11A8}",
+            "\u{AC01}",
+            "\u{1161}",
+        ],
+    );
+    check_graphemes(
+        mem,
+        &["\u{600}a\u{903}\u{200B}\u{301}"],
+        &["\u{600}a\u{903}", "\u{200B}", "\u{301}"],
+    );
+
+    // Clusters spanning two and three leaves
+    check_graphemes(
+        mem,
+        &[
+            "abcdefghie",
+            "\u{301}\u{302}ijklmn\u{1F1E9}",
+            "\u{1F1EA}\u{1F468}\u{200D}",
+            "\u{1F469}opqrstu\r",
+            "\nvwxyze",
+            "\u{301}\u{302}\u{303}\u{304}\u{305}",
+            "\u{306}xyzxyzxyz",
+        ],
+        &[
+            "a",
+            "b",
+            "c",
+            "d",
+            "e",
+            "f",
+            "g",
+            "h",
+            "i",
+            "e\u{301}\u{302}",
+            "i",
+            "j",
+            "k",
+            "l",
+            "m",
+            "n",
+            "\u{1F1E9}\u{1F1EA}",
+            "\u{1F468}\u{200D}\u{1F469}",
+            "o",
+            "p",
+            "q",
+            "r",
+            "s",
+            "t",
+            "u",
+            "\r\n",
+            "v",
+            "w",
+            "x",
+            "y",
+            "z",
+            "e\u{301}\u{302}\u{303}\u{304}\u{305}\u{306}",
+            "x",
+            "y",
+            "z",
+            "x",
+            "y",
+            "z",
+            "x",
+            "y",
+            "z",
+        ],
+    );
+}
+
+/// Iterates the grapheme clusters of the concatenation of the strings
+unsafe fn check_graphemes(mem: &mut TestMemory, strs: &[&str], expected: &[&str]) {
+    let mut text = text_of_str(mem, "");
+    for str in strs {
+        let str_obj = text_of_str(mem, str);
+        text = text_concat(mem, text, str_obj);
+    }
+
+    let iter = text_iter(mem, text);
+    for cluster in expected {
+        assert_eq!(
+            text_iter_done(iter),
+            0,
+            "{:?} ends before {:?}",
+            strs,
+            cluster
+        );
+        let next = text_iter_next_grapheme(mem, iter);
+        let expected_text = text_of_str(mem, cluster);
+        assert_eq!(
+            text_compare(next, expected_text),
+            0,
+            "next cluster of {:?} should be {:?}",
+            strs,
+            cluster
+        );
+    }
+    assert_eq!(text_iter_done(iter), 1, "{:?} has more clusters", strs);
+}
@@ -0,0 +1,273 @@
+#!/usr/bin/env python3
+"""
+Generates src/unicode/tables.rs, the Unicode normalization, grapheme cluster, and case property data
+of the RTS, from the Unicode database of the Python interpreter:
+
+    python3 gen-unicode-tables.py > src/unicode/tables.rs
+
+The properties below that are not in Python's database are from the Unicode 14.0 data files, so
+this needs a Python with the same version of the database (Python 3.11).
+
+See src/unicode/tables.rs for the layout of the tables.
+"""
+
+import sys
+import unicodedata
+
+MAX_CODE_POINT = 0x10FFFF
+HANGUL_FIRST, HANGUL_LAST = 0xAC00, 0xD7A3
+
+
+def utf16(code_points):
+    units = []
+    for cp in code_points:
+        if cp >= 0x10000:
+            cp -= 0x10000
+            units += [0xD800 + (cp >> 10), 0xDC00 + (cp & 0x3FF)]
+        else:
+            units.append(cp)
+    return units
+
+
+def decompositions():
+    entries = []  # (code point, compat, code points)
+    for cp in range(MAX_CODE_POINT + 1):
+        if HANGUL_FIRST <= cp <= HANGUL_LAST:
+            continue
+        decomposition = unicodedata.decomposition(chr(cp))
+        if not decomposition:
+            continue
+        fields = decomposition.split()
+        compat = fields[0].startswith("<")
+        if compat:
+            fields = fields[1:]
+        entries.append((cp, compat, [int(field, 16) for field in fields]))
+    return entries
+
+
+# Properties of UAX #29 and of the case mapping (UAX #44, section 5.3) that are not in Python's
+# database, from the Unicode 14.0 data files
+
+# Other_Grapheme_Extend, from PropList.txt
+OTHER_GRAPHEME_EXTEND = [
+    (0x09BE, 0x09BE), (0x09D7, 0x09D7), (0x0B3E, 0x0B3E), (0x0B57, 0x0B57), (0x0BBE, 0x0BBE),
+    (0x0BD7, 0x0BD7), (0x0CC2, 0x0CC2), (0x0CD5, 0x0CD6), (0x0D3E, 0x0D3E), (0x0D57, 0x0D57),
+    (0x0DCF, 0x0DCF), (0x0DDF, 0x0DDF), (0x1B35, 0x1B35), (0x200C, 0x200C), (0x302E, 0x302F),
+    (0xFF9E, 0xFF9F), (0x1133E, 0x1133E), (0x11357, 0x11357), (0x114B0, 0x114B0),
+    (0x114BD, 0x114BD), (0x115AF, 0x115AF), (0x11930, 0x11930), (0x1D165, 0x1D165),
+    (0x1D16E, 0x1D172), (0xE0020, 0xE007F),
+]
+
+# Emoji_Modifier, from emoji-data.txt
+EMOJI_MODIFIER = [(0x1F3FB, 0x1F3FF)]
+
+# Grapheme_Cluster_Break=Prepend, from GraphemeBreakProperty.txt
+PREPEND = [
+    (0x0600, 0x0605), (0x06DD, 0x06DD), (0x070F, 0x070F), (0x0890, 0x0891), (0x08E2, 0x08E2),
+    (0x0D4E, 0x0D4E), (0x110BD, 0x110BD), (0x110CD, 0x110CD), (0x111C2, 0x111C3),
+    (0x1193F, 0x1193F), (0x11941, 0x11941), (0x11A3A, 0x11A3A), (0x11A84, 0x11A89),
+    (0x11D46, 0x11D46),
+]
+
+# Spacing marks that are not Grapheme_Cluster_Break=SpacingMark, from UAX #29 table 2
+NOT_SPACING_MARK = [
+    (0x102B, 0x102C), (0x1038, 0x1038), (0x1062, 0x1064), (0x1067, 0x106D), (0x1083, 0x1083),
+    (0x1087, 0x108C), (0x108F, 0x108F), (0x109A, 0x109C), (0x1A61, 0x1A61), (0x1A63, 0x1A64),
+    (0xAA7B, 0xAA7B), (0xAA7D, 0xAA7D), (0x11720, 0x11721),
+]
+
+# Unassigned Default_Ignorable_Code_Point, from DerivedCoreProperties.txt
+UNASSIGNED_DEFAULT_IGNORABLE = [
+    (0x2065, 0x2065), (0xFFF0, 0xFFF8), (0xE0000, 0xE0000), (0xE0002, 0xE001F),
+    (0xE0080, 0xE00FF), (0xE01F0, 0xE0FFF),
+]
+
+# Extended_Pictographic, from emoji-data.txt
+EXTENDED_PICTOGRAPHIC = [
+    (0x00A9, 0x00A9), (0x00AE, 0x00AE), (0x203C, 0x203C), (0x2049, 0x2049), (0x2122, 0x2122),
+    (0x2139, 0x2139), (0x2194, 0x2199), (0x21A9, 0x21AA), (0x231A, 0x231B), (0x2328, 0x2328),
+    (0x2388, 0x2388), (0x23CF, 0x23CF), (0x23E9, 0x23F3), (0x23F8, 0x23FA), (0x24C2, 0x24C2),
+    (0x25AA, 0x25AB), (0x25B6, 0x25B6), (0x25C0, 0x25C0), (0x25FB, 0x25FE), (0x2600, 0x2605),
+    (0x2607, 0x2612), (0x2614, 0x2685), (0x2690, 0x2705), (0x2708, 0x2712), (0x2714, 0x2714),
+    (0x2716, 0x2716), (0x271D, 0x271D), (0x2721, 0x2721), (0x2728, 0x2728), (0x2733, 0x2734),
+    (0x2744, 0x2744), (0x2747, 0x2747), (0x274C, 0x274C), (0x274E, 0x274E), (0x2753, 0x2755),
+    (0x2757, 0x2757), (0x2763, 0x2767), (0x2795, 0x2797), (0x27A1, 0x27A1), (0x27B0, 0x27B0),
+    (0x27BF, 0x27BF), (0x2934, 0x2935), (0x2B05, 0x2B07), (0x2B1B, 0x2B1C), (0x2B50, 0x2B50),
+    (0x2B55, 0x2B55), (0x3030, 0x3030), (0x303D, 0x303D), (0x3297, 0x3297), (0x3299, 0x3299),
+    (0x1F000, 0x1F0FF), (0x1F10D, 0x1F10F), (0x1F12F, 0x1F12F), (0x1F16C, 0x1F171),
+    (0x1F17E, 0x1F17F), (0x1F18E, 0x1F18E), (0x1F191, 0x1F19A), (0x1F1AD, 0x1F1E5),
+    (0x1F201, 0x1F20F), (0x1F21A, 0x1F21A), (0x1F22F, 0x1F22F), (0x1F232, 0x1F23A),
+    (0x1F23C, 0x1F23F), (0x1F249, 0x1F3FA), (0x1F400, 0x1F53D), (0x1F546, 0x1F64F),
+    (0x1F680, 0x1F6FF), (0x1F774, 0x1F77F), (0x1F7D5, 0x1F7FF), (0x1F80C, 0x1F80F),
+    (0x1F848, 0x1F84F), (0x1F85A, 0x1F85F), (0x1F888, 0x1F88F), (0x1F8AE, 0x1F8FF),
+    (0x1F90C, 0x1F93A), (0x1F93C, 0x1F945), (0x1F947, 0x1F9FF), (0x1FA00, 0x1FAFF),
+    (0x1FC00, 0x1FFFD),
+]
+
+# Other_Lowercase, from PropList.txt
+OTHER_LOWERCASE = [
+    (0x00AA, 0x00AA), (0x00BA, 0x00BA), (0x02B0, 0x02B8), (0x02C0, 0x02C1), (0x02E0, 0x02E4),
+    (0x0345, 0x0345), (0x037A, 0x037A), (0x1D2C, 0x1D6A), (0x1D78, 0x1D78), (0x1D9B, 0x1DBF),
+    (0x2071, 0x2071), (0x207F, 0x207F), (0x2090, 0x209C), (0x2170, 0x217F), (0x24D0, 0x24E9),
+    (0x2C7C, 0x2C7D), (0xA69C, 0xA69D), (0xA770, 0xA770), (0xA7F8, 0xA7F9), (0xAB5C, 0xAB5F),
+    (0x10780, 0x10780), (0x10783, 0x10785), (0x10787, 0x107B0), (0x107B2, 0x107BA),
+]
+
+# Other_Uppercase, from PropList.txt
+OTHER_UPPERCASE = [
+    (0x2160, 0x216F), (0x24B6, 0x24CF), (0x1F130, 0x1F149), (0x1F150, 0x1F169), (0x1F170, 0x1F189),
+]
+
+# Word_Break=MidLetter, MidNumLet, or Single_Quote, from WordBreakProperty.txt
+MID_LETTER_MID_NUM_LET_SINGLE_QUOTE = [
+    (0x0027, 0x0027), (0x002E, 0x002E), (0x003A, 0x003A), (0x00B7, 0x00B7), (0x0387, 0x0387),
+    (0x055F, 0x055F), (0x05F4, 0x05F4), (0x2018, 0x2019), (0x2024, 0x2024), (0x2027, 0x2027),
+    (0xFE13, 0xFE13), (0xFE52, 0xFE52), (0xFE55, 0xFE55), (0xFF07, 0xFF07), (0xFF0E, 0xFF0E),
+    (0xFF1A, 0xFF1A),
+]
+
+# Values of `GraphemeBreak` in src/unicode.rs. Hangul syllables (LV and LVT) are not in the table.
+GRAPHEME_BREAKS = [
+    "Other", "Cr", "Lf", "Control", "Extend", "Zwj", "RegionalIndicator", "Prepend",
+    "SpacingMark", "L", "V", "T", "Lv", "Lvt", "ExtendedPictographic",
+]
+
+
+def in_ranges(cp, ranges):
+    return any(first <= cp <= last for first, last in ranges)
+
+
+def grapheme_break(cp):
+    """Grapheme_Cluster_Break of the code point as derived in UAX #29 table 2, or
+    ExtendedPictographic for the Extended_Pictographic characters that are Other"""
+    category = unicodedata.category(chr(cp))
+    if cp == 0x0D:
+        value = "Cr"
+    elif cp == 0x0A:
+        value = "Lf"
+    elif cp == 0x200D:
+        value = "Zwj"
+    elif 0x1F1E6 <= cp <= 0x1F1FF:
+        value = "RegionalIndicator"
+    elif in_ranges(cp, PREPEND):
+        value = "Prepend"
+    elif (
+        category in ("Mn", "Me")
+        or in_ranges(cp, OTHER_GRAPHEME_EXTEND)
+        or in_ranges(cp, EMOJI_MODIFIER)
+    ):
+        value = "Extend"
+    elif category in ("Zl", "Zp", "Cc", "Cf") or (
+        category == "Cn" and in_ranges(cp, UNASSIGNED_DEFAULT_IGNORABLE)
+    ):
+        value = "Control"
+    elif (category == "Mc" and not in_ranges(cp, NOT_SPACING_MARK)) or cp in (0x0E33, 0x0EB3):
+        value = "SpacingMark"
+    elif 0x1100 <= cp <= 0x115F or 0xA960 <= cp <= 0xA97C:
+        value = "L"
+    elif 0x1160 <= cp <= 0x11A7 or 0xD7B0 <= cp <= 0xD7C6:
+        value = "V"
+    elif 0x11A8 <= cp <= 0x11FF or 0xD7CB <= cp <= 0xD7FB:
+        value = "T"
+    else:
+        value = "Other"
+
+    if in_ranges(cp, EXTENDED_PICTOGRAPHIC):
+        assert value == "Other"
+        value = "ExtendedPictographic"
+
+    return GRAPHEME_BREAKS.index(value)
+
+
+# Bits of the values in `CASE_PROPERTIES`
+CASED = 1
+CASE_IGNORABLE = 2
+
+
+def case_properties(cp):
+    """Cased and Case_Ignorable properties of the code point, as derived in
+    DerivedCoreProperties.txt"""
+    category = unicodedata.category(chr(cp))
+    value = 0
+    if (
+        category in ("Ll", "Lu", "Lt")
+        or in_ranges(cp, OTHER_LOWERCASE)
+        or in_ranges(cp, OTHER_UPPERCASE)
+    ):
+        value |= CASED
+    if category in ("Mn", "Me", "Cf", "Lm", "Sk") or in_ranges(
+        cp, MID_LETTER_MID_NUM_LET_SINGLE_QUOTE
+    ):
+        value |= CASE_IGNORABLE
+    return value
+
+
+def value_ranges(value_of):
+    """Ranges of at most 2048 code points with the same non-zero value"""
+    ranges = []  # (first, last, value)
+    for cp in range(MAX_CODE_POINT + 1):
+        value = value_of(cp)
+        if value == 0:
+            continue
+        if ranges and ranges[-1][1] == cp - 1 and ranges[-1][2] == value and cp - ranges[-1][0] < 2048:
+            ranges[-1] = (ranges[-1][0], cp, value)
+        else:
+            ranges.append((cp, cp, value))
+    return ranges
+
+
+def is_primary_composite(cp, compat, code_points):
+    c = chr(cp)
+    return (
+        not compat
+        and len(code_points) == 2
+        and unicodedata.normalize("NFC", unicodedata.normalize("NFD", c)) == c
+    )
+
+
+def print_array(name, typ, values, per_line, fmt):
+    print("pub(super) static %s: [%s; %d] = [" % (name, typ, len(values)))
+    for i in range(0, len(values), per_line):
+        print("    " + " ".join(fmt % v + "," for v in values[i : i + per_line]))
+    print("];")
+    print()
+
+
+def main():
+    assert unicodedata.unidata_version == "14.0.0", unicodedata.unidata_version
+
+    ranges = value_ranges(lambda cp: unicodedata.combining(chr(cp)))
+    grapheme_ranges = value_ranges(
+        lambda cp: 0 if 0xD800 <= cp <= 0xDFFF else grapheme_break(cp)
+    )
+    case_ranges = value_ranges(case_properties)
+    entries = decompositions()
+
+    keys, offsets, chars = [], [], []
+    for cp, compat, code_points in entries:
+        assert len(code_points) < 128
+        keys.append(cp << 8 | compat << 7 | len(code_points))
+        offsets.append(len(chars))
+        chars += utf16(code_points)
+    assert len(chars) < 65536
+
+    compositions = [i for i, entry in enumerate(entries) if is_primary_composite(*entry)]
+    compositions.sort(key=lambda i: entries[i][2])
+
+    print("// Generated by gen-unicode-tables.py from the Unicode %s database, do not edit"
+          % unicodedata.unidata_version)
+    print()
+    print_array("COMBINING_CLASS_RANGES", "u32", [f << 11 | (l - f) for f, l, _ in ranges], 6, "%#010x")
+    print_array("COMBINING_CLASSES", "u8", [c for _, _, c in ranges], 16, "%d")
+    print_array("DECOMPOSITION_KEYS", "u32", keys, 6, "%#010x")
+    print_array("DECOMPOSITION_OFFSETS", "u16", offsets, 10, "%d")
+    print_array("DECOMPOSITION_CHARS", "u16", chars, 8, "%#06x")
+    print_array("COMPOSITIONS", "u16", compositions, 10, "%d")
+    print_array("GRAPHEME_BREAK_RANGES", "u32", [f << 11 | (l - f) for f, l, _ in grapheme_ranges], 6, "%#010x")
+    print_array("GRAPHEME_BREAKS", "u8", [v for _, _, v in grapheme_ranges], 16, "%d")
+    print_array("CASE_PROPERTY_RANGES", "u32", [f << 11 | (l - f) for f, l, _ in case_ranges], 6, "%#010x")
+    print_array("CASE_PROPERTIES", "u8", [v for _, _, v in case_ranges], 16, "%d")
+
+
+if __name__ == "__main__":
+    main()
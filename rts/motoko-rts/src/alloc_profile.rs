@@ -0,0 +1,164 @@
+//! Allocation sampling profiler, to find out which code paths drive heap growth.
+//!
+//! When enabled with `set_alloc_sampling`, an allocation is sampled after every `interval` bytes
+//! allocated. A sample records the current allocation site, and the size and tag of the allocated
+//! object. Sites are set by the generated code with `set_alloc_site`: the compiler sets the site to
+//! the hash of the method name (see `Mo_types.Hash.hash`) at the start of each message. Allocations
+//! made while the site is `NO_SITE` (e.g. by the collectors) are not sampled.
+//!
+//! Samples are kept in a fixed-size ring buffer, so only the last `N_SAMPLES` samples are
+//! available. `alloc_profile` returns the samples in the buffer aggregated by site and tag.
+//!
+//! The object is not initialized yet when `alloc_words` returns, so the tag of a sampled object is
+//! read on the next allocation, or when the site changes. The generated code changes the site
+//! before collecting garbage, so the tag is read before the object is moved.
+
+use crate::memory::{alloc_blob, Memory};
+use crate::types::*;
+
+use motoko_rts_macros::ic_mem_fn;
+
+/// Size of the sample buffer
+pub const N_SAMPLES: usize = 1024;
+
+/// Allocations made while the site is `NO_SITE` are not sampled
+pub const NO_SITE: u32 = u32::MAX;
+
+#[derive(Clone, Copy)]
+struct Sample {
+    site: u32,
+    tag: Tag,
+    size: Bytes<u32>,
+}
+
+/// Sampling interval in bytes, 0 when sampling is disabled
+static mut INTERVAL: u32 = 0;
+
+/// Bytes to allocate until the next sample
+static mut UNTIL_NEXT_SAMPLE: u32 = 0;
+
+/// Current allocation site
+static mut SITE: u32 = 0;
+
+static mut SAMPLES: [Sample; N_SAMPLES] = [Sample {
+    site: 0,
+    tag: 0,
+    size: Bytes(0),
+}; N_SAMPLES];
+
+/// Number of samples taken since sampling was enabled. The next sample is stored at index
+/// `N_TAKEN % N_SAMPLES`.
+static mut N_TAKEN: u32 = 0;
+
+/// Address of the last sampled object when its tag is not recorded yet, 0 otherwise
+static mut PENDING: u32 = 0;
+
+/// Enables sampling with the given interval in bytes, or disables it when the interval is 0.
+/// Clears the samples.
+#[no_mangle]
+pub unsafe extern "C" fn set_alloc_sampling(interval: u32) {
+    INTERVAL = interval;
+    UNTIL_NEXT_SAMPLE = interval;
+    N_TAKEN = 0;
+    PENDING = 0;
+}
+
+/// Sets the allocation site of the following allocations
+#[no_mangle]
+pub unsafe extern "C" fn set_alloc_site(site: u32) {
+    record_pending_tag();
+    SITE = site;
+}
+
+/// Called by `Memory` implementations after allocating `size` bytes at `addr`
+#[inline]
+pub unsafe fn note_allocation(addr: u32, size: Bytes<u32>) {
+    if INTERVAL == 0 {
+        return;
+    }
+
+    record_pending_tag();
+
+    if SITE == NO_SITE {
+        return;
+    }
+
+    if size.as_u32() < UNTIL_NEXT_SAMPLE {
+        UNTIL_NEXT_SAMPLE -= size.as_u32();
+        return;
+    }
+
+    UNTIL_NEXT_SAMPLE = INTERVAL;
+
+    SAMPLES[N_TAKEN as usize % N_SAMPLES] = Sample {
+        site: SITE,
+        tag: 0,
+        size,
+    };
+    N_TAKEN += 1;
+    PENDING = addr;
+}
+
+unsafe fn record_pending_tag() {
+    if PENDING != 0 {
+        SAMPLES[(N_TAKEN - 1) as usize % N_SAMPLES].tag = (PENDING as *mut Obj).tag();
+        PENDING = 0;
+    }
+}
+
+/// Returns the samples in the buffer aggregated by site and tag, as a blob of 32-bit
+/// little-endian numbers:
+///
+/// - Header: sampling interval, number of samples taken since sampling was enabled (including the
+///   ones no longer in the buffer), number of entries.
+///
+/// - Entries: for each site and tag, the site, the tag, the number of samples, and the total size
+///   of the sampled objects in bytes. Entries are in the order of their first sample in the buffer.
+#[ic_mem_fn]
+pub unsafe fn alloc_profile<M: Memory>(mem: &mut M) -> Value {
+    const HEADER_WORDS: u32 = 3;
+    const ENTRY_WORDS: u32 = 4;
+
+    record_pending_tag();
+
+    let n_samples = core::cmp::min(N_TAKEN as usize, N_SAMPLES);
+
+    // Allocate for the worst case of one entry per sample, shrink after aggregating. The
+    // allocation is not sampled, as the sample could overwrite one of the samples to aggregate.
+    let max_words = Words(HEADER_WORDS + ENTRY_WORDS * n_samples as u32);
+    let site = SITE;
+    SITE = NO_SITE;
+    let value = alloc_blob(mem, max_words.to_bytes());
+    SITE = site;
+    let blob = value.as_blob_mut();
+    let words = blob.payload_addr() as *mut u32;
+    let entries = words.add(HEADER_WORDS as usize);
+
+    let mut n_entries = 0;
+    for sample in &SAMPLES[0..n_samples] {
+        let mut entry = entries;
+        let entries_end = entries.add((n_entries * ENTRY_WORDS) as usize);
+        while entry != entries_end && (*entry != sample.site || *entry.add(1) != sample.tag) {
+            entry = entry.add(ENTRY_WORDS as usize);
+        }
+
+        if entry == entries_end {
+            *entry = sample.site;
+            *entry.add(1) = sample.tag;
+            *entry.add(2) = 0;
+            *entry.add(3) = 0;
+            n_entries += 1;
+        }
+
+        *entry.add(2) += 1;
+        *entry.add(3) += sample.size.as_u32();
+    }
+
+    *words = INTERVAL;
+    *words.add(1) = N_TAKEN;
+    *words.add(2) = n_entries;
+
+    blob.shrink(Words(HEADER_WORDS + ENTRY_WORDS * n_entries).to_bytes());
+
+    value
+}
@@ -0,0 +1,187 @@
+//! Heap census: a histogram of the live objects in the dynamic heap, by tag and by size.
+//!
+//! Live objects are found by marking from the roots, using the bitmap and the mark stack of the
+//! compacting GC. The census is returned to Motoko as a blob with the following layout, all
+//! numbers are 32-bit little-endian:
+//!
+//! - For each tag (`TAG_OBJECT`, `TAG_OBJ_IND`, ..., `TAG_WEAK_REF`, indexed by `tag / 2`),
+//!   number of objects and total size of the objects in bytes.
+//!
+//! - For each size class `i` (0 to 31), number of objects and total size of the objects with
+//!   size (in words) between `2^i` and `2^(i+1) - 1`.
+
+use crate::constants::WORD_SIZE;
+use crate::gc::mark_compact::bitmap::{alloc_bitmap, free_bitmap, get_bit, set_bit};
+use crate::gc::mark_compact::mark_stack::{
+    alloc_mark_stack, free_mark_stack, pop_mark_stack, push_mark_stack,
+};
+use crate::memory::Memory;
+use crate::types::*;
+use crate::visitor::{pointer_to_dynamic_heap, visit_pointer_fields};
+
+use motoko_rts_macros::ic_mem_fn;
+
+/// Number of object tags. Tags are odd numbers, `tag / 2` is the index of a tag in
+/// `Census::by_tag`.
+pub const N_TAGS: usize = 17;
+
+/// Number of size classes. Objects are at most 4 GiB, i.e. less than 2^30 words.
+pub const N_SIZE_CLASSES: usize = 32;
+
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CensusEntry {
+    pub count: u32,
+    pub bytes: Bytes<u32>,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Census {
+    pub by_tag: [CensusEntry; N_TAGS],
+    pub by_size_class: [CensusEntry; N_SIZE_CLASSES],
+}
+
+const EMPTY_ENTRY: CensusEntry = CensusEntry {
+    count: 0,
+    bytes: Bytes(0),
+};
+
+impl Census {
+    pub fn new() -> Census {
+        Census {
+            by_tag: [EMPTY_ENTRY; N_TAGS],
+            by_size_class: [EMPTY_ENTRY; N_SIZE_CLASSES],
+        }
+    }
+
+    /// Entry of the objects with the given tag
+    pub fn tag(&self, tag: Tag) -> CensusEntry {
+        self.by_tag[tag as usize / 2]
+    }
+
+    /// Size class of an object with the given size
+    pub fn size_class(size: Words<u32>) -> usize {
+        debug_assert!(size.as_u32() != 0);
+        (31 - size.as_u32().leading_zeros()) as usize
+    }
+
+    fn add(&mut self, tag: Tag, size: Words<u32>) {
+        let bytes = size.to_bytes();
+
+        let tag_entry = &mut self.by_tag[tag as usize / 2];
+        tag_entry.count += 1;
+        tag_entry.bytes += bytes;
+
+        let size_entry = &mut self.by_size_class[Census::size_class(size)];
+        size_entry.count += 1;
+        size_entry.bytes += bytes;
+    }
+}
+
+/// Returns the census of the live objects as a blob. See the module documentation for the layout
+/// of the blob.
+#[ic_mem_fn(ic_only)]
+unsafe fn heap_census(mem: &mut crate::memory::ic::IcMemory) -> Value {
+    use crate::continuation_table::continuation_table_loc;
+    use crate::memory::{alloc_blob, ic};
+
+    // Allocate the result first so that the bitmap covers it. It's not reachable so it's not
+    // counted.
+    let blob = alloc_blob(mem, size_of::<Census>().to_bytes());
+
+    let heap = mem.heap();
+    let continuation_table_ptr_loc = continuation_table_loc(mem);
+
+    let census = heap_census_internal(
+        mem,
+        (*heap).heap_start,
+        (*heap).hp,
+        ic::get_static_roots(),
+        continuation_table_ptr_loc,
+    );
+
+    *((blob.get_ptr() as *mut Blob).payload_addr() as *mut Census) = census;
+
+    blob
+}
+
+/// Counts the live objects between `heap_base` and `heap_end`. `heap_base` needs to be where the
+/// first object is (`HeapState::heap_start`), which is not aligned with the copying GC.
+///
+/// Uses the bitmap and the mark stack of the compacting GC, so it cannot be used while the
+/// incremental GC is marking. The bitmap and the mark stack are allocated in a scratch region
+/// after `heap_end`.
+pub unsafe fn heap_census_internal<M: Memory>(
+    mem: &mut M,
+    heap_base: u32,
+    heap_end: u32,
+    static_roots: Value,
+    continuation_table_ptr_loc: *mut Value,
+) -> Census {
+    if crate::gc::incremental::phase() != crate::gc::incremental::Phase::Idle {
+        crate::rts_trap_with("heap_census: incremental GC in progress");
+    }
+
+    let mut census = Census::new();
+
+    let scratch = mem.begin_scratch();
+
+    // The bitmap needs to start at a 32-byte aligned address
+    let bitmap_base = heap_base / 32 * 32;
+    alloc_bitmap(mem, Bytes(heap_end - bitmap_base), bitmap_base / WORD_SIZE);
+    alloc_mark_stack(mem);
+
+    let root_array = static_roots.as_array();
+    for i in 0..root_array.len() {
+        let mutbox = root_array.get(i).as_obj() as *mut MutBox;
+        mark_field(mem, &mut census, &mut (*mutbox).field, heap_base);
+    }
+
+    mark_field(mem, &mut census, continuation_table_ptr_loc, heap_base);
+
+    while let Some((obj, tag)) = pop_mark_stack(mem) {
+        let mut ctx = (&mut *mem, &mut census);
+        visit_pointer_fields(
+            &mut ctx,
+            obj as *mut Obj,
+            tag,
+            heap_base as usize,
+            |ctx, field_addr| mark_object(ctx.0, ctx.1, *field_addr),
+            |_, _, arr| arr.len(),
+        );
+    }
+
+    free_mark_stack(mem);
+    free_bitmap();
+    mem.release_scratch(scratch);
+
+    census
+}
+
+unsafe fn mark_field<M: Memory>(
+    mem: &mut M,
+    census: &mut Census,
+    field_addr: *mut Value,
+    heap_base: u32,
+) {
+    if pointer_to_dynamic_heap(field_addr, heap_base as usize) {
+        mark_object(mem, census, *field_addr);
+    }
+}
+
+unsafe fn mark_object<M: Memory>(mem: &mut M, census: &mut Census, obj: Value) {
+    let obj_tag = obj.tag();
+    let obj = obj.get_ptr() as u32;
+
+    let obj_idx = obj / WORD_SIZE;
+
+    if get_bit(obj_idx) {
+        return;
+    }
+
+    set_bit(obj_idx);
+    push_mark_stack(mem, obj as usize, obj_tag);
+
+    census.add(obj_tag, object_size(obj as usize));
+}
@@ -10,18 +10,21 @@
 //! the actor's control flow.
 //! The current implementation stores the continuations in heap-allocated Motoko array.
 //!
-//! To efficiently look up the next free index, we use an implicit free list: `FREE_SLOT` is the
+//! The table is part of the `Context` of a heap (see `memory.rs`), so each heap has its own table.
+//!
+//! To efficiently look up the next free index, we use an implicit free list: `free_slot` is the
 //! index into the array payload of the next free item. Each free item contains the index of the
 //! next free item, shifted 2 bits to the left (to make the index a scalar and traverse them in
 //! GC).
 //!
-//! The last item will have scalar value `TABLE.len()`, so after adding a continuation to the last
-//! free slot `FREE_SLOT` will be `table_size`, which is when we see that the array is full.
+//! The last item will have scalar value `table.len()`, so after adding a continuation to the last
+//! free slot `free_slot` will be `table_size`, which is when we see that the array is full.
 //!
 //! When the table is full, we double the size, copy the existing table, and add the second half to
 //! the free list. Since all indices are relative to the payload begin, they stay valid. We never
 //! shrink the table.
 
+use crate::gc::write_with_barrier;
 use crate::memory::{alloc_array, Memory};
 use crate::rts_trap_with;
 use crate::types::Value;
@@ -30,37 +33,60 @@ use motoko_rts_macros::ic_mem_fn;
 
 const INITIAL_SIZE: u32 = 256;
 
-// Skewed pointer to the `Array` object. This needs to be a skewed pointer to be able to pass its
-// location to the GC.
-static mut TABLE: Value = Value::from_scalar(0);
+pub struct ContinuationTable {
+    /// Skewed pointer to the `Array` object. This needs to be a skewed pointer to be able to pass
+    /// its location to the GC.
+    table: Value,
+
+    /// Number of currently live continuations
+    n_continuations: u32,
 
-// Number of currently live continuations
-static mut N_CONTINUATIONS: u32 = 0;
+    /// Next free slot
+    free_slot: u32,
+}
+
+impl ContinuationTable {
+    pub const fn new() -> ContinuationTable {
+        ContinuationTable {
+            table: Value::from_scalar(0),
+            n_continuations: 0,
+            free_slot: 0,
+        }
+    }
 
-// Next free slot
-static mut FREE_SLOT: u32 = 0;
+    fn initialized(&self) -> bool {
+        self.table.get_raw() != 0
+    }
+}
+
+/// The continuation table of the heap of `mem`
+unsafe fn get_table<M: Memory>(mem: &mut M) -> *mut ContinuationTable {
+    &mut mem.context().continuation_table
+}
 
 unsafe fn create_continuation_table<M: Memory>(mem: &mut M) {
-    TABLE = alloc_array(mem, INITIAL_SIZE);
-    FREE_SLOT = 0;
-    N_CONTINUATIONS = 0;
+    let table = get_table(mem);
+    (*table).table = alloc_array(mem, INITIAL_SIZE);
+    (*table).free_slot = 0;
+    (*table).n_continuations = 0;
 
-    let table = TABLE.as_array();
+    let array = (*table).table.as_array();
     for i in 0..INITIAL_SIZE {
-        table.set(i, Value::from_scalar(i + 1));
+        array.set(i, Value::from_scalar(i + 1));
     }
 }
 
 unsafe fn double_continuation_table<M: Memory>(mem: &mut M) {
-    let old_array = TABLE.as_array();
+    let table = get_table(mem);
+    let old_array = (*table).table.as_array();
     let old_size = old_array.len();
 
-    assert_eq!(FREE_SLOT, old_size);
+    assert_eq!((*table).free_slot, old_size);
 
     let new_size = old_size * 2;
 
-    TABLE = alloc_array(mem, new_size);
-    let new_array = TABLE.as_array();
+    (*table).table = alloc_array(mem, new_size);
+    let new_array = (*table).table.as_array();
 
     for i in 0..old_size {
         new_array.set(i, old_array.get(i));
@@ -71,17 +97,15 @@ unsafe fn double_continuation_table<M: Memory>(mem: &mut M) {
     }
 }
 
-pub unsafe fn table_initialized() -> bool {
-    TABLE.get_raw() != 0
-}
-
 #[ic_mem_fn]
 pub unsafe fn remember_continuation<M: Memory>(mem: &mut M, ptr: Value) -> u32 {
-    if !table_initialized() {
+    let table = get_table(mem);
+
+    if !(*table).initialized() {
         create_continuation_table(mem);
     }
 
-    if FREE_SLOT == TABLE.as_array().len() {
+    if (*table).free_slot == (*table).table.as_array().len() {
         double_continuation_table(mem);
     }
 
@@ -90,11 +114,12 @@ pub unsafe fn remember_continuation<M: Memory>(mem: &mut M, ptr: Value) -> u32 {
         rts_trap_with("remember_continuation: Argument is not a skewed pointer");
     }
 
-    let idx = FREE_SLOT;
+    let idx = (*table).free_slot;
 
-    FREE_SLOT = TABLE.as_array().get(idx).get_scalar();
-    TABLE.as_array().set(idx, ptr);
-    N_CONTINUATIONS += 1;
+    let array = (*table).table.as_array();
+    (*table).free_slot = array.get(idx).get_scalar();
+    write_with_barrier(mem, array.payload_addr().add(idx as usize), ptr);
+    (*table).n_continuations += 1;
 
     idx
 }
@@ -103,17 +128,19 @@ pub unsafe fn remember_continuation<M: Memory>(mem: &mut M, ptr: Value) -> u32 {
 // Invariant: keep this synchronised with compiler.ml (see future_array_index)
 const FUTURE_ARRAY_INDEX: u32 = 2;
 
-#[no_mangle]
-pub unsafe extern "C" fn peek_future_continuation(idx: u32) -> Value {
-    if !table_initialized() {
+#[ic_mem_fn]
+pub unsafe fn peek_future_continuation<M: Memory>(mem: &mut M, idx: u32) -> Value {
+    let table = get_table(mem);
+
+    if !(*table).initialized() {
         rts_trap_with("peek_future_continuation: Continuation table not allocated");
     }
 
-    if idx >= TABLE.as_array().len() {
+    if idx >= (*table).table.as_array().len() {
         rts_trap_with("peek_future_continuation: Continuation index out of range");
     }
 
-    let ptr = TABLE.as_array().get(idx);
+    let ptr = (*table).table.as_array().get(idx);
 
     if ptr.is_scalar() {
         rts_trap_with("peek_future_continuation: Continuation index not in table");
@@ -122,22 +149,26 @@ pub unsafe extern "C" fn peek_future_continuation(idx: u32) -> Value {
     ptr.as_array().get(FUTURE_ARRAY_INDEX)
 }
 
-#[no_mangle]
-pub unsafe extern "C" fn recall_continuation(idx: u32) -> Value {
-    if !table_initialized() {
+#[ic_mem_fn]
+pub unsafe fn recall_continuation<M: Memory>(mem: &mut M, idx: u32) -> Value {
+    let table = get_table(mem);
+
+    if !(*table).initialized() {
         rts_trap_with("recall_continuation: Continuation table not allocated");
     }
 
-    if idx >= TABLE.as_array().len() {
+    if idx >= (*table).table.as_array().len() {
         rts_trap_with("recall_continuation: Continuation index out of range");
     }
 
-    let ptr = TABLE.as_array().get(idx);
+    let array = (*table).table.as_array();
+    let ptr = array.get(idx);
 
-    TABLE.as_array().set(idx, Value::from_scalar(FREE_SLOT));
-    FREE_SLOT = idx;
+    let slot_addr = array.payload_addr().add(idx as usize);
+    write_with_barrier(mem, slot_addr, Value::from_scalar((*table).free_slot));
+    (*table).free_slot = idx;
 
-    N_CONTINUATIONS -= 1;
+    (*table).n_continuations -= 1;
 
     if ptr.is_scalar() {
         rts_trap_with("recall_continuation: Continuation index not in table");
@@ -146,22 +177,22 @@ pub unsafe extern "C" fn recall_continuation(idx: u32) -> Value {
     ptr
 }
 
-#[no_mangle]
-pub unsafe extern "C" fn continuation_count() -> u32 {
-    N_CONTINUATIONS
+#[ic_mem_fn]
+pub unsafe fn continuation_count<M: Memory>(mem: &mut M) -> u32 {
+    (*get_table(mem)).n_continuations
 }
 
-#[cfg(feature = "ic")]
-pub(crate) unsafe fn continuation_table_loc() -> *mut Value {
-    &mut TABLE
+/// Location of the pointer to the table array, to pass to the GC
+pub unsafe fn continuation_table_loc<M: Memory>(mem: &mut M) -> *mut Value {
+    &mut (*get_table(mem)).table
 }
 
-#[cfg(feature = "ic")]
-#[no_mangle]
-unsafe extern "C" fn continuation_table_size() -> u32 {
-    if !table_initialized() {
+#[ic_mem_fn(ic_only)]
+unsafe fn continuation_table_size<M: Memory>(mem: &mut M) -> u32 {
+    let table = get_table(mem);
+    if !(*table).initialized() {
         0
     } else {
-        TABLE.as_array().len()
+        (*table).table.as_array().len()
     }
 }
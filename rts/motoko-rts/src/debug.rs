@@ -32,7 +32,7 @@ pub unsafe fn dump_heap(
 }
 
 pub(crate) unsafe fn print_continuation_table(continuation_tbl_loc: *mut Value) {
-    if !crate::continuation_table::table_initialized() {
+    if (*continuation_tbl_loc).is_scalar() {
         println!(100, "Continuation table not initialized");
         return;
     }
@@ -218,10 +218,12 @@ pub(crate) unsafe fn print_boxed_object(buf: &mut WriteBuf, p: usize) {
             let concat = obj.as_concat();
             let _ = write!(
                 buf,
-                "<Concat n_bytes={:#x} obj1={:#x} obj2={:#x}>",
+                "<Concat n_bytes={:#x} obj1={:#x} obj2={:#x} depth={} n_chars={:#x}>",
                 (*concat).n_bytes.as_u32(),
                 (*concat).text1.get_raw(),
-                (*concat).text2.get_raw()
+                (*concat).text2.get_raw(),
+                (*concat).depth,
+                (*concat).n_chars
             );
         }
         TAG_ONE_WORD_FILLER => {
@@ -231,6 +233,10 @@ pub(crate) unsafe fn print_boxed_object(buf: &mut WriteBuf, p: usize) {
             let free_space = obj as *const FreeSpace;
             let _ = write!(buf, "<Free space {} words>", (*free_space).words.as_u32());
         }
+        TAG_WEAK_REF => {
+            let weak_ref = obj as *const WeakRef;
+            let _ = write!(buf, "<WeakRef target={:#x}>", (*weak_ref).target.get_raw());
+        }
         other => {
             let _ = write!(buf, "<??? {} ???>", other);
         }
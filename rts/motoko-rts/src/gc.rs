@@ -1,20 +1,29 @@
 pub mod copying;
+pub mod generational;
+pub mod incremental;
+pub mod large_object;
 pub mod mark_compact;
+pub mod mark_sweep;
+pub mod policy;
+pub mod stats;
+
+use crate::memory::Memory;
+use crate::types::Value;
 
 #[cfg(feature = "ic")]
 use crate::types::Bytes;
 
 #[cfg(feature = "ic")]
-unsafe fn should_do_gc(max_live: Bytes<u64>) -> bool {
-    use crate::memory::ic::{HP, LAST_HP};
-
-    // A factor of last heap size. We allow at most this much allocation before doing GC.
-    const HEAP_GROWTH_FACTOR: f64 = 1.5;
-
-    let heap_limit = core::cmp::min(
-        (f64::from(LAST_HP) * HEAP_GROWTH_FACTOR) as u64,
-        (u64::from(LAST_HP) + max_live.0) / 2,
-    );
+unsafe fn should_do_gc(mem: &mut crate::memory::ic::IcMemory, max_live: Bytes<u64>) -> bool {
+    let heap = mem.heap();
+    policy::get_policy().should_collect((*heap).last_hp, (*heap).hp, max_live)
+}
 
-    u64::from(HP) >= heap_limit
+/// Stores a pointer to a heap location, invoking the write barriers of the incremental GC (before
+/// the store) and the generational GC (after the store). The RTS uses this when updating pointer
+/// fields of objects that may have been allocated before the last GC increment.
+pub unsafe fn write_with_barrier<M: Memory>(mem: &mut M, location: *mut Value, value: Value) {
+    incremental::pre_write_barrier(mem, location);
+    *location = value;
+    generational::write_barrier::post_write_barrier(mem, location);
 }
@@ -1,42 +1,103 @@
+//! Semi-space copying GC. The semi-spaces are not fixed: to-space is either a free space left by
+//! the last collection, when it's large enough to hold the objects to evacuate, or the space after
+//! the heap pointer. Live objects are evacuated to their final locations, so there is no need to
+//! copy to-space back to the beginning of the heap after a collection.
+//!
+//! Large objects (see `large_object.rs`) are not evacuated. A live large object is marked in place
+//! instead, by overwriting its header (see `mark_large_object`), and stays where it is. After
+//! evacuating, from-space is walked to restore the headers of the large objects, and the space
+//! between them and to-space is turned into `FreeSpace` objects, which also keeps the heap
+//! parseable from the heap base. Without large objects, this is a single free space between the
+//! heap base and to-space, and from-space is not walked.
+//!
+//! The next collection evacuates into the largest of these free spaces when the objects allocated
+//! since then fit into it, so the live objects go back and forth between the ends of the free
+//! space and the heap does not grow. Whether the objects fit is estimated from the sizes recorded
+//! by the last collection. The estimate may be too low, e.g. when a large blob was shrunk since,
+//! to-space then continues after the heap pointer.
+
+use super::large_object::is_large_object;
+use super::mark_sweep::free_list::write_free_space;
+use super::stats;
 use crate::constants::WORD_SIZE;
-use crate::mem_utils::{memcpy_bytes, memcpy_words};
+use crate::mem_utils::memcpy_words;
 use crate::memory::Memory;
 use crate::types::*;
+use crate::visitor::pointer_to_dynamic_heap;
 
 use motoko_rts_macros::ic_mem_fn;
 
 #[ic_mem_fn(ic_only)]
-unsafe fn schedule_copying_gc<M: Memory>(mem: &mut M) {
-    // Half of the heap.
-    // NB. This expression is evaluated in compile time to a constant.
-    let max_live: Bytes<u64> =
-        Bytes(u64::from((crate::constants::WASM_HEAP_SIZE / 2).as_u32()) * u64::from(WORD_SIZE));
+unsafe fn schedule_copying_gc(mem: &mut crate::memory::ic::IcMemory) {
+    use crate::memory::ic;
+
+    let heap = mem.heap();
 
-    if super::should_do_gc(max_live) {
+    // Evacuating into a free space in the heap does not need more space, otherwise we need half of
+    // the heap.
+    // NB. The heap sizes are evaluated in compile time to constants.
+    let gap = to_space_gap(&mem.context().copying_gc, ic::get_heap_base(), (*heap).hp);
+    let max_live: Bytes<u64> = if gap.is_some() {
+        Bytes(u64::from(crate::constants::WASM_HEAP_SIZE.as_u32()) * u64::from(WORD_SIZE))
+    } else {
+        Bytes(u64::from((crate::constants::WASM_HEAP_SIZE / 2).as_u32()) * u64::from(WORD_SIZE))
+    };
+
+    if super::should_do_gc(mem, max_live) {
         copying_gc(mem);
     }
 }
 
+/// State of the copying GC between collections, kept in the `Context` of the heap
+pub struct CopyingState {
+    /// Start of the largest free space left by the last collection
+    gap_begin: usize,
+
+    /// End of the largest free space left by the last collection
+    gap_end: usize,
+
+    /// Total size of the free spaces left by the last collection
+    free: Bytes<u32>,
+
+    /// Total size of the large objects that survived the last collection
+    large: Bytes<u32>,
+}
+
+impl CopyingState {
+    pub const fn new() -> CopyingState {
+        CopyingState {
+            gap_begin: 0,
+            gap_end: 0,
+            free: Bytes(0),
+            large: Bytes(0),
+        }
+    }
+}
+
 #[ic_mem_fn(ic_only)]
-unsafe fn copying_gc<M: Memory>(mem: &mut M) {
+unsafe fn copying_gc(mem: &mut crate::memory::ic::IcMemory) {
+    use crate::continuation_table::continuation_table_loc;
     use crate::memory::ic;
 
+    let heap = mem.heap();
+    let continuation_table_ptr_loc = continuation_table_loc(mem);
+
     copying_gc_internal(
         mem,
         ic::get_heap_base(),
         // get_hp
-        || ic::HP as usize,
+        || (*heap).hp as usize,
         // set_hp
-        |hp| ic::HP = hp,
+        |hp| (*heap).hp = hp,
         ic::get_static_roots(),
-        crate::continuation_table::continuation_table_loc(),
+        continuation_table_ptr_loc,
         // note_live_size
-        |live_size| ic::MAX_LIVE = ::core::cmp::max(ic::MAX_LIVE, live_size),
+        |live_size| (*heap).max_live = ::core::cmp::max((*heap).max_live, live_size),
         // note_reclaimed
-        |reclaimed| ic::RECLAIMED += Bytes(u64::from(reclaimed.as_u32())),
+        |reclaimed| (*heap).reclaimed += Bytes(u64::from(reclaimed.as_u32())),
     );
 
-    ic::LAST_HP = ic::HP;
+    (*heap).last_hp = (*heap).hp;
 }
 
 pub unsafe fn copying_gc_internal<
@@ -55,77 +116,311 @@ pub unsafe fn copying_gc_internal<
     note_live_size: NoteLiveSize,
     note_reclaimed: NoteReclaimed,
 ) {
-    let begin_from_space = heap_base as usize;
     let end_from_space = get_hp();
-    let begin_to_space = end_from_space;
+
+    let state = &mem.context().copying_gc;
+    let used_before = (end_from_space - heap_base as usize) - state.free.as_usize();
+    let mut to_space = match to_space_gap(state, heap_base, end_from_space as u32) {
+        Some((gap_begin, gap_end)) => ToSpace::new(gap_begin, gap_end),
+        None => ToSpace::new(end_from_space, 0),
+    };
+
+    stats::begin_collection();
+    stats::begin_phase();
 
     let static_roots = static_roots.as_array();
 
     // Evacuate roots
-    evac_static_roots(mem, begin_from_space, begin_to_space, static_roots);
+    evac_static_roots(mem, &mut to_space, heap_base as usize, static_roots);
 
     if (*continuation_table_ptr_loc).is_ptr() {
-        evac(
-            mem,
-            begin_from_space,
-            begin_to_space,
-            continuation_table_ptr_loc as usize,
-        );
+        evac(mem, &mut to_space, continuation_table_ptr_loc as usize);
+    }
+
+    // Scavenge to-space and the marked large arrays
+    let mut p = to_space.begin;
+    let mut weak_refs = false;
+    loop {
+        p = to_space.skip_from_space(p);
+        if p < to_space.free {
+            let size = object_size(p);
+            weak_refs |= (p as *mut Obj).tag() == TAG_WEAK_REF;
+            scav(mem, &mut to_space, heap_base as usize, p);
+            p += size.to_bytes().as_usize();
+        } else if to_space.large_arrays != 0 {
+            let array = to_space.large_arrays as *mut Obj;
+            to_space.large_arrays = (*array).tag as usize;
+            scav_large_array(mem, &mut to_space, heap_base as usize, array);
+        } else {
+            break;
+        }
     }
 
-    // Scavenge to-space
-    let mut p = begin_to_space;
-    while p < get_hp() {
-        let size = object_size(p);
-        scav(mem, begin_from_space, begin_to_space, p);
-        p += size.to_bytes().as_usize();
+    to_space.finish_gap();
+
+    if weak_refs {
+        update_weak_refs(heap_base as usize, &to_space);
     }
 
-    let end_to_space = get_hp();
+    stats::end_phase(stats::Phase::Mark);
+
+    let mut free_spaces = FreeSpaces::new();
+    let new_hp = sweep_from_space(
+        &to_space,
+        heap_base as usize,
+        end_from_space,
+        &mut free_spaces,
+    );
 
     // Note the stats
-    let new_live_size = end_to_space - begin_to_space;
-    note_live_size(Bytes(new_live_size as u32));
+    let new_live_size = to_space.size + to_space.large;
+    note_live_size(new_live_size);
 
-    let reclaimed = (end_from_space - begin_from_space) - (end_to_space - begin_to_space);
+    let reclaimed = used_before - new_live_size.as_usize();
     note_reclaimed(Bytes(reclaimed as u32));
 
-    // Copy to-space to the beginning of from-space
-    memcpy_bytes(
-        begin_from_space,
-        begin_to_space,
-        Bytes((end_to_space - begin_to_space) as u32),
-    );
+    let state = &mut mem.context().copying_gc;
+    state.gap_begin = free_spaces.largest_begin;
+    state.gap_end = free_spaces.largest_end;
+    state.free = free_spaces.total;
+    state.large = to_space.large;
 
-    // Reset the heap pointer
-    let new_hp = begin_from_space + (end_to_space - begin_to_space);
     set_hp(new_hp as u32);
+
+    stats::end_collection();
+}
+
+/// The free space to evacuate into: the largest free space left by the last collection, when the
+/// objects allocated since then and the evacuated objects of the last collection fit into it
+fn to_space_gap(state: &CopyingState, heap_base: u32, heap_end: u32) -> Option<(usize, usize)> {
+    let gap_size = state.gap_end - state.gap_begin;
+    let used = (heap_end - heap_base) as usize - state.free.as_usize() - state.large.as_usize();
+    if gap_size != 0 && gap_size >= used {
+        Some((state.gap_begin, state.gap_end))
+    } else {
+        None
+    }
+}
+
+/// Allocation area of to-space. To-space starts either after the heap pointer, or in a free space
+/// left by the last collection. In the latter case it continues after the heap pointer when the
+/// evacuated objects don't fit into the free space.
+struct ToSpace {
+    /// Address of the first evacuated object
+    begin: usize,
+    /// Address of the next evacuated object
+    free: usize,
+    /// End of the free space when to-space starts in a free space, 0 otherwise. Once the rest of
+    /// the free space is turned into a free space object (see `finish_gap`), the end of the
+    /// objects evacuated there.
+    gap_end: usize,
+    /// Where to-space continues after the heap pointer when it starts in a free space, 0 if it
+    /// doesn't. Objects evacuated after the heap pointer are allocated with `Memory::alloc_words`
+    /// to grow the memory as needed.
+    overflow: usize,
+    /// Total size of the evacuated objects
+    size: Bytes<u32>,
+    /// Total size of the marked large objects
+    large: Bytes<u32>,
+    /// Marked large arrays that are not scavenged yet, linked through their headers. 0 when empty.
+    large_arrays: usize,
+}
+
+impl ToSpace {
+    fn new(begin: usize, gap_end: usize) -> ToSpace {
+        ToSpace {
+            begin,
+            free: begin,
+            gap_end,
+            overflow: 0,
+            size: Bytes(0),
+            large: Bytes(0),
+            large_arrays: 0,
+        }
+    }
+
+    fn in_gap(&self) -> bool {
+        self.gap_end != 0 && self.overflow == 0
+    }
+
+    unsafe fn alloc<M: Memory>(&mut self, mem: &mut M, size: Words<u32>) -> usize {
+        let bytes = size.to_bytes();
+        let addr = if !self.in_gap() {
+            let ptr = mem.alloc_words(size).get_ptr();
+            debug_assert_eq!(ptr, self.free);
+            ptr
+        } else if self.free + bytes.as_usize() <= self.gap_end {
+            self.free
+        } else {
+            // The objects don't fit into the free space, continue after the heap pointer
+            self.finish_gap();
+            let ptr = mem.alloc_words(size).get_ptr();
+            self.overflow = ptr;
+            ptr
+        };
+        self.free = addr + bytes.as_usize();
+        self.size += bytes;
+        addr
+    }
+
+    /// Turns the rest of the free space that to-space starts in into a free space object, so that
+    /// the space is parseable
+    unsafe fn finish_gap(&mut self) {
+        if self.in_gap() {
+            if self.free != self.gap_end {
+                let rest = Bytes((self.gap_end - self.free) as u32).to_words();
+                write_free_space(self.free as u32, rest);
+            }
+            self.gap_end = self.free;
+        }
+    }
+
+    /// Skips from-space when `p` is the end of the objects in the free space that to-space starts
+    /// in, and to-space continues after the heap pointer
+    fn skip_from_space(&self, p: usize) -> usize {
+        if self.overflow != 0 && p == self.gap_end {
+            self.overflow
+        } else {
+            p
+        }
+    }
+}
+
+/// Header of a marked large blob. The header of a marked large array is a link in
+/// `ToSpace::large_arrays`, which is word-aligned. Object tags are odd, so the headers of marked
+/// large objects are even.
+const MARKED_BLOB: u32 = 2;
+
+/// Whether the object in from-space with the given header is a marked large object
+fn is_marked_large_object(header: u32) -> bool {
+    header & 1 == 0
+}
+
+/// Marks a large object in from-space. Arrays are added to `ToSpace::large_arrays` to be
+/// scavenged.
+unsafe fn mark_large_object(to_space: &mut ToSpace, obj: *mut Obj, size: Words<u32>) {
+    if obj.tag() == TAG_BLOB {
+        (*obj).tag = MARKED_BLOB;
+    } else {
+        debug_assert_eq!(obj.tag(), TAG_ARRAY);
+        (*obj).tag = to_space.large_arrays as u32;
+        to_space.large_arrays = obj as usize;
+    }
+    to_space.large += size.to_bytes();
+    stats::note_marked();
+}
+
+/// Free spaces written by `sweep_from_space`
+struct FreeSpaces {
+    /// Total size of the free spaces
+    total: Bytes<u32>,
+    /// Start of the largest free space
+    largest_begin: usize,
+    /// End of the largest free space
+    largest_end: usize,
+}
+
+impl FreeSpaces {
+    fn new() -> FreeSpaces {
+        FreeSpaces {
+            total: Bytes(0),
+            largest_begin: 0,
+            largest_end: 0,
+        }
+    }
+
+    /// Turns the space between `begin` and `end` into a free space object, if it's not empty
+    unsafe fn add(&mut self, begin: usize, end: usize) {
+        if begin == end {
+            return;
+        }
+        let size = Bytes((end - begin) as u32);
+        write_free_space(begin as u32, size.to_words());
+        self.total += size;
+        if end - begin > self.largest_end - self.largest_begin {
+            self.largest_begin = begin;
+            self.largest_end = end;
+        }
+    }
+}
+
+/// Turns the space in from-space that is not a marked large object or to-space into free space,
+/// and restores the headers of the marked large objects. Records the sizes of the objects that were
+/// not evacuated or marked in the GC stats when enabled. Returns the new heap pointer.
+///
+/// From-space is only walked when there are large objects or the stats are enabled. The size of an
+/// evacuated object is read from its copy in to-space, as the header of the original object is
+/// overwritten with a forwarding pointer. Free space is not recorded, it was not freed in this
+/// collection.
+unsafe fn sweep_from_space(
+    to_space: &ToSpace,
+    heap_base: usize,
+    end_from_space: usize,
+    free_spaces: &mut FreeSpaces,
+) -> usize {
+    if to_space.large == Bytes(0) && !stats::freed_by_tag() {
+        // Everything before to-space is free
+        free_spaces.add(heap_base, to_space.begin);
+        if to_space.overflow != 0 {
+            free_spaces.add(to_space.gap_end, end_from_space);
+        }
+        return to_space.free;
+    }
+
+    // Start of the space after the last marked large object or the objects evacuated into a free
+    // space
+    let mut free_start = heap_base;
+    let mut p = heap_base;
+    while p < end_from_space {
+        if p == to_space.begin && to_space.gap_end != 0 {
+            free_spaces.add(free_start, p);
+            p = to_space.gap_end;
+            free_start = p;
+            continue;
+        }
+
+        let obj = p as *mut Obj;
+        let header = (*obj).tag;
+        if is_marked_large_object(header) {
+            (*obj).tag = if header == MARKED_BLOB {
+                TAG_BLOB
+            } else {
+                TAG_ARRAY
+            };
+            free_spaces.add(free_start, p);
+            p += object_size(p).to_bytes().as_usize();
+            free_start = p;
+        } else if header == TAG_FWD_PTR {
+            let fwd = (*(obj as *const FwdPtr)).fwd.get_ptr();
+            p += object_size(fwd).to_bytes().as_usize();
+        } else {
+            let size = object_size(p).to_bytes();
+            if stats::freed_by_tag() && header != TAG_FREE_SPACE && header != TAG_ONE_WORD_FILLER {
+                stats::note_freed(header, size);
+            }
+            p += size.as_usize();
+        }
+    }
+
+    // Whether to-space continues after from-space
+    if to_space.gap_end == 0 || to_space.overflow != 0 {
+        free_spaces.add(free_start, end_from_space);
+        to_space.free
+    } else {
+        // The space after the last large object or to-space is not part of the heap any more
+        free_start
+    }
 }
 
 /// Evacuate (copy) an object in from-space to to-space.
 ///
 /// Arguments:
 ///
-/// - begin_from_space: Where the dynamic heap starts. Used for two things:
-///
-///   - An object is static if its address is below this value. These objects either don't point to
-///     dynamic heap, or are listed in static_roots array. Objects in static_roots are scavenged
-///     separately in `evac_static_roots` below. So we skip these objects here.
-///
-///   - After all objects are evacuated we move to-space to from-space, to be able to do that the
-///     pointers need to point to their (eventual) locations in from-space, which is calculated with
-///     `address_in_to_space - begin_to_space + begin_from_space`.
-///
-/// - begin_to_space: Where to-space starts. See above for how this is used.
+/// - to_space: Where to copy the object.
 ///
 /// - ptr_loc: Location of the object to evacuate, e.g. an object field address.
 ///
-unsafe fn evac<M: Memory>(
-    mem: &mut M,
-    begin_from_space: usize,
-    begin_to_space: usize,
-    ptr_loc: usize,
-) {
+unsafe fn evac<M: Memory>(mem: &mut M, to_space: &mut ToSpace, ptr_loc: usize) {
     // Field holds a skewed pointer to the object to evacuate
     let ptr_loc = ptr_loc as *mut Value;
 
@@ -141,53 +436,112 @@ unsafe fn evac<M: Memory>(
         return;
     }
 
+    // Large objects are not moved
+    if is_marked_large_object(obj.tag()) {
+        return;
+    }
+
     let obj_size = object_size(obj as usize);
 
+    if is_large_object(obj, obj.tag()) {
+        mark_large_object(to_space, obj, obj_size);
+        return;
+    }
+
     // Allocate space in to-space for the object
-    let obj_addr = mem.alloc_words(obj_size).get_ptr();
+    let obj_addr = to_space.alloc(mem, obj_size);
 
     // Copy object to to-space
     memcpy_words(obj_addr, obj as usize, obj_size);
 
-    // Final location of the object after copying to-space back to from-space
-    let obj_loc = (obj_addr - begin_to_space) + begin_from_space;
+    stats::note_marked();
+    stats::note_moved(obj_size.to_bytes());
 
     // Set forwarding pointer
     let fwd = obj as *mut FwdPtr;
     (*fwd).header.tag = TAG_FWD_PTR;
-    (*fwd).fwd = Value::from_ptr(obj_loc);
+    (*fwd).fwd = Value::from_ptr(obj_addr);
 
     // Update evacuated field
-    *ptr_loc = Value::from_ptr(obj_loc);
+    *ptr_loc = Value::from_ptr(obj_addr);
 }
 
-unsafe fn scav<M: Memory>(mem: &mut M, begin_from_space: usize, begin_to_space: usize, obj: usize) {
+/// Evacuate the objects pointed by the fields of an object. Objects below `heap_base` are static.
+/// These objects either don't point to dynamic heap, or are listed in static_roots array. Objects
+/// in static_roots are scavenged separately in `evac_static_roots` below. So we skip these objects
+/// here.
+unsafe fn scav<M: Memory>(mem: &mut M, to_space: &mut ToSpace, heap_base: usize, obj: usize) {
     let obj = obj as *mut Obj;
 
+    let mut ctx = (mem, to_space);
     crate::visitor::visit_pointer_fields(
-        mem,
+        &mut ctx,
         obj,
         obj.tag(),
-        begin_from_space,
-        |mem, field_addr| {
-            evac(mem, begin_from_space, begin_to_space, field_addr as usize);
+        heap_base,
+        |ctx, field_addr| {
+            evac(ctx.0, ctx.1, field_addr as usize);
+        },
+        |_, _, arr| arr.len(),
+    );
+}
+
+/// Evacuate the objects pointed by the elements of a marked large array
+unsafe fn scav_large_array<M: Memory>(
+    mem: &mut M,
+    to_space: &mut ToSpace,
+    heap_base: usize,
+    array: *mut Obj,
+) {
+    // The header is a link in `ToSpace::large_arrays`, so the tag is passed separately
+    let mut ctx = (mem, to_space);
+    crate::visitor::visit_pointer_fields(
+        &mut ctx,
+        array,
+        TAG_ARRAY,
+        heap_base,
+        |ctx, field_addr| {
+            evac(ctx.0, ctx.1, field_addr as usize);
         },
         |_, _, arr| arr.len(),
     );
 }
 
+/// Weak references don't evacuate their targets. Once all live objects are evacuated, updates the
+/// weak references in to-space with evacuated targets, and clears the others. Weak references to
+/// marked large objects are not changed.
+unsafe fn update_weak_refs(heap_base: usize, to_space: &ToSpace) {
+    let mut p = to_space.skip_from_space(to_space.begin);
+    while p < to_space.free {
+        let obj = p as *mut Obj;
+        if obj.tag() == TAG_WEAK_REF {
+            let weak_ref = obj as *mut WeakRef;
+            let target_addr = &mut (*weak_ref).target;
+            if pointer_to_dynamic_heap(target_addr, heap_base) {
+                let target = (*target_addr).as_obj();
+                if target.tag() == TAG_FWD_PTR {
+                    *target_addr = (*(target as *const FwdPtr)).fwd;
+                } else if !is_marked_large_object(target.tag()) {
+                    crate::weak_ref::clear(weak_ref);
+                }
+            }
+        }
+        p = to_space.skip_from_space(p + object_size(p).to_bytes().as_usize());
+    }
+}
+
 // We have a special evacuation routine for "static roots" array: we don't evacuate elements of
 // "static roots", we just scavenge them.
 unsafe fn evac_static_roots<M: Memory>(
     mem: &mut M,
-    begin_from_space: usize,
-    begin_to_space: usize,
+    to_space: &mut ToSpace,
+    heap_base: usize,
     roots: *mut Array,
 ) {
     // The array and the objects pointed by the array are all static so we don't evacuate them. We
     // only evacuate fields of objects in the array.
     for i in 0..roots.len() {
         let obj = roots.get(i);
-        scav(mem, begin_from_space, begin_to_space, obj.get_ptr());
+        scav(mem, to_space, heap_base, obj.get_ptr());
     }
 }
@@ -0,0 +1,189 @@
+//! Generational GC on top of the mark-compact collector.
+//!
+//! The dynamic heap is split in two generations: the old generation is the part of the heap that
+//! survived the last collection (below `last_hp`), the young generation is everything allocated
+//! since then. A young generation collection marks and compacts only the young generation. The
+//! roots are the usual static roots and the continuation table, plus the old generation locations
+//! that may point to the young generation. These locations are recorded in the remembered set by
+//! the write barrier (see `write_barrier.rs`). After a collection all surviving objects are
+//! promoted to the old generation and the remembered set is emptied.
+//!
+//! Garbage in the old generation is only reclaimed by a full collection, which collects the whole
+//! dynamic heap like `compacting_gc`. A full collection is done when the old generation grows past
+//! `OLD_GENERATION_LIMIT`.
+
+pub mod remembered_set;
+pub mod write_barrier;
+
+use super::mark_compact::dedup::take_dedup_request;
+use super::mark_compact::{mark_compact, mark_root_field};
+use crate::memory::Memory;
+use crate::types::*;
+
+use motoko_rts_macros::ic_mem_fn;
+
+/// Which part of the heap to collect
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Strategy {
+    /// Collect only the young generation
+    Young,
+    /// Collect the whole dynamic heap
+    Full,
+}
+
+/// Minimum size of the old generation, in bytes, before doing a full collection
+#[cfg(feature = "ic")]
+const MIN_OLD_GENERATION_LIMIT: u64 = 32 * 1024 * 1024;
+
+/// After a full collection, the old generation can grow by this factor of the live heap before the
+/// next full collection
+#[cfg(feature = "ic")]
+const OLD_GENERATION_GROWTH_FACTOR: f64 = 2.0;
+
+/// Size of the young generation, in bytes, that triggers a young generation collection regardless
+/// of the heap growth
+#[cfg(feature = "ic")]
+const YOUNG_GENERATION_LIMIT: u64 = 8 * 1024 * 1024;
+
+/// Old generation size (in bytes) above which the next collection is a full collection
+#[cfg(feature = "ic")]
+static mut OLD_GENERATION_LIMIT: u64 = MIN_OLD_GENERATION_LIMIT;
+
+#[ic_mem_fn(ic_only)]
+unsafe fn schedule_generational_gc(mem: &mut crate::memory::ic::IcMemory) {
+    let heap = mem.heap();
+
+    // Same limit as the compacting GC, as we need space for the mark stack and the bitmap
+    let slack: u64 = 512 * 1024 * 1024;
+    let heap_size_bytes: u64 = u64::from(crate::constants::WASM_HEAP_SIZE.as_u32())
+        * u64::from(crate::constants::WORD_SIZE);
+    let max_bitmap_size_bytes = heap_size_bytes / 32;
+    // NB. `max_live` is evaluated in compile time to a constant
+    let max_live: Bytes<u64> = Bytes(heap_size_bytes - slack - max_bitmap_size_bytes);
+
+    if super::should_do_gc(mem, max_live)
+        || u64::from((*heap).hp - (*heap).last_hp) >= YOUNG_GENERATION_LIMIT
+        || super::mark_compact::dedup::dedup_requested()
+    {
+        generational_gc(mem);
+    }
+}
+
+#[ic_mem_fn(ic_only)]
+unsafe fn generational_gc(mem: &mut crate::memory::ic::IcMemory) {
+    use crate::continuation_table::continuation_table_loc;
+    use crate::memory::ic;
+
+    let heap = mem.heap();
+    let continuation_table_ptr_loc = continuation_table_loc(mem);
+
+    let heap_base = ic::get_aligned_heap_base();
+
+    // Collect the old generation as well when forced by the heap limit (see `set_heap_limit`), or
+    // when blob deduplication is requested, as only full collections deduplicate
+    let strategy = if (*heap).heap_limit_exceeded
+        || super::mark_compact::dedup::dedup_requested()
+        || u64::from((*heap).last_hp - heap_base) > OLD_GENERATION_LIMIT
+    {
+        Strategy::Full
+    } else {
+        Strategy::Young
+    };
+
+    generational_gc_internal(
+        mem,
+        heap_base,
+        (*heap).last_hp,
+        strategy,
+        // get_hp
+        || (*heap).hp as usize,
+        // set_hp
+        |hp| (*heap).hp = hp,
+        ic::get_static_roots(),
+        continuation_table_ptr_loc,
+        // note_live_size
+        |live_size| (*heap).max_live = ::core::cmp::max((*heap).max_live, live_size),
+        // note_reclaimed
+        |reclaimed| (*heap).reclaimed += Bytes(u64::from(reclaimed.as_u32())),
+    );
+
+    (*heap).last_hp = (*heap).hp;
+
+    if strategy == Strategy::Full {
+        let live = u64::from((*heap).hp - heap_base);
+        OLD_GENERATION_LIMIT = core::cmp::max(
+            MIN_OLD_GENERATION_LIMIT,
+            (live as f64 * OLD_GENERATION_GROWTH_FACTOR) as u64,
+        );
+    }
+}
+
+/// Arguments:
+///
+/// - heap_base: Start of the dynamic heap. Needs to be 32-byte aligned.
+///
+/// - last_hp: Heap pointer after the last collection, i.e. start of the young generation.
+///
+/// - strategy: Whether to collect the young generation or the whole dynamic heap.
+///
+/// The other arguments are the same as in `compacting_gc_internal`.
+pub unsafe fn generational_gc_internal<
+    M: Memory,
+    GetHp: Fn() -> usize,
+    SetHp: Fn(u32),
+    NoteLiveSize: Fn(Bytes<u32>),
+    NoteReclaimed: Fn(Bytes<u32>),
+>(
+    mem: &mut M,
+    heap_base: u32,
+    last_hp: u32,
+    strategy: Strategy,
+    get_hp: GetHp,
+    set_hp: SetHp,
+    static_roots: Value,
+    continuation_table_ptr_loc: *mut Value,
+    note_live_size: NoteLiveSize,
+    note_reclaimed: NoteReclaimed,
+) {
+    let old_hp = get_hp() as u32;
+
+    assert_eq!(heap_base % 32, 0);
+    debug_assert!(heap_base <= last_hp && last_hp <= old_hp);
+
+    let collection_base = match strategy {
+        Strategy::Young => last_hp,
+        Strategy::Full => heap_base,
+    };
+
+    mark_compact(
+        mem,
+        set_hp,
+        collection_base,
+        old_hp,
+        static_roots,
+        continuation_table_ptr_loc,
+        // Blobs are deduplicated only in full collections, the request is kept until the next one
+        strategy == Strategy::Full && take_dedup_request(),
+        // reuse_gaps: objects allocated in the free space would be in the old generation, see
+        // `large_object.rs`
+        false,
+        // mark_additional_roots
+        |mem| {
+            if strategy == Strategy::Young {
+                for location in remembered_set::iter() {
+                    mark_root_field(mem, location, collection_base);
+                }
+            }
+        },
+    );
+
+    // All surviving objects are now in the old generation
+    remembered_set::clear();
+    write_barrier::YOUNG_GENERATION_START = get_hp();
+
+    let reclaimed = old_hp - (get_hp() as u32);
+    note_reclaimed(Bytes(reclaimed));
+
+    let live = get_hp() as u32 - heap_base;
+    note_live_size(Bytes(live));
+}
@@ -0,0 +1,153 @@
+//! The remembered set of the generational GC: locations in the old generation that may point to
+//! the young generation. Filled by the write barrier, used as additional roots in young
+//! generation collections, and emptied after every collection.
+//!
+//! The set is a hash set of locations with open addressing and linear probing, stored in the
+//! payload of a blob. Empty slots are zero, which is never a valid location. When the set gets too
+//! full a new blob with twice the capacity is allocated and the entries are moved there, the old
+//! blob is left for the GC.
+//!
+//! The blob is allocated in the young generation and it's not reachable from the roots, so it's
+//! reclaimed by the next collection. It is only read while marking, before it gets overwritten by
+//! compaction.
+
+use crate::mem_utils::memzero;
+use crate::memory::{alloc_blob, Memory};
+use crate::types::{Blob, Value, Words};
+
+use core::ptr::null_mut;
+
+/// Initial number of slots. Needs to be a power of two.
+pub const INIT_CAPACITY: u32 = 64;
+
+/// Blob holding the slots, or null when the set is empty
+static mut SET_BLOB_PTR: *mut Blob = null_mut();
+
+/// Number of slots in the set
+static mut CAPACITY: u32 = 0;
+
+/// Number of locations in the set
+static mut COUNT: u32 = 0;
+
+unsafe fn slots() -> *mut usize {
+    SET_BLOB_PTR.payload_addr() as *mut usize
+}
+
+unsafe fn alloc_slots<M: Memory>(mem: &mut M, capacity: u32) {
+    SET_BLOB_PTR = alloc_blob(mem, Words(capacity).to_bytes()).as_blob_mut();
+    CAPACITY = capacity;
+    COUNT = 0;
+    memzero(slots() as usize, Words(capacity));
+}
+
+/// Fibonacci hashing of the word index of the location
+fn slot_index(location: usize, capacity: u32) -> u32 {
+    let word_idx = (location / crate::constants::WORD_SIZE as usize) as u32;
+    word_idx.wrapping_mul(2654435769) & (capacity - 1)
+}
+
+/// Adds the location to the slots without checking the load factor. Returns whether the location
+/// was new.
+unsafe fn insert_slot(location: usize) -> bool {
+    let mut idx = slot_index(location, CAPACITY);
+    loop {
+        let slot = slots().add(idx as usize);
+        if *slot == 0 {
+            *slot = location;
+            COUNT += 1;
+            return true;
+        }
+        if *slot == location {
+            return false;
+        }
+        idx = (idx + 1) & (CAPACITY - 1);
+    }
+}
+
+/// Doubles the capacity of the set
+unsafe fn grow_set<M: Memory>(mem: &mut M) {
+    let old_slots = slots();
+    let old_capacity = CAPACITY;
+
+    alloc_slots(mem, old_capacity * 2);
+
+    for i in 0..old_capacity as usize {
+        let location = *old_slots.add(i);
+        if location != 0 {
+            insert_slot(location);
+        }
+    }
+}
+
+/// Adds a location to the remembered set
+pub unsafe fn insert<M: Memory>(mem: &mut M, location: *mut Value) {
+    if SET_BLOB_PTR.is_null() {
+        alloc_slots(mem, INIT_CAPACITY);
+    }
+
+    // Keep the load factor below 3/4
+    if (COUNT + 1) * 4 > CAPACITY * 3 {
+        grow_set(mem);
+    }
+
+    insert_slot(location as usize);
+}
+
+/// Returns whether the location is in the remembered set
+pub unsafe fn contains(location: *mut Value) -> bool {
+    if SET_BLOB_PTR.is_null() {
+        return false;
+    }
+
+    let location = location as usize;
+    let mut idx = slot_index(location, CAPACITY);
+    loop {
+        let slot = *slots().add(idx as usize);
+        if slot == 0 {
+            return false;
+        }
+        if slot == location {
+            return true;
+        }
+        idx = (idx + 1) & (CAPACITY - 1);
+    }
+}
+
+/// Number of locations in the remembered set
+pub unsafe fn len() -> u32 {
+    COUNT
+}
+
+/// Empties the remembered set. The blob holding the slots is left for the GC.
+pub unsafe fn clear() {
+    SET_BLOB_PTR = null_mut();
+    CAPACITY = 0;
+    COUNT = 0;
+}
+
+pub struct RememberedSetIter {
+    /// Index of the next slot to look at
+    next_slot: u32,
+}
+
+/// Iterates the locations in the remembered set. The set should not be modified while iterating.
+pub unsafe fn iter() -> RememberedSetIter {
+    RememberedSetIter { next_slot: 0 }
+}
+
+impl Iterator for RememberedSetIter {
+    type Item = *mut Value;
+
+    fn next(&mut self) -> Option<*mut Value> {
+        unsafe {
+            while self.next_slot < CAPACITY {
+                let location = *slots().add(self.next_slot as usize);
+                self.next_slot += 1;
+                if location != 0 {
+                    return Some(location as *mut Value);
+                }
+            }
+            None
+        }
+    }
+}
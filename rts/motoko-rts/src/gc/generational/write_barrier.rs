@@ -0,0 +1,31 @@
+//! The write barrier of the generational GC.
+//!
+//! Generated code (and the RTS, via `gc::write_with_barrier`) calls `post_write_barrier` after
+//! storing a pointer into an object that may be in the old generation: `MutBox` fields (mutable
+//! variables and object fields), array elements, and RTS-internal objects like the continuation
+//! table and text iterators. If the location is in the old generation and the stored value points
+//! to the young generation the location is added to the remembered set.
+
+use super::remembered_set;
+use crate::memory::Memory;
+use crate::types::{is_ptr, unskew, Value};
+
+use motoko_rts_macros::ic_mem_fn;
+
+/// Start of the young generation. Set by the generational GC after each collection.
+///
+/// Locations below this address are in the old generation (or in the static heap). It's 0 until
+/// the first generational collection, which makes the barrier a no-op when another GC is used.
+pub static mut YOUNG_GENERATION_START: usize = 0;
+
+/// Records the location in the remembered set if it's an old generation location pointing to the
+/// young generation. Needs to be called after the store.
+#[ic_mem_fn]
+pub unsafe fn post_write_barrier<M: Memory>(mem: &mut M, location: *mut Value) {
+    if (location as usize) < YOUNG_GENERATION_START {
+        let value = (*location).get_raw();
+        if is_ptr(value) && unskew(value as usize) >= YOUNG_GENERATION_START {
+            remembered_set::insert(mem, location);
+        }
+    }
+}
@@ -0,0 +1,483 @@
+//! Incremental version of the mark-compact GC, for heaps that are too large to mark in a single
+//! message.
+//!
+//! A collection cycle starts by allocating the bitmap and the mark stack, and marking the roots.
+//! Then every message marks a bounded number of objects (the work budget) until the mark stack is
+//! empty, and the mutator runs in between. The heap at the start of the cycle (`MARK_HEAP_BASE` to
+//! `MARK_HEAP_END`) is the part being collected:
+//!
+//! - Objects allocated during the cycle (above `MARK_HEAP_END`) are live in this cycle, and are
+//!   not marked or scanned.
+//!
+//! - Overwriting a pointer field while marking invokes `pre_write_barrier`, which marks the old
+//!   value (snapshot-at-the-beginning). Together with the previous point this guarantees that
+//!   every object reachable at the start of the cycle is marked, so no live object is missed.
+//!
+//! - Weak references don't mark their targets, so reading the target of a weak reference while
+//!   marking invokes `weak_ref_read_barrier`, which marks the target. Weak references with
+//!   unmarked targets in the heap being collected are cleared in the last increment.
+//!
+//! Marking does not thread pointers (see `mark_compact.rs`) as the mutator needs to be able to
+//! read the fields. Threading and compaction are done in the last increment, once marking is
+//! done: the objects allocated during the cycle are compacted together with the marked objects.
+//! Compaction cannot be interleaved with the mutator as the objects are not readable while their
+//! headers are threaded, and moving objects would require a read barrier. Large objects are not
+//! moved, see `large_object.rs`.
+//!
+//! Only marking is incremental. Threading the pointers and compacting (the `update_refs` pass of
+//! the mark-compact GC) take time linear in the size of the heap in a single message, so the last
+//! increment can still hit the message instruction limit with very large heaps. Spreading
+//! compaction over several messages would need a read barrier in the generated code, which the
+//! compiler doesn't emit.
+
+use super::large_object::compaction_target;
+use super::mark_compact::bitmap::BITMAP_ITER_END;
+use super::mark_compact::bitmap::{alloc_bitmap, free_bitmap, get_bit, iter_bits, set_bit};
+use super::mark_compact::mark_stack::{
+    alloc_mark_stack, free_mark_stack, mark_stack_blob, pop_mark_stack, push_mark_stack,
+};
+use super::mark_compact::{
+    note_freed_objects, thread, thread_fwd_pointers, threaded_tag, unthread,
+};
+use super::mark_sweep::free_list;
+use super::stats;
+use crate::constants::WORD_SIZE;
+use crate::mem_utils::memcpy_words;
+use crate::memory::Memory;
+use crate::types::*;
+use crate::visitor::{pointer_to_dynamic_heap, visit_pointer_fields};
+
+use motoko_rts_macros::ic_mem_fn;
+
+/// Work budget of a GC increment, in number of objects and fields visited while marking
+#[cfg(feature = "ic")]
+const INCREMENT_BUDGET: u32 = 2_000_000;
+
+/// Budget to run the collection cycle to completion
+pub const UNLIMITED_BUDGET: u32 = u32::MAX;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Phase {
+    /// No collection in progress
+    Idle,
+    /// Marking in progress, the mutator may run before the next increment
+    Mark,
+}
+
+static mut PHASE: Phase = Phase::Idle;
+
+/// Start of the heap being collected in the current cycle
+static mut MARK_HEAP_BASE: u32 = 0;
+
+/// End of the heap being collected in the current cycle. Objects after this were allocated during
+/// the cycle. The bitmap is allocated here.
+static mut MARK_HEAP_END: u32 = 0;
+
+/// Returns the phase of the current collection cycle
+pub unsafe fn phase() -> Phase {
+    PHASE
+}
+
+#[ic_mem_fn(ic_only)]
+unsafe fn schedule_incremental_gc(mem: &mut crate::memory::ic::IcMemory) {
+    // Same limit as the compacting GC, as we need space for the mark stack and the bitmap
+    let slack: u64 = 512 * 1024 * 1024;
+    let heap_size_bytes: u64 =
+        u64::from(crate::constants::WASM_HEAP_SIZE.as_u32()) * u64::from(WORD_SIZE);
+    let max_bitmap_size_bytes = heap_size_bytes / 32;
+    // NB. `max_live` is evaluated in compile time to a constant
+    let max_live: Bytes<u64> = Bytes(heap_size_bytes - slack - max_bitmap_size_bytes);
+
+    if PHASE != Phase::Idle {
+        // Finish the cycle in this increment if the mutator allocated more than the size of the
+        // heap being collected, to bound the heap growth during a cycle
+        let allocated = (*mem.heap()).hp - MARK_HEAP_END;
+        let budget = if allocated > MARK_HEAP_END - MARK_HEAP_BASE {
+            UNLIMITED_BUDGET
+        } else {
+            INCREMENT_BUDGET
+        };
+        incremental_gc_increment(mem, budget);
+    } else if super::should_do_gc(mem, max_live) {
+        incremental_gc_increment(mem, INCREMENT_BUDGET);
+    }
+}
+
+/// Runs the current collection cycle, or a new one, to completion
+#[ic_mem_fn(ic_only)]
+unsafe fn incremental_gc(mem: &mut crate::memory::ic::IcMemory) {
+    incremental_gc_increment(mem, UNLIMITED_BUDGET);
+}
+
+#[cfg(feature = "ic")]
+unsafe fn incremental_gc_increment(mem: &mut crate::memory::ic::IcMemory, budget: u32) {
+    use crate::continuation_table::continuation_table_loc;
+    use crate::memory::ic;
+
+    let heap = mem.heap();
+    let continuation_table_ptr_loc = continuation_table_loc(mem);
+
+    let done = incremental_gc_internal(
+        mem,
+        ic::get_aligned_heap_base(),
+        budget,
+        // get_hp
+        || (*heap).hp as usize,
+        // set_hp
+        |hp| (*heap).hp = hp,
+        ic::get_static_roots(),
+        continuation_table_ptr_loc,
+        // note_live_size
+        |live_size| (*heap).max_live = ::core::cmp::max((*heap).max_live, live_size),
+        // note_reclaimed
+        |reclaimed| (*heap).reclaimed += Bytes(u64::from(reclaimed.as_u32())),
+    );
+
+    if done {
+        (*heap).last_hp = (*heap).hp;
+    }
+}
+
+/// Runs one increment of the incremental GC, starting a new collection cycle if there isn't one in
+/// progress. Returns whether the cycle is finished.
+///
+/// Arguments:
+///
+/// - heap_base: Start of the dynamic heap. Needs to be 32-byte aligned.
+///
+/// - budget: Maximum number of objects and fields to visit while marking. `UNLIMITED_BUDGET`
+///   finishes the cycle.
+///
+/// The other arguments are the same as in `compacting_gc_internal`.
+pub unsafe fn incremental_gc_internal<
+    M: Memory,
+    GetHp: Fn() -> usize,
+    SetHp: Fn(u32),
+    NoteLiveSize: Fn(Bytes<u32>),
+    NoteReclaimed: Fn(Bytes<u32>),
+>(
+    mem: &mut M,
+    heap_base: u32,
+    budget: u32,
+    get_hp: GetHp,
+    set_hp: SetHp,
+    static_roots: Value,
+    continuation_table_ptr_loc: *mut Value,
+    note_live_size: NoteLiveSize,
+    note_reclaimed: NoteReclaimed,
+) -> bool {
+    assert_eq!(heap_base % 32, 0);
+
+    if PHASE == Phase::Idle {
+        start_cycle(
+            mem,
+            heap_base,
+            get_hp() as u32,
+            static_roots,
+            continuation_table_ptr_loc,
+        );
+    }
+
+    debug_assert_eq!(MARK_HEAP_BASE, heap_base);
+
+    stats::begin_phase();
+    let marking_done = mark_increment(mem, budget);
+    stats::end_phase(stats::Phase::Mark);
+
+    if !marking_done {
+        return false;
+    }
+
+    let old_hp = get_hp() as u32;
+
+    compact(
+        mem,
+        set_hp,
+        static_roots,
+        continuation_table_ptr_loc,
+        old_hp,
+    );
+
+    PHASE = Phase::Idle;
+
+    stats::end_collection();
+
+    let reclaimed = old_hp - (get_hp() as u32);
+    note_reclaimed(Bytes(reclaimed));
+
+    let live = get_hp() as u32 - heap_base;
+    note_live_size(Bytes(live));
+
+    true
+}
+
+/// Allocates the bitmap and the mark stack for the heap between `heap_base` and `heap_end`, and
+/// marks the roots
+unsafe fn start_cycle<M: Memory>(
+    mem: &mut M,
+    heap_base: u32,
+    heap_end: u32,
+    static_roots: Value,
+    continuation_table_ptr_loc: *mut Value,
+) {
+    MARK_HEAP_BASE = heap_base;
+    MARK_HEAP_END = heap_end;
+
+    stats::begin_collection();
+
+    // Objects allocated during the cycle need to be after `heap_end`, so stop reusing the free
+    // space left by the last cycle (see `large_object.rs`)
+    free_list::clear();
+
+    alloc_bitmap(mem, Bytes(heap_end - heap_base), heap_base / WORD_SIZE);
+    alloc_mark_stack(mem);
+
+    PHASE = Phase::Mark;
+
+    let root_array = static_roots.as_array();
+    for i in 0..root_array.len() {
+        let mutbox = root_array.get(i).as_obj() as *mut MutBox;
+        debug_assert!((mutbox as u32) < heap_base);
+        mark_object(mem, (*mutbox).field);
+    }
+
+    mark_object(mem, *continuation_table_ptr_loc);
+}
+
+/// Marks an object in the heap being collected and pushes it to the mark stack. Other values are
+/// ignored.
+unsafe fn mark_object<M: Memory>(mem: &mut M, value: Value) {
+    if !value.is_ptr() {
+        return;
+    }
+
+    let obj = value.get_ptr() as u32;
+
+    // Objects before `MARK_HEAP_BASE` are static, objects after `MARK_HEAP_END` were allocated in
+    // this cycle
+    if obj < MARK_HEAP_BASE || obj >= MARK_HEAP_END {
+        return;
+    }
+
+    let obj_idx = obj / WORD_SIZE;
+
+    if get_bit(obj_idx) {
+        return;
+    }
+
+    set_bit(obj_idx);
+    push_mark_stack(mem, obj as usize, value.tag());
+
+    stats::note_marked();
+}
+
+/// Marks until the mark stack is empty or the budget is exhausted. Returns whether marking is
+/// done.
+unsafe fn mark_increment<M: Memory>(mem: &mut M, budget: u32) -> bool {
+    let mut work: u32 = 0;
+
+    while work < budget {
+        match pop_mark_stack(mem) {
+            None => return true,
+            Some((obj, tag)) => {
+                work = work.saturating_add(mark_fields(mem, obj as *mut Obj, tag) + 1);
+            }
+        }
+    }
+
+    false
+}
+
+/// Marks the objects pointed by the fields of an object. Returns the number of fields visited.
+unsafe fn mark_fields<M: Memory>(mem: &mut M, obj: *mut Obj, obj_tag: Tag) -> u32 {
+    let mut ctx = (mem, 0u32);
+
+    visit_pointer_fields(
+        &mut ctx,
+        obj,
+        obj_tag,
+        MARK_HEAP_BASE as usize,
+        |ctx, field_addr| {
+            mark_object(ctx.0, *field_addr);
+            ctx.1 += 1;
+        },
+        |ctx, slice_start, arr| {
+            const SLICE_INCREMENT: u32 = 127;
+            debug_assert!(SLICE_INCREMENT >= TAG_ARRAY_SLICE_MIN);
+            if arr.len() - slice_start > SLICE_INCREMENT {
+                let new_start = slice_start + SLICE_INCREMENT;
+                // push an entire (suffix) array slice
+                push_mark_stack(ctx.0, arr as usize, new_start);
+                new_start
+            } else {
+                arr.len()
+            }
+        },
+    );
+
+    ctx.1
+}
+
+/// Snapshot-at-the-beginning write barrier of the incremental GC. Marks the object pointed by the
+/// location, if a marking is in progress. Needs to be called before overwriting a pointer field of
+/// an object that may have been allocated before the current cycle started.
+#[ic_mem_fn]
+pub unsafe fn pre_write_barrier<M: Memory>(mem: &mut M, location: *mut Value) {
+    if PHASE == Phase::Mark {
+        mark_object(mem, *location);
+    }
+}
+
+/// Read barrier of weak references. Marks the target of a weak reference, if a marking is in
+/// progress, as the mutator may store the target in an object that is not scanned in this cycle.
+pub unsafe fn weak_ref_read_barrier<M: Memory>(mem: &mut M, target: Value) {
+    if PHASE == Phase::Mark {
+        mark_object(mem, target);
+    }
+}
+
+/// Threads the pointers and compacts the heap, once marking is done. Marked objects in the heap
+/// being collected and all objects allocated during the cycle (except the bitmap and the mark
+/// stack) are live. Not incremental: runs to completion regardless of the budget.
+unsafe fn compact<M: Memory, SetHp: Fn(u32)>(
+    mem: &mut M,
+    set_hp: SetHp,
+    static_roots: Value,
+    continuation_table_ptr_loc: *mut Value,
+    heap_end: u32,
+) {
+    let heap_base = MARK_HEAP_BASE;
+
+    // The bitmap is the first object allocated in the cycle. The mark stack may have moved.
+    let bitmap_blob = MARK_HEAP_END;
+    let mark_stack_blob = mark_stack_blob(mem) as u32;
+
+    stats::begin_phase();
+
+    // Thread backwards and self pointers of the live objects. Forward pointers are threaded while
+    // compacting, as in the non-incremental collector. We need to read the headers of the objects
+    // allocated during the cycle to find the next object, so these cannot be threaded before we
+    // visit them.
+    let mut bitmap_iter = iter_bits();
+    let mut bit = bitmap_iter.next();
+    while bit != BITMAP_ITER_END {
+        thread_bwd_pointers((bit * WORD_SIZE) as *mut Obj, heap_base);
+        bit = bitmap_iter.next();
+    }
+
+    let mut p = MARK_HEAP_END;
+    while p < heap_end {
+        let size = object_size(p as usize);
+        if p != bitmap_blob && p != mark_stack_blob {
+            thread_bwd_pointers(p as *mut Obj, heap_base);
+        }
+        p += size.to_bytes().as_u32();
+    }
+
+    // Thread the roots. These are not moved, so it's OK to thread forward pointers.
+    let root_array = static_roots.as_array();
+    for i in 0..root_array.len() {
+        let mutbox = root_array.get(i).as_obj() as *mut MutBox;
+        let field_addr = &mut (*mutbox).field;
+        if pointer_to_dynamic_heap(field_addr, heap_base as usize) {
+            thread(field_addr);
+        }
+    }
+
+    if pointer_to_dynamic_heap(continuation_table_ptr_loc, heap_base as usize) {
+        thread(continuation_table_ptr_loc);
+    }
+
+    free_mark_stack(mem);
+
+    stats::end_phase(stats::Phase::Thread);
+    stats::begin_phase();
+
+    // Compact the marked objects. Dead objects are not threaded, record their sizes before they
+    // are overwritten.
+    let mut free = heap_base;
+    let mut dead = heap_base;
+
+    let mut bitmap_iter = iter_bits();
+    let mut bit = bitmap_iter.next();
+    while bit != BITMAP_ITER_END {
+        let p = bit * WORD_SIZE;
+        note_freed_objects(dead, p);
+        let p_new = compaction_target(p, threaded_tag(p as *mut Obj), free, true);
+        free = move_object(p, p_new, heap_base);
+        dead = p + (free - p_new);
+        bit = bitmap_iter.next();
+    }
+
+    note_freed_objects(dead, MARK_HEAP_END);
+
+    // Compact the objects allocated during the cycle. The bitmap is not needed anymore, so it's
+    // fine to overwrite it.
+    free_bitmap();
+
+    let mut p = MARK_HEAP_END;
+    while p < heap_end {
+        if p == bitmap_blob || p == mark_stack_blob {
+            // Nothing points to these, so their headers are not threaded
+            let size = object_size(p as usize).to_bytes();
+            stats::note_freed(TAG_BLOB, size);
+            p += size.as_u32();
+            continue;
+        }
+        // The header needs to be unthreaded before we can get the size
+        let p_new = compaction_target(p, threaded_tag(p as *mut Obj), free, true);
+        free = move_object(p, p_new, heap_base);
+        p += free - p_new;
+    }
+
+    set_hp(free);
+
+    stats::end_phase(stats::Phase::Update);
+}
+
+/// Thread backwards and self pointers of an object. Clears weak references whose targets are not
+/// marked.
+unsafe fn thread_bwd_pointers(obj: *mut Obj, heap_base: u32) {
+    if obj.tag() == TAG_WEAK_REF {
+        let weak_ref = obj as *mut WeakRef;
+        let target_addr = &mut (*weak_ref).target;
+        if pointer_to_dynamic_heap(target_addr, heap_base as usize) {
+            let target = (*target_addr).get_ptr() as u32;
+            if target < MARK_HEAP_END && !get_bit(target / WORD_SIZE) {
+                crate::weak_ref::clear(weak_ref);
+            } else if target <= obj as u32 {
+                thread(target_addr);
+            }
+        }
+        return;
+    }
+
+    visit_pointer_fields(
+        &mut (),
+        obj,
+        obj.tag(),
+        heap_base as usize,
+        |_, field_addr| {
+            if (*field_addr).get_ptr() <= obj as usize {
+                thread(field_addr)
+            }
+        },
+        |_, _, arr| arr.len(),
+    );
+}
+
+/// Updates the references to the object at `p`, moves it to `p_new`, and threads its forward
+/// pointers. Returns the address after the moved object.
+unsafe fn move_object(p: u32, p_new: u32, heap_base: u32) -> u32 {
+    unthread(p as *mut Obj, p_new);
+
+    let p_size_words = object_size(p as usize);
+    if p_new != p {
+        memcpy_words(p_new as usize, p as usize, p_size_words);
+        stats::note_moved(p_size_words.to_bytes());
+    }
+
+    thread_fwd_pointers(p_new as *mut Obj, heap_base);
+
+    p_new + p_size_words.to_bytes().as_u32()
+}
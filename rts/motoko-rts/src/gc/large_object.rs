@@ -0,0 +1,64 @@
+//! Large object space: blobs and arrays of at least `LARGE_OBJECT_THRESHOLD` are never moved by
+//! the collectors, so multi-megabyte objects are not copied on every collection.
+//!
+//! Large objects are allocated in the dynamic heap like other objects, and marked in the same
+//! bitmap. Whether an object is in the large object space only depends on its size, so all blobs
+//! and arrays allocated with `alloc_blob` and `alloc_array` are in the space when they are large
+//! enough, and the collectors don't need a separate list of large objects.
+//!
+//! When compacting, a live large object stays where it is, and the objects after it are compacted
+//! to the end of it. The space between the compacted objects before it and the large object is
+//! turned into free space. `compacting_gc` and `incremental_gc` add this space to the free lists
+//! of the mark-sweep GC (see `free_list.rs`), so it's reused by `alloc_words` until the next
+//! collection. The free lists are emptied at the start of a collection, as compaction rewrites the
+//! heap. The incremental GC empties them when a cycle starts, so that all objects allocated during
+//! the cycle are after the heap being marked.
+//!
+//! `generational_gc` does not reuse the space: objects allocated in it would be in the old
+//! generation, and their initializing stores don't go through the write barrier. Spaces smaller
+//! than `free_list::MIN_BLOCK_SIZE` are not reused either. Space that is not reused is lost until
+//! the large object dies, so a heap with many live large objects may be fragmented. A dead large
+//! object is an ordinary dead object: the objects after it are compacted over it and over the free
+//! space before it.
+//!
+//! The copying GC does not evacuate large objects: it marks them in place, and turns the space
+//! around them into free space after the collection. The next collection evacuates into the
+//! largest such space when it's large enough (see `copying.rs`). The mark-sweep GC does not move
+//! any objects.
+
+use super::mark_sweep::free_list;
+use crate::types::*;
+
+/// Size of the smallest large object, 64 KiB
+pub const LARGE_OBJECT_THRESHOLD: Words<u32> = Words(16 * 1024);
+
+/// Whether the object at `obj` with the given tag is in the large object space. The header of the
+/// object may be threaded, so the tag is passed separately.
+pub unsafe fn is_large_object(obj: *mut Obj, tag: Tag) -> bool {
+    let size = match tag {
+        TAG_BLOB => size_of::<Blob>() + (obj as *mut Blob).len().to_words(),
+        TAG_ARRAY => size_of::<Array>() + Words((obj as *mut Array).len()),
+        _ => return false,
+    };
+    size >= LARGE_OBJECT_THRESHOLD
+}
+
+/// Returns where to move the live object at `p` when compacting to `free`: `free`, or `p` when the
+/// object is large. In the latter case the space between `free` and `p` is turned into free space,
+/// which is added to the free lists with `reuse_gap`.
+pub(crate) unsafe fn compaction_target(p: u32, tag: Tag, free: u32, reuse_gap: bool) -> u32 {
+    if !is_large_object(p as *mut Obj, tag) {
+        return free;
+    }
+
+    let gap = Bytes(p - free).to_words();
+    if gap != Words(0) {
+        if reuse_gap {
+            free_list::add_free_space(free, gap);
+        } else {
+            free_list::write_free_space(free, gap);
+        }
+    }
+
+    p
+}
@@ -3,11 +3,15 @@
 //! threaded compaction algorithm described in The Garbage Collection Handbook section 3.3.
 
 pub mod bitmap;
+pub mod dedup;
 pub mod mark_stack;
 
 use bitmap::{alloc_bitmap, free_bitmap, get_bit, iter_bits, set_bit, BITMAP_ITER_END};
 use mark_stack::{alloc_mark_stack, free_mark_stack, pop_mark_stack, push_mark_stack};
 
+use super::large_object::compaction_target;
+use super::mark_sweep::free_list;
+use super::stats;
 use crate::constants::WORD_SIZE;
 use crate::mem_utils::memcpy_words;
 use crate::memory::Memory;
@@ -16,8 +20,11 @@ use crate::visitor::{pointer_to_dynamic_heap, visit_pointer_fields};
 
 use motoko_rts_macros::ic_mem_fn;
 
+/// Whether a weak reference was marked in the current collection
+static mut MARKED_WEAK_REFS: bool = false;
+
 #[ic_mem_fn(ic_only)]
-unsafe fn schedule_compacting_gc<M: Memory>(mem: &mut M) {
+unsafe fn schedule_compacting_gc(mem: &mut crate::memory::ic::IcMemory) {
     // 512 MiB slack for mark stack + allocation area for the next message
     let slack: u64 = 512 * 1024 * 1024;
     let heap_size_bytes: u64 =
@@ -27,31 +34,35 @@ unsafe fn schedule_compacting_gc<M: Memory>(mem: &mut M) {
     // NB. `max_live` is evaluated in compile time to a constant
     let max_live: Bytes<u64> = Bytes(heap_size_bytes - slack - max_bitmap_size_bytes);
 
-    if super::should_do_gc(max_live) {
+    if super::should_do_gc(mem, max_live) || dedup::dedup_requested() {
         compacting_gc(mem);
     }
 }
 
 #[ic_mem_fn(ic_only)]
-unsafe fn compacting_gc<M: Memory>(mem: &mut M) {
+unsafe fn compacting_gc(mem: &mut crate::memory::ic::IcMemory) {
+    use crate::continuation_table::continuation_table_loc;
     use crate::memory::ic;
 
+    let heap = mem.heap();
+    let continuation_table_ptr_loc = continuation_table_loc(mem);
+
     compacting_gc_internal(
         mem,
         ic::get_aligned_heap_base(),
         // get_hp
-        || ic::HP as usize,
+        || (*heap).hp as usize,
         // set_hp
-        |hp| ic::HP = hp,
+        |hp| (*heap).hp = hp,
         ic::get_static_roots(),
-        crate::continuation_table::continuation_table_loc(),
+        continuation_table_ptr_loc,
         // note_live_size
-        |live_size| ic::MAX_LIVE = ::core::cmp::max(ic::MAX_LIVE, live_size),
+        |live_size| (*heap).max_live = ::core::cmp::max((*heap).max_live, live_size),
         // note_reclaimed
-        |reclaimed| ic::RECLAIMED += Bytes(u64::from(reclaimed.as_u32())),
+        |reclaimed| (*heap).reclaimed += Bytes(u64::from(reclaimed.as_u32())),
     );
 
-    ic::LAST_HP = ic::HP;
+    (*heap).last_hp = (*heap).hp;
 }
 
 pub unsafe fn compacting_gc_internal<
@@ -81,6 +92,11 @@ pub unsafe fn compacting_gc_internal<
         old_hp,
         static_roots,
         continuation_table_ptr_loc,
+        dedup::take_dedup_request(),
+        // reuse_gaps
+        true,
+        // mark_additional_roots
+        |_mem| {},
     );
 
     let reclaimed = old_hp - (get_hp() as u32);
@@ -90,34 +106,90 @@ pub unsafe fn compacting_gc_internal<
     note_live_size(Bytes(live));
 }
 
-unsafe fn mark_compact<M: Memory, SetHp: Fn(u32)>(
+/// Marks and compacts the objects at and above `heap_base`. Objects below `heap_base` are neither
+/// marked nor moved, so `heap_base` does not need to be the start of the dynamic heap: the
+/// generational GC passes the start of the young generation here, and marks the old generation
+/// fields pointing to the young generation in `mark_additional_roots` using `mark_root_field`.
+///
+/// With `dedup_blobs`, live blobs with the same contents are deduplicated before marking (see
+/// `dedup.rs`). This requires `heap_base` to be the start of the dynamic heap.
+///
+/// With `reuse_gaps`, the free space left before large objects is added to the free lists (see
+/// `large_object.rs`).
+pub(crate) unsafe fn mark_compact<M: Memory, SetHp: Fn(u32), MarkAdditionalRoots: Fn(&mut M)>(
     mem: &mut M,
     set_hp: SetHp,
     heap_base: u32,
     heap_end: u32,
     static_roots: Value,
     continuation_table_ptr_loc: *mut Value,
+    dedup_blobs: bool,
+    reuse_gaps: bool,
+    mark_additional_roots: MarkAdditionalRoots,
 ) {
-    let mem_size = Bytes(heap_end - heap_base);
+    // Compaction overwrites the free space left by the last collection. Empty the free lists so
+    // that the bitmap and the mark stack are allocated after `heap_end`.
+    free_list::clear();
+
+    // The bitmap needs to start at a 32-byte aligned address (see bitmap.rs). The dynamic heap
+    // start is aligned, but the young generation may start anywhere. Objects below `heap_base`
+    // are never marked so it's fine to cover a few more words with the bitmap.
+    let bitmap_base = heap_base & !31;
+    let mem_size = Bytes(heap_end - bitmap_base);
+
+    stats::begin_collection();
+    stats::begin_phase();
+
+    // Deduplication is reported as part of marking
+    if dedup_blobs {
+        dedup::dedup_blobs(
+            mem,
+            bitmap_base,
+            heap_base,
+            heap_end,
+            static_roots,
+            continuation_table_ptr_loc,
+        );
+    }
+
+    // The bitmap and the mark stack are allocated after `heap_end`, and freed before moving the
+    // heap pointer back
+    let scratch = mem.begin_scratch();
 
-    alloc_bitmap(mem, mem_size, heap_base / WORD_SIZE);
+    alloc_bitmap(mem, mem_size, bitmap_base / WORD_SIZE);
     alloc_mark_stack(mem);
 
+    MARKED_WEAK_REFS = false;
+
     mark_static_roots(mem, static_roots, heap_base);
 
-    if (*continuation_table_ptr_loc).is_ptr() {
-        mark_object(mem, *continuation_table_ptr_loc);
-        // Similar to `mark_root_mutbox_fields`, `continuation_table_ptr_loc` is in static heap so
-        // it will be readable when we unthread the continuation table
-        thread(continuation_table_ptr_loc);
-    }
+    // Similar to `mark_root_mutbox_fields`, `continuation_table_ptr_loc` is in static heap so it
+    // will be readable when we unthread the continuation table
+    mark_root_field(mem, continuation_table_ptr_loc, heap_base);
+
+    mark_additional_roots(mem);
 
     mark_stack(mem, heap_base);
 
-    update_refs(set_hp, heap_base);
+    if MARKED_WEAK_REFS {
+        clear_weak_refs(heap_base);
+    }
+
+    // Backwards pointers are threaded while marking
+    stats::end_phase(stats::Phase::Mark);
+    stats::begin_phase();
+
+    let free = update_refs(heap_base, heap_end, reuse_gaps);
+
+    stats::end_phase(stats::Phase::Update);
 
-    free_mark_stack();
+    free_mark_stack(mem);
     free_bitmap();
+    mem.release_scratch(scratch);
+
+    set_hp(free);
+
+    stats::end_collection();
 }
 
 unsafe fn mark_static_roots<M: Memory>(mem: &mut M, static_roots: Value, heap_base: u32) {
@@ -149,10 +221,16 @@ unsafe fn mark_object<M: Memory>(mem: &mut M, obj: Value) {
 
     set_bit(obj_idx);
     push_mark_stack(mem, obj as usize, obj_tag);
+
+    if obj_tag == TAG_WEAK_REF {
+        MARKED_WEAK_REFS = true;
+    }
+
+    stats::note_marked();
 }
 
 unsafe fn mark_stack<M: Memory>(mem: &mut M, heap_base: u32) {
-    while let Some((obj, tag)) = pop_mark_stack() {
+    while let Some((obj, tag)) = pop_mark_stack(mem) {
         mark_fields(mem, obj as *mut Obj, tag, heap_base)
     }
 }
@@ -187,9 +265,51 @@ unsafe fn mark_fields<M: Memory>(mem: &mut M, obj: *mut Obj, obj_tag: Tag, heap_
     );
 }
 
+/// Clears the marked weak references whose targets are not marked, and threads the backwards and
+/// self pointers of the others. Forward pointers are threaded by `thread_fwd_pointers`, as for the
+/// other fields.
+unsafe fn clear_weak_refs(heap_base: u32) {
+    let mut bitmap_iter = iter_bits();
+    let mut bit = bitmap_iter.next();
+    while bit != BITMAP_ITER_END {
+        let obj = (bit * WORD_SIZE) as *mut Obj;
+        if threaded_tag(obj) == TAG_WEAK_REF {
+            let weak_ref = obj as *mut WeakRef;
+            let target_addr = &mut (*weak_ref).target;
+            if pointer_to_dynamic_heap(target_addr, heap_base as usize) {
+                let target = (*target_addr).get_ptr();
+                if !get_bit((target as u32) / WORD_SIZE) {
+                    crate::weak_ref::clear(weak_ref);
+                } else if target <= obj as usize {
+                    thread(target_addr);
+                }
+            }
+        }
+        bit = bitmap_iter.next();
+    }
+}
+
+/// Returns the original tag of an object that may have threaded pointers
+pub(crate) unsafe fn threaded_tag(obj: *mut Obj) -> Tag {
+    let mut header = obj.tag();
+    while header & 0b1 == 0 {
+        header = (header as *const Obj).tag();
+    }
+    header
+}
+
 /// Specialized version of `mark_fields` for root `MutBox`es.
 unsafe fn mark_root_mutbox_fields<M: Memory>(mem: &mut M, mutbox: *mut MutBox, heap_base: u32) {
-    let field_addr = &mut (*mutbox).field;
+    mark_root_field(mem, &mut (*mutbox).field, heap_base);
+}
+
+/// Marks the object pointed by a field of an object that won't be moved in this collection (e.g.
+/// static objects), and threads the field.
+pub(crate) unsafe fn mark_root_field<M: Memory>(
+    mem: &mut M,
+    field_addr: *mut Value,
+    heap_base: u32,
+) {
     if pointer_to_dynamic_heap(field_addr, heap_base as usize) {
         mark_object(mem, *field_addr);
         // It's OK to thread forward pointers here as the static objects won't be moved, so we will
@@ -203,18 +323,27 @@ unsafe fn mark_root_mutbox_fields<M: Memory>(mem: &mut M, mutbox: *mut MutBox, h
 /// - Mark step threads all backwards pointers and pointers from roots, so unthread to update those
 ///   pointers to the objects new location.
 ///
-/// - Move the object
+/// - Move the object, unless it's a large object (see `large_object.rs`)
 ///
 /// - Thread forward pointers of the object
 ///
-unsafe fn update_refs<SetHp: Fn(u32)>(set_hp: SetHp, heap_base: u32) {
+/// The dead objects between the live objects are not threaded, so we can also record their sizes
+/// before they are overwritten.
+///
+/// Returns the end of the compacted objects, the new heap pointer.
+unsafe fn update_refs(heap_base: u32, heap_end: u32, reuse_gaps: bool) -> u32 {
     let mut free = heap_base;
+    let mut dead = heap_base;
 
     let mut bitmap_iter = iter_bits();
     let mut bit = bitmap_iter.next();
     while bit != BITMAP_ITER_END {
         let p = (bit * WORD_SIZE) as *mut Obj;
-        let p_new = free;
+
+        note_freed_objects(dead, p as u32);
+
+        // Large objects are not moved
+        let p_new = compaction_target(p as u32, threaded_tag(p), free, reuse_gaps);
 
         // Update backwards references to the object's new location and restore object header
         unthread(p, p_new);
@@ -223,9 +352,11 @@ unsafe fn update_refs<SetHp: Fn(u32)>(set_hp: SetHp, heap_base: u32) {
         let p_size_words = object_size(p as usize);
         if p_new as usize != p as usize {
             memcpy_words(p_new as usize, p as usize, p_size_words);
+            stats::note_moved(p_size_words.to_bytes());
         }
 
-        free += p_size_words.to_bytes().as_u32();
+        free = p_new + p_size_words.to_bytes().as_u32();
+        dead = p as u32 + p_size_words.to_bytes().as_u32();
 
         // Thread forward pointers of the object
         thread_fwd_pointers(p_new as *mut Obj, heap_base);
@@ -233,11 +364,42 @@ unsafe fn update_refs<SetHp: Fn(u32)>(set_hp: SetHp, heap_base: u32) {
         bit = bitmap_iter.next();
     }
 
-    set_hp(free);
+    note_freed_objects(dead, heap_end);
+
+    free
 }
 
-/// Thread forward pointers in object
-unsafe fn thread_fwd_pointers(obj: *mut Obj, heap_base: u32) {
+/// Records the sizes of the dead objects between `start` and `end` in the GC stats, when enabled
+/// with `stats::set_gc_stats_freed_by_tag`. Free space is skipped, it was not freed in this
+/// collection.
+pub(crate) unsafe fn note_freed_objects(start: u32, end: u32) {
+    if !stats::freed_by_tag() {
+        return;
+    }
+
+    let mut p = start;
+    while p < end {
+        let tag = (p as *mut Obj).tag();
+        let size = object_size(p as usize).to_bytes();
+        if tag != TAG_FREE_SPACE && tag != TAG_ONE_WORD_FILLER {
+            stats::note_freed(tag, size);
+        }
+        p += size.as_u32();
+    }
+}
+
+/// Thread forward pointers in object, including the target of a weak reference
+pub(crate) unsafe fn thread_fwd_pointers(obj: *mut Obj, heap_base: u32) {
+    if obj.tag() == TAG_WEAK_REF {
+        let target_addr = &mut (*(obj as *mut WeakRef)).target;
+        if pointer_to_dynamic_heap(target_addr, heap_base as usize)
+            && (*target_addr).get_ptr() > obj as usize
+        {
+            thread(target_addr);
+        }
+        return;
+    }
+
     visit_pointer_fields(
         &mut (),
         obj,
@@ -253,7 +415,7 @@ unsafe fn thread_fwd_pointers(obj: *mut Obj, heap_base: u32) {
 }
 
 /// Thread a pointer field
-unsafe fn thread(field: *mut Value) {
+pub(crate) unsafe fn thread(field: *mut Value) {
     // Store pointed object's header in the field, field address in the pointed object's header
     let pointed = (*field).as_obj();
     let pointed_header = pointed.tag();
@@ -262,7 +424,7 @@ unsafe fn thread(field: *mut Value) {
 }
 
 /// Unthread all references at given header, replacing with `new_loc`. Restores object header.
-unsafe fn unthread(obj: *mut Obj, new_loc: u32) {
+pub(crate) unsafe fn unthread(obj: *mut Obj, new_loc: u32) {
     let mut header = obj.tag();
 
     // All objects and fields are word-aligned, and tags have the lowest bit set, so use the lowest
@@ -274,7 +436,7 @@ unsafe fn unthread(obj: *mut Obj, new_loc: u32) {
     }
 
     // At the end of the chain is the original header for the object
-    debug_assert!(header >= TAG_OBJECT && header <= TAG_NULL);
+    debug_assert!((header >= TAG_OBJECT && header <= TAG_NULL) || header == TAG_WEAK_REF);
 
     (*obj).tag = header;
 }
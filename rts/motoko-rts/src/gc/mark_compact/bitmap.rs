@@ -76,6 +76,7 @@ pub unsafe fn alloc_bitmap<M: Memory>(mem: &mut M, heap_size: Bytes<u32>, heap_p
     // Also round allocation up to 8-bytes to make iteration efficient. We want to be able to read
     // 64 bits in a single read and check as many bits as possible with a single `word != 0`.
     let bitmap_bytes = Bytes(((BITMAP_SIZE + 7) / 8) * 8);
+    crate::gc::stats::note_bitmap_size(bitmap_bytes);
     // Allocating an actual object here as otherwise dump_heap gets confused
     let blob = alloc_blob(mem, bitmap_bytes).get_ptr() as *mut Blob;
     memzero(blob.payload_addr() as usize, bitmap_bytes.to_words());
@@ -0,0 +1,255 @@
+//! Blob deduplication pass, run before marking in a compacting collection when requested with
+//! `request_blob_dedup` (e.g. before an upgrade, to make the serialized heap smaller).
+//!
+//! The pass marks the live objects (without threading), and finds the live blobs with the same
+//! contents using a hash table of the blobs seen so far. Each duplicate is then turned into a
+//! forwarding pointer to the first blob with the same contents, the references to the duplicates
+//! are redirected, and the duplicates are restored as blobs, now unreachable. The normal marking
+//! that follows doesn't reach them, so they are reclaimed by the same collection.
+//!
+//! All data of the pass is allocated in a scratch region after the heap, which is released before
+//! the normal marking.
+//!
+//! Only the compacting GC and the full collections of the generational GC do the pass. The other
+//! collectors ignore the request.
+
+use super::bitmap::{alloc_bitmap, free_bitmap, get_bit, iter_bits, set_bit, BITMAP_ITER_END};
+use super::mark_stack::{alloc_mark_stack, free_mark_stack, pop_mark_stack, push_mark_stack};
+
+use crate::constants::WORD_SIZE;
+use crate::gc::stats;
+use crate::memory::{alloc_blob, with_scratch, Memory};
+use crate::principal_id::compute_crc32;
+use crate::text::blob_compare;
+use crate::types::*;
+use crate::visitor::{pointer_to_dynamic_heap, visit_pointer_fields};
+
+/// Whether the next compacting collection should deduplicate blobs
+static mut DEDUP_REQUESTED: bool = false;
+
+/// Requests blob deduplication in the next compacting collection. The collection is done at the
+/// end of the current message, regardless of the heap growth.
+#[no_mangle]
+pub unsafe extern "C" fn request_blob_dedup() {
+    DEDUP_REQUESTED = true;
+}
+
+/// Whether blob deduplication was requested and not done yet
+pub unsafe fn dedup_requested() -> bool {
+    DEDUP_REQUESTED
+}
+
+/// Returns whether blob deduplication was requested, and clears the request
+pub(crate) unsafe fn take_dedup_request() -> bool {
+    let requested = DEDUP_REQUESTED;
+    DEDUP_REQUESTED = false;
+    requested
+}
+
+/// Redirects the references to live blobs with the same contents to one of them. Arguments are the
+/// same as in `mark_compact`. `heap_base` needs to be the start of the dynamic heap, as the objects
+/// below it are not scanned for references to the duplicates.
+pub(crate) unsafe fn dedup_blobs<M: Memory>(
+    mem: &mut M,
+    bitmap_base: u32,
+    heap_base: u32,
+    heap_end: u32,
+    static_roots: Value,
+    continuation_table_ptr_loc: *mut Value,
+) {
+    with_scratch(mem, |mem| {
+        alloc_bitmap(mem, Bytes(heap_end - bitmap_base), bitmap_base / WORD_SIZE);
+        alloc_mark_stack(mem);
+
+        mark(mem, heap_base, static_roots, continuation_table_ptr_loc);
+
+        if forward_duplicates(mem) {
+            redirect_references(mem, heap_base, static_roots, continuation_table_ptr_loc);
+            restore_duplicates();
+        }
+
+        free_mark_stack(mem);
+        free_bitmap();
+    });
+}
+
+/// Marks the live objects
+unsafe fn mark<M: Memory>(
+    mem: &mut M,
+    heap_base: u32,
+    static_roots: Value,
+    continuation_table_ptr_loc: *mut Value,
+) {
+    let root_array = static_roots.as_array();
+    for i in 0..root_array.len() {
+        let mutbox = root_array.get(i).as_obj() as *mut MutBox;
+        mark_field(mem, &mut (*mutbox).field, heap_base);
+    }
+
+    mark_field(mem, continuation_table_ptr_loc, heap_base);
+
+    while let Some((obj, tag)) = pop_mark_stack(mem) {
+        visit_pointer_fields(
+            mem,
+            obj as *mut Obj,
+            tag,
+            heap_base as usize,
+            |mem, field_addr| mark_field(mem, field_addr, heap_base),
+            |_, _, arr| arr.len(),
+        );
+    }
+}
+
+unsafe fn mark_field<M: Memory>(mem: &mut M, field_addr: *mut Value, heap_base: u32) {
+    if !pointer_to_dynamic_heap(field_addr, heap_base as usize) {
+        return;
+    }
+
+    let obj = *field_addr;
+    let obj_idx = obj.get_ptr() as u32 / WORD_SIZE;
+    if get_bit(obj_idx) {
+        return;
+    }
+
+    set_bit(obj_idx);
+
+    // Blobs don't have pointer fields
+    let tag = obj.tag();
+    if tag != TAG_BLOB {
+        push_mark_stack(mem, obj.get_ptr(), tag);
+    }
+}
+
+/// Turns the marked blobs that have the same contents as a blob before them into forwarding
+/// pointers to that blob. Returns whether there were any duplicates.
+///
+/// The hash table is a blob with two words per slot: the address of a blob and its CRC32, or 0 and
+/// 0 for unused slots. It has at least twice as many slots as there are blobs.
+unsafe fn forward_duplicates<M: Memory>(mem: &mut M) -> bool {
+    let n_blobs = count_marked_blobs();
+    let mut capacity: u32 = 1;
+    while capacity < n_blobs * 2 {
+        capacity *= 2;
+    }
+    let mask = capacity - 1;
+
+    let table_blob = alloc_blob(mem, Words(2 * capacity).to_bytes()).as_blob_mut();
+    let table = table_blob.payload_addr() as *mut u32;
+    for i in 0..(2 * capacity) as usize {
+        *table.add(i) = 0;
+    }
+
+    let mut found_duplicates = false;
+
+    let mut bitmap_iter = iter_bits();
+    let mut bit = bitmap_iter.next();
+    while bit != BITMAP_ITER_END {
+        let obj = (bit * WORD_SIZE) as *mut Obj;
+        if obj.tag() == TAG_BLOB {
+            let blob = Value::from_ptr(obj as usize);
+            let hash = compute_crc32(blob);
+
+            let mut slot = hash & mask;
+            loop {
+                let entry = table.add(2 * slot as usize);
+                if *entry == 0 {
+                    *entry = obj as u32;
+                    *entry.add(1) = hash;
+                    break;
+                }
+
+                let canonical = Value::from_ptr(*entry as usize);
+                if *entry.add(1) == hash && blob_compare(canonical, blob) == 0 {
+                    stats::note_deduplicated(object_size(obj as usize).to_bytes());
+                    let fwd = obj as *mut FwdPtr;
+                    (*fwd).header.tag = TAG_FWD_PTR;
+                    (*fwd).fwd = canonical;
+                    found_duplicates = true;
+                    break;
+                }
+
+                slot = (slot + 1) & mask;
+            }
+        }
+        bit = bitmap_iter.next();
+    }
+
+    found_duplicates
+}
+
+unsafe fn count_marked_blobs() -> u32 {
+    let mut n_blobs = 0;
+    let mut bitmap_iter = iter_bits();
+    let mut bit = bitmap_iter.next();
+    while bit != BITMAP_ITER_END {
+        if ((bit * WORD_SIZE) as *mut Obj).tag() == TAG_BLOB {
+            n_blobs += 1;
+        }
+        bit = bitmap_iter.next();
+    }
+    n_blobs
+}
+
+/// Replaces the references to the duplicates (now forwarding pointers) with references to the
+/// blobs with the same contents
+unsafe fn redirect_references<M: Memory>(
+    mem: &mut M,
+    heap_base: u32,
+    static_roots: Value,
+    continuation_table_ptr_loc: *mut Value,
+) {
+    let root_array = static_roots.as_array();
+    for i in 0..root_array.len() {
+        let mutbox = root_array.get(i).as_obj() as *mut MutBox;
+        redirect_field(&mut (*mutbox).field, heap_base);
+    }
+
+    redirect_field(continuation_table_ptr_loc, heap_base);
+
+    let mut bitmap_iter = iter_bits();
+    let mut bit = bitmap_iter.next();
+    while bit != BITMAP_ITER_END {
+        let obj = (bit * WORD_SIZE) as *mut Obj;
+        let tag = obj.tag();
+        if tag == TAG_WEAK_REF {
+            // Targets of weak references are not visited by `visit_pointer_fields`
+            redirect_field(&mut (*(obj as *mut WeakRef)).target, heap_base);
+        } else if tag != TAG_BLOB && tag != TAG_FWD_PTR {
+            visit_pointer_fields(
+                mem,
+                obj,
+                tag,
+                heap_base as usize,
+                |_, field_addr| redirect_field(field_addr, heap_base),
+                |_, _, arr| arr.len(),
+            );
+        }
+        bit = bitmap_iter.next();
+    }
+}
+
+unsafe fn redirect_field(field_addr: *mut Value, heap_base: u32) {
+    if pointer_to_dynamic_heap(field_addr, heap_base as usize) && (*field_addr).tag() == TAG_FWD_PTR
+    {
+        *field_addr = (*(*field_addr).as_obj().cast::<FwdPtr>()).fwd;
+    }
+}
+
+/// Turns the forwarding pointers back into blobs, so that the collection can skip over them. The
+/// length of a blob is stored where the forwarding pointer stores the address, so it's restored
+/// from the blob with the same contents.
+unsafe fn restore_duplicates() {
+    let mut bitmap_iter = iter_bits();
+    let mut bit = bitmap_iter.next();
+    while bit != BITMAP_ITER_END {
+        let obj = (bit * WORD_SIZE) as *mut Obj;
+        if obj.tag() == TAG_FWD_PTR {
+            let fwd = (*(obj as *mut FwdPtr)).fwd;
+            let len = fwd.as_blob().len();
+            let blob = obj as *mut Blob;
+            (*blob).header.tag = TAG_BLOB;
+            (*blob).len = len;
+        }
+        bit = bitmap_iter.next();
+    }
+}
@@ -1,74 +1,122 @@
-//! A stack for marking heap objects (for GC). There should be no allocation after the stack
-//! otherwise things will break as we push. This invariant is checked in debug builds.
+//! A stack for marking heap objects (for GC). The stack is grown in place when nothing was
+//! allocated after it. Otherwise (e.g. when the mutator allocates between the increments of the
+//! incremental GC) the stack is moved to a new blob when it needs to grow.
+//!
+//! The stack is part of the `Context` of a heap (see `memory.rs`).
 
+use crate::mem_utils::memcpy_words;
 use crate::memory::{alloc_blob, Memory};
-use crate::types::{Blob, Tag, Words};
+use crate::types::{size_of, Blob, Bytes, Tag, Words, TAG_BLOB};
 
 use core::ptr::null_mut;
 
 /// Initial stack size
 pub const INIT_STACK_SIZE: Words<u32> = Words(64);
 
-/// Pointer to the `blob` object for the mark stack. Used to get the capacity of the stack.
-static mut STACK_BLOB_PTR: *mut Blob = null_mut();
+pub struct MarkStack {
+    /// Pointer to the `blob` object for the mark stack. Used to get the capacity of the stack.
+    blob_ptr: *mut Blob,
 
-/// Bottom of the mark stack
-pub static mut STACK_BASE: *mut usize = null_mut();
+    /// Bottom of the mark stack
+    pub base: *mut usize,
 
-/// Top of the mark stack
-pub static mut STACK_TOP: *mut usize = null_mut();
+    /// Top of the mark stack
+    pub top: *mut usize,
 
-/// Next free slot in the mark stack
-pub static mut STACK_PTR: *mut usize = null_mut();
+    /// Next free slot in the mark stack
+    pub ptr: *mut usize,
+}
+
+impl MarkStack {
+    pub const fn new() -> MarkStack {
+        MarkStack {
+            blob_ptr: null_mut(),
+            base: null_mut(),
+            top: null_mut(),
+            ptr: null_mut(),
+        }
+    }
+}
+
+/// The mark stack of the heap of `mem`
+unsafe fn get_stack<M: Memory>(mem: &mut M) -> *mut MarkStack {
+    &mut mem.context().mark_stack
+}
 
 pub unsafe fn alloc_mark_stack<M: Memory>(mem: &mut M) {
-    debug_assert!(STACK_BLOB_PTR.is_null());
+    let stack = get_stack(mem);
+    debug_assert!((*stack).blob_ptr.is_null());
 
     // Allocating an actual object here to not break dump_heap
-    STACK_BLOB_PTR = alloc_blob(mem, INIT_STACK_SIZE.to_bytes()).get_ptr() as *mut Blob;
-    STACK_BASE = STACK_BLOB_PTR.payload_addr() as *mut usize;
-    STACK_PTR = STACK_BASE;
-    STACK_TOP = STACK_BASE.add(INIT_STACK_SIZE.as_usize());
+    (*stack).blob_ptr = alloc_blob(mem, INIT_STACK_SIZE.to_bytes()).get_ptr() as *mut Blob;
+    (*stack).base = (*stack).blob_ptr.payload_addr() as *mut usize;
+    (*stack).ptr = (*stack).base;
+    (*stack).top = (*stack).base.add(INIT_STACK_SIZE.as_usize());
 }
 
-pub unsafe fn free_mark_stack() {
-    STACK_BLOB_PTR = null_mut();
-    STACK_BASE = null_mut();
-    STACK_PTR = null_mut();
-    STACK_TOP = null_mut();
+pub unsafe fn free_mark_stack<M: Memory>(mem: &mut M) {
+    *get_stack(mem) = MarkStack::new();
 }
 
 /// Doubles the stack size
 pub unsafe fn grow_stack<M: Memory>(mem: &mut M) {
-    let stack_cap: Words<u32> = STACK_BLOB_PTR.len().to_words();
+    let stack = get_stack(mem);
+    let stack_cap: Words<u32> = (*stack).blob_ptr.len().to_words();
     let p = mem.alloc_words(stack_cap).get_ptr() as *mut usize;
 
-    // Make sure nothing was allocated after the stack
-    debug_assert_eq!(STACK_TOP, p);
-
     let new_cap: Words<u32> = stack_cap * 2;
-    (*STACK_BLOB_PTR).len = new_cap.to_bytes();
-    STACK_TOP = STACK_BASE.add(new_cap.as_usize());
+
+    if p == (*stack).top {
+        // Nothing was allocated after the stack, extend it in place
+        (*(*stack).blob_ptr).len = new_cap.to_bytes();
+        (*stack).top = (*stack).base.add(new_cap.as_usize());
+        return;
+    }
+
+    // Something was allocated after the stack. Turn the space we just allocated into a blob to
+    // keep the heap parseable, and move the stack to a new blob. The old blobs are left for the
+    // next GC.
+    let filler = p as *mut Blob;
+    (*filler).header.tag = TAG_BLOB;
+    (*filler).len = (stack_cap - size_of::<Blob>()).to_bytes();
+
+    let len = Bytes((*stack).ptr as u32 - (*stack).base as u32);
+    (*stack).blob_ptr = alloc_blob(mem, new_cap.to_bytes()).get_ptr() as *mut Blob;
+    let new_base = (*stack).blob_ptr.payload_addr() as *mut usize;
+    memcpy_words(new_base as usize, (*stack).base as usize, len.to_words());
+    (*stack).base = new_base;
+    (*stack).ptr = (*stack).base.add(len.to_words().as_usize());
+    (*stack).top = (*stack).base.add(new_cap.as_usize());
+}
+
+/// Returns the blob holding the mark stack
+pub unsafe fn mark_stack_blob<M: Memory>(mem: &mut M) -> *mut Blob {
+    (*get_stack(mem)).blob_ptr
 }
 
 pub unsafe fn push_mark_stack<M: Memory>(mem: &mut M, obj: usize, obj_tag: Tag) {
-    // We add 2 words in a push, and `STACK_PTR` and `STACK_TOP` are both multiples of 2, so we can
-    // do simple equality check here
-    if STACK_PTR == STACK_TOP {
+    let stack = get_stack(mem);
+
+    // We add 2 words in a push, and `ptr` and `top` are both multiples of 2, so we can do simple
+    // equality check here
+    if (*stack).ptr == (*stack).top {
         grow_stack(mem);
     }
 
-    *STACK_PTR = obj;
-    *STACK_PTR.add(1) = obj_tag as usize;
-    STACK_PTR = STACK_PTR.add(2);
+    *(*stack).ptr = obj;
+    *(*stack).ptr.add(1) = obj_tag as usize;
+    (*stack).ptr = (*stack).ptr.add(2);
+
+    crate::gc::stats::note_mark_stack_size(Bytes((*stack).ptr as u32 - (*stack).base as u32));
 }
 
-pub unsafe fn pop_mark_stack() -> Option<(usize, Tag)> {
-    if STACK_PTR == STACK_BASE {
+pub unsafe fn pop_mark_stack<M: Memory>(mem: &mut M) -> Option<(usize, Tag)> {
+    let stack = get_stack(mem);
+    if (*stack).ptr == (*stack).base {
         return None;
     }
-    STACK_PTR = STACK_PTR.sub(2);
-    let p = *STACK_PTR;
-    let tag = *STACK_PTR.add(1);
+    (*stack).ptr = (*stack).ptr.sub(2);
+    let p = *(*stack).ptr;
+    let tag = *(*stack).ptr.add(1);
     return Some((p, tag as u32));
 }
@@ -0,0 +1,248 @@
+//! A non-moving mark-sweep collector.
+//!
+//! Marking uses the bitmap and the mark stack of the compacting GC. Live objects are not moved:
+//! the sweep phase turns each run of dead objects between the live objects into free space, which
+//! is linked into the free lists (see `free_list.rs`) and reused by `alloc_words`. Dead objects
+//! after the last live object are reclaimed by moving the heap pointer back.
+
+pub mod free_list;
+
+use super::mark_compact::bitmap::{
+    alloc_bitmap, free_bitmap, get_bit, iter_bits, set_bit, BITMAP_ITER_END,
+};
+use super::mark_compact::mark_stack::{
+    alloc_mark_stack, free_mark_stack, pop_mark_stack, push_mark_stack,
+};
+use super::mark_compact::note_freed_objects;
+use super::stats;
+use crate::constants::WORD_SIZE;
+use crate::memory::Memory;
+use crate::types::*;
+use crate::visitor::{pointer_to_dynamic_heap, visit_pointer_fields};
+
+use motoko_rts_macros::ic_mem_fn;
+
+/// Whether a weak reference was marked in the current collection
+static mut MARKED_WEAK_REFS: bool = false;
+
+#[ic_mem_fn(ic_only)]
+unsafe fn schedule_marksweep_gc(mem: &mut crate::memory::ic::IcMemory) {
+    // Same limit as the compacting GC, as we need space for the mark stack and the bitmap
+    let slack: u64 = 512 * 1024 * 1024;
+    let heap_size_bytes: u64 =
+        u64::from(crate::constants::WASM_HEAP_SIZE.as_u32()) * u64::from(WORD_SIZE);
+    let max_bitmap_size_bytes = heap_size_bytes / 32;
+    // NB. `max_live` is evaluated in compile time to a constant
+    let max_live: Bytes<u64> = Bytes(heap_size_bytes - slack - max_bitmap_size_bytes);
+
+    if super::should_do_gc(mem, max_live) {
+        marksweep_gc(mem);
+    }
+}
+
+#[ic_mem_fn(ic_only)]
+unsafe fn marksweep_gc(mem: &mut crate::memory::ic::IcMemory) {
+    use crate::continuation_table::continuation_table_loc;
+    use crate::memory::ic;
+
+    let heap = mem.heap();
+    let continuation_table_ptr_loc = continuation_table_loc(mem);
+
+    marksweep_gc_internal(
+        mem,
+        ic::get_aligned_heap_base(),
+        // get_hp
+        || (*heap).hp as usize,
+        // set_hp
+        |hp| (*heap).hp = hp,
+        ic::get_static_roots(),
+        continuation_table_ptr_loc,
+        // note_live_size
+        |live_size| (*heap).max_live = ::core::cmp::max((*heap).max_live, live_size),
+        // note_reclaimed
+        |reclaimed| (*heap).reclaimed += Bytes(u64::from(reclaimed.as_u32())),
+    );
+
+    (*heap).last_hp = (*heap).hp;
+}
+
+pub unsafe fn marksweep_gc_internal<
+    M: Memory,
+    GetHp: Fn() -> usize,
+    SetHp: Fn(u32),
+    NoteLiveSize: Fn(Bytes<u32>),
+    NoteReclaimed: Fn(Bytes<u32>),
+>(
+    mem: &mut M,
+    heap_base: u32,
+    get_hp: GetHp,
+    set_hp: SetHp,
+    static_roots: Value,
+    continuation_table_ptr_loc: *mut Value,
+    note_live_size: NoteLiveSize,
+    note_reclaimed: NoteReclaimed,
+) {
+    let heap_end = get_hp() as u32;
+
+    assert_eq!(heap_base % 32, 0);
+
+    // The free lists are rebuilt by the sweep. Empty them first so that the bitmap and the mark
+    // stack are allocated after `heap_end`, rather than in free space that the sweep overwrites.
+    let free_space = free_list::free_space();
+    free_list::clear();
+
+    stats::begin_collection();
+    stats::begin_phase();
+
+    let scratch = mem.begin_scratch();
+
+    alloc_bitmap(mem, Bytes(heap_end - heap_base), heap_base / WORD_SIZE);
+    alloc_mark_stack(mem);
+
+    MARKED_WEAK_REFS = false;
+
+    let root_array = static_roots.as_array();
+    for i in 0..root_array.len() {
+        let mutbox = root_array.get(i).as_obj() as *mut MutBox;
+        mark_field(mem, &mut (*mutbox).field, heap_base);
+    }
+
+    mark_field(mem, continuation_table_ptr_loc, heap_base);
+
+    while let Some((obj, tag)) = pop_mark_stack(mem) {
+        mark_fields(mem, obj as *mut Obj, tag, heap_base);
+    }
+
+    if MARKED_WEAK_REFS {
+        clear_weak_refs(heap_base);
+    }
+
+    stats::end_phase(stats::Phase::Mark);
+    stats::begin_phase();
+
+    let sweep = sweep(heap_base, heap_end, free_space);
+
+    stats::end_phase(stats::Phase::Update);
+
+    free_mark_stack(mem);
+    free_bitmap();
+    mem.release_scratch(scratch);
+
+    set_hp(sweep.live_end);
+
+    stats::end_collection();
+
+    note_reclaimed(sweep.reclaimed);
+    note_live_size(sweep.live);
+}
+
+unsafe fn mark_field<M: Memory>(mem: &mut M, field_addr: *mut Value, heap_base: u32) {
+    if pointer_to_dynamic_heap(field_addr, heap_base as usize) {
+        mark_object(mem, *field_addr);
+    }
+}
+
+unsafe fn mark_object<M: Memory>(mem: &mut M, obj: Value) {
+    let obj_tag = obj.tag();
+    let obj = obj.get_ptr() as u32;
+
+    let obj_idx = obj / WORD_SIZE;
+
+    if get_bit(obj_idx) {
+        // Already marked
+        return;
+    }
+
+    set_bit(obj_idx);
+    push_mark_stack(mem, obj as usize, obj_tag);
+
+    if obj_tag == TAG_WEAK_REF {
+        MARKED_WEAK_REFS = true;
+    }
+
+    stats::note_marked();
+}
+
+unsafe fn mark_fields<M: Memory>(mem: &mut M, obj: *mut Obj, obj_tag: Tag, heap_base: u32) {
+    visit_pointer_fields(
+        mem,
+        obj,
+        obj_tag,
+        heap_base as usize,
+        |mem, field_addr| mark_object(mem, *field_addr),
+        |mem, slice_start, arr| {
+            const SLICE_INCREMENT: u32 = 127;
+            debug_assert!(SLICE_INCREMENT >= TAG_ARRAY_SLICE_MIN);
+            if arr.len() - slice_start > SLICE_INCREMENT {
+                let new_start = slice_start + SLICE_INCREMENT;
+                // push an entire (suffix) array slice
+                push_mark_stack(mem, arr as usize, new_start);
+                new_start
+            } else {
+                arr.len()
+            }
+        },
+    );
+}
+
+/// Clears the marked weak references whose targets are not marked
+unsafe fn clear_weak_refs(heap_base: u32) {
+    let mut bitmap_iter = iter_bits();
+    let mut bit = bitmap_iter.next();
+    while bit != BITMAP_ITER_END {
+        let obj = (bit * WORD_SIZE) as *mut Obj;
+        if obj.tag() == TAG_WEAK_REF {
+            let weak_ref = obj as *mut WeakRef;
+            let target_addr = &mut (*weak_ref).target;
+            if pointer_to_dynamic_heap(target_addr, heap_base as usize)
+                && !get_bit(((*target_addr).get_ptr() as u32) / WORD_SIZE)
+            {
+                crate::weak_ref::clear(weak_ref);
+            }
+        }
+        bit = bitmap_iter.next();
+    }
+}
+
+struct Sweep {
+    /// End of the last live object, the new heap pointer
+    live_end: u32,
+    /// Total size of the live objects
+    live: Bytes<u32>,
+    /// Total size of the dead objects, excluding free space left by the previous sweep
+    reclaimed: Bytes<u32>,
+}
+
+/// Turns the runs of dead objects between the marked objects into free space. Adjacent dead
+/// objects and free space objects are merged into one free space object. `free_space` is the total
+/// size of the free space objects in the heap before the sweep.
+unsafe fn sweep(heap_base: u32, heap_end: u32, free_space: Words<u32>) -> Sweep {
+    let mut sweep = Sweep {
+        live_end: heap_base,
+        live: Bytes(0),
+        reclaimed: Bytes(0),
+    };
+
+    let mut bitmap_iter = iter_bits();
+    let mut bit = bitmap_iter.next();
+    while bit != BITMAP_ITER_END {
+        let p = bit * WORD_SIZE;
+
+        if p != sweep.live_end {
+            note_freed_objects(sweep.live_end, p);
+            free_list::add_free_space(sweep.live_end, Bytes(p - sweep.live_end).to_words());
+        }
+
+        let size = object_size(p as usize).to_bytes();
+        sweep.live += size;
+        sweep.live_end = p + size.as_u32();
+
+        bit = bitmap_iter.next();
+    }
+
+    note_freed_objects(sweep.live_end, heap_end);
+
+    sweep.reclaimed = Bytes(heap_end - heap_base) - sweep.live - free_space.to_bytes();
+
+    sweep
+}
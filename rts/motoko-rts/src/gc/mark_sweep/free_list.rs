@@ -0,0 +1,155 @@
+//! Segregated free lists of the mark-sweep GC.
+//!
+//! Free space is kept in the heap as `FreeSpace` and `OneWordFiller` objects, so the heap can
+//! still be walked object by object. Free space objects of at least `MIN_BLOCK_SIZE` words are
+//! linked into a free list by their size: list `i` holds the blocks with sizes (in words) between
+//! `2^i` and `2^(i+1) - 1`. The link to the next block is stored in the word after the `FreeSpace`
+//! header. Smaller free space is not reused until the next sweep merges it with adjacent dead
+//! objects.
+//!
+//! An allocation takes the first large enough block in the list of the requested size, or the
+//! first block of a list with larger blocks. The rest of the block goes back to the free lists.
+//!
+//! The compacting collectors also add the space left before large objects to the free lists (see
+//! `large_object.rs`).
+
+use crate::types::*;
+
+/// Number of free lists. Objects are at most 4 GiB, i.e. less than 2^30 words.
+pub const N_SIZE_CLASSES: usize = 32;
+
+/// Size of the smallest free space linked into a free list: header, size, and the link
+pub const MIN_BLOCK_SIZE: Words<u32> = Words(3);
+
+/// A free space object in a free list
+#[repr(C)]
+struct FreeBlock {
+    header: FreeSpace,
+    /// Address of the next block in the list, 0 at the end of the list
+    next: u32,
+}
+
+/// Heads of the free lists, 0 when a list is empty
+static mut FREE_LISTS: [u32; N_SIZE_CLASSES] = [0; N_SIZE_CLASSES];
+
+/// Bit `i` is set when list `i` is not empty. Allows `alloc` to return quickly when there is no
+/// free space, which is always the case with the other collectors.
+static mut NON_EMPTY_LISTS: u32 = 0;
+
+/// Total size of the free space objects added since the last `clear`, including the ones too
+/// small for a free list, minus the allocated space. Used by the mark-sweep GC to find out how
+/// much of the dead space was already free before a collection, without walking the dead objects.
+static mut FREE_SPACE: Words<u32> = Words(0);
+
+/// Size class (free list index) of a block with the given size
+pub fn size_class(size: Words<u32>) -> usize {
+    debug_assert!(size.as_u32() != 0);
+    (31 - size.as_u32().leading_zeros()) as usize
+}
+
+/// Empties the free lists. The free space objects stay in the heap.
+pub unsafe fn clear() {
+    FREE_LISTS = [0; N_SIZE_CLASSES];
+    NON_EMPTY_LISTS = 0;
+    FREE_SPACE = Words(0);
+}
+
+/// See `FREE_SPACE`
+pub unsafe fn free_space() -> Words<u32> {
+    FREE_SPACE
+}
+
+/// Number of blocks in the free list of the given size class. Walks the list.
+pub unsafe fn list_len(class: usize) -> u32 {
+    let mut len = 0;
+    let mut block = FREE_LISTS[class];
+    while block != 0 {
+        len += 1;
+        block = (*(block as *mut FreeBlock)).next;
+    }
+    len
+}
+
+/// Turns the space at `addr` into a free space object, without adding it to a free list
+pub unsafe fn write_free_space(addr: u32, size: Words<u32>) {
+    debug_assert!(size.as_u32() != 0);
+
+    if size == Words(1) {
+        (*(addr as *mut OneWordFiller)).header.tag = TAG_ONE_WORD_FILLER;
+        return;
+    }
+
+    let free_space = addr as *mut FreeSpace;
+    (*free_space).header.tag = TAG_FREE_SPACE;
+    (*free_space).words = size - size_of::<Obj>();
+}
+
+/// Turns the space at `addr` into a free space object, and adds it to a free list if it's large
+/// enough
+pub unsafe fn add_free_space(addr: u32, size: Words<u32>) {
+    write_free_space(addr, size);
+    FREE_SPACE += size;
+
+    if size < MIN_BLOCK_SIZE {
+        return;
+    }
+
+    let class = size_class(size);
+    (*(addr as *mut FreeBlock)).next = FREE_LISTS[class];
+    FREE_LISTS[class] = addr;
+    NON_EMPTY_LISTS |= 1 << class;
+}
+
+/// Allocates `n` words from the free lists. Returns the address of the allocated space, or `None`
+/// when there isn't a large enough block.
+pub unsafe fn alloc(n: Words<u32>) -> Option<u32> {
+    if NON_EMPTY_LISTS == 0 {
+        return None;
+    }
+
+    let class = size_class(n);
+
+    // First fit in the list of the requested size. Blocks in this list may be smaller than `n`.
+    let mut prev: *mut u32 = &mut FREE_LISTS[class];
+    while *prev != 0 {
+        let block = *prev as *mut FreeBlock;
+        if (block as *mut FreeSpace).size() >= n {
+            *prev = (*block).next;
+            if FREE_LISTS[class] == 0 {
+                NON_EMPTY_LISTS &= !(1 << class);
+            }
+            return Some(split(block, n));
+        }
+        prev = &mut (*block).next;
+    }
+
+    // Any block in a list of larger blocks is large enough
+    let larger_lists = NON_EMPTY_LISTS & !(u32::MAX >> (31 - class));
+    if larger_lists == 0 {
+        return None;
+    }
+
+    let class = larger_lists.trailing_zeros() as usize;
+    let block = FREE_LISTS[class] as *mut FreeBlock;
+    FREE_LISTS[class] = (*block).next;
+    if FREE_LISTS[class] == 0 {
+        NON_EMPTY_LISTS &= !(1 << class);
+    }
+
+    Some(split(block, n))
+}
+
+/// Allocates `n` words at the start of a block taken from a free list. The rest of the block is
+/// added back to the free lists.
+unsafe fn split(block: *mut FreeBlock, n: Words<u32>) -> u32 {
+    let size = (block as *mut FreeSpace).size();
+    let addr = block as u32;
+
+    FREE_SPACE -= size;
+
+    if size > n {
+        add_free_space(addr + n.to_bytes().as_u32(), size - n);
+    }
+
+    addr
+}
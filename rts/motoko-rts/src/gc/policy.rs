@@ -0,0 +1,84 @@
+//! GC scheduling policies. The `schedule_*_gc` functions use the current policy to decide whether
+//! to do a collection at the end of a message.
+//!
+//! The policy can be changed with `set_gc_policy`. The compiler calls it on initialization when a
+//! policy is given with the `--gc-*` flags, so GC frequency can be tuned per canister.
+
+use crate::types::Bytes;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Policy {
+    /// Collect when the heap reaches the given percentage of the heap size after the last
+    /// collection, e.g. 150 collects when the heap grew by 50%. At least 100.
+    GrowthFactor(u32),
+
+    /// Collect when the given number of bytes were allocated since the last collection
+    AllocationBudget(u32),
+
+    /// Collect after every message. Useful for testing.
+    Always,
+}
+
+/// Policy used when `set_gc_policy` is not called
+pub const DEFAULT_POLICY: Policy = Policy::GrowthFactor(150);
+
+/// Policy codes used by `set_gc_policy`. Keep in sync with `compile.ml`.
+const POLICY_GROWTH_FACTOR: u32 = 0;
+const POLICY_ALLOCATION_BUDGET: u32 = 1;
+const POLICY_ALWAYS: u32 = 2;
+
+static mut POLICY: Policy = DEFAULT_POLICY;
+
+pub unsafe fn get_policy() -> Policy {
+    POLICY
+}
+
+pub unsafe fn set_policy(policy: Policy) {
+    if let Policy::GrowthFactor(percent) = policy {
+        if percent < 100 {
+            crate::rts_trap_with("set_gc_policy: growth factor should be at least 100%");
+        }
+    }
+
+    POLICY = policy;
+}
+
+/// Sets the scheduling policy. `parameter` is the growth factor in percent for the growth factor
+/// policy, the number of bytes for the allocation budget policy, and ignored for the "always"
+/// policy.
+#[no_mangle]
+pub unsafe extern "C" fn set_gc_policy(policy: u32, parameter: u32) {
+    let policy = match policy {
+        POLICY_GROWTH_FACTOR => Policy::GrowthFactor(parameter),
+        POLICY_ALLOCATION_BUDGET => Policy::AllocationBudget(parameter),
+        POLICY_ALWAYS => Policy::Always,
+        _ => crate::rts_trap_with("set_gc_policy: unknown policy"),
+    };
+
+    set_policy(policy);
+}
+
+impl Policy {
+    /// Whether to do a collection.
+    ///
+    /// - last_hp: Heap pointer after the last collection
+    ///
+    /// - hp: Current heap pointer
+    ///
+    /// - max_live: Maximum live data the collector can handle. Regardless of the policy, we
+    ///   collect when the heap reaches halfway between `last_hp` and `last_hp + max_live`, to
+    ///   leave space for the collection.
+    pub fn should_collect(self, last_hp: u32, hp: u32, max_live: Bytes<u64>) -> bool {
+        let last_hp = u64::from(last_hp);
+
+        let max_limit = (last_hp + max_live.0) / 2;
+
+        let heap_limit = match self {
+            Policy::GrowthFactor(percent) => last_hp * u64::from(percent) / 100,
+            Policy::AllocationBudget(bytes) => last_hp + u64::from(bytes),
+            Policy::Always => return true,
+        };
+
+        u64::from(hp) >= core::cmp::min(heap_limit, max_limit)
+    }
+}
@@ -0,0 +1,212 @@
+//! Statistics of the last garbage collection, for tuning the GC of a canister.
+//!
+//! The collectors call `begin_collection` when a collection starts, the `note_*` functions while
+//! collecting, and `end_collection` when the collection is done. The stats of the last finished
+//! collection can be read with the `gc_stats_*` functions. Stats of a collection in progress (e.g.
+//! between increments of the incremental GC) are not visible until the collection finishes.
+
+use crate::types::{Bytes, Tag};
+
+/// Phases of a collection, for instruction counts. Not all collectors have all phases: the
+/// copying GC reports evacuation as marking, the compacting GC threads pointers while marking,
+/// the mark-sweep GC reports sweeping as updating.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Phase {
+    Mark = 0,
+    Thread = 1,
+    Update = 2,
+}
+
+const N_PHASES: usize = 3;
+
+/// Number of object tags. Tags are odd numbers, `tag / 2` is the index of a tag in
+/// `GcStats::bytes_freed`.
+const N_TAGS: usize = 17;
+
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct GcStats {
+    /// Number of live objects found
+    pub objects_marked: u32,
+
+    /// Total size of the objects moved
+    pub bytes_moved: Bytes<u32>,
+
+    /// Size of the reclaimed objects, per tag. Free space left by earlier collections is not
+    /// included. Only recorded when enabled with `set_gc_stats_freed_by_tag`.
+    pub bytes_freed: [Bytes<u32>; N_TAGS],
+
+    /// Maximum size of the mark stack
+    pub mark_stack_high_water_mark: Bytes<u32>,
+
+    /// Size of the mark bitmap
+    pub bitmap_size: Bytes<u32>,
+
+    /// Total size of the blobs removed by deduplication, see `request_blob_dedup`
+    pub bytes_deduplicated: Bytes<u32>,
+
+    /// Wasm instructions executed in each phase. Always 0 outside of the IC.
+    pub instructions: [u64; N_PHASES],
+}
+
+impl GcStats {
+    pub const fn new() -> GcStats {
+        GcStats {
+            objects_marked: 0,
+            bytes_moved: Bytes(0),
+            bytes_freed: [Bytes(0); N_TAGS],
+            mark_stack_high_water_mark: Bytes(0),
+            bitmap_size: Bytes(0),
+            bytes_deduplicated: Bytes(0),
+            instructions: [0; N_PHASES],
+        }
+    }
+
+    /// Size of the reclaimed objects of the given tag
+    pub fn bytes_freed(&self, tag: Tag) -> Bytes<u32> {
+        self.bytes_freed[tag as usize / 2]
+    }
+
+    /// Total size of the reclaimed objects
+    pub fn total_bytes_freed(&self) -> Bytes<u32> {
+        self.bytes_freed
+            .iter()
+            .fold(Bytes(0), |acc, bytes| acc + *bytes)
+    }
+}
+
+/// Stats of the collection in progress
+static mut CURRENT: GcStats = GcStats::new();
+
+/// Stats of the last finished collection
+static mut LAST: GcStats = GcStats::new();
+
+/// Instruction counter at the start of the current phase
+static mut PHASE_START: u64 = 0;
+
+/// Whether `GcStats::bytes_freed` is recorded. The collectors don't visit dead objects otherwise,
+/// so this costs an extra walk over the dead objects in each collection.
+static mut FREED_BY_TAG: bool = false;
+
+/// Enables recording the sizes of the reclaimed objects per tag. The compiler calls this on
+/// initialization when `--gc-stats-freed-by-tag` is given.
+#[no_mangle]
+pub unsafe extern "C" fn set_gc_stats_freed_by_tag(enabled: bool) {
+    FREED_BY_TAG = enabled;
+}
+
+pub unsafe fn freed_by_tag() -> bool {
+    FREED_BY_TAG
+}
+
+pub unsafe fn begin_collection() {
+    CURRENT = GcStats::new();
+}
+
+pub unsafe fn end_collection() {
+    LAST = CURRENT;
+}
+
+/// Stats of the last finished collection
+pub unsafe fn last() -> GcStats {
+    LAST
+}
+
+pub unsafe fn note_marked() {
+    CURRENT.objects_marked += 1;
+}
+
+pub unsafe fn note_moved(size: Bytes<u32>) {
+    CURRENT.bytes_moved += size;
+}
+
+pub unsafe fn note_freed(tag: Tag, size: Bytes<u32>) {
+    debug_assert!((tag as usize / 2) < N_TAGS);
+    CURRENT.bytes_freed[tag as usize / 2] += size;
+}
+
+pub unsafe fn note_mark_stack_size(size: Bytes<u32>) {
+    CURRENT.mark_stack_high_water_mark = ::core::cmp::max(CURRENT.mark_stack_high_water_mark, size);
+}
+
+pub unsafe fn note_bitmap_size(size: Bytes<u32>) {
+    CURRENT.bitmap_size = size;
+}
+
+pub unsafe fn note_deduplicated(size: Bytes<u32>) {
+    CURRENT.bytes_deduplicated += size;
+}
+
+pub unsafe fn begin_phase() {
+    PHASE_START = instruction_counter();
+}
+
+/// Adds the instructions executed since the last `begin_phase` to the given phase. Phases can be
+/// ended multiple times in a collection, e.g. once per increment of the incremental GC.
+pub unsafe fn end_phase(phase: Phase) {
+    CURRENT.instructions[phase as usize] += instruction_counter() - PHASE_START;
+}
+
+#[cfg(feature = "ic")]
+unsafe fn instruction_counter() -> u64 {
+    extern "C" {
+        // generated by `moc`
+        fn performance_counter_moc(counter_type: u32) -> u64;
+    }
+
+    performance_counter_moc(0)
+}
+
+#[cfg(not(feature = "ic"))]
+unsafe fn instruction_counter() -> u64 {
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn gc_stats_objects_marked() -> u32 {
+    LAST.objects_marked
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn gc_stats_bytes_moved() -> u32 {
+    LAST.bytes_moved.as_u32()
+}
+
+/// Size of the objects with the given tag reclaimed in the last collection. Always 0 unless
+/// enabled with `set_gc_stats_freed_by_tag`.
+#[no_mangle]
+pub unsafe extern "C" fn gc_stats_bytes_freed(tag: Tag) -> u32 {
+    if tag as usize / 2 >= N_TAGS {
+        crate::rts_trap_with("gc_stats_bytes_freed: invalid tag");
+    }
+
+    LAST.bytes_freed(tag).as_u32()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn gc_stats_mark_stack_high_water_mark() -> u32 {
+    LAST.mark_stack_high_water_mark.as_u32()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn gc_stats_bitmap_size() -> u32 {
+    LAST.bitmap_size.as_u32()
+}
+
+/// Size of the blobs removed by deduplication in the last collection. These are also included in
+/// `gc_stats_bytes_freed(TAG_BLOB)`, when enabled.
+#[no_mangle]
+pub unsafe extern "C" fn gc_stats_bytes_deduplicated() -> u32 {
+    LAST.bytes_deduplicated.as_u32()
+}
+
+/// Instructions executed in the given phase (0: mark, 1: thread, 2: update) of the last
+/// collection
+#[no_mangle]
+pub unsafe extern "C" fn gc_stats_instructions(phase: u32) -> u64 {
+    if phase as usize >= N_PHASES {
+        crate::rts_trap_with("gc_stats_instructions: invalid phase");
+    }
+
+    LAST.instructions[phase as usize]
+}
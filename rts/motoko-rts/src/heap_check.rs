@@ -0,0 +1,299 @@
+//! Heap integrity checker. Unlike the functions in `debug.rs`, this is available in release builds
+//! so that it can be enabled in a canister, e.g. after an upgrade.
+//!
+//! The check walks the dynamic heap twice. The first walk checks the object headers and sizes and
+//! records where the objects start, the second walk checks that the pointer fields point to the
+//! start of an object. The object starts are recorded in a blob allocated in a scratch region
+//! after the heap.
+
+use crate::constants::WORD_SIZE;
+use crate::memory::{alloc_blob, with_scratch, Memory};
+use crate::tommath_bindings::mp_digit;
+use crate::types::*;
+use crate::visitor::{pointer_to_dynamic_heap, visit_pointer_fields};
+
+use core::fmt;
+
+use motoko_rts_macros::ic_mem_fn;
+
+/// Result of a successful heap check
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct HeapReport {
+    /// Number of objects in the heap, including filler objects
+    pub objects: u32,
+    /// Number of pointer fields checked
+    pub pointers: u32,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum HeapError {
+    /// Object header is not a valid tag
+    InvalidTag { obj: u32, tag: Tag },
+
+    /// Forwarding pointer in the heap. These should only exist while the copying GC is running.
+    ForwardingPointer { obj: u32 },
+
+    /// Free space object smaller than its header
+    InvalidFiller { obj: u32 },
+
+    /// Object extends past the heap pointer. Size is in words.
+    ObjectPastHeapEnd { obj: u32, size: u64 },
+
+    /// Pointer field points past the heap pointer
+    PointerPastHeapEnd { field: u32, ptr: u32 },
+
+    /// Pointer field does not point to the start of an object (points into the middle of an
+    /// object, or to a filler object)
+    PointerNotToObject { field: u32, ptr: u32 },
+}
+
+impl fmt::Display for HeapError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            HeapError::InvalidTag { obj, tag } => {
+                write!(f, "object at {:#x} has invalid tag {:#x}", obj, tag)
+            }
+            HeapError::ForwardingPointer { obj } => {
+                write!(f, "forwarding pointer at {:#x}", obj)
+            }
+            HeapError::InvalidFiller { obj } => {
+                write!(f, "free space object at {:#x} is malformed", obj)
+            }
+            HeapError::ObjectPastHeapEnd { obj, size } => write!(
+                f,
+                "object at {:#x} with size {:#x} words extends past the heap end",
+                obj, size
+            ),
+            HeapError::PointerPastHeapEnd { field, ptr } => write!(
+                f,
+                "field at {:#x} points to {:#x}, past the heap end",
+                field, ptr
+            ),
+            HeapError::PointerNotToObject { field, ptr } => write!(
+                f,
+                "field at {:#x} points to {:#x}, which is not the start of an object",
+                field, ptr
+            ),
+        }
+    }
+}
+
+/// Checks the dynamic heap. Traps with a description of the first problem found, otherwise
+/// returns the number of objects in the heap.
+#[ic_mem_fn(ic_only)]
+unsafe fn check_heap(mem: &mut crate::memory::ic::IcMemory) -> u32 {
+    use crate::continuation_table::continuation_table_loc;
+    use crate::memory::ic;
+    use crate::print::WriteBuf;
+    use core::fmt::Write;
+
+    let heap = mem.heap();
+    let continuation_table_ptr_loc = continuation_table_loc(mem);
+
+    match check_heap_internal(
+        mem,
+        (*heap).heap_start,
+        (*heap).hp,
+        ic::get_static_roots(),
+        continuation_table_ptr_loc,
+    ) {
+        Ok(report) => report.objects,
+        Err(err) => {
+            let mut buf = [0u8; 200];
+            let mut write_buf = WriteBuf::new(&mut buf);
+            let _ = write!(&mut write_buf, "RTS error: check_heap: {}", err);
+            let msg = write_buf.as_bytes();
+            crate::rts_trap(msg.as_ptr(), Bytes(msg.len() as u32));
+        }
+    }
+}
+
+/// Checks the objects between `heap_base` and `heap_end`, and the pointers to the dynamic heap in
+/// the static roots and the continuation table. `heap_base` needs to be where the first object is
+/// (`HeapState::heap_start`), which is after the heap base of the generated code when the heap is
+/// aligned.
+pub unsafe fn check_heap_internal<M: Memory>(
+    mem: &mut M,
+    heap_base: u32,
+    heap_end: u32,
+    static_roots: Value,
+    continuation_table_ptr_loc: *mut Value,
+) -> Result<HeapReport, HeapError> {
+    with_scratch(mem, |mem| {
+        let object_starts = ObjectStarts::new(mem, heap_base, heap_end);
+
+        let mut report = HeapReport {
+            objects: 0,
+            pointers: 0,
+        };
+
+        // Check headers and sizes, record object starts
+        let mut p = heap_base;
+        while p < heap_end {
+            let obj = p as *mut Obj;
+            let size = check_object_header(obj, heap_end)?;
+            if !is_filler(obj.tag()) {
+                object_starts.set(p);
+            }
+            report.objects += 1;
+            p += size.to_bytes().as_u32();
+        }
+
+        // Check pointers
+        let mut ctx = (object_starts, heap_end, &mut report, Ok(()));
+
+        let root_array = static_roots.as_array();
+        for i in 0..root_array.len() {
+            let mutbox = root_array.get(i).as_obj() as *mut MutBox;
+            check_field(&mut ctx, &mut (*mutbox).field, heap_base);
+        }
+
+        check_field(&mut ctx, continuation_table_ptr_loc, heap_base);
+
+        let mut p = heap_base;
+        while p < heap_end && ctx.3.is_ok() {
+            let obj = p as *mut Obj;
+            let tag = obj.tag();
+            if !is_filler(tag) {
+                visit_pointer_fields(
+                    &mut ctx,
+                    obj,
+                    tag,
+                    heap_base as usize,
+                    |ctx, field_addr| check_pointer(ctx, field_addr),
+                    |_, _, arr| arr.len(),
+                );
+            }
+            p += object_size(p as usize).to_bytes().as_u32();
+        }
+
+        ctx.3.map(|()| report)
+    })
+}
+
+type CheckCtx<'a> = (ObjectStarts, u32, &'a mut HeapReport, Result<(), HeapError>);
+
+unsafe fn check_field(ctx: &mut CheckCtx, field_addr: *mut Value, heap_base: u32) {
+    if pointer_to_dynamic_heap(field_addr, heap_base as usize) {
+        check_pointer(ctx, field_addr);
+    }
+}
+
+unsafe fn check_pointer(ctx: &mut CheckCtx, field_addr: *mut Value) {
+    if ctx.3.is_err() {
+        return;
+    }
+
+    let field = field_addr as u32;
+    let ptr = (*field_addr).get_ptr() as u32;
+
+    ctx.2.pointers += 1;
+
+    if ptr >= ctx.1 {
+        ctx.3 = Err(HeapError::PointerPastHeapEnd { field, ptr });
+    } else if !ctx.0.get(ptr) {
+        ctx.3 = Err(HeapError::PointerNotToObject { field, ptr });
+    }
+}
+
+fn is_filler(tag: Tag) -> bool {
+    tag == TAG_ONE_WORD_FILLER || tag == TAG_FREE_SPACE
+}
+
+/// Checks the header of an object, returns the size of the object
+unsafe fn check_object_header(obj: *mut Obj, heap_end: u32) -> Result<Words<u32>, HeapError> {
+    let tag = obj.tag();
+    let p = obj as u32;
+
+    if tag == TAG_FWD_PTR {
+        return Err(HeapError::ForwardingPointer { obj: p });
+    }
+
+    if tag < TAG_OBJECT || tag > TAG_WEAK_REF || tag % 2 == 0 {
+        return Err(HeapError::InvalidTag { obj: p, tag });
+    }
+
+    // Size of the part of the object before the payload, which includes the length of the
+    // variable-size objects. Check that it's in the heap before reading the length.
+    let fixed_size = match tag {
+        TAG_OBJECT => size_of::<Object>(),
+        TAG_ARRAY => size_of::<Array>(),
+        TAG_CLOSURE => size_of::<Closure>(),
+        TAG_BLOB => size_of::<Blob>(),
+        TAG_BIGINT => size_of::<BigInt>(),
+        TAG_FREE_SPACE => size_of::<FreeSpace>(),
+        _ => object_size(p as usize),
+    };
+
+    if u64::from(p) + u64::from(fixed_size.to_bytes().as_u32()) > u64::from(heap_end) {
+        return Err(if tag == TAG_FREE_SPACE {
+            HeapError::InvalidFiller { obj: p }
+        } else {
+            HeapError::ObjectPastHeapEnd {
+                obj: p,
+                size: u64::from(fixed_size.as_u32()),
+            }
+        });
+    }
+
+    // Lengths of corrupted objects can be large enough to overflow the size computation, so
+    // compute the size in 64 bits
+    let bytes_to_words = |bytes: u64| (bytes + u64::from(WORD_SIZE) - 1) / u64::from(WORD_SIZE);
+    let payload_size = match tag {
+        TAG_OBJECT => u64::from((obj as *mut Object).size()),
+        TAG_ARRAY => u64::from((obj as *mut Array).len()),
+        TAG_CLOSURE => u64::from((obj as *mut Closure).size()),
+        TAG_BLOB => bytes_to_words(u64::from((obj as *mut Blob).len().as_u32())),
+        TAG_BIGINT => bytes_to_words(
+            u64::from((*(obj as *mut BigInt)).mp_int.alloc as u32)
+                * core::mem::size_of::<mp_digit>() as u64,
+        ),
+        TAG_FREE_SPACE => {
+            // The size of free space includes the length field
+            let words = (*(obj as *mut FreeSpace)).words.as_u32();
+            if words == 0 {
+                return Err(HeapError::InvalidFiller { obj: p });
+            }
+            u64::from(words) - 1
+        }
+        _ => 0,
+    };
+    let size = u64::from(fixed_size.as_u32()) + payload_size;
+
+    if u64::from(p) + size * u64::from(WORD_SIZE) > u64::from(heap_end) {
+        return Err(HeapError::ObjectPastHeapEnd { obj: p, size });
+    }
+
+    Ok(Words(size as u32))
+}
+
+/// A bitmap of object start addresses in the dynamic heap
+struct ObjectStarts {
+    heap_base: u32,
+    bits: *mut u8,
+}
+
+impl ObjectStarts {
+    unsafe fn new<M: Memory>(mem: &mut M, heap_base: u32, heap_end: u32) -> ObjectStarts {
+        let n_words = (heap_end - heap_base) / WORD_SIZE;
+        let n_bytes = Bytes((n_words + 7) / 8);
+        let blob = alloc_blob(mem, n_bytes).get_ptr() as *mut Blob;
+        let bits = blob.payload_addr();
+        crate::mem_utils::memzero(bits as usize, n_bytes.to_words());
+        ObjectStarts { heap_base, bits }
+    }
+
+    unsafe fn set(&self, addr: u32) {
+        let idx = (addr - self.heap_base) / WORD_SIZE;
+        *self.bits.add(idx as usize / 8) |= 1 << (idx % 8);
+    }
+
+    /// Whether an object starts at the given address. The address needs to be in the heap.
+    unsafe fn get(&self, addr: u32) -> bool {
+        if addr % WORD_SIZE != 0 {
+            return false;
+        }
+        let idx = (addr - self.heap_base) / WORD_SIZE;
+        (*self.bits.add(idx as usize / 8) >> (idx % 8)) & 0b1 != 0
+    }
+}
@@ -0,0 +1,198 @@
+//! Heap snapshots: serializes the object graph of the dynamic heap into a blob, for offline
+//! analysis. `rts/heap-snapshot` converts the snapshots to the V8 `.heapsnapshot` format.
+//!
+//! All numbers in a snapshot are 32-bit little-endian. A snapshot has the following layout:
+//!
+//! - Header: magic number (`SNAPSHOT_MAGIC`), format version (`SNAPSHOT_VERSION`), start and end
+//!   of the dynamic heap, number of roots, number of objects.
+//!
+//! - Roots: for each root, its kind (`ROOT_STATIC` or `ROOT_CONTINUATION_TABLE`), the index of the
+//!   root (in the static roots array for static roots, 0 for the continuation table), and the
+//!   address of the object pointed by the root.
+//!
+//! - Objects: for each object in the dynamic heap (except filler objects), in heap order: its
+//!   address, tag, size in bytes, number of outgoing pointers, followed by the outgoing pointers.
+//!   A pointer is the index of the field in the object (in words, from the start of the object)
+//!   and the address of the pointed object.
+//!
+//! Only pointers to the dynamic heap are recorded. Objects are not marked, so the snapshot may
+//! include unreachable objects.
+
+use crate::constants::WORD_SIZE;
+use crate::memory::{alloc_blob, Memory};
+use crate::types::*;
+use crate::visitor::{pointer_to_dynamic_heap, visit_pointer_fields};
+
+use motoko_rts_macros::ic_mem_fn;
+
+/// "MOHS" in little-endian
+pub const SNAPSHOT_MAGIC: u32 = 0x5348_4f4d;
+
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+pub const ROOT_STATIC: u32 = 0;
+pub const ROOT_CONTINUATION_TABLE: u32 = 1;
+
+/// Returns a snapshot of the dynamic heap as a blob. See the module documentation for the format.
+#[ic_mem_fn(ic_only)]
+unsafe fn heap_snapshot(mem: &mut crate::memory::ic::IcMemory) -> Value {
+    use crate::continuation_table::continuation_table_loc;
+    use crate::memory::ic;
+
+    let heap = mem.heap();
+    let continuation_table_ptr_loc = continuation_table_loc(mem);
+
+    heap_snapshot_internal(
+        mem,
+        (*heap).heap_start,
+        (*heap).hp,
+        ic::get_static_roots(),
+        continuation_table_ptr_loc,
+    )
+}
+
+/// Takes a snapshot of the objects between `heap_base` and `heap_end`. `heap_base` needs to be
+/// where the first object is (`HeapState::heap_start`). The snapshot blob is allocated after
+/// `heap_end`.
+pub unsafe fn heap_snapshot_internal<M: Memory>(
+    mem: &mut M,
+    heap_base: u32,
+    heap_end: u32,
+    static_roots: Value,
+    continuation_table_ptr_loc: *mut Value,
+) -> Value {
+    // Count the roots, objects and pointers to find the snapshot size
+    let mut writer = SnapshotWriter::counter();
+    write_snapshot(
+        &mut writer,
+        heap_base,
+        heap_end,
+        static_roots,
+        continuation_table_ptr_loc,
+    );
+
+    let blob = alloc_blob(mem, Bytes(writer.n_words * WORD_SIZE));
+
+    let mut writer = SnapshotWriter::new((blob.get_ptr() as *mut Blob).payload_addr() as *mut u32);
+    write_snapshot(
+        &mut writer,
+        heap_base,
+        heap_end,
+        static_roots,
+        continuation_table_ptr_loc,
+    );
+
+    blob
+}
+
+/// Writes words to a buffer, or only counts the words when the buffer is null
+struct SnapshotWriter {
+    buf: *mut u32,
+    n_words: u32,
+}
+
+impl SnapshotWriter {
+    fn counter() -> SnapshotWriter {
+        SnapshotWriter::new(core::ptr::null_mut())
+    }
+
+    fn new(buf: *mut u32) -> SnapshotWriter {
+        SnapshotWriter { buf, n_words: 0 }
+    }
+
+    unsafe fn write(&mut self, word: u32) {
+        if !self.buf.is_null() {
+            *self.buf.add(self.n_words as usize) = word;
+        }
+        self.n_words += 1;
+    }
+
+    /// Reserves a word to be written later with `write_at`. Returns the index of the word.
+    unsafe fn reserve(&mut self) -> u32 {
+        let idx = self.n_words;
+        self.write(0);
+        idx
+    }
+
+    unsafe fn write_at(&mut self, idx: u32, word: u32) {
+        if !self.buf.is_null() {
+            *self.buf.add(idx as usize) = word;
+        }
+    }
+}
+
+unsafe fn write_snapshot(
+    writer: &mut SnapshotWriter,
+    heap_base: u32,
+    heap_end: u32,
+    static_roots: Value,
+    continuation_table_ptr_loc: *mut Value,
+) {
+    writer.write(SNAPSHOT_MAGIC);
+    writer.write(SNAPSHOT_VERSION);
+    writer.write(heap_base);
+    writer.write(heap_end);
+    let n_roots_idx = writer.reserve();
+    let n_objects_idx = writer.reserve();
+
+    let mut n_roots = 0;
+
+    let root_array = static_roots.as_array();
+    for i in 0..root_array.len() {
+        let mutbox = root_array.get(i).as_obj() as *mut MutBox;
+        let field_addr = &mut (*mutbox).field as *mut Value;
+        if pointer_to_dynamic_heap(field_addr, heap_base as usize) {
+            writer.write(ROOT_STATIC);
+            writer.write(i);
+            writer.write((*field_addr).get_ptr() as u32);
+            n_roots += 1;
+        }
+    }
+
+    if pointer_to_dynamic_heap(continuation_table_ptr_loc, heap_base as usize) {
+        writer.write(ROOT_CONTINUATION_TABLE);
+        writer.write(0);
+        writer.write((*continuation_table_ptr_loc).get_ptr() as u32);
+        n_roots += 1;
+    }
+
+    writer.write_at(n_roots_idx, n_roots);
+
+    let mut n_objects = 0;
+
+    let mut p = heap_base;
+    while p < heap_end {
+        let obj = p as *mut Obj;
+        let tag = obj.tag();
+        let size = object_size(p as usize).to_bytes();
+
+        if tag != TAG_ONE_WORD_FILLER && tag != TAG_FREE_SPACE {
+            writer.write(p);
+            writer.write(tag);
+            writer.write(size.as_u32());
+            let n_pointers_idx = writer.reserve();
+
+            let mut ctx = (&mut *writer, 0u32);
+            visit_pointer_fields(
+                &mut ctx,
+                obj,
+                tag,
+                heap_base as usize,
+                |ctx, field_addr| {
+                    ctx.0.write((field_addr as u32 - p) / WORD_SIZE);
+                    ctx.0.write((*field_addr).get_ptr() as u32);
+                    ctx.1 += 1;
+                },
+                |_, _, arr| arr.len(),
+            );
+
+            let n_pointers = ctx.1;
+            writer.write_at(n_pointers_idx, n_pointers);
+            n_objects += 1;
+        }
+
+        p += size.as_u32();
+    }
+
+    writer.write_at(n_objects_idx, n_objects);
+}
@@ -70,7 +70,9 @@ unsafe fn parse_fields(buf: *mut Buf, n_types: u32) {
     }
 }
 
-// NB. This function assumes the allocation does not need to survive GC
+// NB. This function assumes the allocation does not need to survive GC. The type table can't be in
+// a scratch region (see `Memory::begin_scratch`), as the generated code allocates the deserialized
+// values while using the table.
 unsafe fn alloc<M: Memory>(mem: &mut M, size: Words<u32>) -> *mut u8 {
     alloc_blob(mem, size.to_bytes())
         .as_blob_mut()
@@ -0,0 +1,191 @@
+//! Intern table for immutable blobs: `blob_intern` returns a blob with the same contents that was
+//! interned before, if there is one, so that blobs stored many times (e.g. principals and short
+//! texts) can share one copy. Only blobs that are not mutated after interning can be interned,
+//! which is the case for Motoko `Blob`, `Text`, and `Principal` values.
+//!
+//! The table is an open addressing hash table in an array. The first element is the number of used
+//! slots, followed by two elements per slot: a weak reference to the interned blob (see
+//! `weak_ref.rs`) and the CRC32 of the blob contents, or `EMPTY` and `EMPTY` for unused slots.
+//! Holding the blobs weakly means that the table doesn't keep them alive: slots with cleared weak
+//! references are reused for new blobs, and dropped when the table is resized.
+//!
+//! The table is stored in a static `MutBox` allocated by the compiler (see `get_intern_table`), so
+//! that the collectors treat it as a root.
+
+use crate::gc::write_with_barrier;
+use crate::memory::{alloc_array, Memory};
+use crate::principal_id::compute_crc32;
+use crate::rts_trap_with;
+use crate::text::blob_compare;
+use crate::types::*;
+use crate::weak_ref::{weak_ref_get, weak_ref_is_live, weak_ref_new};
+
+use motoko_rts_macros::ic_mem_fn;
+
+/// Number of slots in a new table. Needs to be a power of two.
+pub const INIT_CAPACITY: u32 = 64;
+
+/// Value of the elements of unused slots
+const EMPTY: Value = Value::from_scalar(0);
+
+/// Array index of the number of used slots
+const N_USED_IDX: u32 = 0;
+
+/// Array index of the first slot
+const SLOTS_IDX: u32 = 1;
+
+/// Returns an interned blob with the same contents as `blob`. Interns `blob` if there isn't one.
+#[ic_mem_fn(ic_only)]
+unsafe fn blob_intern<M: Memory>(mem: &mut M, blob: Value) -> Value {
+    intern(mem, intern_table_loc(), blob)
+}
+
+/// Same as `blob_intern`, but concatenated texts are flattened to a blob first
+#[ic_mem_fn(ic_only)]
+unsafe fn text_intern<M: Memory>(mem: &mut M, text: Value) -> Value {
+    let blob = crate::text::blob_of_text(mem, text);
+    intern(mem, intern_table_loc(), blob)
+}
+
+#[cfg(feature = "ic")]
+unsafe fn intern_table_loc() -> *mut Value {
+    extern "C" {
+        fn get_intern_table() -> Value;
+    }
+
+    let mutbox = get_intern_table().as_obj() as *mut MutBox;
+    &mut (*mutbox).field
+}
+
+/// Returns a blob with the same contents as `blob` from the table at `table_loc`, or adds `blob` to
+/// the table and returns it when there isn't one. `table_loc` is a scalar until the first blob is
+/// interned.
+pub unsafe fn intern<M: Memory>(mem: &mut M, table_loc: *mut Value, blob: Value) -> Value {
+    if blob.tag() != TAG_BLOB {
+        rts_trap_with("blob_intern: Blob expected");
+    }
+
+    if !(*table_loc).is_ptr() {
+        let table = alloc_table(mem, INIT_CAPACITY);
+        write_with_barrier(mem, table_loc, table);
+    }
+
+    let table = (*table_loc).as_array();
+    let hash = Value::from_scalar(compute_crc32(blob) >> 1);
+
+    let mask = capacity(table) - 1;
+    let mut slot = hash.get_scalar() & mask;
+    let mut free_slot = None;
+
+    loop {
+        let weak_ref = table.get(weak_ref_idx(slot));
+
+        if weak_ref == EMPTY {
+            break;
+        }
+
+        if !weak_ref_is_live(weak_ref) {
+            free_slot = free_slot.or(Some(slot));
+        } else if table.get(hash_idx(slot)) == hash
+            && blob_compare((*weak_ref.as_weak_ref()).target, blob) == 0
+        {
+            return weak_ref_get(mem, weak_ref);
+        }
+
+        slot = (slot + 1) & mask;
+    }
+
+    let weak_ref = weak_ref_new(mem, blob);
+
+    // Reuse the first slot with a cleared weak reference on the way, if there is one
+    let slot = match free_slot {
+        Some(free_slot) => free_slot,
+        None => {
+            let n_used = table.get(N_USED_IDX).get_scalar() + 1;
+            table.set(N_USED_IDX, Value::from_scalar(n_used));
+            slot
+        }
+    };
+
+    write_with_barrier(
+        mem,
+        table.payload_addr().add(weak_ref_idx(slot) as usize),
+        weak_ref,
+    );
+    table.set(hash_idx(slot), hash);
+
+    // Keep the load factor below 3/4
+    if table.get(N_USED_IDX).get_scalar() * 4 > capacity(table) * 3 {
+        let new_table = rehash(mem, table);
+        write_with_barrier(mem, table_loc, new_table);
+    }
+
+    blob
+}
+
+unsafe fn alloc_table<M: Memory>(mem: &mut M, capacity: u32) -> Value {
+    debug_assert!(capacity.is_power_of_two());
+
+    let table = alloc_array(mem, SLOTS_IDX + 2 * capacity);
+    let array = table.as_array();
+    for i in 0..array.len() {
+        array.set(i, EMPTY);
+    }
+    table
+}
+
+/// Number of slots in the table
+unsafe fn capacity(table: *mut Array) -> u32 {
+    (table.len() - SLOTS_IDX) / 2
+}
+
+fn weak_ref_idx(slot: u32) -> u32 {
+    SLOTS_IDX + 2 * slot
+}
+
+fn hash_idx(slot: u32) -> u32 {
+    SLOTS_IDX + 2 * slot + 1
+}
+
+/// Moves the live entries to a new table, at most half full. Slots with cleared weak references
+/// are dropped.
+unsafe fn rehash<M: Memory>(mem: &mut M, table: *mut Array) -> Value {
+    let mut n_live = 0;
+    for slot in 0..capacity(table) {
+        let weak_ref = table.get(weak_ref_idx(slot));
+        if weak_ref != EMPTY && weak_ref_is_live(weak_ref) {
+            n_live += 1;
+        }
+    }
+
+    let mut new_capacity = INIT_CAPACITY;
+    while new_capacity < n_live * 2 {
+        new_capacity *= 2;
+    }
+
+    let new_table = alloc_table(mem, new_capacity);
+    let new_array = new_table.as_array();
+    let mask = new_capacity - 1;
+
+    for slot in 0..capacity(table) {
+        let weak_ref = table.get(weak_ref_idx(slot));
+        if weak_ref == EMPTY || !weak_ref_is_live(weak_ref) {
+            continue;
+        }
+
+        let hash = table.get(hash_idx(slot));
+        let mut new_slot = hash.get_scalar() & mask;
+        while new_array.get(weak_ref_idx(new_slot)) != EMPTY {
+            new_slot = (new_slot + 1) & mask;
+        }
+
+        // The new table is not scanned by the incremental GC in the current cycle, but the weak
+        // references are reachable from the old table, so no barrier is needed
+        new_array.set(weak_ref_idx(new_slot), weak_ref);
+        new_array.set(hash_idx(new_slot), hash);
+    }
+
+    new_array.set(N_USED_IDX, Value::from_scalar(n_live));
+
+    new_table
+}
@@ -9,30 +9,39 @@ mod print;
 #[cfg(debug_assertions)]
 pub mod debug;
 
+pub mod alloc_profile;
 pub mod bigint;
 #[cfg(feature = "ic")]
 mod blob_iter;
 pub mod buf;
+pub mod census;
 mod char;
 pub mod constants;
 pub mod continuation_table;
 #[cfg(feature = "ic")]
 mod float;
 pub mod gc;
+pub mod heap_check;
+pub mod heap_snapshot;
 #[cfg(feature = "ic")]
 mod idl;
+pub mod intern;
 pub mod leb128;
 mod mem_utils;
 pub mod memory;
 pub mod principal_id;
+pub mod retainer_path;
 mod static_checks;
 pub mod stream;
 pub mod text;
 pub mod text_iter;
+pub mod text_search;
 mod tommath_bindings;
 pub mod types;
+pub mod unicode;
 pub mod utf8;
 mod visitor;
+pub mod weak_ref;
 
 use types::Bytes;
 
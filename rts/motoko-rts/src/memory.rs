@@ -2,6 +2,9 @@
 pub mod ic;
 
 use crate::constants::WASM_HEAP_SIZE;
+use crate::continuation_table::ContinuationTable;
+use crate::gc::copying::CopyingState;
+use crate::gc::mark_compact::mark_stack::MarkStack;
 use crate::rts_trap_with;
 use crate::types::*;
 
@@ -10,8 +13,8 @@ use motoko_rts_macros::ic_mem_fn;
 /// A trait for heap allocation. RTS functions allocate in heap via this trait.
 ///
 /// To be able to link the RTS with moc-generated code, we implement wrappers around allocating
-/// functions that pass `ic::IcMemory` for the `Memory` arguments, and export these functions with
-/// the expected names for the generated code. For example, for a function like
+/// functions that pass `ic::IcMemory::current()` for the `Memory` arguments, and export these
+/// functions with the expected names for the generated code. For example, for a function like
 ///
 /// ```
 /// unsafe fn allocating_function<M: Memory>(mem: &mut M) { ... }
@@ -21,15 +24,71 @@ use motoko_rts_macros::ic_mem_fn;
 ///
 /// ```
 /// #[no_mangle]
-/// unsafe extern "C" fn export_name() { allocating_function(crate::memory::ic::IcMemory) }
+/// unsafe extern "C" fn export_name() {
+///     allocating_function(&mut crate::memory::ic::IcMemory::current())
+/// }
 /// ```
 ///
 /// This function does not take any `Memory` arguments can be used by the generated code.
+///
+/// Besides allocating, a `Memory` holds the RTS state of its heap (see `Context`), so that
+/// several heaps can be used independently.
 pub trait Memory {
     unsafe fn alloc_words(&mut self, n: Words<u32>) -> Value;
+
+    /// The RTS state of the heap
+    unsafe fn context(&mut self) -> &mut Context;
+
+    /// Starts a scratch region. The objects allocated until the region is released with
+    /// `release_scratch` are scratch data, which is freed all at once by `release_scratch`.
+    ///
+    /// Scratch data must not be used after the region is released, and only scratch data can be
+    /// allocated in a region. Regions can be nested, and must be released in the reverse order.
+    unsafe fn begin_scratch(&mut self) -> ScratchRegion;
+
+    /// Frees the objects allocated since the region was started, in O(1)
+    unsafe fn release_scratch(&mut self, region: ScratchRegion);
+}
+
+/// RTS state that belongs to a heap, rather than to the whole RTS. It's kept in the `Memory` of the
+/// heap instead of in globals, so that tests can use several heaps. Most of the GC state is still
+/// global, so the RTS can't be used from several threads yet (see "Threads" in `rts/README.md`).
+pub struct Context {
+    /// See `continuation_table.rs`
+    pub continuation_table: ContinuationTable,
+
+    /// See `gc/mark_compact/mark_stack.rs`
+    pub mark_stack: MarkStack,
+
+    /// See `gc/copying.rs`
+    pub copying_gc: CopyingState,
+}
+
+impl Context {
+    pub const fn new() -> Context {
+        Context {
+            continuation_table: ContinuationTable::new(),
+            mark_stack: MarkStack::new(),
+            copying_gc: CopyingState::new(),
+        }
+    }
+}
+
+/// A scratch region started with `Memory::begin_scratch`. Holds the heap pointer at the start of
+/// the region.
+#[must_use]
+pub struct ScratchRegion(pub usize);
+
+/// Runs `f` in a scratch region. The result of `f` cannot be an object allocated in the region.
+pub unsafe fn with_scratch<M: Memory, R, F: FnOnce(&mut M) -> R>(mem: &mut M, f: F) -> R {
+    let region = mem.begin_scratch();
+    let result = f(mem);
+    mem.release_scratch(region);
+    result
 }
 
-/// Helper for allocating blobs
+/// Helper for allocating blobs. Large blobs are not moved by the collectors, see
+/// `gc/large_object.rs`.
 #[ic_mem_fn]
 pub unsafe fn alloc_blob<M: Memory>(mem: &mut M, size: Bytes<u32>) -> Value {
     let ptr = mem.alloc_words(size_of::<Blob>() + size.to_words());
@@ -40,7 +99,8 @@ pub unsafe fn alloc_blob<M: Memory>(mem: &mut M, size: Bytes<u32>) -> Value {
     ptr
 }
 
-/// Helper for allocating arrays
+/// Helper for allocating arrays. Large arrays are not moved by the collectors, see
+/// `gc/large_object.rs`.
 #[ic_mem_fn]
 pub unsafe fn alloc_array<M: Memory>(mem: &mut M, len: u32) -> Value {
     // Array payload should not be larger than half of the memory
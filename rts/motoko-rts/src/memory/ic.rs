@@ -1,31 +1,69 @@
 // This module is only enabled when compiling the RTS for IC or WASI.
 
-use super::Memory;
+use super::{Context, Memory, ScratchRegion};
 use crate::constants::WASM_PAGE_SIZE;
 use crate::rts_trap_with;
 use crate::types::*;
 
 use core::arch::wasm32;
 
-/// Maximum live data retained in a GC.
-pub(crate) static mut MAX_LIVE: Bytes<u32> = Bytes(0);
+use motoko_rts_macros::ic_mem_fn;
 
-/// Amount of garbage collected so far.
-pub(crate) static mut RECLAIMED: Bytes<u64> = Bytes(0);
+/// State of a heap: the allocator state, and the RTS state of the heap (`Context`). It's passed to
+/// the RTS functions in `IcMemory`, rather than read from globals, as a step towards per-thread
+/// heaps (see "Threads" in `rts/README.md`).
+pub(crate) struct HeapState {
+    /// Start of the dynamic heap, where the first object is allocated (see `init`). Functions that
+    /// walk the heap object by object need to start here rather than at `get_heap_base`.
+    pub heap_start: u32,
 
-/// Counter for total allocations
-pub(crate) static mut ALLOCATED: Bytes<u64> = Bytes(0);
+    /// Heap pointer
+    pub hp: u32,
 
-/// Heap pointer
-pub(crate) static mut HP: u32 = 0;
+    /// Heap pointer after last GC
+    pub last_hp: u32,
 
-/// Heap pointer after last GC
-pub(crate) static mut LAST_HP: u32 = 0;
+    /// Maximum live data retained in a GC
+    pub max_live: Bytes<u32>,
+
+    /// Amount of garbage collected so far
+    pub reclaimed: Bytes<u64>,
+
+    /// Counter for total allocations
+    pub allocated: Bytes<u64>,
+
+    /// Soft limit of the heap pointer, see `set_heap_limit`. No limit by default.
+    pub heap_limit: u32,
+
+    /// Whether an allocation crossed `heap_limit` since the last `check_heap_limit`
+    pub heap_limit_exceeded: bool,
+
+    /// Whether the low-memory hook is running, to avoid calling it recursively
+    pub in_low_memory_hook: bool,
+
+    /// RTS state of the heap
+    pub context: Context,
+}
+
+/// The heap of the generated code. The RTS is single-threaded so far, so there's only one.
+static mut HEAP: HeapState = HeapState {
+    heap_start: 0,
+    hp: 0,
+    last_hp: 0,
+    max_live: Bytes(0),
+    reclaimed: Bytes(0),
+    allocated: Bytes(0),
+    heap_limit: u32::MAX,
+    heap_limit_exceeded: false,
+    in_low_memory_hook: false,
+    context: Context::new(),
+};
 
 // Provided by generated code
 extern "C" {
     pub(crate) fn get_heap_base() -> u32;
     pub(crate) fn get_static_roots() -> Value;
+    fn low_memory_moc();
 }
 
 pub(crate) unsafe fn get_aligned_heap_base() -> u32 {
@@ -33,60 +71,148 @@ pub(crate) unsafe fn get_aligned_heap_base() -> u32 {
     ((get_heap_base() + 31) / 32) * 32
 }
 
-#[no_mangle]
-unsafe extern "C" fn init(align: bool) {
-    HP = if align {
+/// Initializes the heap. The dynamic heap starts at the heap base, aligned to 32 bytes when `align`
+/// is set (for all GCs except the copying GC, which doesn't need the alignment). The words between
+/// the heap base and the aligned heap base are then not part of the heap.
+#[ic_mem_fn(ic_only)]
+unsafe fn init(mem: &mut IcMemory, align: bool) {
+    let heap = mem.heap();
+    (*heap).heap_start = if align {
         get_aligned_heap_base()
     } else {
         get_heap_base()
     };
-    LAST_HP = HP;
+    (*heap).hp = (*heap).heap_start;
+    (*heap).last_hp = (*heap).hp;
+}
+
+#[ic_mem_fn(ic_only)]
+unsafe fn get_max_live_size(mem: &mut IcMemory) -> Bytes<u32> {
+    (*mem.heap()).max_live
 }
 
-#[no_mangle]
-unsafe extern "C" fn get_max_live_size() -> Bytes<u32> {
-    MAX_LIVE
+#[ic_mem_fn(ic_only)]
+unsafe fn get_reclaimed(mem: &mut IcMemory) -> Bytes<u64> {
+    (*mem.heap()).reclaimed
 }
 
-#[no_mangle]
-unsafe extern "C" fn get_reclaimed() -> Bytes<u64> {
-    RECLAIMED
+#[ic_mem_fn(ic_only)]
+unsafe fn get_total_allocations(mem: &mut IcMemory) -> Bytes<u64> {
+    (*mem.heap()).allocated
 }
 
-#[no_mangle]
-unsafe extern "C" fn get_total_allocations() -> Bytes<u64> {
-    ALLOCATED
+#[ic_mem_fn(ic_only)]
+unsafe fn get_heap_size(mem: &mut IcMemory) -> Bytes<u32> {
+    Bytes((*mem.heap()).hp - get_aligned_heap_base())
 }
 
-#[no_mangle]
-unsafe extern "C" fn get_heap_size() -> Bytes<u32> {
-    Bytes(HP - get_aligned_heap_base())
+/// Sets a soft limit of the heap size, lower than the Wasm memory limit. The compiler calls this on
+/// initialization when the limit is given with `--heap-limit`.
+///
+/// Allocations can cross the limit, but a full collection is forced at the end of the message (see
+/// `heap_limit_exceeded`). If the heap is still larger than the limit after the collection,
+/// `check_heap_limit` calls the low-memory hook of the generated code, which runs the hook
+/// registered by the program. The hook can't run earlier: the collector can only run at the end of
+/// a message, and when growing the memory fails the message traps and the state changes of the hook
+/// would be rolled back.
+#[ic_mem_fn(ic_only)]
+unsafe fn set_heap_limit(mem: &mut IcMemory, limit: Bytes<u32>) {
+    (*mem.heap()).heap_limit = get_aligned_heap_base().saturating_add(limit.as_u32());
+}
+
+/// Whether the generated code should force a full collection after the scheduled one, as an
+/// allocation crossed the heap limit
+#[ic_mem_fn(ic_only)]
+unsafe fn heap_limit_exceeded(mem: &mut IcMemory) -> bool {
+    (*mem.heap()).heap_limit_exceeded
+}
+
+/// Called by the generated code after the forced collection. Calls the low-memory hook if the heap
+/// is still larger than the limit.
+#[ic_mem_fn(ic_only)]
+unsafe fn check_heap_limit(mem: &mut IcMemory) {
+    let heap = mem.heap();
+    (*heap).heap_limit_exceeded = false;
+    if (*heap).hp > (*heap).heap_limit && !(*heap).in_low_memory_hook {
+        (*heap).in_low_memory_hook = true;
+        low_memory_moc();
+        (*heap).in_low_memory_hook = false;
+    }
 }
 
 /// Provides a `Memory` implementation, to be used in functions compiled for IC or WASI. The
 /// `Memory` implementation allocates in Wasm heap with Wasm `memory.grow` instruction.
-pub struct IcMemory;
+pub struct IcMemory {
+    heap: *mut HeapState,
+}
+
+impl IcMemory {
+    /// The `Memory` of the heap of the generated code. The `ic_mem_fn` wrappers pass this to the
+    /// RTS functions.
+    #[inline]
+    pub unsafe fn current() -> IcMemory {
+        IcMemory { heap: &mut HEAP }
+    }
+
+    /// State of the heap. A pointer rather than a reference, so that the GC callbacks can update
+    /// the state while the `IcMemory` is passed to the GC.
+    #[inline]
+    pub(crate) fn heap(&mut self) -> *mut HeapState {
+        self.heap
+    }
+}
 
 impl Memory for IcMemory {
     #[inline]
     unsafe fn alloc_words(&mut self, n: Words<u32>) -> Value {
+        let heap = self.heap;
         let bytes = n.to_bytes();
-        // Update ALLOCATED
+        // Update the allocation counter
         let delta = u64::from(bytes.as_u32());
-        ALLOCATED += Bytes(delta);
+        (*heap).allocated += Bytes(delta);
+
+        // Reuse the free space left by the mark-sweep GC, or before large objects when compacting
+        if let Some(addr) = crate::gc::mark_sweep::free_list::alloc(n) {
+            crate::alloc_profile::note_allocation(addr, bytes);
+            return Value::from_ptr(addr as usize);
+        }
 
         // Update heap pointer
-        let old_hp = u64::from(HP);
+        let old_hp = u64::from((*heap).hp);
         let new_hp = old_hp + delta;
 
+        if new_hp > u64::from((*heap).heap_limit) {
+            (*heap).heap_limit_exceeded = true;
+        }
+
         // Grow memory if needed
         grow_memory(new_hp);
 
         debug_assert!(new_hp <= u64::from(core::u32::MAX));
-        HP = new_hp as u32;
+        (*heap).hp = new_hp as u32;
+
+        crate::alloc_profile::note_allocation(old_hp as u32, bytes);
 
         Value::from_ptr(old_hp as usize)
     }
+
+    #[inline]
+    unsafe fn context(&mut self) -> &mut Context {
+        &mut (*self.heap).context
+    }
+
+    #[inline]
+    unsafe fn begin_scratch(&mut self) -> ScratchRegion {
+        ScratchRegion((*self.heap).hp as usize)
+    }
+
+    /// Scratch data allocated from the free lists of the mark-sweep GC is not freed, it's
+    /// reclaimed by the next collection instead.
+    #[inline]
+    unsafe fn release_scratch(&mut self, region: ScratchRegion) {
+        debug_assert!(region.0 <= (*self.heap).hp as usize);
+        (*self.heap).hp = region.0 as u32;
+    }
 }
 
 /// Page allocation. Ensures that the memory up to, but excluding, the given pointer is allocated.
@@ -98,6 +224,7 @@ unsafe fn grow_memory(ptr: u64) {
     let current_pages = wasm32::memory_size(0);
     if total_pages_needed > current_pages {
         if wasm32::memory_grow(0, total_pages_needed - current_pages) == core::usize::MAX {
+            // No collection or low-memory hook here, see `set_heap_limit`
             rts_trap_with("Cannot grow memory");
         }
     }
@@ -0,0 +1,298 @@
+//! Retainer paths: finds out why an object is alive by searching for the shortest path from the
+//! roots (static roots and the continuation table) to the object.
+//!
+//! The search is a breadth-first traversal of the heap. Visited objects are marked in the bitmap
+//! of the compacting GC. The bitmap and the traversal queue are allocated in a scratch region
+//! after the heap. Each entry in the queue is the object, the queue index of the object that
+//! points to it (its parent), and the index of the field in the parent. The queue is never
+//! popped, so the path to an object can be recovered by following the parents.
+
+use crate::constants::WORD_SIZE;
+use crate::gc::mark_compact::bitmap::{alloc_bitmap, free_bitmap, get_bit, set_bit};
+use crate::mem_utils::memcpy_words;
+use crate::memory::{alloc_array, alloc_blob, with_scratch, Memory};
+use crate::types::*;
+use crate::visitor::{pointer_to_dynamic_heap, visit_pointer_fields};
+
+use motoko_rts_macros::ic_mem_fn;
+
+/// Root kinds in the retainer path
+pub const ROOT_STATIC: u32 = 0;
+pub const ROOT_CONTINUATION_TABLE: u32 = 1;
+
+/// Parent of the queue entries of objects pointed by the roots. Indices of other parents are
+/// smaller than these, as the queue can't have that many entries.
+const PARENT_STATIC_ROOT: u32 = u32::MAX;
+const PARENT_CONTINUATION_TABLE: u32 = u32::MAX - 1;
+
+/// Initial capacity of the traversal queue, in entries
+const INIT_QUEUE_CAPACITY: u32 = 64;
+
+/// Returns the shortest retainer path of an object as an array of `Nat`s:
+///
+/// - First the root: `ROOT_STATIC` and the index of the root in the static roots array, or
+///   `ROOT_CONTINUATION_TABLE` and 0.
+///
+/// - Then for each edge in the path, the tag of the object and the index of the field (in words,
+///   from the start of the object) pointing to the next object. The last edge points to the given
+///   object.
+///
+/// The array is empty when the object is not reachable, or is not in the dynamic heap.
+#[ic_mem_fn(ic_only)]
+unsafe fn retainer_path(mem: &mut crate::memory::ic::IcMemory, target: Value) -> Value {
+    use crate::continuation_table::continuation_table_loc;
+    use crate::memory::ic;
+
+    let heap = mem.heap();
+    let continuation_table_ptr_loc = continuation_table_loc(mem);
+
+    retainer_path_internal(
+        mem,
+        (*heap).heap_start,
+        (*heap).hp,
+        ic::get_static_roots(),
+        continuation_table_ptr_loc,
+        target,
+    )
+}
+
+/// Finds the retainer path of `target` among the objects between `heap_base` and `heap_end`.
+/// `heap_base` needs to be where the first object is (`HeapState::heap_start`), which is not
+/// aligned with the copying GC. See `retainer_path` for the result.
+///
+/// Uses the bitmap of the compacting GC, so it cannot be used while the incremental GC is marking.
+pub unsafe fn retainer_path_internal<M: Memory>(
+    mem: &mut M,
+    heap_base: u32,
+    heap_end: u32,
+    static_roots: Value,
+    continuation_table_ptr_loc: *mut Value,
+    target: Value,
+) -> Value {
+    if crate::gc::incremental::phase() != crate::gc::incremental::Phase::Idle {
+        crate::rts_trap_with("retainer_path: incremental GC in progress");
+    }
+
+    if !target.is_ptr()
+        || (target.get_ptr() as u32) < heap_base
+        || (target.get_ptr() as u32) >= heap_end
+    {
+        return alloc_array(mem, 0);
+    }
+
+    let target = target.get_ptr() as u32;
+
+    // The path array cannot be allocated in the scratch region of the search, so the search runs
+    // twice: first to find the length of the path, then to fill the path array allocated in
+    // between. The search is deterministic, so both runs find the same path.
+    let n_edges = with_scratch(mem, |mem| {
+        let queue = search(
+            mem,
+            heap_base,
+            heap_end,
+            static_roots,
+            continuation_table_ptr_loc,
+            target,
+        );
+        queue.target_idx.map(|idx| queue.path_len(idx))
+    });
+
+    let n_edges = match n_edges {
+        None => return alloc_array(mem, 0),
+        Some(n_edges) => n_edges,
+    };
+
+    let path = alloc_array(mem, 2 + 2 * n_edges);
+
+    with_scratch(mem, |mem| {
+        let queue = search(
+            mem,
+            heap_base,
+            heap_end,
+            static_roots,
+            continuation_table_ptr_loc,
+            target,
+        );
+        queue.fill_path(path, queue.target_idx.unwrap(), n_edges);
+    });
+
+    path
+}
+
+/// Breadth-first search for `target`. The bitmap and the queue are allocated in the current
+/// scratch region, the queue is only valid until the region is released.
+unsafe fn search<M: Memory>(
+    mem: &mut M,
+    heap_base: u32,
+    heap_end: u32,
+    static_roots: Value,
+    continuation_table_ptr_loc: *mut Value,
+    target: u32,
+) -> Queue {
+    // The bitmap needs to start at a 32-byte aligned address
+    let bitmap_base = heap_base / 32 * 32;
+    alloc_bitmap(mem, Bytes(heap_end - bitmap_base), bitmap_base / WORD_SIZE);
+
+    let mut queue = Queue::new(mem, target);
+
+    let root_array = static_roots.as_array();
+    for i in 0..root_array.len() {
+        let mutbox = root_array.get(i).as_obj() as *mut MutBox;
+        let field_addr = &mut (*mutbox).field as *mut Value;
+        if pointer_to_dynamic_heap(field_addr, heap_base as usize) {
+            queue.visit(mem, *field_addr, PARENT_STATIC_ROOT, i);
+        }
+    }
+
+    if pointer_to_dynamic_heap(continuation_table_ptr_loc, heap_base as usize) {
+        queue.visit(
+            mem,
+            *continuation_table_ptr_loc,
+            PARENT_CONTINUATION_TABLE,
+            0,
+        );
+    }
+
+    let mut next = 0;
+
+    while queue.target_idx.is_none() && next < queue.len {
+        let obj = (*queue.entry(next)).obj;
+
+        let mut ctx = (&mut *mem, &mut queue);
+        visit_pointer_fields(
+            &mut ctx,
+            obj as *mut Obj,
+            (obj as *mut Obj).tag(),
+            heap_base as usize,
+            |ctx, field_addr| {
+                if ((*field_addr).get_ptr() as u32) < heap_end {
+                    let field_idx = (field_addr as u32 - obj) / WORD_SIZE;
+                    ctx.1.visit(ctx.0, *field_addr, next, field_idx);
+                }
+            },
+            |_, _, arr| arr.len(),
+        );
+
+        next += 1;
+    }
+
+    free_bitmap();
+
+    queue
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct QueueEntry {
+    /// Address of the object
+    obj: u32,
+    /// Queue index of the object that points to this object, or `PARENT_STATIC_ROOT` or
+    /// `PARENT_CONTINUATION_TABLE`
+    parent: u32,
+    /// Index of the field in the parent, or index of the root
+    field: u32,
+}
+
+/// Traversal queue. Entries are never removed.
+struct Queue {
+    /// Blob holding the entries
+    blob: *mut Blob,
+    /// Number of entries
+    len: u32,
+    /// The object to find. Compared when adding entries so that the search can stop early.
+    target: u32,
+    /// Index of the target object, when found
+    target_idx: Option<u32>,
+}
+
+impl Queue {
+    unsafe fn new<M: Memory>(mem: &mut M, target: u32) -> Queue {
+        Queue {
+            blob: Queue::alloc_entries(mem, INIT_QUEUE_CAPACITY),
+            len: 0,
+            target,
+            target_idx: None,
+        }
+    }
+
+    unsafe fn alloc_entries<M: Memory>(mem: &mut M, capacity: u32) -> *mut Blob {
+        let bytes = size_of::<QueueEntry>().to_bytes().as_u32() * capacity;
+        alloc_blob(mem, Bytes(bytes)).get_ptr() as *mut Blob
+    }
+
+    unsafe fn capacity(&self) -> u32 {
+        self.blob.len().as_u32() / size_of::<QueueEntry>().to_bytes().as_u32()
+    }
+
+    unsafe fn entry(&self, idx: u32) -> *mut QueueEntry {
+        (self.blob.payload_addr() as *mut QueueEntry).add(idx as usize)
+    }
+
+    /// Adds the object to the queue if it's not visited yet
+    unsafe fn visit<M: Memory>(&mut self, mem: &mut M, obj: Value, parent: u32, field: u32) {
+        let obj = obj.get_ptr() as u32;
+        let obj_idx = obj / WORD_SIZE;
+
+        if get_bit(obj_idx) {
+            return;
+        }
+
+        set_bit(obj_idx);
+
+        if self.len == self.capacity() {
+            // Move the entries to a larger blob. The old blob is freed with the scratch region.
+            let new_blob = Queue::alloc_entries(mem, self.capacity() * 2);
+            memcpy_words(
+                new_blob.payload_addr() as usize,
+                self.blob.payload_addr() as usize,
+                self.blob.len().to_words(),
+            );
+            self.blob = new_blob;
+        }
+
+        *self.entry(self.len) = QueueEntry { obj, parent, field };
+
+        if obj == self.target {
+            self.target_idx = Some(self.len);
+        }
+
+        self.len += 1;
+    }
+
+    /// Returns the number of edges in the path of the object with the given queue index
+    unsafe fn path_len(&self, idx: u32) -> u32 {
+        let mut n_edges = 0;
+        let mut entry = *self.entry(idx);
+        while entry.parent < PARENT_CONTINUATION_TABLE {
+            n_edges += 1;
+            entry = *self.entry(entry.parent);
+        }
+        n_edges
+    }
+
+    /// Fills the path array of the object with the given queue index. The array needs to have
+    /// `2 + 2 * n_edges` elements, where `n_edges` is the result of `path_len`.
+    unsafe fn fill_path(&self, path: Value, idx: u32, n_edges: u32) {
+        let array = path.as_array();
+        debug_assert_eq!(array.len(), 2 + 2 * n_edges);
+
+        // Fill the array backwards, from the target to the root
+        let mut entry = *self.entry(idx);
+        let mut i = n_edges;
+        while entry.parent < PARENT_CONTINUATION_TABLE {
+            let parent = *self.entry(entry.parent);
+            array.set(2 * i, Value::from_scalar((parent.obj as *mut Obj).tag()));
+            array.set(2 * i + 1, Value::from_scalar(entry.field));
+            entry = parent;
+            i -= 1;
+        }
+
+        let root_kind = if entry.parent == PARENT_STATIC_ROOT {
+            ROOT_STATIC
+        } else {
+            ROOT_CONTINUATION_TABLE
+        };
+        array.set(0, Value::from_scalar(root_kind));
+        array.set(1, Value::from_scalar(entry.field));
+    }
+}
@@ -25,7 +25,7 @@ const _: () = assert!(size_of::<BigInt>() == 5 * WORD_SIZE);
 const _: () = assert!(size_of::<MutBox>() == 2 * WORD_SIZE);
 const _: () = assert!(size_of::<Some>() == 2 * WORD_SIZE);
 const _: () = assert!(size_of::<Variant>() == 3 * WORD_SIZE);
-const _: () = assert!(size_of::<Concat>() == 4 * WORD_SIZE);
+const _: () = assert!(size_of::<Concat>() == 6 * WORD_SIZE);
 const _: () = assert!(size_of::<Null>() == 1 * WORD_SIZE);
 const _: () = assert!(size_of::<Bits32>() == 2 * WORD_SIZE);
 const _: () = assert!(size_of::<Bits64>() == 3 * WORD_SIZE);
@@ -12,15 +12,16 @@
 //!
 //! In a subsequent step, the actual concatenation node has been introduced.
 //!
-//! From here on, there are stretch goals like:
-//!  - restructure recursive code to not use unbounded Rust stack
-//!  - maybe rebalancing
+//! Concatenation trees are kept balanced (see `text_concat`), so the depth of a text is
+//! logarithmic in its size, and the recursive functions below use bounded Rust stack.
 
 // Layout of a concat node:
 //
-//      ┌──────────────┬─────────┬───────┬───────┐
-//      │ tag (concat) │ n_bytes │ text1 │ text2 │
-//      └──────────────┴─────────┴───────┴───────┘
+//      ┌──────────────┬─────────┬───────┬───────┬───────┬─────────┐
+//      │ tag (concat) │ n_bytes │ text1 │ text2 │ depth │ n_chars │
+//      └──────────────┴─────────┴───────┴───────┴───────┴─────────┘
+//
+// `n_chars` is `N_CHARS_UNKNOWN` until the number of characters is needed, see `text_len`.
 //
 // Note that `CONCAT_LEN` and `BLOB_LEN` are identical, so no need to check the tag to know the
 // size of the text.
@@ -30,7 +31,7 @@ use crate::memory::{alloc_blob, Memory};
 use crate::rts_trap_with;
 use crate::types::{size_of, Blob, Bytes, Concat, Stream, Value, TAG_BLOB, TAG_CONCAT};
 
-use core::cmp::{min, Ordering};
+use core::cmp::{max, min, Ordering};
 use core::{slice, str};
 
 use motoko_rts_macros::ic_mem_fn;
@@ -41,7 +42,17 @@ const MAX_STR_SIZE: Bytes<u32> = Bytes((1 << 30) - 1);
 // Make this MAX_STR_SIZE to disable the use of ropes completely, e.g. for debugging
 const MIN_CONCAT_SIZE: Bytes<u32> = Bytes(9);
 
-unsafe fn alloc_text_blob<M: Memory>(mem: &mut M, size: Bytes<u32>) -> Value {
+// How much deeper than `2 * log2(size)` a text can get before it's rebalanced
+const DEPTH_SLACK: u32 = 8;
+
+// Maximum depth of a text, as texts are smaller than 2^30 bytes. A concat node created by
+// `text_concat` can be one deeper than this before it's rebalanced.
+pub(crate) const MAX_DEPTH: u32 = 2 * 29 + DEPTH_SLACK;
+
+// `n_chars` of concat nodes whose number of characters is not computed yet
+const N_CHARS_UNKNOWN: u32 = u32::MAX;
+
+pub(crate) unsafe fn alloc_text_blob<M: Memory>(mem: &mut M, size: Bytes<u32>) -> Value {
     if size > MAX_STR_SIZE {
         rts_trap_with("alloc_text_blob: Text too large");
     }
@@ -60,6 +71,13 @@ pub unsafe fn text_of_str<M: Memory>(mem: &mut M, s: &str) -> Value {
     text_of_ptr_size(mem, s.as_ptr(), Bytes(s.len() as u32))
 }
 
+/// Concatenates two texts.
+///
+/// To keep the trees balanced when a text is built by appending or prepending in a loop, the
+/// deeper text is split at the end next to the other text, like a carry in a binary counter: while
+/// the subtree at that end has the same depth as the other text, the two are concatenated first.
+/// Texts that still get deeper than `2 * log2(size) + DEPTH_SLACK` (e.g. when built by mixing
+/// concatenations at both ends and in the middle) are rebalanced.
 #[ic_mem_fn]
 pub unsafe fn text_concat<M: Memory>(mem: &mut M, s1: Value, s2: Value) -> Value {
     let blob1_len = text_size(s1);
@@ -73,6 +91,41 @@ pub unsafe fn text_concat<M: Memory>(mem: &mut M, s1: Value, s2: Value) -> Value
         return s1;
     }
 
+    // Check max size
+    if blob1_len + blob2_len > MAX_STR_SIZE {
+        rts_trap_with("text_concat: Text too large");
+    }
+
+    let mut s1 = s1;
+    let mut s2 = s2;
+
+    if text_depth(s1) >= text_depth(s2) {
+        while s1.tag() == TAG_CONCAT && text_depth(s1.as_concat().text2()) == text_depth(s2) {
+            let concat = s1.as_concat();
+            s2 = concat_nodes(mem, concat.text2(), s2);
+            s1 = concat.text1();
+        }
+    } else {
+        while s2.tag() == TAG_CONCAT && text_depth(s2.as_concat().text1()) == text_depth(s1) {
+            let concat = s2.as_concat();
+            s1 = concat_nodes(mem, s1, concat.text1());
+            s2 = concat.text2();
+        }
+    }
+
+    let r = concat_nodes(mem, s1, s2);
+
+    if text_depth(r) > max_depth(text_size(r)) {
+        rebalance(mem, r)
+    } else {
+        r
+    }
+}
+
+/// Concatenates two non-empty texts without rebalancing
+unsafe fn concat_nodes<M: Memory>(mem: &mut M, s1: Value, s2: Value) -> Value {
+    let blob1_len = text_size(s1);
+    let blob2_len = text_size(s2);
     let new_len = blob1_len + blob2_len;
 
     // Short texts are copied into a single blob
@@ -98,11 +151,6 @@ pub unsafe fn text_concat<M: Memory>(mem: &mut M, s1: Value, s2: Value) -> Value
         return r;
     }
 
-    // Check max size
-    if new_len > MAX_STR_SIZE {
-        rts_trap_with("text_concat: Text too large");
-    }
-
     // Create concat node
     let r = mem.alloc_words(size_of::<Concat>());
     let r_concat = r.get_ptr() as *mut Concat;
@@ -110,6 +158,71 @@ pub unsafe fn text_concat<M: Memory>(mem: &mut M, s1: Value, s2: Value) -> Value
     (*r_concat).n_bytes = new_len;
     (*r_concat).text1 = s1;
     (*r_concat).text2 = s2;
+    (*r_concat).depth = max(text_depth(s1), text_depth(s2)) + 1;
+    (*r_concat).n_chars = N_CHARS_UNKNOWN;
+    r
+}
+
+/// Depth of the text: 0 for blobs, one more than the depth of the deeper text for concat nodes
+pub unsafe fn text_depth(s: Value) -> u32 {
+    if s.tag() == TAG_CONCAT {
+        s.as_concat().depth()
+    } else {
+        0
+    }
+}
+
+/// Depth of a text of the given size above which the text is rebalanced
+fn max_depth(size: Bytes<u32>) -> u32 {
+    2 * (31 - size.as_u32().leading_zeros()) + DEPTH_SLACK
+}
+
+/// Rebuilds the text with the same blobs as a balanced tree. The blobs are added from left to
+/// right to a forest of trees with 2^i blobs at level i, like in a binary counter, and the trees
+/// are then concatenated from the smallest to the largest. The result has depth at most
+/// `2 * log2(number of blobs) + 1`.
+unsafe fn rebalance<M: Memory>(mem: &mut M, text: Value) -> Value {
+    let none = Value::from_scalar(0);
+
+    // Texts have less than 2^30 blobs
+    let mut forest = [none; 32];
+
+    // Right subtrees of the concat nodes on the path to the current blob that are not visited yet
+    let mut todo = [none; MAX_DEPTH as usize + 1];
+    let mut n_todo = 0;
+
+    let mut s = text;
+    loop {
+        while s.tag() == TAG_CONCAT {
+            let concat = s.as_concat();
+            todo[n_todo] = concat.text2();
+            n_todo += 1;
+            s = concat.text1();
+        }
+
+        let mut level = 0;
+        while forest[level] != none {
+            s = concat_nodes(mem, forest[level], s);
+            forest[level] = none;
+            level += 1;
+        }
+        forest[level] = s;
+
+        if n_todo == 0 {
+            break;
+        }
+        n_todo -= 1;
+        s = todo[n_todo];
+    }
+
+    let mut r = none;
+    for tree in forest.iter().filter(|tree| **tree != none) {
+        r = if r == none {
+            *tree
+        } else {
+            concat_nodes(mem, *tree, r)
+        };
+    }
     r
 }
 
@@ -204,7 +317,7 @@ pub unsafe extern "C" fn text_size(s: Value) -> Bytes<u32> {
 }
 
 /// Compares texts from given offset on for the given number of bytes. All assumed to be in range.
-unsafe fn text_compare_range(
+pub(crate) unsafe fn text_compare_range(
     s1: Value,
     offset1: Bytes<u32>,
     s2: Value,
@@ -352,24 +465,148 @@ pub(crate) unsafe fn blob_compare(s1: Value, s2: Value) -> i32 {
     }
 }
 
-/// Length in characters
+/// Length in characters. The lengths of concat nodes are computed once and stored in the nodes.
 #[no_mangle]
 pub unsafe extern "C" fn text_len(text: Value) -> u32 {
+    if text.tag() == TAG_BLOB {
+        blob_str(text.as_blob()).chars().count() as u32
+    } else {
+        let concat = text.as_concat() as *mut Concat;
+        if (*concat).n_chars == N_CHARS_UNKNOWN {
+            (*concat).n_chars = text_len((*concat).text1) + text_len((*concat).text2);
+        }
+        (*concat).n_chars
+    }
+}
+
+pub(crate) unsafe fn blob_str<'a>(blob: *const Blob) -> &'a str {
+    str::from_utf8_unchecked(slice::from_raw_parts(
+        blob.payload_const(),
+        blob.len().as_usize(),
+    ))
+}
+
+/// Offset of the character with the given index in the blob, or the size of the blob when the
+/// index is the number of characters
+unsafe fn blob_char_offset(blob: *const Blob, index: u32) -> Bytes<u32> {
+    match blob_str(blob).char_indices().nth(index as usize) {
+        Some((offset, _)) => Bytes(offset as u32),
+        None => blob.len(),
+    }
+}
+
+/// Returns `len` characters of the text, starting at character `start`. Blobs and concat nodes
+/// that are entirely in the range are shared with the text, only the blobs at the ends of the
+/// range are copied.
+#[ic_mem_fn]
+pub unsafe fn text_slice<M: Memory>(mem: &mut M, text: Value, start: u32, len: u32) -> Value {
+    let n_chars = text_len(text);
+    if start > n_chars || len > n_chars - start {
+        rts_trap_with("text_slice: Text index out of bounds");
+    }
+
+    let start_offset = char_offset(text, start);
+    let end_offset = char_offset(text, start + len);
+    text_slice_bytes(mem, text, start_offset, end_offset - start_offset)
+}
+
+/// Like `text_slice`, but with the range in bytes. The range needs to be in the text, and start and
+/// end at character boundaries.
+pub(crate) unsafe fn text_slice_bytes<M: Memory>(
+    mem: &mut M,
+    text: Value,
+    start: Bytes<u32>,
+    len: Bytes<u32>,
+) -> Value {
+    if len == text_size(text) {
+        return text;
+    }
+
     if text.tag() == TAG_BLOB {
         let blob = text.as_blob();
-        let payload_addr = blob.payload_const();
-        let len = blob.len();
-
-        str::from_utf8_unchecked(slice::from_raw_parts(
-            payload_addr as *const u8,
-            len.as_usize(),
-        ))
-        .chars()
-        .count() as u32
+        return text_of_ptr_size(mem, blob.payload_const().add(start.as_usize()), len);
+    }
+
+    let concat = text.as_concat();
+    let size1 = text_size(concat.text1());
+
+    if start + len <= size1 {
+        text_slice_bytes(mem, concat.text1(), start, len)
+    } else if start >= size1 {
+        text_slice_bytes(mem, concat.text2(), start - size1, len)
     } else {
+        let s1 = text_slice_bytes(mem, concat.text1(), start, size1 - start);
+        let s2 = text_slice_bytes(mem, concat.text2(), Bytes(0), start + len - size1);
+        text_concat(mem, s1, s2)
+    }
+}
+
+/// Offset of the character with the given index, or the size of the text when the index is the
+/// number of characters
+unsafe fn char_offset(mut text: Value, mut index: u32) -> Bytes<u32> {
+    let mut offset = Bytes(0);
+
+    while text.tag() == TAG_CONCAT {
+        let concat = text.as_concat();
+        let n_chars1 = text_len(concat.text1());
+        if index < n_chars1 {
+            text = concat.text1();
+        } else {
+            offset += text_size(concat.text1());
+            text = concat.text2();
+            index -= n_chars1;
+        }
+    }
+
+    offset + blob_char_offset(text.as_blob(), index)
+}
+
+/// Number of characters before the given offset, which needs to be at a character boundary
+pub(crate) unsafe fn chars_before(mut text: Value, mut offset: Bytes<u32>) -> u32 {
+    let mut n_chars = 0;
+
+    while text.tag() == TAG_CONCAT {
         let concat = text.as_concat();
-        text_len(concat.text1()) + text_len(concat.text2())
+        let size1 = text_size(concat.text1());
+        if offset < size1 {
+            text = concat.text1();
+        } else {
+            n_chars += text_len(concat.text1());
+            text = concat.text2();
+            offset -= size1;
+        }
     }
+
+    let blob = text.as_blob();
+    let prefix = str::from_utf8_unchecked(slice::from_raw_parts(
+        blob.payload_const(),
+        offset.as_usize(),
+    ));
+    n_chars + prefix.chars().count() as u32
+}
+
+/// Returns the character at the given index
+#[no_mangle]
+pub unsafe extern "C" fn text_char_at(mut text: Value, mut index: u32) -> u32 {
+    if index >= text_len(text) {
+        rts_trap_with("text_char_at: Text index out of bounds");
+    }
+
+    while text.tag() == TAG_CONCAT {
+        let concat = text.as_concat();
+        let n_chars1 = text_len(concat.text1());
+        if index < n_chars1 {
+            text = concat.text1();
+        } else {
+            text = concat.text2();
+            index -= n_chars1;
+        }
+    }
+
+    let blob = text.as_blob();
+    let offset = blob_char_offset(blob, index);
+    let mut size = 0;
+    decode_code_point(blob.payload_const().add(offset.as_usize()), &mut size)
 }
 
 /// Decodes the character at the pointer. Returns the character, the size via the `size` parameter
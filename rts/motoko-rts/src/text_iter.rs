@@ -10,10 +10,12 @@
 //! 1. A pointer to the text
 //! 2. 0, or a pointer to the next list entry
 
+use crate::gc::write_with_barrier;
 use crate::memory::{alloc_array, Memory};
 use crate::rts_trap_with;
-use crate::text::decode_code_point;
-use crate::types::{Value, TAG_BLOB, TAG_CONCAT};
+use crate::text::{decode_code_point, text_concat, text_slice_bytes};
+use crate::types::{Bytes, Value, TAG_BLOB, TAG_CONCAT};
+use crate::unicode::GraphemeBreaks;
 
 use motoko_rts_macros::ic_mem_fn;
 
@@ -31,7 +33,8 @@ unsafe fn find_leaf<M: Memory>(mem: &mut M, mut text: Value, todo: *mut Value) -
         let new_todo_array = new_todo.as_array();
         new_todo_array.set(TODO_TEXT_IDX, (*concat).text2);
         new_todo_array.set(TODO_LINK_IDX, *todo);
-        *todo = new_todo;
+        // `todo` can be a field of an iterator that survived a GC
+        write_with_barrier(mem, todo, new_todo);
 
         // Follow left node
         text = (*concat).text1;
@@ -79,21 +82,24 @@ pub unsafe extern "C" fn text_iter_done(iter: Value) -> u32 {
     }
 }
 
-/// Returns next character in the iterator, advances the iterator
-#[ic_mem_fn]
-pub unsafe fn text_iter_next<M: Memory>(mem: &mut M, iter: Value) -> u32 {
+/// Moves the iterator to the next leaf when it is at the end of the current one. Returns whether
+/// there are more characters.
+unsafe fn skip_to_char<M: Memory>(mem: &mut M, iter: Value) -> bool {
     let iter_array = iter.as_array();
 
-    let blob = iter_array.get(ITER_BLOB_IDX).as_blob();
-    let pos = iter_array.get(ITER_POS_IDX).get_scalar();
+    loop {
+        let blob = iter_array.get(ITER_BLOB_IDX).as_blob();
+        let pos = iter_array.get(ITER_POS_IDX).get_scalar();
 
-    // If we are at the end of the current blob, find the next blob
-    if pos >= blob.len().as_u32() {
+        if pos < blob.len().as_u32() {
+            return true;
+        }
+
+        // We are at the end of the current blob, find the next blob
         let todo = iter_array.get(ITER_TODO_IDX);
 
         if todo.get_raw() == 0 {
-            // Caller should check with text_iter_done
-            rts_trap_with("text_iter_next: Iter already done");
+            return false;
         }
 
         let todo_array = todo.as_array();
@@ -102,27 +108,106 @@ pub unsafe fn text_iter_next<M: Memory>(mem: &mut M, iter: Value) -> u32 {
 
         if text.tag() == TAG_CONCAT {
             // If next one is a concat node re-use both the iterator and the todo objects (avoids
-            // allocation)
+            // allocation). These may have survived a GC, so pointer stores go through the write
+            // barrier.
             let concat = text.as_concat();
-            todo_array.set(TODO_TEXT_IDX, (*concat).text2);
+            let todo_text_addr = todo_array.payload_addr().add(TODO_TEXT_IDX as usize);
+            write_with_barrier(mem, todo_text_addr, (*concat).text2);
             iter_array.set(ITER_POS_IDX, Value::from_scalar(0));
             let todo_addr = iter_array.payload_addr().add(ITER_TODO_IDX as usize);
-            iter_array.set(ITER_BLOB_IDX, find_leaf(mem, (*concat).text1, todo_addr));
-            text_iter_next(mem, iter)
+            let blob = find_leaf(mem, (*concat).text1, todo_addr);
+            let blob_addr = iter_array.payload_addr().add(ITER_BLOB_IDX as usize);
+            write_with_barrier(mem, blob_addr, blob);
         } else {
             // Otherwise remove the entry from the chain
             debug_assert_eq!(text.tag(), TAG_BLOB);
-            iter_array.set(ITER_BLOB_IDX, text);
+            let blob_addr = iter_array.payload_addr().add(ITER_BLOB_IDX as usize);
+            write_with_barrier(mem, blob_addr, text);
             iter_array.set(ITER_POS_IDX, Value::from_scalar(0));
-            iter_array.set(ITER_TODO_IDX, todo_array.get(TODO_LINK_IDX));
-            text_iter_next(mem, iter)
+            let todo_addr = iter_array.payload_addr().add(ITER_TODO_IDX as usize);
+            write_with_barrier(mem, todo_addr, todo_array.get(TODO_LINK_IDX));
         }
-    } else {
-        // We are not at the end, read the next character from the blob
-        let blob_payload = blob.payload_const();
-        let mut step: u32 = 0;
-        let char = decode_code_point(blob_payload.add(pos as usize), &mut step as *mut u32);
-        iter_array.set(ITER_POS_IDX, Value::from_scalar(pos + step));
-        char
     }
 }
+
+/// Returns the character at the position of the iterator, without advancing it, and its size in
+/// bytes. The iterator must not be at the end of the current blob.
+unsafe fn peek_char(iter: Value) -> (u32, u32) {
+    let iter_array = iter.as_array();
+    let blob = iter_array.get(ITER_BLOB_IDX).as_blob();
+    let pos = iter_array.get(ITER_POS_IDX).get_scalar();
+    debug_assert!(pos < blob.len().as_u32());
+
+    let mut step: u32 = 0;
+    let char = decode_code_point(
+        blob.payload_const().add(pos as usize),
+        &mut step as *mut u32,
+    );
+    (char, step)
+}
+
+/// Returns next character in the iterator, advances the iterator
+#[ic_mem_fn]
+pub unsafe fn text_iter_next<M: Memory>(mem: &mut M, iter: Value) -> u32 {
+    if !skip_to_char(mem, iter) {
+        // Caller should check with text_iter_done
+        rts_trap_with("text_iter_next: Iter already done");
+    }
+
+    let iter_array = iter.as_array();
+    let pos = iter_array.get(ITER_POS_IDX).get_scalar();
+    let (char, step) = peek_char(iter);
+    iter_array.set(ITER_POS_IDX, Value::from_scalar(pos + step));
+    char
+}
+
+/// Returns the next extended grapheme cluster in the iterator as a text, advances the iterator
+/// past it. The parts of the cluster in different leaves are sliced from the leaves and
+/// concatenated.
+#[ic_mem_fn]
+pub unsafe fn text_iter_next_grapheme<M: Memory>(mem: &mut M, iter: Value) -> Value {
+    if !skip_to_char(mem, iter) {
+        // Caller should check with text_iter_done
+        rts_trap_with("text_iter_next_grapheme: Iter already done");
+    }
+
+    let iter_array = iter.as_array();
+
+    let (first, step) = peek_char(iter);
+    let mut breaks = GraphemeBreaks::new(first);
+
+    // Parts of the cluster in the previous leaves, and the start of the part in the current leaf
+    let mut cluster: Option<Value> = None;
+    let mut start = iter_array.get(ITER_POS_IDX).get_scalar();
+    iter_array.set(ITER_POS_IDX, Value::from_scalar(start + step));
+
+    loop {
+        let blob = iter_array.get(ITER_BLOB_IDX);
+        let pos = iter_array.get(ITER_POS_IDX).get_scalar();
+        let at_leaf_end = pos >= blob.as_blob().len().as_u32();
+
+        if !at_leaf_end {
+            let (char, step) = peek_char(iter);
+            if !breaks.is_break(char) {
+                iter_array.set(ITER_POS_IDX, Value::from_scalar(pos + step));
+                continue;
+            }
+        }
+
+        // The cluster ends here, or continues in the next leaf
+        if pos > start {
+            let part = text_slice_bytes(mem, blob, Bytes(start), Bytes(pos - start));
+            cluster = Some(match cluster {
+                None => part,
+                Some(cluster) => text_concat(mem, cluster, part),
+            });
+        }
+
+        if !at_leaf_end || !skip_to_char(mem, iter) {
+            break;
+        }
+        start = 0;
+    }
+
+    cluster.unwrap()
+}
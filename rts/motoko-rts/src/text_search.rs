@@ -0,0 +1,236 @@
+//! Text search, split and replace.
+//!
+//! The occurrences of a pattern are found with the Boyer-Moore-Horspool algorithm, over the bytes
+//! of the text. As UTF-8 is self-synchronizing, byte-wise matches of a valid pattern always start
+//! and end at character boundaries.
+//!
+//! The text is not flattened: the last byte of each candidate position is read with a cursor that
+//! moves forward over the blobs of the text, skipping the subtrees before the position, and the
+//! candidates are compared with the pattern in place with `text_compare_range`. Only the pattern
+//! is flattened. The results of `text_split` and `text_replace` share the unchanged parts of the
+//! text (see `text_slice`).
+
+use crate::memory::{alloc_array, Memory};
+use crate::text::{
+    blob_of_text, chars_before, text_compare_range, text_concat, text_size, text_slice_bytes,
+    MAX_DEPTH,
+};
+use crate::types::{Bytes, Value, TAG_CONCAT};
+
+use core::cmp::Ordering;
+
+use motoko_rts_macros::ic_mem_fn;
+
+/// Reads the bytes of a text at increasing offsets
+struct TextCursor {
+    /// Texts after the current blob, the next one last
+    todo: [Value; MAX_DEPTH as usize + 1],
+    n_todo: usize,
+
+    /// The current blob, and its start and end offsets in the text
+    blob: Value,
+    start: Bytes<u32>,
+    end: Bytes<u32>,
+}
+
+impl TextCursor {
+    unsafe fn new(text: Value) -> Self {
+        let mut cursor = TextCursor {
+            todo: [Value::from_scalar(0); MAX_DEPTH as usize + 1],
+            n_todo: 0,
+            blob: Value::from_scalar(0),
+            start: Bytes(0),
+            end: Bytes(0),
+        };
+        cursor.push(text);
+        cursor
+    }
+
+    unsafe fn push(&mut self, text: Value) {
+        self.todo[self.n_todo] = text;
+        self.n_todo += 1;
+    }
+
+    /// Returns the byte at the offset, which needs to be in the text, and not before the offset of
+    /// the previous call
+    unsafe fn byte_at(&mut self, offset: Bytes<u32>) -> u8 {
+        debug_assert!(offset >= self.start);
+
+        while offset >= self.end {
+            self.n_todo -= 1;
+            let mut text = self.todo[self.n_todo];
+
+            // Skip the text if the offset is after it, otherwise find the blob with the offset
+            if offset >= self.end + text_size(text) {
+                self.end += text_size(text);
+                continue;
+            }
+
+            while text.tag() == TAG_CONCAT {
+                let concat = text.as_concat();
+                let size1 = text_size(concat.text1());
+                if offset >= self.end + size1 {
+                    self.end += size1;
+                    text = concat.text2();
+                } else {
+                    self.push(concat.text2());
+                    text = concat.text1();
+                }
+            }
+
+            self.blob = text;
+            self.start = self.end;
+            self.end += text_size(text);
+        }
+
+        *self
+            .blob
+            .as_blob()
+            .payload_const()
+            .add((offset - self.start).as_usize())
+    }
+}
+
+/// Finds the occurrences of a pattern in a text, from left to right, without overlaps
+struct Searcher {
+    text: Value,
+    cursor: TextCursor,
+
+    /// The pattern, as a blob
+    pattern: Value,
+
+    /// How far to move the candidate position when its last byte is the index
+    skip: [u32; 256],
+
+    /// Where to start the next search
+    next: Bytes<u32>,
+}
+
+impl Searcher {
+    /// The pattern must not be empty
+    unsafe fn new<M: Memory>(mem: &mut M, text: Value, pattern: Value) -> Self {
+        let pattern = blob_of_text(mem, pattern);
+        let pattern_blob = pattern.as_blob();
+        let pattern_len = pattern_blob.len().as_u32();
+
+        let mut skip = [pattern_len; 256];
+        for i in 0..pattern_len - 1 {
+            skip[pattern_blob.get(i) as usize] = pattern_len - 1 - i;
+        }
+
+        Searcher {
+            text,
+            cursor: TextCursor::new(text),
+            pattern,
+            skip,
+            next: Bytes(0),
+        }
+    }
+
+    /// Returns the offset of the next occurrence of the pattern
+    unsafe fn next(&mut self) -> Option<Bytes<u32>> {
+        let pattern_len = text_size(self.pattern);
+        let text_len = text_size(self.text);
+
+        let last_byte = self.pattern.as_blob().get(pattern_len.as_u32() - 1);
+
+        let mut pos = self.next;
+        while pos + pattern_len <= text_len {
+            let byte = self.cursor.byte_at(pos + pattern_len - Bytes(1));
+            if byte == last_byte
+                && text_compare_range(self.text, pos, self.pattern, Bytes(0), pattern_len)
+                    == Ordering::Equal
+            {
+                self.next = pos + pattern_len;
+                return Some(pos);
+            }
+            pos += Bytes(self.skip[byte as usize]);
+        }
+
+        self.next = text_len;
+        None
+    }
+}
+
+/// Returns the index of the first character of the first occurrence of `pattern` in `text`, or -1
+/// if there is none
+#[ic_mem_fn]
+pub unsafe fn text_find<M: Memory>(mem: &mut M, text: Value, pattern: Value) -> i32 {
+    if text_size(pattern) == Bytes(0) {
+        return 0;
+    }
+
+    match Searcher::new(mem, text, pattern).next() {
+        Some(offset) => chars_before(text, offset) as i32,
+        None => -1,
+    }
+}
+
+/// Returns an array of the parts of `text` between the occurrences of `separator`. The array has
+/// one more element than there are occurrences. An empty separator does not split the text.
+#[ic_mem_fn]
+pub unsafe fn text_split<M: Memory>(mem: &mut M, text: Value, separator: Value) -> Value {
+    if text_size(separator) == Bytes(0) {
+        let array = alloc_array(mem, 1);
+        array.as_array().set(0, text);
+        return array;
+    }
+
+    let separator = blob_of_text(mem, separator);
+
+    let mut n_occurrences = 0;
+    let mut searcher = Searcher::new(mem, text, separator);
+    while searcher.next().is_some() {
+        n_occurrences += 1;
+    }
+
+    let array = alloc_array(mem, n_occurrences + 1);
+    let separator_len = text_size(separator);
+
+    let mut searcher = Searcher::new(mem, text, separator);
+    let mut part_start = Bytes(0);
+    for i in 0..=n_occurrences {
+        let part_end = searcher.next().unwrap_or_else(|| text_size(text));
+        let part = text_slice_bytes(mem, text, part_start, part_end - part_start);
+        array.as_array().set(i, part);
+        part_start = part_end + separator_len;
+    }
+
+    array
+}
+
+/// Replaces the occurrences of `pattern` in `text` with `replacement`. An empty pattern is not
+/// replaced.
+#[ic_mem_fn]
+pub unsafe fn text_replace<M: Memory>(
+    mem: &mut M,
+    text: Value,
+    pattern: Value,
+    replacement: Value,
+) -> Value {
+    if text_size(pattern) == Bytes(0) {
+        return text;
+    }
+
+    let mut searcher = Searcher::new(mem, text, pattern);
+    let pattern_len = text_size(pattern);
+
+    let first = match searcher.next() {
+        Some(offset) => offset,
+        None => return text,
+    };
+
+    let mut result = text_slice_bytes(mem, text, Bytes(0), first);
+    result = text_concat(mem, result, replacement);
+    let mut part_start = first + pattern_len;
+
+    while let Some(part_end) = searcher.next() {
+        let part = text_slice_bytes(mem, text, part_start, part_end - part_start);
+        result = text_concat(mem, result, part);
+        result = text_concat(mem, result, replacement);
+        part_start = part_end + pattern_len;
+    }
+
+    let rest = text_slice_bytes(mem, text, part_start, text_size(text) - part_start);
+    text_concat(mem, result, rest)
+}
@@ -309,6 +309,13 @@ impl Value {
         self.get_ptr() as *mut BigInt
     }
 
+    /// Get the pointer as `WeakRef`. In debug mode panics if the value is not a pointer or the
+    /// pointed object is not a `WeakRef`.
+    pub unsafe fn as_weak_ref(self) -> *mut WeakRef {
+        debug_assert_eq!(self.tag(), TAG_WEAK_REF);
+        self.get_ptr() as *mut WeakRef
+    }
+
     pub fn as_tiny(self) -> i32 {
         debug_assert!(self.is_scalar());
         self.0 as i32 >> 1
@@ -351,6 +358,7 @@ pub const TAG_CONCAT: Tag = 25;
 pub const TAG_NULL: Tag = 27;
 pub const TAG_ONE_WORD_FILLER: Tag = 29;
 pub const TAG_FREE_SPACE: Tag = 31;
+pub const TAG_WEAK_REF: Tag = 33;
 
 // Special value to visit only a range of array fields.
 // This and all values above it are reserved and mean
@@ -358,7 +366,7 @@ pub const TAG_FREE_SPACE: Tag = 31;
 // purposes of `visit_pointer_fields`.
 // Invariant: the value of this (pseudo-)tag must be
 //            higher than all other tags defined above
-pub const TAG_ARRAY_SLICE_MIN: Tag = 32;
+pub const TAG_ARRAY_SLICE_MIN: Tag = 34;
 
 // Common parts of any object. Other object pointers can be coerced into a pointer to this.
 #[repr(C)] // See the note at the beginning of this module
@@ -594,6 +602,10 @@ pub struct Concat {
     pub n_bytes: Bytes<u32>,
     pub text1: Value,
     pub text2: Value,
+    /// Length of the longest path from this node to a blob, see `text_concat`
+    pub depth: u32,
+    /// Number of characters, computed when first needed, see `text_len`
+    pub n_chars: u32,
 }
 
 impl Concat {
@@ -604,6 +616,10 @@ impl Concat {
     pub unsafe fn text2(self: *const Self) -> Value {
         (*self).text2
     }
+
+    pub unsafe fn depth(self: *const Self) -> u32 {
+        (*self).depth
+    }
 }
 
 #[repr(C)] // See the note at the beginning of this module
@@ -652,6 +668,14 @@ impl FreeSpace {
     }
 }
 
+/// A reference that doesn't keep its target alive. When the target dies, the collectors clear the
+/// reference by setting `target` to `WEAK_REF_CLEARED` (see `weak_ref.rs`).
+#[repr(C)] // See the note at the beginning of this module
+pub struct WeakRef {
+    pub header: Obj,
+    pub target: Value,
+}
+
 /// Returns object size in words
 pub(crate) unsafe fn object_size(obj: usize) -> Words<u32> {
     let obj = obj as *mut Obj;
@@ -711,6 +735,8 @@ pub(crate) unsafe fn object_size(obj: usize) -> Words<u32> {
             free_space.size()
         }
 
+        TAG_WEAK_REF => size_of::<WeakRef>(),
+
         _ => {
             rts_trap_with("object_size: invalid object tag");
         }
@@ -0,0 +1,472 @@
+//! Full case mapping, normalization, and grapheme cluster boundaries of texts
+//!
+//! Case mapping uses the case conversions of `core`, which include the mappings to several
+//! characters (e.g. "ß" to "SS"), and implements the only context-dependent rule of the default
+//! special casing: final sigma. The language-specific rules (e.g. for Turkish and Lithuanian) are
+//! not implemented. The Unicode version of the `core` conversions is the one of the Rust toolchain
+//! that builds the RTS, which can differ from the version of the generated tables (Unicode 14.0).
+//! The `Cased` and `Case_Ignorable` properties of the final sigma rule are in the generated tables.
+//!
+//! Normalization (NFC, NFD, NFKC) implements the algorithms of UAX #15, with the data in
+//! `unicode/tables.rs`, generated by `gen-unicode-tables.py`. To keep the tables small,
+//! decompositions are stored one level deep and applied recursively, the characters are stored in
+//! UTF-16, and compositions are stored as indices of the decompositions they invert. Hangul
+//! syllables are decomposed and composed algorithmically.
+//!
+//! All case mapping and normalization functions flatten the text first, and return a new blob,
+//! except that ASCII texts are returned unchanged by normalization.
+//!
+//! Grapheme cluster boundaries are the extended grapheme cluster boundaries of UAX #29, found with
+//! `GraphemeBreaks` one character at a time, so texts can be iterated without flattening (see
+//! `text_iter_next_grapheme`). The property values are in the same tables; Hangul syllables are
+//! classified algorithmically. The break rules are tested with the cases in
+//! `motoko-rts-tests/src/unicode.rs`, not with the `GraphemeBreakTest.txt` data of the Unicode
+//! database.
+//!
+//! Word boundaries (UAX #29, section 4) are not implemented. They are left out of grapheme cluster
+//! iteration on purpose: they would need the Word_Break property, which can't be derived from the
+//! Unicode database of Python like the other properties, and a matching implementation in the
+//! interpreter.
+
+#[rustfmt::skip]
+mod tables;
+
+use crate::memory::{alloc_blob, Memory};
+use crate::text::{alloc_text_blob, blob_of_text, blob_str};
+use crate::types::{Bytes, Value};
+
+use core::cmp::Ordering;
+
+use motoko_rts_macros::ic_mem_fn;
+
+use tables::*;
+
+/// Returns the text in upper case
+#[ic_mem_fn]
+pub unsafe fn text_to_upper<M: Memory>(mem: &mut M, text: Value) -> Value {
+    map_chars(mem, text, |s, f| {
+        for c in s.chars() {
+            c.to_uppercase().for_each(&mut *f);
+        }
+    })
+}
+
+/// Returns the text in lower case
+#[ic_mem_fn]
+pub unsafe fn text_to_lower<M: Memory>(mem: &mut M, text: Value) -> Value {
+    map_chars(mem, text, |s, f| {
+        for (i, c) in s.char_indices() {
+            if c == 'Σ' && is_final_sigma(s, i) {
+                f('ς');
+            } else {
+                c.to_lowercase().for_each(&mut *f);
+            }
+        }
+    })
+}
+
+/// Returns the text in Normalization Form C (canonical decomposition, then canonical composition)
+#[ic_mem_fn]
+pub unsafe fn text_nfc<M: Memory>(mem: &mut M, text: Value) -> Value {
+    normalize(mem, text, false, true)
+}
+
+/// Returns the text in Normalization Form D (canonical decomposition)
+#[ic_mem_fn]
+pub unsafe fn text_nfd<M: Memory>(mem: &mut M, text: Value) -> Value {
+    normalize(mem, text, false, false)
+}
+
+/// Returns the text in Normalization Form KC (compatibility decomposition, then canonical
+/// composition)
+#[ic_mem_fn]
+pub unsafe fn text_nfkc<M: Memory>(mem: &mut M, text: Value) -> Value {
+    normalize(mem, text, true, true)
+}
+
+/// Allocates a text with the characters passed to the callback by `map`, which is called twice:
+/// once to find the size of the text, and once to write the characters.
+unsafe fn map_chars<M: Memory, F: Fn(&str, &mut dyn FnMut(char))>(
+    mem: &mut M,
+    text: Value,
+    map: F,
+) -> Value {
+    let blob = blob_of_text(mem, text);
+    let s = blob_str(blob.as_blob());
+
+    let mut size = 0;
+    map(s, &mut |c| size += c.len_utf8());
+
+    let r = alloc_text_blob(mem, Bytes(size as u32));
+    let payload = r.as_blob_mut().payload_addr();
+
+    // `s` is still valid as allocation doesn't move objects
+    let mut offset = 0;
+    map(s, &mut |c| {
+        let buf = core::slice::from_raw_parts_mut(payload.add(offset), c.len_utf8());
+        offset += c.encode_utf8(buf).len();
+    });
+
+    r
+}
+
+/// Whether the capital sigma at the offset is at the end of a word: preceded by a cased letter and
+/// not followed by one, skipping case-ignorable characters (the `Final_Sigma` condition of the
+/// Unicode standard, section 3.13)
+fn is_final_sigma(s: &str, offset: usize) -> bool {
+    // A character can be both cased and case-ignorable, e.g. modifier letters
+    fn next_is_cased<I: Iterator<Item = char>>(chars: I) -> bool {
+        for c in chars {
+            let properties = case_properties(c as u32);
+            if properties & CASED != 0 {
+                return true;
+            }
+            if properties & CASE_IGNORABLE == 0 {
+                return false;
+            }
+        }
+        false
+    }
+
+    next_is_cased(s[..offset].chars().rev()) && !next_is_cased(s[offset + 'Σ'.len_utf8()..].chars())
+}
+
+/// Bits of the values in `CASE_PROPERTIES`
+const CASED: u8 = 1;
+const CASE_IGNORABLE: u8 = 2;
+
+/// `Cased` and `Case_Ignorable` properties of the character, see `CASED` and `CASE_IGNORABLE`
+fn case_properties(c: u32) -> u8 {
+    match find_range(&CASE_PROPERTY_RANGES, c) {
+        Some(idx) => CASE_PROPERTIES[idx],
+        None => 0,
+    }
+}
+
+/// Finds the range with the character in a table of ranges, sorted by the first character of the
+/// range. Ranges are encoded as the first character shifted left by 11 bits, plus the number of
+/// characters after the first one.
+fn find_range(ranges: &[u32], c: u32) -> Option<usize> {
+    ranges
+        .binary_search_by(|range| {
+            let first = range >> 11;
+            let last = first + (range & 0x7FF);
+            if last < c {
+                Ordering::Less
+            } else if first > c {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .ok()
+}
+
+unsafe fn normalize<M: Memory>(mem: &mut M, text: Value, compat: bool, compose: bool) -> Value {
+    let blob = blob_of_text(mem, text);
+    let s = blob_str(blob.as_blob());
+
+    if s.is_ascii() {
+        return text;
+    }
+
+    // Decompose into a buffer of code points
+    let mut n_chars = 0;
+    for c in s.chars() {
+        decompose(c as u32, compat, &mut |_| n_chars += 1);
+    }
+
+    let buf_blob = alloc_blob(mem, Bytes(n_chars * 4));
+    let buf = core::slice::from_raw_parts_mut(
+        buf_blob.as_blob_mut().payload_addr() as *mut u32,
+        n_chars as usize,
+    );
+
+    let mut len = 0;
+    for c in s.chars() {
+        decompose(c as u32, compat, &mut |d| {
+            buf[len] = d;
+            len += 1;
+        });
+    }
+
+    reorder(buf);
+
+    if compose {
+        len = compose_in_place(buf);
+    }
+
+    let mut size = 0;
+    for c in &buf[..len] {
+        size += char::from_u32_unchecked(*c).len_utf8();
+    }
+
+    let r = alloc_text_blob(mem, Bytes(size as u32));
+    let payload = r.as_blob_mut().payload_addr();
+    let mut offset = 0;
+    for c in &buf[..len] {
+        let c = char::from_u32_unchecked(*c);
+        let char_buf = core::slice::from_raw_parts_mut(payload.add(offset), c.len_utf8());
+        offset += c.encode_utf8(char_buf).len();
+    }
+
+    r
+}
+
+/// Canonical combining class of the character
+fn combining_class(c: u32) -> u8 {
+    match find_range(&COMBINING_CLASS_RANGES, c) {
+        Some(idx) => COMBINING_CLASSES[idx],
+        None => 0,
+    }
+}
+
+const HANGUL_S_BASE: u32 = 0xAC00;
+const HANGUL_L_BASE: u32 = 0x1100;
+const HANGUL_V_BASE: u32 = 0x1161;
+const HANGUL_T_BASE: u32 = 0x11A7;
+const HANGUL_L_COUNT: u32 = 19;
+const HANGUL_V_COUNT: u32 = 21;
+const HANGUL_T_COUNT: u32 = 28;
+const HANGUL_N_COUNT: u32 = HANGUL_V_COUNT * HANGUL_T_COUNT;
+const HANGUL_S_COUNT: u32 = HANGUL_L_COUNT * HANGUL_N_COUNT;
+
+/// Passes the full decomposition of the character to `f`, one code point at a time
+fn decompose<F: FnMut(u32)>(c: u32, compat: bool, f: &mut F) {
+    if (HANGUL_S_BASE..HANGUL_S_BASE + HANGUL_S_COUNT).contains(&c) {
+        let s_index = c - HANGUL_S_BASE;
+        f(HANGUL_L_BASE + s_index / HANGUL_N_COUNT);
+        f(HANGUL_V_BASE + (s_index % HANGUL_N_COUNT) / HANGUL_T_COUNT);
+        if s_index % HANGUL_T_COUNT != 0 {
+            f(HANGUL_T_BASE + s_index % HANGUL_T_COUNT);
+        }
+        return;
+    }
+
+    match DECOMPOSITION_KEYS.binary_search_by_key(&c, |key| key >> 8) {
+        Ok(idx) if compat || DECOMPOSITION_KEYS[idx] & 0x80 == 0 => {
+            decomposition_chars(idx, |d| decompose(d, compat, f))
+        }
+        _ => f(c),
+    }
+}
+
+/// Passes the code points of the decomposition with the given index to `f`
+fn decomposition_chars<F: FnMut(u32)>(idx: usize, mut f: F) {
+    let n_chars = DECOMPOSITION_KEYS[idx] & 0x7F;
+    let mut offset = DECOMPOSITION_OFFSETS[idx] as usize;
+    for _ in 0..n_chars {
+        let unit = DECOMPOSITION_CHARS[offset] as u32;
+        if (0xD800..0xDC00).contains(&unit) {
+            let low = DECOMPOSITION_CHARS[offset + 1] as u32;
+            f(0x10000 + ((unit - 0xD800) << 10) + (low - 0xDC00));
+            offset += 2;
+        } else {
+            f(unit);
+            offset += 1;
+        }
+    }
+}
+
+/// Sorts the sequences of non-starters by combining class, keeping the order of the characters
+/// with the same class
+fn reorder(buf: &mut [u32]) {
+    for i in 1..buf.len() {
+        let class = combining_class(buf[i]);
+        if class == 0 {
+            continue;
+        }
+        let mut j = i;
+        while j > 0 && combining_class(buf[j - 1]) > class {
+            buf.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+/// Composes the decomposed and reordered characters, returns the new length
+fn compose_in_place(buf: &mut [u32]) -> usize {
+    // Index of the last starter in the result, if it can be composed
+    let mut starter: Option<usize> = None;
+    // Combining class of the last character in the result
+    let mut last_class = 0;
+    let mut len = 0;
+
+    for i in 0..buf.len() {
+        let c = buf[i];
+        let class = combining_class(c);
+
+        if let Some(starter) = starter {
+            // A character is blocked from the starter by a character between them with class 0
+            // or a class not lower than its class
+            let blocked = len > starter + 1 && (last_class == 0 || last_class >= class);
+            if !blocked {
+                if let Some(composite) = compose_pair(buf[starter], c) {
+                    buf[starter] = composite;
+                    continue;
+                }
+            }
+        }
+
+        if class == 0 {
+            starter = Some(len);
+        }
+        last_class = class;
+        buf[len] = c;
+        len += 1;
+    }
+
+    len
+}
+
+/// Returns the primary composite of the two characters, if any
+fn compose_pair(first: u32, second: u32) -> Option<u32> {
+    let l_index = first.wrapping_sub(HANGUL_L_BASE);
+    let v_index = second.wrapping_sub(HANGUL_V_BASE);
+    if l_index < HANGUL_L_COUNT && v_index < HANGUL_V_COUNT {
+        return Some(HANGUL_S_BASE + (l_index * HANGUL_V_COUNT + v_index) * HANGUL_T_COUNT);
+    }
+
+    let s_index = first.wrapping_sub(HANGUL_S_BASE);
+    let t_index = second.wrapping_sub(HANGUL_T_BASE);
+    if s_index < HANGUL_S_COUNT
+        && s_index % HANGUL_T_COUNT == 0
+        && 0 < t_index
+        && t_index < HANGUL_T_COUNT
+    {
+        return Some(first + t_index);
+    }
+
+    COMPOSITIONS
+        .binary_search_by(|idx| {
+            let mut pair = [0; 2];
+            let mut n = 0;
+            decomposition_chars(*idx as usize, |c| {
+                pair[n] = c;
+                n += 1;
+            });
+            (pair[0], pair[1]).cmp(&(first, second))
+        })
+        .ok()
+        .map(|idx| DECOMPOSITION_KEYS[COMPOSITIONS[idx] as usize] >> 8)
+}
+
+/// Grapheme_Cluster_Break property values of UAX #29, with Extended_Pictographic as a separate
+/// value, as no character with that property has another Grapheme_Cluster_Break value
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum GraphemeBreak {
+    Other,
+    Cr,
+    Lf,
+    Control,
+    Extend,
+    Zwj,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    L,
+    V,
+    T,
+    Lv,
+    Lvt,
+    ExtendedPictographic,
+}
+
+/// `GraphemeBreak` values in the order of the values in `GRAPHEME_BREAKS`
+const GRAPHEME_BREAK_VALUES: [GraphemeBreak; 15] = [
+    GraphemeBreak::Other,
+    GraphemeBreak::Cr,
+    GraphemeBreak::Lf,
+    GraphemeBreak::Control,
+    GraphemeBreak::Extend,
+    GraphemeBreak::Zwj,
+    GraphemeBreak::RegionalIndicator,
+    GraphemeBreak::Prepend,
+    GraphemeBreak::SpacingMark,
+    GraphemeBreak::L,
+    GraphemeBreak::V,
+    GraphemeBreak::T,
+    GraphemeBreak::Lv,
+    GraphemeBreak::Lvt,
+    GraphemeBreak::ExtendedPictographic,
+];
+
+pub(crate) fn grapheme_break(c: u32) -> GraphemeBreak {
+    let s_index = c.wrapping_sub(HANGUL_S_BASE);
+    if s_index < HANGUL_S_COUNT {
+        return if s_index % HANGUL_T_COUNT == 0 {
+            GraphemeBreak::Lv
+        } else {
+            GraphemeBreak::Lvt
+        };
+    }
+
+    match find_range(&GRAPHEME_BREAK_RANGES, c) {
+        Some(idx) => GRAPHEME_BREAK_VALUES[GRAPHEME_BREAKS[idx] as usize],
+        None => GraphemeBreak::Other,
+    }
+}
+
+/// Finds the extended grapheme cluster boundaries between the characters of a text, which are
+/// passed to `is_break` one at a time, in order
+pub(crate) struct GraphemeBreaks {
+    /// Property of the last character
+    prev: GraphemeBreak,
+
+    /// Whether the last character is an Extended_Pictographic character followed by zero or more
+    /// Extend characters and at most one ZWJ, for rule GB11
+    ext_pict: bool,
+
+    /// Whether the last character is a regional indicator that starts a flag, i.e. preceded by an
+    /// even number of regional indicators, for rules GB12 and GB13
+    ri_odd: bool,
+}
+
+impl GraphemeBreaks {
+    /// Starts with the first character of a text
+    pub(crate) fn new(first: u32) -> Self {
+        let prop = grapheme_break(first);
+        GraphemeBreaks {
+            prev: prop,
+            ext_pict: prop == GraphemeBreak::ExtendedPictographic,
+            ri_odd: prop == GraphemeBreak::RegionalIndicator,
+        }
+    }
+
+    /// Whether there is a boundary between the last character and `c`, which becomes the last
+    /// character
+    pub(crate) fn is_break(&mut self, c: u32) -> bool {
+        use GraphemeBreak::*;
+
+        let prev = self.prev;
+        let next = grapheme_break(c);
+
+        let is_break = match (prev, next) {
+            // GB3
+            (Cr, Lf) => false,
+            // GB4, GB5
+            (Cr | Lf | Control, _) | (_, Cr | Lf | Control) => true,
+            // GB6, GB7, GB8
+            (L, L | V | Lv | Lvt) | (Lv | V, V | T) | (Lvt | T, T) => false,
+            // GB9, GB9a, GB9b
+            (_, Extend | Zwj | SpacingMark) | (Prepend, _) => false,
+            // GB11
+            (Zwj, ExtendedPictographic) if self.ext_pict => false,
+            // GB12, GB13
+            (RegionalIndicator, RegionalIndicator) if self.ri_odd => false,
+            // GB999
+            _ => true,
+        };
+
+        self.ext_pict = match next {
+            ExtendedPictographic => true,
+            Extend => self.ext_pict && prev != Zwj,
+            Zwj => self.ext_pict && prev != Zwj,
+            _ => false,
+        };
+        self.ri_odd = next == RegionalIndicator && !(prev == RegionalIndicator && self.ri_odd);
+        self.prev = next;
+
+        is_break
+    }
+}
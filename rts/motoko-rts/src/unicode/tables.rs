@@ -0,0 +1,3139 @@
+// Generated by gen-unicode-tables.py from the Unicode 14.0.0 database, do not edit
+
+pub(super) static COMBINING_CLASS_RANGES: [u32; 382] = [
+    0x00180014, 0x0018a800, 0x0018b003, 0x0018d000, 0x0018d800, 0x0018e004,
+    0x00190801, 0x00191803, 0x00193801, 0x0019480a, 0x0019a004, 0x0019c803,
+    0x0019e807, 0x001a2800, 0x001a3000, 0x001a3802, 0x001a5002, 0x001a6801,
+    0x001a8002, 0x001a9803, 0x001ab800, 0x001ac000, 0x001ac801, 0x001ad800,
+    0x001ae000, 0x001ae801, 0x001af800, 0x001b0001, 0x001b1000, 0x001b180c,
+    0x00241804, 0x002c8800, 0x002c9003, 0x002cb000, 0x002cb802, 0x002cd000,
+    0x002cd800, 0x002ce005, 0x002d1005, 0x002d4001, 0x002d5000, 0x002d5801,
+    0x002d6800, 0x002d7000, 0x002d7800, 0x002d8000, 0x002d8800, 0x002d9000,
+    0x002d9800, 0x002da000, 0x002da800, 0x002db000, 0x002db800, 0x002dc000,
+    0x002dc801, 0x002dd800, 0x002de000, 0x002de800, 0x002df800, 0x002e0800,
+    0x002e1000, 0x002e2000, 0x002e2800, 0x002e3800, 0x00308007, 0x0030c000,
+    0x0030c800, 0x0030d000, 0x00325800, 0x00326000, 0x00326800, 0x00327000,
+    0x00327800, 0x00328000, 0x00328800, 0x00329000, 0x00329801, 0x0032a801,
+    0x0032b804, 0x0032e000, 0x0032e801, 0x0032f800, 0x00338000, 0x0036b006,
+    0x0036f803, 0x00371800, 0x00372000, 0x00373801, 0x00375000, 0x00375801,
+    0x00376800, 0x00388800, 0x00398000, 0x00398800, 0x00399001, 0x0039a000,
+    0x0039a801, 0x0039b802, 0x0039d000, 0x0039d801, 0x0039e800, 0x0039f000,
+    0x0039f802, 0x003a1000, 0x003a1800, 0x003a2000, 0x003a2800, 0x003a3000,
+    0x003a3800, 0x003a4000, 0x003a4801, 0x003f5806, 0x003f9000, 0x003f9800,
+    0x003fe800, 0x0040b003, 0x0040d808, 0x00412802, 0x00414804, 0x0042c802,
+    0x0044c000, 0x0044c802, 0x0044e003, 0x00465004, 0x00467804, 0x0046a00d,
+    0x00471800, 0x00472001, 0x00473000, 0x00473801, 0x00474800, 0x00475002,
+    0x00476802, 0x00478000, 0x00478800, 0x00479000, 0x00479802, 0x0047b000,
+    0x0047b801, 0x0047c801, 0x0047d804, 0x0049e000, 0x004a6800, 0x004a8800,
+    0x004a9000, 0x004a9801, 0x004de000, 0x004e6800, 0x004ff000, 0x0051e000,
+    0x00526800, 0x0055e000, 0x00566800, 0x0059e000, 0x005a6800, 0x005e6800,
+    0x0061e000, 0x00626800, 0x0062a800, 0x0062b000, 0x0065e000, 0x00666800,
+    0x0069d801, 0x006a6800, 0x006e5000, 0x0071c001, 0x0071d000, 0x00724003,
+    0x0075c001, 0x0075d000, 0x00764003, 0x0078c001, 0x0079a800, 0x0079b800,
+    0x0079c800, 0x007b8800, 0x007b9000, 0x007ba000, 0x007bd003, 0x007c0000,
+    0x007c1001, 0x007c2000, 0x007c3001, 0x007e3000, 0x0081b800, 0x0081c801,
+    0x00846800, 0x009ae802, 0x00b8a001, 0x00b9a000, 0x00be9000, 0x00bee800,
+    0x00c54800, 0x00c9c800, 0x00c9d000, 0x00c9d800, 0x00d0b800, 0x00d0c000,
+    0x00d30000, 0x00d3a807, 0x00d3f800, 0x00d58004, 0x00d5a805, 0x00d5d801,
+    0x00d5e800, 0x00d5f801, 0x00d60801, 0x00d61801, 0x00d62804, 0x00d65000,
+    0x00d65803, 0x00d9a000, 0x00da2000, 0x00db5800, 0x00db6000, 0x00db6806,
+    0x00dd5001, 0x00df3000, 0x00df9001, 0x00e1b800, 0x00e68002, 0x00e6a000,
+    0x00e6a804, 0x00e6d001, 0x00e6e003, 0x00e70000, 0x00e71006, 0x00e76800,
+    0x00e7a000, 0x00e7c001, 0x00ee0001, 0x00ee1000, 0x00ee1806, 0x00ee5000,
+    0x00ee5801, 0x00ee6800, 0x00ee7000, 0x00ee7800, 0x00ee8000, 0x00ee8824,
+    0x00efb000, 0x00efb801, 0x00efc800, 0x00efd000, 0x00efd800, 0x00efe000,
+    0x00efe800, 0x00eff000, 0x00eff800, 0x01068001, 0x01069001, 0x0106a003,
+    0x0106c002, 0x0106d801, 0x01070800, 0x01072801, 0x01073800, 0x01074000,
+    0x01074800, 0x01075001, 0x01076003, 0x01078000, 0x01677802, 0x016bf800,
+    0x016f001f, 0x01815000, 0x01815800, 0x01816000, 0x01816800, 0x01817001,
+    0x0184c801, 0x05337800, 0x0533a009, 0x0534f001, 0x05378001, 0x05403000,
+    0x05416000, 0x05462000, 0x05470011, 0x05495802, 0x054a9800, 0x054d9800,
+    0x054e0000, 0x05558000, 0x05559001, 0x0555a000, 0x0555b801, 0x0555f001,
+    0x05560800, 0x0557b000, 0x055f6800, 0x07d8f000, 0x07f10006, 0x07f13806,
+    0x07f17001, 0x080fe800, 0x08170000, 0x081bb004, 0x08506800, 0x08507800,
+    0x0851c000, 0x0851c800, 0x0851d000, 0x0851f800, 0x08572800, 0x08573000,
+    0x08692003, 0x08755801, 0x087a3001, 0x087a4002, 0x087a5800, 0x087a6000,
+    0x087a6803, 0x087c1000, 0x087c1800, 0x087c2000, 0x087c2800, 0x08823000,
+    0x08838000, 0x0883f800, 0x0885c800, 0x0885d000, 0x08880002, 0x08899801,
+    0x088b9800, 0x088e0000, 0x088e5000, 0x0891a800, 0x0891b000, 0x08974800,
+    0x08975000, 0x0899d801, 0x089a6800, 0x089b3006, 0x089b8004, 0x08a21000,
+    0x08a23000, 0x08a2f000, 0x08a61000, 0x08a61800, 0x08adf800, 0x08ae0000,
+    0x08b1f800, 0x08b5b000, 0x08b5b800, 0x08b95800, 0x08c1c800, 0x08c1d000,
+    0x08c9e801, 0x08ca1800, 0x08cf0000, 0x08d1a000, 0x08d23800, 0x08d4c800,
+    0x08e1f800, 0x08ea1000, 0x08ea2001, 0x08ecb800, 0x0b578004, 0x0b598006,
+    0x0b7f8001, 0x0de4f000, 0x0e8b2801, 0x0e8b3802, 0x0e8b6800, 0x0e8b7004,
+    0x0e8bd807, 0x0e8c2804, 0x0e8c5001, 0x0e8d5003, 0x0e921002, 0x0f000006,
+    0x0f004010, 0x0f00d806, 0x0f011801, 0x0f013004, 0x0f098006, 0x0f157000,
+    0x0f176003, 0x0f468006, 0x0f4a2005, 0x0f4a5000,
+];
+
+pub(super) static COMBINING_CLASSES: [u8; 382] = [
+    230, 232, 220, 232, 216, 220, 202, 220, 202, 220, 1, 220, 230, 240, 230, 220,
+    230, 220, 230, 220, 230, 232, 220, 230, 233, 234, 233, 234, 233, 230, 230, 220,
+    230, 220, 230, 222, 220, 230, 220, 230, 220, 230, 222, 228, 230, 10, 11, 12,
+    13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 230, 220, 18,
+    230, 30, 31, 32, 27, 28, 29, 30, 31, 32, 33, 34, 230, 220, 230, 220,
+    230, 220, 35, 230, 230, 220, 230, 230, 220, 230, 220, 36, 230, 220, 230, 220,
+    230, 220, 230, 220, 230, 220, 230, 220, 230, 220, 230, 220, 230, 220, 230, 230,
+    220, 230, 220, 230, 230, 230, 230, 220, 230, 220, 230, 230, 220, 230, 220, 230,
+    220, 230, 220, 230, 220, 27, 28, 29, 230, 220, 230, 220, 230, 7, 9, 230,
+    220, 230, 7, 9, 230, 7, 9, 7, 9, 7, 9, 9, 7, 9, 84, 91,
+    7, 9, 9, 9, 9, 103, 9, 107, 118, 9, 122, 220, 220, 220, 216, 129,
+    130, 132, 130, 130, 230, 9, 230, 220, 7, 9, 220, 230, 9, 9, 9, 230,
+    228, 222, 230, 220, 230, 220, 9, 230, 220, 230, 220, 230, 220, 220, 230, 220,
+    230, 220, 230, 7, 9, 230, 220, 230, 9, 7, 9, 7, 230, 1, 220, 230,
+    220, 230, 1, 220, 230, 230, 230, 220, 230, 220, 230, 234, 214, 220, 202, 230,
+    232, 228, 220, 218, 230, 233, 220, 230, 220, 230, 1, 230, 1, 230, 230, 1,
+    230, 220, 230, 1, 220, 230, 230, 9, 230, 218, 228, 232, 222, 224, 8, 230,
+    230, 230, 230, 9, 9, 9, 230, 220, 9, 7, 9, 230, 230, 220, 230, 230,
+    230, 9, 9, 26, 230, 220, 230, 220, 220, 230, 220, 230, 230, 1, 220, 9,
+    230, 220, 230, 230, 220, 230, 220, 230, 220, 230, 220, 230, 220, 9, 9, 9,
+    9, 7, 230, 9, 7, 9, 7, 9, 7, 7, 9, 7, 9, 230, 230, 9,
+    7, 230, 9, 7, 9, 7, 9, 9, 7, 9, 9, 7, 9, 7, 9, 9,
+    9, 9, 9, 7, 9, 9, 1, 230, 6, 1, 216, 1, 226, 216, 220, 230,
+    220, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 220, 230, 7,
+];
+
+pub(super) static DECOMPOSITION_KEYS: [u32; 5795] = [
+    0x0000a081, 0x0000a882, 0x0000aa81, 0x0000af82, 0x0000b281, 0x0000b381,
+    0x0000b482, 0x0000b581, 0x0000b882, 0x0000b981, 0x0000ba81, 0x0000bc83,
+    0x0000bd83, 0x0000be83, 0x0000c002, 0x0000c102, 0x0000c202, 0x0000c302,
+    0x0000c402, 0x0000c502, 0x0000c702, 0x0000c802, 0x0000c902, 0x0000ca02,
+    0x0000cb02, 0x0000cc02, 0x0000cd02, 0x0000ce02, 0x0000cf02, 0x0000d102,
+    0x0000d202, 0x0000d302, 0x0000d402, 0x0000d502, 0x0000d602, 0x0000d902,
+    0x0000da02, 0x0000db02, 0x0000dc02, 0x0000dd02, 0x0000e002, 0x0000e102,
+    0x0000e202, 0x0000e302, 0x0000e402, 0x0000e502, 0x0000e702, 0x0000e802,
+    0x0000e902, 0x0000ea02, 0x0000eb02, 0x0000ec02, 0x0000ed02, 0x0000ee02,
+    0x0000ef02, 0x0000f102, 0x0000f202, 0x0000f302, 0x0000f402, 0x0000f502,
+    0x0000f602, 0x0000f902, 0x0000fa02, 0x0000fb02, 0x0000fc02, 0x0000fd02,
+    0x0000ff02, 0x00010002, 0x00010102, 0x00010202, 0x00010302, 0x00010402,
+    0x00010502, 0x00010602, 0x00010702, 0x00010802, 0x00010902, 0x00010a02,
+    0x00010b02, 0x00010c02, 0x00010d02, 0x00010e02, 0x00010f02, 0x00011202,
+    0x00011302, 0x00011402, 0x00011502, 0x00011602, 0x00011702, 0x00011802,
+    0x00011902, 0x00011a02, 0x00011b02, 0x00011c02, 0x00011d02, 0x00011e02,
+    0x00011f02, 0x00012002, 0x00012102, 0x00012202, 0x00012302, 0x00012402,
+    0x00012502, 0x00012802, 0x00012902, 0x00012a02, 0x00012b02, 0x00012c02,
+    0x00012d02, 0x00012e02, 0x00012f02, 0x00013002, 0x00013282, 0x00013382,
+    0x00013402, 0x00013502, 0x00013602, 0x00013702, 0x00013902, 0x00013a02,
+    0x00013b02, 0x00013c02, 0x00013d02, 0x00013e02, 0x00013f82, 0x00014082,
+    0x00014302, 0x00014402, 0x00014502, 0x00014602, 0x00014702, 0x00014802,
+    0x00014982, 0x00014c02, 0x00014d02, 0x00014e02, 0x00014f02, 0x00015002,
+    0x00015102, 0x00015402, 0x00015502, 0x00015602, 0x00015702, 0x00015802,
+    0x00015902, 0x00015a02, 0x00015b02, 0x00015c02, 0x00015d02, 0x00015e02,
+    0x00015f02, 0x00016002, 0x00016102, 0x00016202, 0x00016302, 0x00016402,
+    0x00016502, 0x00016802, 0x00016902, 0x00016a02, 0x00016b02, 0x00016c02,
+    0x00016d02, 0x00016e02, 0x00016f02, 0x00017002, 0x00017102, 0x00017202,
+    0x00017302, 0x00017402, 0x00017502, 0x00017602, 0x00017702, 0x00017802,
+    0x00017902, 0x00017a02, 0x00017b02, 0x00017c02, 0x00017d02, 0x00017e02,
+    0x00017f81, 0x0001a002, 0x0001a102, 0x0001af02, 0x0001b002, 0x0001c482,
+    0x0001c582, 0x0001c682, 0x0001c782, 0x0001c882, 0x0001c982, 0x0001ca82,
+    0x0001cb82, 0x0001cc82, 0x0001cd02, 0x0001ce02, 0x0001cf02, 0x0001d002,
+    0x0001d102, 0x0001d202, 0x0001d302, 0x0001d402, 0x0001d502, 0x0001d602,
+    0x0001d702, 0x0001d802, 0x0001d902, 0x0001da02, 0x0001db02, 0x0001dc02,
+    0x0001de02, 0x0001df02, 0x0001e002, 0x0001e102, 0x0001e202, 0x0001e302,
+    0x0001e602, 0x0001e702, 0x0001e802, 0x0001e902, 0x0001ea02, 0x0001eb02,
+    0x0001ec02, 0x0001ed02, 0x0001ee02, 0x0001ef02, 0x0001f002, 0x0001f182,
+    0x0001f282, 0x0001f382, 0x0001f402, 0x0001f502, 0x0001f802, 0x0001f902,
+    0x0001fa02, 0x0001fb02, 0x0001fc02, 0x0001fd02, 0x0001fe02, 0x0001ff02,
+    0x00020002, 0x00020102, 0x00020202, 0x00020302, 0x00020402, 0x00020502,
+    0x00020602, 0x00020702, 0x00020802, 0x00020902, 0x00020a02, 0x00020b02,
+    0x00020c02, 0x00020d02, 0x00020e02, 0x00020f02, 0x00021002, 0x00021102,
+    0x00021202, 0x00021302, 0x00021402, 0x00021502, 0x00021602, 0x00021702,
+    0x00021802, 0x00021902, 0x00021a02, 0x00021b02, 0x00021e02, 0x00021f02,
+    0x00022602, 0x00022702, 0x00022802, 0x00022902, 0x00022a02, 0x00022b02,
+    0x00022c02, 0x00022d02, 0x00022e02, 0x00022f02, 0x00023002, 0x00023102,
+    0x00023202, 0x00023302, 0x0002b081, 0x0002b181, 0x0002b281, 0x0002b381,
+    0x0002b481, 0x0002b581, 0x0002b681, 0x0002b781, 0x0002b881, 0x0002d882,
+    0x0002d982, 0x0002da82, 0x0002db82, 0x0002dc82, 0x0002dd82, 0x0002e081,
+    0x0002e181, 0x0002e281, 0x0002e381, 0x0002e481, 0x00034001, 0x00034101,
+    0x00034301, 0x00034402, 0x00037401, 0x00037a82, 0x00037e01, 0x00038482,
+    0x00038502, 0x00038602, 0x00038701, 0x00038802, 0x00038902, 0x00038a02,
+    0x00038c02, 0x00038e02, 0x00038f02, 0x00039002, 0x0003aa02, 0x0003ab02,
+    0x0003ac02, 0x0003ad02, 0x0003ae02, 0x0003af02, 0x0003b002, 0x0003ca02,
+    0x0003cb02, 0x0003cc02, 0x0003cd02, 0x0003ce02, 0x0003d081, 0x0003d181,
+    0x0003d281, 0x0003d302, 0x0003d402, 0x0003d581, 0x0003d681, 0x0003f081,
+    0x0003f181, 0x0003f281, 0x0003f481, 0x0003f581, 0x0003f981, 0x00040002,
+    0x00040102, 0x00040302, 0x00040702, 0x00040c02, 0x00040d02, 0x00040e02,
+    0x00041902, 0x00043902, 0x00045002, 0x00045102, 0x00045302, 0x00045702,
+    0x00045c02, 0x00045d02, 0x00045e02, 0x00047602, 0x00047702, 0x0004c102,
+    0x0004c202, 0x0004d002, 0x0004d102, 0x0004d202, 0x0004d302, 0x0004d602,
+    0x0004d702, 0x0004da02, 0x0004db02, 0x0004dc02, 0x0004dd02, 0x0004de02,
+    0x0004df02, 0x0004e202, 0x0004e302, 0x0004e402, 0x0004e502, 0x0004e602,
+    0x0004e702, 0x0004ea02, 0x0004eb02, 0x0004ec02, 0x0004ed02, 0x0004ee02,
+    0x0004ef02, 0x0004f002, 0x0004f102, 0x0004f202, 0x0004f302, 0x0004f402,
+    0x0004f502, 0x0004f802, 0x0004f902, 0x00058782, 0x00062202, 0x00062302,
+    0x00062402, 0x00062502, 0x00062602, 0x00067582, 0x00067682, 0x00067782,
+    0x00067882, 0x0006c002, 0x0006c202, 0x0006d302, 0x00092902, 0x00093102,
+    0x00093402, 0x00095802, 0x00095902, 0x00095a02, 0x00095b02, 0x00095c02,
+    0x00095d02, 0x00095e02, 0x00095f02, 0x0009cb02, 0x0009cc02, 0x0009dc02,
+    0x0009dd02, 0x0009df02, 0x000a3302, 0x000a3602, 0x000a5902, 0x000a5a02,
+    0x000a5b02, 0x000a5e02, 0x000b4802, 0x000b4b02, 0x000b4c02, 0x000b5c02,
+    0x000b5d02, 0x000b9402, 0x000bca02, 0x000bcb02, 0x000bcc02, 0x000c4802,
+    0x000cc002, 0x000cc702, 0x000cc802, 0x000cca02, 0x000ccb02, 0x000d4a02,
+    0x000d4b02, 0x000d4c02, 0x000dda02, 0x000ddc02, 0x000ddd02, 0x000dde02,
+    0x000e3382, 0x000eb382, 0x000edc82, 0x000edd82, 0x000f0c81, 0x000f4302,
+    0x000f4d02, 0x000f5202, 0x000f5702, 0x000f5c02, 0x000f6902, 0x000f7302,
+    0x000f7502, 0x000f7602, 0x000f7782, 0x000f7802, 0x000f7982, 0x000f8102,
+    0x000f9302, 0x000f9d02, 0x000fa202, 0x000fa702, 0x000fac02, 0x000fb902,
+    0x00102602, 0x0010fc81, 0x001b0602, 0x001b0802, 0x001b0a02, 0x001b0c02,
+    0x001b0e02, 0x001b1202, 0x001b3b02, 0x001b3d02, 0x001b4002, 0x001b4102,
+    0x001b4302, 0x001d2c81, 0x001d2d81, 0x001d2e81, 0x001d3081, 0x001d3181,
+    0x001d3281, 0x001d3381, 0x001d3481, 0x001d3581, 0x001d3681, 0x001d3781,
+    0x001d3881, 0x001d3981, 0x001d3a81, 0x001d3c81, 0x001d3d81, 0x001d3e81,
+    0x001d3f81, 0x001d4081, 0x001d4181, 0x001d4281, 0x001d4381, 0x001d4481,
+    0x001d4581, 0x001d4681, 0x001d4781, 0x001d4881, 0x001d4981, 0x001d4a81,
+    0x001d4b81, 0x001d4c81, 0x001d4d81, 0x001d4f81, 0x001d5081, 0x001d5181,
+    0x001d5281, 0x001d5381, 0x001d5481, 0x001d5581, 0x001d5681, 0x001d5781,
+    0x001d5881, 0x001d5981, 0x001d5a81, 0x001d5b81, 0x001d5c81, 0x001d5d81,
+    0x001d5e81, 0x001d5f81, 0x001d6081, 0x001d6181, 0x001d6281, 0x001d6381,
+    0x001d6481, 0x001d6581, 0x001d6681, 0x001d6781, 0x001d6881, 0x001d6981,
+    0x001d6a81, 0x001d7881, 0x001d9b81, 0x001d9c81, 0x001d9d81, 0x001d9e81,
+    0x001d9f81, 0x001da081, 0x001da181, 0x001da281, 0x001da381, 0x001da481,
+    0x001da581, 0x001da681, 0x001da781, 0x001da881, 0x001da981, 0x001daa81,
+    0x001dab81, 0x001dac81, 0x001dad81, 0x001dae81, 0x001daf81, 0x001db081,
+    0x001db181, 0x001db281, 0x001db381, 0x001db481, 0x001db581, 0x001db681,
+    0x001db781, 0x001db881, 0x001db981, 0x001dba81, 0x001dbb81, 0x001dbc81,
+    0x001dbd81, 0x001dbe81, 0x001dbf81, 0x001e0002, 0x001e0102, 0x001e0202,
+    0x001e0302, 0x001e0402, 0x001e0502, 0x001e0602, 0x001e0702, 0x001e0802,
+    0x001e0902, 0x001e0a02, 0x001e0b02, 0x001e0c02, 0x001e0d02, 0x001e0e02,
+    0x001e0f02, 0x001e1002, 0x001e1102, 0x001e1202, 0x001e1302, 0x001e1402,
+    0x001e1502, 0x001e1602, 0x001e1702, 0x001e1802, 0x001e1902, 0x001e1a02,
+    0x001e1b02, 0x001e1c02, 0x001e1d02, 0x001e1e02, 0x001e1f02, 0x001e2002,
+    0x001e2102, 0x001e2202, 0x001e2302, 0x001e2402, 0x001e2502, 0x001e2602,
+    0x001e2702, 0x001e2802, 0x001e2902, 0x001e2a02, 0x001e2b02, 0x001e2c02,
+    0x001e2d02, 0x001e2e02, 0x001e2f02, 0x001e3002, 0x001e3102, 0x001e3202,
+    0x001e3302, 0x001e3402, 0x001e3502, 0x001e3602, 0x001e3702, 0x001e3802,
+    0x001e3902, 0x001e3a02, 0x001e3b02, 0x001e3c02, 0x001e3d02, 0x001e3e02,
+    0x001e3f02, 0x001e4002, 0x001e4102, 0x001e4202, 0x001e4302, 0x001e4402,
+    0x001e4502, 0x001e4602, 0x001e4702, 0x001e4802, 0x001e4902, 0x001e4a02,
+    0x001e4b02, 0x001e4c02, 0x001e4d02, 0x001e4e02, 0x001e4f02, 0x001e5002,
+    0x001e5102, 0x001e5202, 0x001e5302, 0x001e5402, 0x001e5502, 0x001e5602,
+    0x001e5702, 0x001e5802, 0x001e5902, 0x001e5a02, 0x001e5b02, 0x001e5c02,
+    0x001e5d02, 0x001e5e02, 0x001e5f02, 0x001e6002, 0x001e6102, 0x001e6202,
+    0x001e6302, 0x001e6402, 0x001e6502, 0x001e6602, 0x001e6702, 0x001e6802,
+    0x001e6902, 0x001e6a02, 0x001e6b02, 0x001e6c02, 0x001e6d02, 0x001e6e02,
+    0x001e6f02, 0x001e7002, 0x001e7102, 0x001e7202, 0x001e7302, 0x001e7402,
+    0x001e7502, 0x001e7602, 0x001e7702, 0x001e7802, 0x001e7902, 0x001e7a02,
+    0x001e7b02, 0x001e7c02, 0x001e7d02, 0x001e7e02, 0x001e7f02, 0x001e8002,
+    0x001e8102, 0x001e8202, 0x001e8302, 0x001e8402, 0x001e8502, 0x001e8602,
+    0x001e8702, 0x001e8802, 0x001e8902, 0x001e8a02, 0x001e8b02, 0x001e8c02,
+    0x001e8d02, 0x001e8e02, 0x001e8f02, 0x001e9002, 0x001e9102, 0x001e9202,
+    0x001e9302, 0x001e9402, 0x001e9502, 0x001e9602, 0x001e9702, 0x001e9802,
+    0x001e9902, 0x001e9a82, 0x001e9b02, 0x001ea002, 0x001ea102, 0x001ea202,
+    0x001ea302, 0x001ea402, 0x001ea502, 0x001ea602, 0x001ea702, 0x001ea802,
+    0x001ea902, 0x001eaa02, 0x001eab02, 0x001eac02, 0x001ead02, 0x001eae02,
+    0x001eaf02, 0x001eb002, 0x001eb102, 0x001eb202, 0x001eb302, 0x001eb402,
+    0x001eb502, 0x001eb602, 0x001eb702, 0x001eb802, 0x001eb902, 0x001eba02,
+    0x001ebb02, 0x001ebc02, 0x001ebd02, 0x001ebe02, 0x001ebf02, 0x001ec002,
+    0x001ec102, 0x001ec202, 0x001ec302, 0x001ec402, 0x001ec502, 0x001ec602,
+    0x001ec702, 0x001ec802, 0x001ec902, 0x001eca02, 0x001ecb02, 0x001ecc02,
+    0x001ecd02, 0x001ece02, 0x001ecf02, 0x001ed002, 0x001ed102, 0x001ed202,
+    0x001ed302, 0x001ed402, 0x001ed502, 0x001ed602, 0x001ed702, 0x001ed802,
+    0x001ed902, 0x001eda02, 0x001edb02, 0x001edc02, 0x001edd02, 0x001ede02,
+    0x001edf02, 0x001ee002, 0x001ee102, 0x001ee202, 0x001ee302, 0x001ee402,
+    0x001ee502, 0x001ee602, 0x001ee702, 0x001ee802, 0x001ee902, 0x001eea02,
+    0x001eeb02, 0x001eec02, 0x001eed02, 0x001eee02, 0x001eef02, 0x001ef002,
+    0x001ef102, 0x001ef202, 0x001ef302, 0x001ef402, 0x001ef502, 0x001ef602,
+    0x001ef702, 0x001ef802, 0x001ef902, 0x001f0002, 0x001f0102, 0x001f0202,
+    0x001f0302, 0x001f0402, 0x001f0502, 0x001f0602, 0x001f0702, 0x001f0802,
+    0x001f0902, 0x001f0a02, 0x001f0b02, 0x001f0c02, 0x001f0d02, 0x001f0e02,
+    0x001f0f02, 0x001f1002, 0x001f1102, 0x001f1202, 0x001f1302, 0x001f1402,
+    0x001f1502, 0x001f1802, 0x001f1902, 0x001f1a02, 0x001f1b02, 0x001f1c02,
+    0x001f1d02, 0x001f2002, 0x001f2102, 0x001f2202, 0x001f2302, 0x001f2402,
+    0x001f2502, 0x001f2602, 0x001f2702, 0x001f2802, 0x001f2902, 0x001f2a02,
+    0x001f2b02, 0x001f2c02, 0x001f2d02, 0x001f2e02, 0x001f2f02, 0x001f3002,
+    0x001f3102, 0x001f3202, 0x001f3302, 0x001f3402, 0x001f3502, 0x001f3602,
+    0x001f3702, 0x001f3802, 0x001f3902, 0x001f3a02, 0x001f3b02, 0x001f3c02,
+    0x001f3d02, 0x001f3e02, 0x001f3f02, 0x001f4002, 0x001f4102, 0x001f4202,
+    0x001f4302, 0x001f4402, 0x001f4502, 0x001f4802, 0x001f4902, 0x001f4a02,
+    0x001f4b02, 0x001f4c02, 0x001f4d02, 0x001f5002, 0x001f5102, 0x001f5202,
+    0x001f5302, 0x001f5402, 0x001f5502, 0x001f5602, 0x001f5702, 0x001f5902,
+    0x001f5b02, 0x001f5d02, 0x001f5f02, 0x001f6002, 0x001f6102, 0x001f6202,
+    0x001f6302, 0x001f6402, 0x001f6502, 0x001f6602, 0x001f6702, 0x001f6802,
+    0x001f6902, 0x001f6a02, 0x001f6b02, 0x001f6c02, 0x001f6d02, 0x001f6e02,
+    0x001f6f02, 0x001f7002, 0x001f7101, 0x001f7202, 0x001f7301, 0x001f7402,
+    0x001f7501, 0x001f7602, 0x001f7701, 0x001f7802, 0x001f7901, 0x001f7a02,
+    0x001f7b01, 0x001f7c02, 0x001f7d01, 0x001f8002, 0x001f8102, 0x001f8202,
+    0x001f8302, 0x001f8402, 0x001f8502, 0x001f8602, 0x001f8702, 0x001f8802,
+    0x001f8902, 0x001f8a02, 0x001f8b02, 0x001f8c02, 0x001f8d02, 0x001f8e02,
+    0x001f8f02, 0x001f9002, 0x001f9102, 0x001f9202, 0x001f9302, 0x001f9402,
+    0x001f9502, 0x001f9602, 0x001f9702, 0x001f9802, 0x001f9902, 0x001f9a02,
+    0x001f9b02, 0x001f9c02, 0x001f9d02, 0x001f9e02, 0x001f9f02, 0x001fa002,
+    0x001fa102, 0x001fa202, 0x001fa302, 0x001fa402, 0x001fa502, 0x001fa602,
+    0x001fa702, 0x001fa802, 0x001fa902, 0x001faa02, 0x001fab02, 0x001fac02,
+    0x001fad02, 0x001fae02, 0x001faf02, 0x001fb002, 0x001fb102, 0x001fb202,
+    0x001fb302, 0x001fb402, 0x001fb602, 0x001fb702, 0x001fb802, 0x001fb902,
+    0x001fba02, 0x001fbb01, 0x001fbc02, 0x001fbd82, 0x001fbe01, 0x001fbf82,
+    0x001fc082, 0x001fc102, 0x001fc202, 0x001fc302, 0x001fc402, 0x001fc602,
+    0x001fc702, 0x001fc802, 0x001fc901, 0x001fca02, 0x001fcb01, 0x001fcc02,
+    0x001fcd02, 0x001fce02, 0x001fcf02, 0x001fd002, 0x001fd102, 0x001fd202,
+    0x001fd301, 0x001fd602, 0x001fd702, 0x001fd802, 0x001fd902, 0x001fda02,
+    0x001fdb01, 0x001fdd02, 0x001fde02, 0x001fdf02, 0x001fe002, 0x001fe102,
+    0x001fe202, 0x001fe301, 0x001fe402, 0x001fe502, 0x001fe602, 0x001fe702,
+    0x001fe802, 0x001fe902, 0x001fea02, 0x001feb01, 0x001fec02, 0x001fed02,
+    0x001fee01, 0x001fef01, 0x001ff202, 0x001ff302, 0x001ff402, 0x001ff602,
+    0x001ff702, 0x001ff802, 0x001ff901, 0x001ffa02, 0x001ffb01, 0x001ffc02,
+    0x001ffd01, 0x001ffe82, 0x00200001, 0x00200101, 0x00200281, 0x00200381,
+    0x00200481, 0x00200581, 0x00200681, 0x00200781, 0x00200881, 0x00200981,
+    0x00200a81, 0x00201181, 0x00201782, 0x00202481, 0x00202582, 0x00202683,
+    0x00202f81, 0x00203382, 0x00203483, 0x00203682, 0x00203783, 0x00203c82,
+    0x00203e82, 0x00204782, 0x00204882, 0x00204982, 0x00205784, 0x00205f81,
+    0x00207081, 0x00207181, 0x00207481, 0x00207581, 0x00207681, 0x00207781,
+    0x00207881, 0x00207981, 0x00207a81, 0x00207b81, 0x00207c81, 0x00207d81,
+    0x00207e81, 0x00207f81, 0x00208081, 0x00208181, 0x00208281, 0x00208381,
+    0x00208481, 0x00208581, 0x00208681, 0x00208781, 0x00208881, 0x00208981,
+    0x00208a81, 0x00208b81, 0x00208c81, 0x00208d81, 0x00208e81, 0x00209081,
+    0x00209181, 0x00209281, 0x00209381, 0x00209481, 0x00209581, 0x00209681,
+    0x00209781, 0x00209881, 0x00209981, 0x00209a81, 0x00209b81, 0x00209c81,
+    0x0020a882, 0x00210083, 0x00210183, 0x00210281, 0x00210382, 0x00210583,
+    0x00210683, 0x00210781, 0x00210982, 0x00210a81, 0x00210b81, 0x00210c81,
+    0x00210d81, 0x00210e81, 0x00210f81, 0x00211081, 0x00211181, 0x00211281,
+    0x00211381, 0x00211581, 0x00211682, 0x00211981, 0x00211a81, 0x00211b81,
+    0x00211c81, 0x00211d81, 0x00212082, 0x00212183, 0x00212282, 0x00212481,
+    0x00212601, 0x00212881, 0x00212a01, 0x00212b01, 0x00212c81, 0x00212d81,
+    0x00212f81, 0x00213081, 0x00213181, 0x00213381, 0x00213481, 0x00213581,
+    0x00213681, 0x00213781, 0x00213881, 0x00213981, 0x00213b83, 0x00213c81,
+    0x00213d81, 0x00213e81, 0x00213f81, 0x00214081, 0x00214581, 0x00214681,
+    0x00214781, 0x00214881, 0x00214981, 0x00215083, 0x00215183, 0x00215284,
+    0x00215383, 0x00215483, 0x00215583, 0x00215683, 0x00215783, 0x00215883,
+    0x00215983, 0x00215a83, 0x00215b83, 0x00215c83, 0x00215d83, 0x00215e83,
+    0x00215f82, 0x00216081, 0x00216182, 0x00216283, 0x00216382, 0x00216481,
+    0x00216582, 0x00216683, 0x00216784, 0x00216882, 0x00216981, 0x00216a82,
+    0x00216b83, 0x00216c81, 0x00216d81, 0x00216e81, 0x00216f81, 0x00217081,
+    0x00217182, 0x00217283, 0x00217382, 0x00217481, 0x00217582, 0x00217683,
+    0x00217784, 0x00217882, 0x00217981, 0x00217a82, 0x00217b83, 0x00217c81,
+    0x00217d81, 0x00217e81, 0x00217f81, 0x00218983, 0x00219a02, 0x00219b02,
+    0x0021ae02, 0x0021cd02, 0x0021ce02, 0x0021cf02, 0x00220402, 0x00220902,
+    0x00220c02, 0x00222402, 0x00222602, 0x00222c82, 0x00222d83, 0x00222f82,
+    0x00223083, 0x00224102, 0x00224402, 0x00224702, 0x00224902, 0x00226002,
+    0x00226202, 0x00226d02, 0x00226e02, 0x00226f02, 0x00227002, 0x00227102,
+    0x00227402, 0x00227502, 0x00227802, 0x00227902, 0x00228002, 0x00228102,
+    0x00228402, 0x00228502, 0x00228802, 0x00228902, 0x0022ac02, 0x0022ad02,
+    0x0022ae02, 0x0022af02, 0x0022e002, 0x0022e102, 0x0022e202, 0x0022e302,
+    0x0022ea02, 0x0022eb02, 0x0022ec02, 0x0022ed02, 0x00232901, 0x00232a01,
+    0x00246081, 0x00246181, 0x00246281, 0x00246381, 0x00246481, 0x00246581,
+    0x00246681, 0x00246781, 0x00246881, 0x00246982, 0x00246a82, 0x00246b82,
+    0x00246c82, 0x00246d82, 0x00246e82, 0x00246f82, 0x00247082, 0x00247182,
+    0x00247282, 0x00247382, 0x00247483, 0x00247583, 0x00247683, 0x00247783,
+    0x00247883, 0x00247983, 0x00247a83, 0x00247b83, 0x00247c83, 0x00247d84,
+    0x00247e84, 0x00247f84, 0x00248084, 0x00248184, 0x00248284, 0x00248384,
+    0x00248484, 0x00248584, 0x00248684, 0x00248784, 0x00248882, 0x00248982,
+    0x00248a82, 0x00248b82, 0x00248c82, 0x00248d82, 0x00248e82, 0x00248f82,
+    0x00249082, 0x00249183, 0x00249283, 0x00249383, 0x00249483, 0x00249583,
+    0x00249683, 0x00249783, 0x00249883, 0x00249983, 0x00249a83, 0x00249b83,
+    0x00249c83, 0x00249d83, 0x00249e83, 0x00249f83, 0x0024a083, 0x0024a183,
+    0x0024a283, 0x0024a383, 0x0024a483, 0x0024a583, 0x0024a683, 0x0024a783,
+    0x0024a883, 0x0024a983, 0x0024aa83, 0x0024ab83, 0x0024ac83, 0x0024ad83,
+    0x0024ae83, 0x0024af83, 0x0024b083, 0x0024b183, 0x0024b283, 0x0024b383,
+    0x0024b483, 0x0024b583, 0x0024b681, 0x0024b781, 0x0024b881, 0x0024b981,
+    0x0024ba81, 0x0024bb81, 0x0024bc81, 0x0024bd81, 0x0024be81, 0x0024bf81,
+    0x0024c081, 0x0024c181, 0x0024c281, 0x0024c381, 0x0024c481, 0x0024c581,
+    0x0024c681, 0x0024c781, 0x0024c881, 0x0024c981, 0x0024ca81, 0x0024cb81,
+    0x0024cc81, 0x0024cd81, 0x0024ce81, 0x0024cf81, 0x0024d081, 0x0024d181,
+    0x0024d281, 0x0024d381, 0x0024d481, 0x0024d581, 0x0024d681, 0x0024d781,
+    0x0024d881, 0x0024d981, 0x0024da81, 0x0024db81, 0x0024dc81, 0x0024dd81,
+    0x0024de81, 0x0024df81, 0x0024e081, 0x0024e181, 0x0024e281, 0x0024e381,
+    0x0024e481, 0x0024e581, 0x0024e681, 0x0024e781, 0x0024e881, 0x0024e981,
+    0x0024ea81, 0x002a0c84, 0x002a7483, 0x002a7582, 0x002a7683, 0x002adc02,
+    0x002c7c81, 0x002c7d81, 0x002d6f81, 0x002e9f81, 0x002ef381, 0x002f0081,
+    0x002f0181, 0x002f0281, 0x002f0381, 0x002f0481, 0x002f0581, 0x002f0681,
+    0x002f0781, 0x002f0881, 0x002f0981, 0x002f0a81, 0x002f0b81, 0x002f0c81,
+    0x002f0d81, 0x002f0e81, 0x002f0f81, 0x002f1081, 0x002f1181, 0x002f1281,
+    0x002f1381, 0x002f1481, 0x002f1581, 0x002f1681, 0x002f1781, 0x002f1881,
+    0x002f1981, 0x002f1a81, 0x002f1b81, 0x002f1c81, 0x002f1d81, 0x002f1e81,
+    0x002f1f81, 0x002f2081, 0x002f2181, 0x002f2281, 0x002f2381, 0x002f2481,
+    0x002f2581, 0x002f2681, 0x002f2781, 0x002f2881, 0x002f2981, 0x002f2a81,
+    0x002f2b81, 0x002f2c81, 0x002f2d81, 0x002f2e81, 0x002f2f81, 0x002f3081,
+    0x002f3181, 0x002f3281, 0x002f3381, 0x002f3481, 0x002f3581, 0x002f3681,
+    0x002f3781, 0x002f3881, 0x002f3981, 0x002f3a81, 0x002f3b81, 0x002f3c81,
+    0x002f3d81, 0x002f3e81, 0x002f3f81, 0x002f4081, 0x002f4181, 0x002f4281,
+    0x002f4381, 0x002f4481, 0x002f4581, 0x002f4681, 0x002f4781, 0x002f4881,
+    0x002f4981, 0x002f4a81, 0x002f4b81, 0x002f4c81, 0x002f4d81, 0x002f4e81,
+    0x002f4f81, 0x002f5081, 0x002f5181, 0x002f5281, 0x002f5381, 0x002f5481,
+    0x002f5581, 0x002f5681, 0x002f5781, 0x002f5881, 0x002f5981, 0x002f5a81,
+    0x002f5b81, 0x002f5c81, 0x002f5d81, 0x002f5e81, 0x002f5f81, 0x002f6081,
+    0x002f6181, 0x002f6281, 0x002f6381, 0x002f6481, 0x002f6581, 0x002f6681,
+    0x002f6781, 0x002f6881, 0x002f6981, 0x002f6a81, 0x002f6b81, 0x002f6c81,
+    0x002f6d81, 0x002f6e81, 0x002f6f81, 0x002f7081, 0x002f7181, 0x002f7281,
+    0x002f7381, 0x002f7481, 0x002f7581, 0x002f7681, 0x002f7781, 0x002f7881,
+    0x002f7981, 0x002f7a81, 0x002f7b81, 0x002f7c81, 0x002f7d81, 0x002f7e81,
+    0x002f7f81, 0x002f8081, 0x002f8181, 0x002f8281, 0x002f8381, 0x002f8481,
+    0x002f8581, 0x002f8681, 0x002f8781, 0x002f8881, 0x002f8981, 0x002f8a81,
+    0x002f8b81, 0x002f8c81, 0x002f8d81, 0x002f8e81, 0x002f8f81, 0x002f9081,
+    0x002f9181, 0x002f9281, 0x002f9381, 0x002f9481, 0x002f9581, 0x002f9681,
+    0x002f9781, 0x002f9881, 0x002f9981, 0x002f9a81, 0x002f9b81, 0x002f9c81,
+    0x002f9d81, 0x002f9e81, 0x002f9f81, 0x002fa081, 0x002fa181, 0x002fa281,
+    0x002fa381, 0x002fa481, 0x002fa581, 0x002fa681, 0x002fa781, 0x002fa881,
+    0x002fa981, 0x002faa81, 0x002fab81, 0x002fac81, 0x002fad81, 0x002fae81,
+    0x002faf81, 0x002fb081, 0x002fb181, 0x002fb281, 0x002fb381, 0x002fb481,
+    0x002fb581, 0x002fb681, 0x002fb781, 0x002fb881, 0x002fb981, 0x002fba81,
+    0x002fbb81, 0x002fbc81, 0x002fbd81, 0x002fbe81, 0x002fbf81, 0x002fc081,
+    0x002fc181, 0x002fc281, 0x002fc381, 0x002fc481, 0x002fc581, 0x002fc681,
+    0x002fc781, 0x002fc881, 0x002fc981, 0x002fca81, 0x002fcb81, 0x002fcc81,
+    0x002fcd81, 0x002fce81, 0x002fcf81, 0x002fd081, 0x002fd181, 0x002fd281,
+    0x002fd381, 0x002fd481, 0x002fd581, 0x00300081, 0x00303681, 0x00303881,
+    0x00303981, 0x00303a81, 0x00304c02, 0x00304e02, 0x00305002, 0x00305202,
+    0x00305402, 0x00305602, 0x00305802, 0x00305a02, 0x00305c02, 0x00305e02,
+    0x00306002, 0x00306202, 0x00306502, 0x00306702, 0x00306902, 0x00307002,
+    0x00307102, 0x00307302, 0x00307402, 0x00307602, 0x00307702, 0x00307902,
+    0x00307a02, 0x00307c02, 0x00307d02, 0x00309402, 0x00309b82, 0x00309c82,
+    0x00309e02, 0x00309f82, 0x0030ac02, 0x0030ae02, 0x0030b002, 0x0030b202,
+    0x0030b402, 0x0030b602, 0x0030b802, 0x0030ba02, 0x0030bc02, 0x0030be02,
+    0x0030c002, 0x0030c202, 0x0030c502, 0x0030c702, 0x0030c902, 0x0030d002,
+    0x0030d102, 0x0030d302, 0x0030d402, 0x0030d602, 0x0030d702, 0x0030d902,
+    0x0030da02, 0x0030dc02, 0x0030dd02, 0x0030f402, 0x0030f702, 0x0030f802,
+    0x0030f902, 0x0030fa02, 0x0030fe02, 0x0030ff82, 0x00313181, 0x00313281,
+    0x00313381, 0x00313481, 0x00313581, 0x00313681, 0x00313781, 0x00313881,
+    0x00313981, 0x00313a81, 0x00313b81, 0x00313c81, 0x00313d81, 0x00313e81,
+    0x00313f81, 0x00314081, 0x00314181, 0x00314281, 0x00314381, 0x00314481,
+    0x00314581, 0x00314681, 0x00314781, 0x00314881, 0x00314981, 0x00314a81,
+    0x00314b81, 0x00314c81, 0x00314d81, 0x00314e81, 0x00314f81, 0x00315081,
+    0x00315181, 0x00315281, 0x00315381, 0x00315481, 0x00315581, 0x00315681,
+    0x00315781, 0x00315881, 0x00315981, 0x00315a81, 0x00315b81, 0x00315c81,
+    0x00315d81, 0x00315e81, 0x00315f81, 0x00316081, 0x00316181, 0x00316281,
+    0x00316381, 0x00316481, 0x00316581, 0x00316681, 0x00316781, 0x00316881,
+    0x00316981, 0x00316a81, 0x00316b81, 0x00316c81, 0x00316d81, 0x00316e81,
+    0x00316f81, 0x00317081, 0x00317181, 0x00317281, 0x00317381, 0x00317481,
+    0x00317581, 0x00317681, 0x00317781, 0x00317881, 0x00317981, 0x00317a81,
+    0x00317b81, 0x00317c81, 0x00317d81, 0x00317e81, 0x00317f81, 0x00318081,
+    0x00318181, 0x00318281, 0x00318381, 0x00318481, 0x00318581, 0x00318681,
+    0x00318781, 0x00318881, 0x00318981, 0x00318a81, 0x00318b81, 0x00318c81,
+    0x00318d81, 0x00318e81, 0x00319281, 0x00319381, 0x00319481, 0x00319581,
+    0x00319681, 0x00319781, 0x00319881, 0x00319981, 0x00319a81, 0x00319b81,
+    0x00319c81, 0x00319d81, 0x00319e81, 0x00319f81, 0x00320083, 0x00320183,
+    0x00320283, 0x00320383, 0x00320483, 0x00320583, 0x00320683, 0x00320783,
+    0x00320883, 0x00320983, 0x00320a83, 0x00320b83, 0x00320c83, 0x00320d83,
+    0x00320e84, 0x00320f84, 0x00321084, 0x00321184, 0x00321284, 0x00321384,
+    0x00321484, 0x00321584, 0x00321684, 0x00321784, 0x00321884, 0x00321984,
+    0x00321a84, 0x00321b84, 0x00321c84, 0x00321d87, 0x00321e86, 0x00322083,
+    0x00322183, 0x00322283, 0x00322383, 0x00322483, 0x00322583, 0x00322683,
+    0x00322783, 0x00322883, 0x00322983, 0x00322a83, 0x00322b83, 0x00322c83,
+    0x00322d83, 0x00322e83, 0x00322f83, 0x00323083, 0x00323183, 0x00323283,
+    0x00323383, 0x00323483, 0x00323583, 0x00323683, 0x00323783, 0x00323883,
+    0x00323983, 0x00323a83, 0x00323b83, 0x00323c83, 0x00323d83, 0x00323e83,
+    0x00323f83, 0x00324083, 0x00324183, 0x00324283, 0x00324383, 0x00324481,
+    0x00324581, 0x00324681, 0x00324781, 0x00325083, 0x00325182, 0x00325282,
+    0x00325382, 0x00325482, 0x00325582, 0x00325682, 0x00325782, 0x00325882,
+    0x00325982, 0x00325a82, 0x00325b82, 0x00325c82, 0x00325d82, 0x00325e82,
+    0x00325f82, 0x00326081, 0x00326181, 0x00326281, 0x00326381, 0x00326481,
+    0x00326581, 0x00326681, 0x00326781, 0x00326881, 0x00326981, 0x00326a81,
+    0x00326b81, 0x00326c81, 0x00326d81, 0x00326e82, 0x00326f82, 0x00327082,
+    0x00327182, 0x00327282, 0x00327382, 0x00327482, 0x00327582, 0x00327682,
+    0x00327782, 0x00327882, 0x00327982, 0x00327a82, 0x00327b82, 0x00327c85,
+    0x00327d84, 0x00327e82, 0x00328081, 0x00328181, 0x00328281, 0x00328381,
+    0x00328481, 0x00328581, 0x00328681, 0x00328781, 0x00328881, 0x00328981,
+    0x00328a81, 0x00328b81, 0x00328c81, 0x00328d81, 0x00328e81, 0x00328f81,
+    0x00329081, 0x00329181, 0x00329281, 0x00329381, 0x00329481, 0x00329581,
+    0x00329681, 0x00329781, 0x00329881, 0x00329981, 0x00329a81, 0x00329b81,
+    0x00329c81, 0x00329d81, 0x00329e81, 0x00329f81, 0x0032a081, 0x0032a181,
+    0x0032a281, 0x0032a381, 0x0032a481, 0x0032a581, 0x0032a681, 0x0032a781,
+    0x0032a881, 0x0032a981, 0x0032aa81, 0x0032ab81, 0x0032ac81, 0x0032ad81,
+    0x0032ae81, 0x0032af81, 0x0032b081, 0x0032b182, 0x0032b282, 0x0032b382,
+    0x0032b482, 0x0032b582, 0x0032b682, 0x0032b782, 0x0032b882, 0x0032b982,
+    0x0032ba82, 0x0032bb82, 0x0032bc82, 0x0032bd82, 0x0032be82, 0x0032bf82,
+    0x0032c082, 0x0032c182, 0x0032c282, 0x0032c382, 0x0032c482, 0x0032c582,
+    0x0032c682, 0x0032c782, 0x0032c882, 0x0032c983, 0x0032ca83, 0x0032cb83,
+    0x0032cc82, 0x0032cd83, 0x0032ce82, 0x0032cf83, 0x0032d081, 0x0032d181,
+    0x0032d281, 0x0032d381, 0x0032d481, 0x0032d581, 0x0032d681, 0x0032d781,
+    0x0032d881, 0x0032d981, 0x0032da81, 0x0032db81, 0x0032dc81, 0x0032dd81,
+    0x0032de81, 0x0032df81, 0x0032e081, 0x0032e181, 0x0032e281, 0x0032e381,
+    0x0032e481, 0x0032e581, 0x0032e681, 0x0032e781, 0x0032e881, 0x0032e981,
+    0x0032ea81, 0x0032eb81, 0x0032ec81, 0x0032ed81, 0x0032ee81, 0x0032ef81,
+    0x0032f081, 0x0032f181, 0x0032f281, 0x0032f381, 0x0032f481, 0x0032f581,
+    0x0032f681, 0x0032f781, 0x0032f881, 0x0032f981, 0x0032fa81, 0x0032fb81,
+    0x0032fc81, 0x0032fd81, 0x0032fe81, 0x0032ff82, 0x00330084, 0x00330184,
+    0x00330284, 0x00330383, 0x00330484, 0x00330583, 0x00330683, 0x00330785,
+    0x00330884, 0x00330983, 0x00330a83, 0x00330b83, 0x00330c84, 0x00330d84,
+    0x00330e83, 0x00330f83, 0x00331082, 0x00331183, 0x00331284, 0x00331384,
+    0x00331482, 0x00331585, 0x00331686, 0x00331785, 0x00331883, 0x00331985,
+    0x00331a85, 0x00331b84, 0x00331c83, 0x00331d83, 0x00331e83, 0x00331f84,
+    0x00332085, 0x00332184, 0x00332283, 0x00332383, 0x00332483, 0x00332582,
+    0x00332682, 0x00332782, 0x00332882, 0x00332983, 0x00332a83, 0x00332b85,
+    0x00332c83, 0x00332d84, 0x00332e85, 0x00332f83, 0x00333082, 0x00333182,
+    0x00333285, 0x00333384, 0x00333485, 0x00333583, 0x00333685, 0x00333782,
+    0x00333883, 0x00333983, 0x00333a83, 0x00333b83, 0x00333c83, 0x00333d84,
+    0x00333e83, 0x00333f82, 0x00334083, 0x00334183, 0x00334283, 0x00334384,
+    0x00334483, 0x00334583, 0x00334683, 0x00334785, 0x00334884, 0x00334982,
+    0x00334a85, 0x00334b82, 0x00334c84, 0x00334d84, 0x00334e83, 0x00334f83,
+    0x00335083, 0x00335184, 0x00335282, 0x00335383, 0x00335484, 0x00335582,
+    0x00335685, 0x00335783, 0x00335882, 0x00335982, 0x00335a82, 0x00335b82,
+    0x00335c82, 0x00335d82, 0x00335e82, 0x00335f82, 0x00336082, 0x00336182,
+    0x00336283, 0x00336383, 0x00336483, 0x00336583, 0x00336683, 0x00336783,
+    0x00336883, 0x00336983, 0x00336a83, 0x00336b83, 0x00336c83, 0x00336d83,
+    0x00336e83, 0x00336f83, 0x00337083, 0x00337183, 0x00337282, 0x00337382,
+    0x00337483, 0x00337582, 0x00337682, 0x00337782, 0x00337883, 0x00337983,
+    0x00337a82, 0x00337b82, 0x00337c82, 0x00337d82, 0x00337e82, 0x00337f84,
+    0x00338082, 0x00338182, 0x00338282, 0x00338382, 0x00338482, 0x00338582,
+    0x00338682, 0x00338782, 0x00338883, 0x00338984, 0x00338a82, 0x00338b82,
+    0x00338c82, 0x00338d82, 0x00338e82, 0x00338f82, 0x00339082, 0x00339183,
+    0x00339283, 0x00339383, 0x00339483, 0x00339582, 0x00339682, 0x00339782,
+    0x00339882, 0x00339982, 0x00339a82, 0x00339b82, 0x00339c82, 0x00339d82,
+    0x00339e82, 0x00339f83, 0x0033a083, 0x0033a182, 0x0033a283, 0x0033a383,
+    0x0033a483, 0x0033a582, 0x0033a683, 0x0033a783, 0x0033a884, 0x0033a982,
+    0x0033aa83, 0x0033ab83, 0x0033ac83, 0x0033ad83, 0x0033ae85, 0x0033af86,
+    0x0033b082, 0x0033b182, 0x0033b282, 0x0033b382, 0x0033b482, 0x0033b582,
+    0x0033b682, 0x0033b782, 0x0033b882, 0x0033b982, 0x0033ba82, 0x0033bb82,
+    0x0033bc82, 0x0033bd82, 0x0033be82, 0x0033bf82, 0x0033c082, 0x0033c182,
+    0x0033c284, 0x0033c382, 0x0033c482, 0x0033c582, 0x0033c684, 0x0033c783,
+    0x0033c882, 0x0033c982, 0x0033ca82, 0x0033cb82, 0x0033cc82, 0x0033cd82,
+    0x0033ce82, 0x0033cf82, 0x0033d082, 0x0033d182, 0x0033d283, 0x0033d382,
+    0x0033d482, 0x0033d583, 0x0033d683, 0x0033d782, 0x0033d884, 0x0033d983,
+    0x0033da82, 0x0033db82, 0x0033dc82, 0x0033dd82, 0x0033de83, 0x0033df83,
+    0x0033e082, 0x0033e182, 0x0033e282, 0x0033e382, 0x0033e482, 0x0033e582,
+    0x0033e682, 0x0033e782, 0x0033e882, 0x0033e983, 0x0033ea83, 0x0033eb83,
+    0x0033ec83, 0x0033ed83, 0x0033ee83, 0x0033ef83, 0x0033f083, 0x0033f183,
+    0x0033f283, 0x0033f383, 0x0033f483, 0x0033f583, 0x0033f683, 0x0033f783,
+    0x0033f883, 0x0033f983, 0x0033fa83, 0x0033fb83, 0x0033fc83, 0x0033fd83,
+    0x0033fe83, 0x0033ff83, 0x00a69c81, 0x00a69d81, 0x00a77081, 0x00a7f281,
+    0x00a7f381, 0x00a7f481, 0x00a7f881, 0x00a7f981, 0x00ab5c81, 0x00ab5d81,
+    0x00ab5e81, 0x00ab5f81, 0x00ab6981, 0x00f90001, 0x00f90101, 0x00f90201,
+    0x00f90301, 0x00f90401, 0x00f90501, 0x00f90601, 0x00f90701, 0x00f90801,
+    0x00f90901, 0x00f90a01, 0x00f90b01, 0x00f90c01, 0x00f90d01, 0x00f90e01,
+    0x00f90f01, 0x00f91001, 0x00f91101, 0x00f91201, 0x00f91301, 0x00f91401,
+    0x00f91501, 0x00f91601, 0x00f91701, 0x00f91801, 0x00f91901, 0x00f91a01,
+    0x00f91b01, 0x00f91c01, 0x00f91d01, 0x00f91e01, 0x00f91f01, 0x00f92001,
+    0x00f92101, 0x00f92201, 0x00f92301, 0x00f92401, 0x00f92501, 0x00f92601,
+    0x00f92701, 0x00f92801, 0x00f92901, 0x00f92a01, 0x00f92b01, 0x00f92c01,
+    0x00f92d01, 0x00f92e01, 0x00f92f01, 0x00f93001, 0x00f93101, 0x00f93201,
+    0x00f93301, 0x00f93401, 0x00f93501, 0x00f93601, 0x00f93701, 0x00f93801,
+    0x00f93901, 0x00f93a01, 0x00f93b01, 0x00f93c01, 0x00f93d01, 0x00f93e01,
+    0x00f93f01, 0x00f94001, 0x00f94101, 0x00f94201, 0x00f94301, 0x00f94401,
+    0x00f94501, 0x00f94601, 0x00f94701, 0x00f94801, 0x00f94901, 0x00f94a01,
+    0x00f94b01, 0x00f94c01, 0x00f94d01, 0x00f94e01, 0x00f94f01, 0x00f95001,
+    0x00f95101, 0x00f95201, 0x00f95301, 0x00f95401, 0x00f95501, 0x00f95601,
+    0x00f95701, 0x00f95801, 0x00f95901, 0x00f95a01, 0x00f95b01, 0x00f95c01,
+    0x00f95d01, 0x00f95e01, 0x00f95f01, 0x00f96001, 0x00f96101, 0x00f96201,
+    0x00f96301, 0x00f96401, 0x00f96501, 0x00f96601, 0x00f96701, 0x00f96801,
+    0x00f96901, 0x00f96a01, 0x00f96b01, 0x00f96c01, 0x00f96d01, 0x00f96e01,
+    0x00f96f01, 0x00f97001, 0x00f97101, 0x00f97201, 0x00f97301, 0x00f97401,
+    0x00f97501, 0x00f97601, 0x00f97701, 0x00f97801, 0x00f97901, 0x00f97a01,
+    0x00f97b01, 0x00f97c01, 0x00f97d01, 0x00f97e01, 0x00f97f01, 0x00f98001,
+    0x00f98101, 0x00f98201, 0x00f98301, 0x00f98401, 0x00f98501, 0x00f98601,
+    0x00f98701, 0x00f98801, 0x00f98901, 0x00f98a01, 0x00f98b01, 0x00f98c01,
+    0x00f98d01, 0x00f98e01, 0x00f98f01, 0x00f99001, 0x00f99101, 0x00f99201,
+    0x00f99301, 0x00f99401, 0x00f99501, 0x00f99601, 0x00f99701, 0x00f99801,
+    0x00f99901, 0x00f99a01, 0x00f99b01, 0x00f99c01, 0x00f99d01, 0x00f99e01,
+    0x00f99f01, 0x00f9a001, 0x00f9a101, 0x00f9a201, 0x00f9a301, 0x00f9a401,
+    0x00f9a501, 0x00f9a601, 0x00f9a701, 0x00f9a801, 0x00f9a901, 0x00f9aa01,
+    0x00f9ab01, 0x00f9ac01, 0x00f9ad01, 0x00f9ae01, 0x00f9af01, 0x00f9b001,
+    0x00f9b101, 0x00f9b201, 0x00f9b301, 0x00f9b401, 0x00f9b501, 0x00f9b601,
+    0x00f9b701, 0x00f9b801, 0x00f9b901, 0x00f9ba01, 0x00f9bb01, 0x00f9bc01,
+    0x00f9bd01, 0x00f9be01, 0x00f9bf01, 0x00f9c001, 0x00f9c101, 0x00f9c201,
+    0x00f9c301, 0x00f9c401, 0x00f9c501, 0x00f9c601, 0x00f9c701, 0x00f9c801,
+    0x00f9c901, 0x00f9ca01, 0x00f9cb01, 0x00f9cc01, 0x00f9cd01, 0x00f9ce01,
+    0x00f9cf01, 0x00f9d001, 0x00f9d101, 0x00f9d201, 0x00f9d301, 0x00f9d401,
+    0x00f9d501, 0x00f9d601, 0x00f9d701, 0x00f9d801, 0x00f9d901, 0x00f9da01,
+    0x00f9db01, 0x00f9dc01, 0x00f9dd01, 0x00f9de01, 0x00f9df01, 0x00f9e001,
+    0x00f9e101, 0x00f9e201, 0x00f9e301, 0x00f9e401, 0x00f9e501, 0x00f9e601,
+    0x00f9e701, 0x00f9e801, 0x00f9e901, 0x00f9ea01, 0x00f9eb01, 0x00f9ec01,
+    0x00f9ed01, 0x00f9ee01, 0x00f9ef01, 0x00f9f001, 0x00f9f101, 0x00f9f201,
+    0x00f9f301, 0x00f9f401, 0x00f9f501, 0x00f9f601, 0x00f9f701, 0x00f9f801,
+    0x00f9f901, 0x00f9fa01, 0x00f9fb01, 0x00f9fc01, 0x00f9fd01, 0x00f9fe01,
+    0x00f9ff01, 0x00fa0001, 0x00fa0101, 0x00fa0201, 0x00fa0301, 0x00fa0401,
+    0x00fa0501, 0x00fa0601, 0x00fa0701, 0x00fa0801, 0x00fa0901, 0x00fa0a01,
+    0x00fa0b01, 0x00fa0c01, 0x00fa0d01, 0x00fa1001, 0x00fa1201, 0x00fa1501,
+    0x00fa1601, 0x00fa1701, 0x00fa1801, 0x00fa1901, 0x00fa1a01, 0x00fa1b01,
+    0x00fa1c01, 0x00fa1d01, 0x00fa1e01, 0x00fa2001, 0x00fa2201, 0x00fa2501,
+    0x00fa2601, 0x00fa2a01, 0x00fa2b01, 0x00fa2c01, 0x00fa2d01, 0x00fa2e01,
+    0x00fa2f01, 0x00fa3001, 0x00fa3101, 0x00fa3201, 0x00fa3301, 0x00fa3401,
+    0x00fa3501, 0x00fa3601, 0x00fa3701, 0x00fa3801, 0x00fa3901, 0x00fa3a01,
+    0x00fa3b01, 0x00fa3c01, 0x00fa3d01, 0x00fa3e01, 0x00fa3f01, 0x00fa4001,
+    0x00fa4101, 0x00fa4201, 0x00fa4301, 0x00fa4401, 0x00fa4501, 0x00fa4601,
+    0x00fa4701, 0x00fa4801, 0x00fa4901, 0x00fa4a01, 0x00fa4b01, 0x00fa4c01,
+    0x00fa4d01, 0x00fa4e01, 0x00fa4f01, 0x00fa5001, 0x00fa5101, 0x00fa5201,
+    0x00fa5301, 0x00fa5401, 0x00fa5501, 0x00fa5601, 0x00fa5701, 0x00fa5801,
+    0x00fa5901, 0x00fa5a01, 0x00fa5b01, 0x00fa5c01, 0x00fa5d01, 0x00fa5e01,
+    0x00fa5f01, 0x00fa6001, 0x00fa6101, 0x00fa6201, 0x00fa6301, 0x00fa6401,
+    0x00fa6501, 0x00fa6601, 0x00fa6701, 0x00fa6801, 0x00fa6901, 0x00fa6a01,
+    0x00fa6b01, 0x00fa6c01, 0x00fa6d01, 0x00fa7001, 0x00fa7101, 0x00fa7201,
+    0x00fa7301, 0x00fa7401, 0x00fa7501, 0x00fa7601, 0x00fa7701, 0x00fa7801,
+    0x00fa7901, 0x00fa7a01, 0x00fa7b01, 0x00fa7c01, 0x00fa7d01, 0x00fa7e01,
+    0x00fa7f01, 0x00fa8001, 0x00fa8101, 0x00fa8201, 0x00fa8301, 0x00fa8401,
+    0x00fa8501, 0x00fa8601, 0x00fa8701, 0x00fa8801, 0x00fa8901, 0x00fa8a01,
+    0x00fa8b01, 0x00fa8c01, 0x00fa8d01, 0x00fa8e01, 0x00fa8f01, 0x00fa9001,
+    0x00fa9101, 0x00fa9201, 0x00fa9301, 0x00fa9401, 0x00fa9501, 0x00fa9601,
+    0x00fa9701, 0x00fa9801, 0x00fa9901, 0x00fa9a01, 0x00fa9b01, 0x00fa9c01,
+    0x00fa9d01, 0x00fa9e01, 0x00fa9f01, 0x00faa001, 0x00faa101, 0x00faa201,
+    0x00faa301, 0x00faa401, 0x00faa501, 0x00faa601, 0x00faa701, 0x00faa801,
+    0x00faa901, 0x00faaa01, 0x00faab01, 0x00faac01, 0x00faad01, 0x00faae01,
+    0x00faaf01, 0x00fab001, 0x00fab101, 0x00fab201, 0x00fab301, 0x00fab401,
+    0x00fab501, 0x00fab601, 0x00fab701, 0x00fab801, 0x00fab901, 0x00faba01,
+    0x00fabb01, 0x00fabc01, 0x00fabd01, 0x00fabe01, 0x00fabf01, 0x00fac001,
+    0x00fac101, 0x00fac201, 0x00fac301, 0x00fac401, 0x00fac501, 0x00fac601,
+    0x00fac701, 0x00fac801, 0x00fac901, 0x00faca01, 0x00facb01, 0x00facc01,
+    0x00facd01, 0x00face01, 0x00facf01, 0x00fad001, 0x00fad101, 0x00fad201,
+    0x00fad301, 0x00fad401, 0x00fad501, 0x00fad601, 0x00fad701, 0x00fad801,
+    0x00fad901, 0x00fb0082, 0x00fb0182, 0x00fb0282, 0x00fb0383, 0x00fb0483,
+    0x00fb0582, 0x00fb0682, 0x00fb1382, 0x00fb1482, 0x00fb1582, 0x00fb1682,
+    0x00fb1782, 0x00fb1d02, 0x00fb1f02, 0x00fb2081, 0x00fb2181, 0x00fb2281,
+    0x00fb2381, 0x00fb2481, 0x00fb2581, 0x00fb2681, 0x00fb2781, 0x00fb2881,
+    0x00fb2981, 0x00fb2a02, 0x00fb2b02, 0x00fb2c02, 0x00fb2d02, 0x00fb2e02,
+    0x00fb2f02, 0x00fb3002, 0x00fb3102, 0x00fb3202, 0x00fb3302, 0x00fb3402,
+    0x00fb3502, 0x00fb3602, 0x00fb3802, 0x00fb3902, 0x00fb3a02, 0x00fb3b02,
+    0x00fb3c02, 0x00fb3e02, 0x00fb4002, 0x00fb4102, 0x00fb4302, 0x00fb4402,
+    0x00fb4602, 0x00fb4702, 0x00fb4802, 0x00fb4902, 0x00fb4a02, 0x00fb4b02,
+    0x00fb4c02, 0x00fb4d02, 0x00fb4e02, 0x00fb4f82, 0x00fb5081, 0x00fb5181,
+    0x00fb5281, 0x00fb5381, 0x00fb5481, 0x00fb5581, 0x00fb5681, 0x00fb5781,
+    0x00fb5881, 0x00fb5981, 0x00fb5a81, 0x00fb5b81, 0x00fb5c81, 0x00fb5d81,
+    0x00fb5e81, 0x00fb5f81, 0x00fb6081, 0x00fb6181, 0x00fb6281, 0x00fb6381,
+    0x00fb6481, 0x00fb6581, 0x00fb6681, 0x00fb6781, 0x00fb6881, 0x00fb6981,
+    0x00fb6a81, 0x00fb6b81, 0x00fb6c81, 0x00fb6d81, 0x00fb6e81, 0x00fb6f81,
+    0x00fb7081, 0x00fb7181, 0x00fb7281, 0x00fb7381, 0x00fb7481, 0x00fb7581,
+    0x00fb7681, 0x00fb7781, 0x00fb7881, 0x00fb7981, 0x00fb7a81, 0x00fb7b81,
+    0x00fb7c81, 0x00fb7d81, 0x00fb7e81, 0x00fb7f81, 0x00fb8081, 0x00fb8181,
+    0x00fb8281, 0x00fb8381, 0x00fb8481, 0x00fb8581, 0x00fb8681, 0x00fb8781,
+    0x00fb8881, 0x00fb8981, 0x00fb8a81, 0x00fb8b81, 0x00fb8c81, 0x00fb8d81,
+    0x00fb8e81, 0x00fb8f81, 0x00fb9081, 0x00fb9181, 0x00fb9281, 0x00fb9381,
+    0x00fb9481, 0x00fb9581, 0x00fb9681, 0x00fb9781, 0x00fb9881, 0x00fb9981,
+    0x00fb9a81, 0x00fb9b81, 0x00fb9c81, 0x00fb9d81, 0x00fb9e81, 0x00fb9f81,
+    0x00fba081, 0x00fba181, 0x00fba281, 0x00fba381, 0x00fba481, 0x00fba581,
+    0x00fba681, 0x00fba781, 0x00fba881, 0x00fba981, 0x00fbaa81, 0x00fbab81,
+    0x00fbac81, 0x00fbad81, 0x00fbae81, 0x00fbaf81, 0x00fbb081, 0x00fbb181,
+    0x00fbd381, 0x00fbd481, 0x00fbd581, 0x00fbd681, 0x00fbd781, 0x00fbd881,
+    0x00fbd981, 0x00fbda81, 0x00fbdb81, 0x00fbdc81, 0x00fbdd81, 0x00fbde81,
+    0x00fbdf81, 0x00fbe081, 0x00fbe181, 0x00fbe281, 0x00fbe381, 0x00fbe481,
+    0x00fbe581, 0x00fbe681, 0x00fbe781, 0x00fbe881, 0x00fbe981, 0x00fbea82,
+    0x00fbeb82, 0x00fbec82, 0x00fbed82, 0x00fbee82, 0x00fbef82, 0x00fbf082,
+    0x00fbf182, 0x00fbf282, 0x00fbf382, 0x00fbf482, 0x00fbf582, 0x00fbf682,
+    0x00fbf782, 0x00fbf882, 0x00fbf982, 0x00fbfa82, 0x00fbfb82, 0x00fbfc81,
+    0x00fbfd81, 0x00fbfe81, 0x00fbff81, 0x00fc0082, 0x00fc0182, 0x00fc0282,
+    0x00fc0382, 0x00fc0482, 0x00fc0582, 0x00fc0682, 0x00fc0782, 0x00fc0882,
+    0x00fc0982, 0x00fc0a82, 0x00fc0b82, 0x00fc0c82, 0x00fc0d82, 0x00fc0e82,
+    0x00fc0f82, 0x00fc1082, 0x00fc1182, 0x00fc1282, 0x00fc1382, 0x00fc1482,
+    0x00fc1582, 0x00fc1682, 0x00fc1782, 0x00fc1882, 0x00fc1982, 0x00fc1a82,
+    0x00fc1b82, 0x00fc1c82, 0x00fc1d82, 0x00fc1e82, 0x00fc1f82, 0x00fc2082,
+    0x00fc2182, 0x00fc2282, 0x00fc2382, 0x00fc2482, 0x00fc2582, 0x00fc2682,
+    0x00fc2782, 0x00fc2882, 0x00fc2982, 0x00fc2a82, 0x00fc2b82, 0x00fc2c82,
+    0x00fc2d82, 0x00fc2e82, 0x00fc2f82, 0x00fc3082, 0x00fc3182, 0x00fc3282,
+    0x00fc3382, 0x00fc3482, 0x00fc3582, 0x00fc3682, 0x00fc3782, 0x00fc3882,
+    0x00fc3982, 0x00fc3a82, 0x00fc3b82, 0x00fc3c82, 0x00fc3d82, 0x00fc3e82,
+    0x00fc3f82, 0x00fc4082, 0x00fc4182, 0x00fc4282, 0x00fc4382, 0x00fc4482,
+    0x00fc4582, 0x00fc4682, 0x00fc4782, 0x00fc4882, 0x00fc4982, 0x00fc4a82,
+    0x00fc4b82, 0x00fc4c82, 0x00fc4d82, 0x00fc4e82, 0x00fc4f82, 0x00fc5082,
+    0x00fc5182, 0x00fc5282, 0x00fc5382, 0x00fc5482, 0x00fc5582, 0x00fc5682,
+    0x00fc5782, 0x00fc5882, 0x00fc5982, 0x00fc5a82, 0x00fc5b82, 0x00fc5c82,
+    0x00fc5d82, 0x00fc5e83, 0x00fc5f83, 0x00fc6083, 0x00fc6183, 0x00fc6283,
+    0x00fc6383, 0x00fc6482, 0x00fc6582, 0x00fc6682, 0x00fc6782, 0x00fc6882,
+    0x00fc6982, 0x00fc6a82, 0x00fc6b82, 0x00fc6c82, 0x00fc6d82, 0x00fc6e82,
+    0x00fc6f82, 0x00fc7082, 0x00fc7182, 0x00fc7282, 0x00fc7382, 0x00fc7482,
+    0x00fc7582, 0x00fc7682, 0x00fc7782, 0x00fc7882, 0x00fc7982, 0x00fc7a82,
+    0x00fc7b82, 0x00fc7c82, 0x00fc7d82, 0x00fc7e82, 0x00fc7f82, 0x00fc8082,
+    0x00fc8182, 0x00fc8282, 0x00fc8382, 0x00fc8482, 0x00fc8582, 0x00fc8682,
+    0x00fc8782, 0x00fc8882, 0x00fc8982, 0x00fc8a82, 0x00fc8b82, 0x00fc8c82,
+    0x00fc8d82, 0x00fc8e82, 0x00fc8f82, 0x00fc9082, 0x00fc9182, 0x00fc9282,
+    0x00fc9382, 0x00fc9482, 0x00fc9582, 0x00fc9682, 0x00fc9782, 0x00fc9882,
+    0x00fc9982, 0x00fc9a82, 0x00fc9b82, 0x00fc9c82, 0x00fc9d82, 0x00fc9e82,
+    0x00fc9f82, 0x00fca082, 0x00fca182, 0x00fca282, 0x00fca382, 0x00fca482,
+    0x00fca582, 0x00fca682, 0x00fca782, 0x00fca882, 0x00fca982, 0x00fcaa82,
+    0x00fcab82, 0x00fcac82, 0x00fcad82, 0x00fcae82, 0x00fcaf82, 0x00fcb082,
+    0x00fcb182, 0x00fcb282, 0x00fcb382, 0x00fcb482, 0x00fcb582, 0x00fcb682,
+    0x00fcb782, 0x00fcb882, 0x00fcb982, 0x00fcba82, 0x00fcbb82, 0x00fcbc82,
+    0x00fcbd82, 0x00fcbe82, 0x00fcbf82, 0x00fcc082, 0x00fcc182, 0x00fcc282,
+    0x00fcc382, 0x00fcc482, 0x00fcc582, 0x00fcc682, 0x00fcc782, 0x00fcc882,
+    0x00fcc982, 0x00fcca82, 0x00fccb82, 0x00fccc82, 0x00fccd82, 0x00fcce82,
+    0x00fccf82, 0x00fcd082, 0x00fcd182, 0x00fcd282, 0x00fcd382, 0x00fcd482,
+    0x00fcd582, 0x00fcd682, 0x00fcd782, 0x00fcd882, 0x00fcd982, 0x00fcda82,
+    0x00fcdb82, 0x00fcdc82, 0x00fcdd82, 0x00fcde82, 0x00fcdf82, 0x00fce082,
+    0x00fce182, 0x00fce282, 0x00fce382, 0x00fce482, 0x00fce582, 0x00fce682,
+    0x00fce782, 0x00fce882, 0x00fce982, 0x00fcea82, 0x00fceb82, 0x00fcec82,
+    0x00fced82, 0x00fcee82, 0x00fcef82, 0x00fcf082, 0x00fcf182, 0x00fcf283,
+    0x00fcf383, 0x00fcf483, 0x00fcf582, 0x00fcf682, 0x00fcf782, 0x00fcf882,
+    0x00fcf982, 0x00fcfa82, 0x00fcfb82, 0x00fcfc82, 0x00fcfd82, 0x00fcfe82,
+    0x00fcff82, 0x00fd0082, 0x00fd0182, 0x00fd0282, 0x00fd0382, 0x00fd0482,
+    0x00fd0582, 0x00fd0682, 0x00fd0782, 0x00fd0882, 0x00fd0982, 0x00fd0a82,
+    0x00fd0b82, 0x00fd0c82, 0x00fd0d82, 0x00fd0e82, 0x00fd0f82, 0x00fd1082,
+    0x00fd1182, 0x00fd1282, 0x00fd1382, 0x00fd1482, 0x00fd1582, 0x00fd1682,
+    0x00fd1782, 0x00fd1882, 0x00fd1982, 0x00fd1a82, 0x00fd1b82, 0x00fd1c82,
+    0x00fd1d82, 0x00fd1e82, 0x00fd1f82, 0x00fd2082, 0x00fd2182, 0x00fd2282,
+    0x00fd2382, 0x00fd2482, 0x00fd2582, 0x00fd2682, 0x00fd2782, 0x00fd2882,
+    0x00fd2982, 0x00fd2a82, 0x00fd2b82, 0x00fd2c82, 0x00fd2d82, 0x00fd2e82,
+    0x00fd2f82, 0x00fd3082, 0x00fd3182, 0x00fd3282, 0x00fd3382, 0x00fd3482,
+    0x00fd3582, 0x00fd3682, 0x00fd3782, 0x00fd3882, 0x00fd3982, 0x00fd3a82,
+    0x00fd3b82, 0x00fd3c82, 0x00fd3d82, 0x00fd5083, 0x00fd5183, 0x00fd5283,
+    0x00fd5383, 0x00fd5483, 0x00fd5583, 0x00fd5683, 0x00fd5783, 0x00fd5883,
+    0x00fd5983, 0x00fd5a83, 0x00fd5b83, 0x00fd5c83, 0x00fd5d83, 0x00fd5e83,
+    0x00fd5f83, 0x00fd6083, 0x00fd6183, 0x00fd6283, 0x00fd6383, 0x00fd6483,
+    0x00fd6583, 0x00fd6683, 0x00fd6783, 0x00fd6883, 0x00fd6983, 0x00fd6a83,
+    0x00fd6b83, 0x00fd6c83, 0x00fd6d83, 0x00fd6e83, 0x00fd6f83, 0x00fd7083,
+    0x00fd7183, 0x00fd7283, 0x00fd7383, 0x00fd7483, 0x00fd7583, 0x00fd7683,
+    0x00fd7783, 0x00fd7883, 0x00fd7983, 0x00fd7a83, 0x00fd7b83, 0x00fd7c83,
+    0x00fd7d83, 0x00fd7e83, 0x00fd7f83, 0x00fd8083, 0x00fd8183, 0x00fd8283,
+    0x00fd8383, 0x00fd8483, 0x00fd8583, 0x00fd8683, 0x00fd8783, 0x00fd8883,
+    0x00fd8983, 0x00fd8a83, 0x00fd8b83, 0x00fd8c83, 0x00fd8d83, 0x00fd8e83,
+    0x00fd8f83, 0x00fd9283, 0x00fd9383, 0x00fd9483, 0x00fd9583, 0x00fd9683,
+    0x00fd9783, 0x00fd9883, 0x00fd9983, 0x00fd9a83, 0x00fd9b83, 0x00fd9c83,
+    0x00fd9d83, 0x00fd9e83, 0x00fd9f83, 0x00fda083, 0x00fda183, 0x00fda283,
+    0x00fda383, 0x00fda483, 0x00fda583, 0x00fda683, 0x00fda783, 0x00fda883,
+    0x00fda983, 0x00fdaa83, 0x00fdab83, 0x00fdac83, 0x00fdad83, 0x00fdae83,
+    0x00fdaf83, 0x00fdb083, 0x00fdb183, 0x00fdb283, 0x00fdb383, 0x00fdb483,
+    0x00fdb583, 0x00fdb683, 0x00fdb783, 0x00fdb883, 0x00fdb983, 0x00fdba83,
+    0x00fdbb83, 0x00fdbc83, 0x00fdbd83, 0x00fdbe83, 0x00fdbf83, 0x00fdc083,
+    0x00fdc183, 0x00fdc283, 0x00fdc383, 0x00fdc483, 0x00fdc583, 0x00fdc683,
+    0x00fdc783, 0x00fdf083, 0x00fdf183, 0x00fdf284, 0x00fdf384, 0x00fdf484,
+    0x00fdf584, 0x00fdf684, 0x00fdf784, 0x00fdf884, 0x00fdf983, 0x00fdfa92,
+    0x00fdfb88, 0x00fdfc84, 0x00fe1081, 0x00fe1181, 0x00fe1281, 0x00fe1381,
+    0x00fe1481, 0x00fe1581, 0x00fe1681, 0x00fe1781, 0x00fe1881, 0x00fe1981,
+    0x00fe3081, 0x00fe3181, 0x00fe3281, 0x00fe3381, 0x00fe3481, 0x00fe3581,
+    0x00fe3681, 0x00fe3781, 0x00fe3881, 0x00fe3981, 0x00fe3a81, 0x00fe3b81,
+    0x00fe3c81, 0x00fe3d81, 0x00fe3e81, 0x00fe3f81, 0x00fe4081, 0x00fe4181,
+    0x00fe4281, 0x00fe4381, 0x00fe4481, 0x00fe4781, 0x00fe4881, 0x00fe4981,
+    0x00fe4a81, 0x00fe4b81, 0x00fe4c81, 0x00fe4d81, 0x00fe4e81, 0x00fe4f81,
+    0x00fe5081, 0x00fe5181, 0x00fe5281, 0x00fe5481, 0x00fe5581, 0x00fe5681,
+    0x00fe5781, 0x00fe5881, 0x00fe5981, 0x00fe5a81, 0x00fe5b81, 0x00fe5c81,
+    0x00fe5d81, 0x00fe5e81, 0x00fe5f81, 0x00fe6081, 0x00fe6181, 0x00fe6281,
+    0x00fe6381, 0x00fe6481, 0x00fe6581, 0x00fe6681, 0x00fe6881, 0x00fe6981,
+    0x00fe6a81, 0x00fe6b81, 0x00fe7082, 0x00fe7182, 0x00fe7282, 0x00fe7482,
+    0x00fe7682, 0x00fe7782, 0x00fe7882, 0x00fe7982, 0x00fe7a82, 0x00fe7b82,
+    0x00fe7c82, 0x00fe7d82, 0x00fe7e82, 0x00fe7f82, 0x00fe8081, 0x00fe8181,
+    0x00fe8281, 0x00fe8381, 0x00fe8481, 0x00fe8581, 0x00fe8681, 0x00fe8781,
+    0x00fe8881, 0x00fe8981, 0x00fe8a81, 0x00fe8b81, 0x00fe8c81, 0x00fe8d81,
+    0x00fe8e81, 0x00fe8f81, 0x00fe9081, 0x00fe9181, 0x00fe9281, 0x00fe9381,
+    0x00fe9481, 0x00fe9581, 0x00fe9681, 0x00fe9781, 0x00fe9881, 0x00fe9981,
+    0x00fe9a81, 0x00fe9b81, 0x00fe9c81, 0x00fe9d81, 0x00fe9e81, 0x00fe9f81,
+    0x00fea081, 0x00fea181, 0x00fea281, 0x00fea381, 0x00fea481, 0x00fea581,
+    0x00fea681, 0x00fea781, 0x00fea881, 0x00fea981, 0x00feaa81, 0x00feab81,
+    0x00feac81, 0x00fead81, 0x00feae81, 0x00feaf81, 0x00feb081, 0x00feb181,
+    0x00feb281, 0x00feb381, 0x00feb481, 0x00feb581, 0x00feb681, 0x00feb781,
+    0x00feb881, 0x00feb981, 0x00feba81, 0x00febb81, 0x00febc81, 0x00febd81,
+    0x00febe81, 0x00febf81, 0x00fec081, 0x00fec181, 0x00fec281, 0x00fec381,
+    0x00fec481, 0x00fec581, 0x00fec681, 0x00fec781, 0x00fec881, 0x00fec981,
+    0x00feca81, 0x00fecb81, 0x00fecc81, 0x00fecd81, 0x00fece81, 0x00fecf81,
+    0x00fed081, 0x00fed181, 0x00fed281, 0x00fed381, 0x00fed481, 0x00fed581,
+    0x00fed681, 0x00fed781, 0x00fed881, 0x00fed981, 0x00feda81, 0x00fedb81,
+    0x00fedc81, 0x00fedd81, 0x00fede81, 0x00fedf81, 0x00fee081, 0x00fee181,
+    0x00fee281, 0x00fee381, 0x00fee481, 0x00fee581, 0x00fee681, 0x00fee781,
+    0x00fee881, 0x00fee981, 0x00feea81, 0x00feeb81, 0x00feec81, 0x00feed81,
+    0x00feee81, 0x00feef81, 0x00fef081, 0x00fef181, 0x00fef281, 0x00fef381,
+    0x00fef481, 0x00fef582, 0x00fef682, 0x00fef782, 0x00fef882, 0x00fef982,
+    0x00fefa82, 0x00fefb82, 0x00fefc82, 0x00ff0181, 0x00ff0281, 0x00ff0381,
+    0x00ff0481, 0x00ff0581, 0x00ff0681, 0x00ff0781, 0x00ff0881, 0x00ff0981,
+    0x00ff0a81, 0x00ff0b81, 0x00ff0c81, 0x00ff0d81, 0x00ff0e81, 0x00ff0f81,
+    0x00ff1081, 0x00ff1181, 0x00ff1281, 0x00ff1381, 0x00ff1481, 0x00ff1581,
+    0x00ff1681, 0x00ff1781, 0x00ff1881, 0x00ff1981, 0x00ff1a81, 0x00ff1b81,
+    0x00ff1c81, 0x00ff1d81, 0x00ff1e81, 0x00ff1f81, 0x00ff2081, 0x00ff2181,
+    0x00ff2281, 0x00ff2381, 0x00ff2481, 0x00ff2581, 0x00ff2681, 0x00ff2781,
+    0x00ff2881, 0x00ff2981, 0x00ff2a81, 0x00ff2b81, 0x00ff2c81, 0x00ff2d81,
+    0x00ff2e81, 0x00ff2f81, 0x00ff3081, 0x00ff3181, 0x00ff3281, 0x00ff3381,
+    0x00ff3481, 0x00ff3581, 0x00ff3681, 0x00ff3781, 0x00ff3881, 0x00ff3981,
+    0x00ff3a81, 0x00ff3b81, 0x00ff3c81, 0x00ff3d81, 0x00ff3e81, 0x00ff3f81,
+    0x00ff4081, 0x00ff4181, 0x00ff4281, 0x00ff4381, 0x00ff4481, 0x00ff4581,
+    0x00ff4681, 0x00ff4781, 0x00ff4881, 0x00ff4981, 0x00ff4a81, 0x00ff4b81,
+    0x00ff4c81, 0x00ff4d81, 0x00ff4e81, 0x00ff4f81, 0x00ff5081, 0x00ff5181,
+    0x00ff5281, 0x00ff5381, 0x00ff5481, 0x00ff5581, 0x00ff5681, 0x00ff5781,
+    0x00ff5881, 0x00ff5981, 0x00ff5a81, 0x00ff5b81, 0x00ff5c81, 0x00ff5d81,
+    0x00ff5e81, 0x00ff5f81, 0x00ff6081, 0x00ff6181, 0x00ff6281, 0x00ff6381,
+    0x00ff6481, 0x00ff6581, 0x00ff6681, 0x00ff6781, 0x00ff6881, 0x00ff6981,
+    0x00ff6a81, 0x00ff6b81, 0x00ff6c81, 0x00ff6d81, 0x00ff6e81, 0x00ff6f81,
+    0x00ff7081, 0x00ff7181, 0x00ff7281, 0x00ff7381, 0x00ff7481, 0x00ff7581,
+    0x00ff7681, 0x00ff7781, 0x00ff7881, 0x00ff7981, 0x00ff7a81, 0x00ff7b81,
+    0x00ff7c81, 0x00ff7d81, 0x00ff7e81, 0x00ff7f81, 0x00ff8081, 0x00ff8181,
+    0x00ff8281, 0x00ff8381, 0x00ff8481, 0x00ff8581, 0x00ff8681, 0x00ff8781,
+    0x00ff8881, 0x00ff8981, 0x00ff8a81, 0x00ff8b81, 0x00ff8c81, 0x00ff8d81,
+    0x00ff8e81, 0x00ff8f81, 0x00ff9081, 0x00ff9181, 0x00ff9281, 0x00ff9381,
+    0x00ff9481, 0x00ff9581, 0x00ff9681, 0x00ff9781, 0x00ff9881, 0x00ff9981,
+    0x00ff9a81, 0x00ff9b81, 0x00ff9c81, 0x00ff9d81, 0x00ff9e81, 0x00ff9f81,
+    0x00ffa081, 0x00ffa181, 0x00ffa281, 0x00ffa381, 0x00ffa481, 0x00ffa581,
+    0x00ffa681, 0x00ffa781, 0x00ffa881, 0x00ffa981, 0x00ffaa81, 0x00ffab81,
+    0x00ffac81, 0x00ffad81, 0x00ffae81, 0x00ffaf81, 0x00ffb081, 0x00ffb181,
+    0x00ffb281, 0x00ffb381, 0x00ffb481, 0x00ffb581, 0x00ffb681, 0x00ffb781,
+    0x00ffb881, 0x00ffb981, 0x00ffba81, 0x00ffbb81, 0x00ffbc81, 0x00ffbd81,
+    0x00ffbe81, 0x00ffc281, 0x00ffc381, 0x00ffc481, 0x00ffc581, 0x00ffc681,
+    0x00ffc781, 0x00ffca81, 0x00ffcb81, 0x00ffcc81, 0x00ffcd81, 0x00ffce81,
+    0x00ffcf81, 0x00ffd281, 0x00ffd381, 0x00ffd481, 0x00ffd581, 0x00ffd681,
+    0x00ffd781, 0x00ffda81, 0x00ffdb81, 0x00ffdc81, 0x00ffe081, 0x00ffe181,
+    0x00ffe281, 0x00ffe381, 0x00ffe481, 0x00ffe581, 0x00ffe681, 0x00ffe881,
+    0x00ffe981, 0x00ffea81, 0x00ffeb81, 0x00ffec81, 0x00ffed81, 0x00ffee81,
+    0x01078181, 0x01078281, 0x01078381, 0x01078481, 0x01078581, 0x01078781,
+    0x01078881, 0x01078981, 0x01078a81, 0x01078b81, 0x01078c81, 0x01078d81,
+    0x01078e81, 0x01078f81, 0x01079081, 0x01079181, 0x01079281, 0x01079381,
+    0x01079481, 0x01079581, 0x01079681, 0x01079781, 0x01079881, 0x01079981,
+    0x01079a81, 0x01079b81, 0x01079c81, 0x01079d81, 0x01079e81, 0x01079f81,
+    0x0107a081, 0x0107a181, 0x0107a281, 0x0107a381, 0x0107a481, 0x0107a581,
+    0x0107a681, 0x0107a781, 0x0107a881, 0x0107a981, 0x0107aa81, 0x0107ab81,
+    0x0107ac81, 0x0107ad81, 0x0107ae81, 0x0107af81, 0x0107b081, 0x0107b281,
+    0x0107b381, 0x0107b481, 0x0107b581, 0x0107b681, 0x0107b781, 0x0107b881,
+    0x0107b981, 0x0107ba81, 0x01109a02, 0x01109c02, 0x0110ab02, 0x01112e02,
+    0x01112f02, 0x01134b02, 0x01134c02, 0x0114bb02, 0x0114bc02, 0x0114be02,
+    0x0115ba02, 0x0115bb02, 0x01193802, 0x01d15e02, 0x01d15f02, 0x01d16002,
+    0x01d16102, 0x01d16202, 0x01d16302, 0x01d16402, 0x01d1bb02, 0x01d1bc02,
+    0x01d1bd02, 0x01d1be02, 0x01d1bf02, 0x01d1c002, 0x01d40081, 0x01d40181,
+    0x01d40281, 0x01d40381, 0x01d40481, 0x01d40581, 0x01d40681, 0x01d40781,
+    0x01d40881, 0x01d40981, 0x01d40a81, 0x01d40b81, 0x01d40c81, 0x01d40d81,
+    0x01d40e81, 0x01d40f81, 0x01d41081, 0x01d41181, 0x01d41281, 0x01d41381,
+    0x01d41481, 0x01d41581, 0x01d41681, 0x01d41781, 0x01d41881, 0x01d41981,
+    0x01d41a81, 0x01d41b81, 0x01d41c81, 0x01d41d81, 0x01d41e81, 0x01d41f81,
+    0x01d42081, 0x01d42181, 0x01d42281, 0x01d42381, 0x01d42481, 0x01d42581,
+    0x01d42681, 0x01d42781, 0x01d42881, 0x01d42981, 0x01d42a81, 0x01d42b81,
+    0x01d42c81, 0x01d42d81, 0x01d42e81, 0x01d42f81, 0x01d43081, 0x01d43181,
+    0x01d43281, 0x01d43381, 0x01d43481, 0x01d43581, 0x01d43681, 0x01d43781,
+    0x01d43881, 0x01d43981, 0x01d43a81, 0x01d43b81, 0x01d43c81, 0x01d43d81,
+    0x01d43e81, 0x01d43f81, 0x01d44081, 0x01d44181, 0x01d44281, 0x01d44381,
+    0x01d44481, 0x01d44581, 0x01d44681, 0x01d44781, 0x01d44881, 0x01d44981,
+    0x01d44a81, 0x01d44b81, 0x01d44c81, 0x01d44d81, 0x01d44e81, 0x01d44f81,
+    0x01d45081, 0x01d45181, 0x01d45281, 0x01d45381, 0x01d45481, 0x01d45681,
+    0x01d45781, 0x01d45881, 0x01d45981, 0x01d45a81, 0x01d45b81, 0x01d45c81,
+    0x01d45d81, 0x01d45e81, 0x01d45f81, 0x01d46081, 0x01d46181, 0x01d46281,
+    0x01d46381, 0x01d46481, 0x01d46581, 0x01d46681, 0x01d46781, 0x01d46881,
+    0x01d46981, 0x01d46a81, 0x01d46b81, 0x01d46c81, 0x01d46d81, 0x01d46e81,
+    0x01d46f81, 0x01d47081, 0x01d47181, 0x01d47281, 0x01d47381, 0x01d47481,
+    0x01d47581, 0x01d47681, 0x01d47781, 0x01d47881, 0x01d47981, 0x01d47a81,
+    0x01d47b81, 0x01d47c81, 0x01d47d81, 0x01d47e81, 0x01d47f81, 0x01d48081,
+    0x01d48181, 0x01d48281, 0x01d48381, 0x01d48481, 0x01d48581, 0x01d48681,
+    0x01d48781, 0x01d48881, 0x01d48981, 0x01d48a81, 0x01d48b81, 0x01d48c81,
+    0x01d48d81, 0x01d48e81, 0x01d48f81, 0x01d49081, 0x01d49181, 0x01d49281,
+    0x01d49381, 0x01d49481, 0x01d49581, 0x01d49681, 0x01d49781, 0x01d49881,
+    0x01d49981, 0x01d49a81, 0x01d49b81, 0x01d49c81, 0x01d49e81, 0x01d49f81,
+    0x01d4a281, 0x01d4a581, 0x01d4a681, 0x01d4a981, 0x01d4aa81, 0x01d4ab81,
+    0x01d4ac81, 0x01d4ae81, 0x01d4af81, 0x01d4b081, 0x01d4b181, 0x01d4b281,
+    0x01d4b381, 0x01d4b481, 0x01d4b581, 0x01d4b681, 0x01d4b781, 0x01d4b881,
+    0x01d4b981, 0x01d4bb81, 0x01d4bd81, 0x01d4be81, 0x01d4bf81, 0x01d4c081,
+    0x01d4c181, 0x01d4c281, 0x01d4c381, 0x01d4c581, 0x01d4c681, 0x01d4c781,
+    0x01d4c881, 0x01d4c981, 0x01d4ca81, 0x01d4cb81, 0x01d4cc81, 0x01d4cd81,
+    0x01d4ce81, 0x01d4cf81, 0x01d4d081, 0x01d4d181, 0x01d4d281, 0x01d4d381,
+    0x01d4d481, 0x01d4d581, 0x01d4d681, 0x01d4d781, 0x01d4d881, 0x01d4d981,
+    0x01d4da81, 0x01d4db81, 0x01d4dc81, 0x01d4dd81, 0x01d4de81, 0x01d4df81,
+    0x01d4e081, 0x01d4e181, 0x01d4e281, 0x01d4e381, 0x01d4e481, 0x01d4e581,
+    0x01d4e681, 0x01d4e781, 0x01d4e881, 0x01d4e981, 0x01d4ea81, 0x01d4eb81,
+    0x01d4ec81, 0x01d4ed81, 0x01d4ee81, 0x01d4ef81, 0x01d4f081, 0x01d4f181,
+    0x01d4f281, 0x01d4f381, 0x01d4f481, 0x01d4f581, 0x01d4f681, 0x01d4f781,
+    0x01d4f881, 0x01d4f981, 0x01d4fa81, 0x01d4fb81, 0x01d4fc81, 0x01d4fd81,
+    0x01d4fe81, 0x01d4ff81, 0x01d50081, 0x01d50181, 0x01d50281, 0x01d50381,
+    0x01d50481, 0x01d50581, 0x01d50781, 0x01d50881, 0x01d50981, 0x01d50a81,
+    0x01d50d81, 0x01d50e81, 0x01d50f81, 0x01d51081, 0x01d51181, 0x01d51281,
+    0x01d51381, 0x01d51481, 0x01d51681, 0x01d51781, 0x01d51881, 0x01d51981,
+    0x01d51a81, 0x01d51b81, 0x01d51c81, 0x01d51e81, 0x01d51f81, 0x01d52081,
+    0x01d52181, 0x01d52281, 0x01d52381, 0x01d52481, 0x01d52581, 0x01d52681,
+    0x01d52781, 0x01d52881, 0x01d52981, 0x01d52a81, 0x01d52b81, 0x01d52c81,
+    0x01d52d81, 0x01d52e81, 0x01d52f81, 0x01d53081, 0x01d53181, 0x01d53281,
+    0x01d53381, 0x01d53481, 0x01d53581, 0x01d53681, 0x01d53781, 0x01d53881,
+    0x01d53981, 0x01d53b81, 0x01d53c81, 0x01d53d81, 0x01d53e81, 0x01d54081,
+    0x01d54181, 0x01d54281, 0x01d54381, 0x01d54481, 0x01d54681, 0x01d54a81,
+    0x01d54b81, 0x01d54c81, 0x01d54d81, 0x01d54e81, 0x01d54f81, 0x01d55081,
+    0x01d55281, 0x01d55381, 0x01d55481, 0x01d55581, 0x01d55681, 0x01d55781,
+    0x01d55881, 0x01d55981, 0x01d55a81, 0x01d55b81, 0x01d55c81, 0x01d55d81,
+    0x01d55e81, 0x01d55f81, 0x01d56081, 0x01d56181, 0x01d56281, 0x01d56381,
+    0x01d56481, 0x01d56581, 0x01d56681, 0x01d56781, 0x01d56881, 0x01d56981,
+    0x01d56a81, 0x01d56b81, 0x01d56c81, 0x01d56d81, 0x01d56e81, 0x01d56f81,
+    0x01d57081, 0x01d57181, 0x01d57281, 0x01d57381, 0x01d57481, 0x01d57581,
+    0x01d57681, 0x01d57781, 0x01d57881, 0x01d57981, 0x01d57a81, 0x01d57b81,
+    0x01d57c81, 0x01d57d81, 0x01d57e81, 0x01d57f81, 0x01d58081, 0x01d58181,
+    0x01d58281, 0x01d58381, 0x01d58481, 0x01d58581, 0x01d58681, 0x01d58781,
+    0x01d58881, 0x01d58981, 0x01d58a81, 0x01d58b81, 0x01d58c81, 0x01d58d81,
+    0x01d58e81, 0x01d58f81, 0x01d59081, 0x01d59181, 0x01d59281, 0x01d59381,
+    0x01d59481, 0x01d59581, 0x01d59681, 0x01d59781, 0x01d59881, 0x01d59981,
+    0x01d59a81, 0x01d59b81, 0x01d59c81, 0x01d59d81, 0x01d59e81, 0x01d59f81,
+    0x01d5a081, 0x01d5a181, 0x01d5a281, 0x01d5a381, 0x01d5a481, 0x01d5a581,
+    0x01d5a681, 0x01d5a781, 0x01d5a881, 0x01d5a981, 0x01d5aa81, 0x01d5ab81,
+    0x01d5ac81, 0x01d5ad81, 0x01d5ae81, 0x01d5af81, 0x01d5b081, 0x01d5b181,
+    0x01d5b281, 0x01d5b381, 0x01d5b481, 0x01d5b581, 0x01d5b681, 0x01d5b781,
+    0x01d5b881, 0x01d5b981, 0x01d5ba81, 0x01d5bb81, 0x01d5bc81, 0x01d5bd81,
+    0x01d5be81, 0x01d5bf81, 0x01d5c081, 0x01d5c181, 0x01d5c281, 0x01d5c381,
+    0x01d5c481, 0x01d5c581, 0x01d5c681, 0x01d5c781, 0x01d5c881, 0x01d5c981,
+    0x01d5ca81, 0x01d5cb81, 0x01d5cc81, 0x01d5cd81, 0x01d5ce81, 0x01d5cf81,
+    0x01d5d081, 0x01d5d181, 0x01d5d281, 0x01d5d381, 0x01d5d481, 0x01d5d581,
+    0x01d5d681, 0x01d5d781, 0x01d5d881, 0x01d5d981, 0x01d5da81, 0x01d5db81,
+    0x01d5dc81, 0x01d5dd81, 0x01d5de81, 0x01d5df81, 0x01d5e081, 0x01d5e181,
+    0x01d5e281, 0x01d5e381, 0x01d5e481, 0x01d5e581, 0x01d5e681, 0x01d5e781,
+    0x01d5e881, 0x01d5e981, 0x01d5ea81, 0x01d5eb81, 0x01d5ec81, 0x01d5ed81,
+    0x01d5ee81, 0x01d5ef81, 0x01d5f081, 0x01d5f181, 0x01d5f281, 0x01d5f381,
+    0x01d5f481, 0x01d5f581, 0x01d5f681, 0x01d5f781, 0x01d5f881, 0x01d5f981,
+    0x01d5fa81, 0x01d5fb81, 0x01d5fc81, 0x01d5fd81, 0x01d5fe81, 0x01d5ff81,
+    0x01d60081, 0x01d60181, 0x01d60281, 0x01d60381, 0x01d60481, 0x01d60581,
+    0x01d60681, 0x01d60781, 0x01d60881, 0x01d60981, 0x01d60a81, 0x01d60b81,
+    0x01d60c81, 0x01d60d81, 0x01d60e81, 0x01d60f81, 0x01d61081, 0x01d61181,
+    0x01d61281, 0x01d61381, 0x01d61481, 0x01d61581, 0x01d61681, 0x01d61781,
+    0x01d61881, 0x01d61981, 0x01d61a81, 0x01d61b81, 0x01d61c81, 0x01d61d81,
+    0x01d61e81, 0x01d61f81, 0x01d62081, 0x01d62181, 0x01d62281, 0x01d62381,
+    0x01d62481, 0x01d62581, 0x01d62681, 0x01d62781, 0x01d62881, 0x01d62981,
+    0x01d62a81, 0x01d62b81, 0x01d62c81, 0x01d62d81, 0x01d62e81, 0x01d62f81,
+    0x01d63081, 0x01d63181, 0x01d63281, 0x01d63381, 0x01d63481, 0x01d63581,
+    0x01d63681, 0x01d63781, 0x01d63881, 0x01d63981, 0x01d63a81, 0x01d63b81,
+    0x01d63c81, 0x01d63d81, 0x01d63e81, 0x01d63f81, 0x01d64081, 0x01d64181,
+    0x01d64281, 0x01d64381, 0x01d64481, 0x01d64581, 0x01d64681, 0x01d64781,
+    0x01d64881, 0x01d64981, 0x01d64a81, 0x01d64b81, 0x01d64c81, 0x01d64d81,
+    0x01d64e81, 0x01d64f81, 0x01d65081, 0x01d65181, 0x01d65281, 0x01d65381,
+    0x01d65481, 0x01d65581, 0x01d65681, 0x01d65781, 0x01d65881, 0x01d65981,
+    0x01d65a81, 0x01d65b81, 0x01d65c81, 0x01d65d81, 0x01d65e81, 0x01d65f81,
+    0x01d66081, 0x01d66181, 0x01d66281, 0x01d66381, 0x01d66481, 0x01d66581,
+    0x01d66681, 0x01d66781, 0x01d66881, 0x01d66981, 0x01d66a81, 0x01d66b81,
+    0x01d66c81, 0x01d66d81, 0x01d66e81, 0x01d66f81, 0x01d67081, 0x01d67181,
+    0x01d67281, 0x01d67381, 0x01d67481, 0x01d67581, 0x01d67681, 0x01d67781,
+    0x01d67881, 0x01d67981, 0x01d67a81, 0x01d67b81, 0x01d67c81, 0x01d67d81,
+    0x01d67e81, 0x01d67f81, 0x01d68081, 0x01d68181, 0x01d68281, 0x01d68381,
+    0x01d68481, 0x01d68581, 0x01d68681, 0x01d68781, 0x01d68881, 0x01d68981,
+    0x01d68a81, 0x01d68b81, 0x01d68c81, 0x01d68d81, 0x01d68e81, 0x01d68f81,
+    0x01d69081, 0x01d69181, 0x01d69281, 0x01d69381, 0x01d69481, 0x01d69581,
+    0x01d69681, 0x01d69781, 0x01d69881, 0x01d69981, 0x01d69a81, 0x01d69b81,
+    0x01d69c81, 0x01d69d81, 0x01d69e81, 0x01d69f81, 0x01d6a081, 0x01d6a181,
+    0x01d6a281, 0x01d6a381, 0x01d6a481, 0x01d6a581, 0x01d6a881, 0x01d6a981,
+    0x01d6aa81, 0x01d6ab81, 0x01d6ac81, 0x01d6ad81, 0x01d6ae81, 0x01d6af81,
+    0x01d6b081, 0x01d6b181, 0x01d6b281, 0x01d6b381, 0x01d6b481, 0x01d6b581,
+    0x01d6b681, 0x01d6b781, 0x01d6b881, 0x01d6b981, 0x01d6ba81, 0x01d6bb81,
+    0x01d6bc81, 0x01d6bd81, 0x01d6be81, 0x01d6bf81, 0x01d6c081, 0x01d6c181,
+    0x01d6c281, 0x01d6c381, 0x01d6c481, 0x01d6c581, 0x01d6c681, 0x01d6c781,
+    0x01d6c881, 0x01d6c981, 0x01d6ca81, 0x01d6cb81, 0x01d6cc81, 0x01d6cd81,
+    0x01d6ce81, 0x01d6cf81, 0x01d6d081, 0x01d6d181, 0x01d6d281, 0x01d6d381,
+    0x01d6d481, 0x01d6d581, 0x01d6d681, 0x01d6d781, 0x01d6d881, 0x01d6d981,
+    0x01d6da81, 0x01d6db81, 0x01d6dc81, 0x01d6dd81, 0x01d6de81, 0x01d6df81,
+    0x01d6e081, 0x01d6e181, 0x01d6e281, 0x01d6e381, 0x01d6e481, 0x01d6e581,
+    0x01d6e681, 0x01d6e781, 0x01d6e881, 0x01d6e981, 0x01d6ea81, 0x01d6eb81,
+    0x01d6ec81, 0x01d6ed81, 0x01d6ee81, 0x01d6ef81, 0x01d6f081, 0x01d6f181,
+    0x01d6f281, 0x01d6f381, 0x01d6f481, 0x01d6f581, 0x01d6f681, 0x01d6f781,
+    0x01d6f881, 0x01d6f981, 0x01d6fa81, 0x01d6fb81, 0x01d6fc81, 0x01d6fd81,
+    0x01d6fe81, 0x01d6ff81, 0x01d70081, 0x01d70181, 0x01d70281, 0x01d70381,
+    0x01d70481, 0x01d70581, 0x01d70681, 0x01d70781, 0x01d70881, 0x01d70981,
+    0x01d70a81, 0x01d70b81, 0x01d70c81, 0x01d70d81, 0x01d70e81, 0x01d70f81,
+    0x01d71081, 0x01d71181, 0x01d71281, 0x01d71381, 0x01d71481, 0x01d71581,
+    0x01d71681, 0x01d71781, 0x01d71881, 0x01d71981, 0x01d71a81, 0x01d71b81,
+    0x01d71c81, 0x01d71d81, 0x01d71e81, 0x01d71f81, 0x01d72081, 0x01d72181,
+    0x01d72281, 0x01d72381, 0x01d72481, 0x01d72581, 0x01d72681, 0x01d72781,
+    0x01d72881, 0x01d72981, 0x01d72a81, 0x01d72b81, 0x01d72c81, 0x01d72d81,
+    0x01d72e81, 0x01d72f81, 0x01d73081, 0x01d73181, 0x01d73281, 0x01d73381,
+    0x01d73481, 0x01d73581, 0x01d73681, 0x01d73781, 0x01d73881, 0x01d73981,
+    0x01d73a81, 0x01d73b81, 0x01d73c81, 0x01d73d81, 0x01d73e81, 0x01d73f81,
+    0x01d74081, 0x01d74181, 0x01d74281, 0x01d74381, 0x01d74481, 0x01d74581,
+    0x01d74681, 0x01d74781, 0x01d74881, 0x01d74981, 0x01d74a81, 0x01d74b81,
+    0x01d74c81, 0x01d74d81, 0x01d74e81, 0x01d74f81, 0x01d75081, 0x01d75181,
+    0x01d75281, 0x01d75381, 0x01d75481, 0x01d75581, 0x01d75681, 0x01d75781,
+    0x01d75881, 0x01d75981, 0x01d75a81, 0x01d75b81, 0x01d75c81, 0x01d75d81,
+    0x01d75e81, 0x01d75f81, 0x01d76081, 0x01d76181, 0x01d76281, 0x01d76381,
+    0x01d76481, 0x01d76581, 0x01d76681, 0x01d76781, 0x01d76881, 0x01d76981,
+    0x01d76a81, 0x01d76b81, 0x01d76c81, 0x01d76d81, 0x01d76e81, 0x01d76f81,
+    0x01d77081, 0x01d77181, 0x01d77281, 0x01d77381, 0x01d77481, 0x01d77581,
+    0x01d77681, 0x01d77781, 0x01d77881, 0x01d77981, 0x01d77a81, 0x01d77b81,
+    0x01d77c81, 0x01d77d81, 0x01d77e81, 0x01d77f81, 0x01d78081, 0x01d78181,
+    0x01d78281, 0x01d78381, 0x01d78481, 0x01d78581, 0x01d78681, 0x01d78781,
+    0x01d78881, 0x01d78981, 0x01d78a81, 0x01d78b81, 0x01d78c81, 0x01d78d81,
+    0x01d78e81, 0x01d78f81, 0x01d79081, 0x01d79181, 0x01d79281, 0x01d79381,
+    0x01d79481, 0x01d79581, 0x01d79681, 0x01d79781, 0x01d79881, 0x01d79981,
+    0x01d79a81, 0x01d79b81, 0x01d79c81, 0x01d79d81, 0x01d79e81, 0x01d79f81,
+    0x01d7a081, 0x01d7a181, 0x01d7a281, 0x01d7a381, 0x01d7a481, 0x01d7a581,
+    0x01d7a681, 0x01d7a781, 0x01d7a881, 0x01d7a981, 0x01d7aa81, 0x01d7ab81,
+    0x01d7ac81, 0x01d7ad81, 0x01d7ae81, 0x01d7af81, 0x01d7b081, 0x01d7b181,
+    0x01d7b281, 0x01d7b381, 0x01d7b481, 0x01d7b581, 0x01d7b681, 0x01d7b781,
+    0x01d7b881, 0x01d7b981, 0x01d7ba81, 0x01d7bb81, 0x01d7bc81, 0x01d7bd81,
+    0x01d7be81, 0x01d7bf81, 0x01d7c081, 0x01d7c181, 0x01d7c281, 0x01d7c381,
+    0x01d7c481, 0x01d7c581, 0x01d7c681, 0x01d7c781, 0x01d7c881, 0x01d7c981,
+    0x01d7ca81, 0x01d7cb81, 0x01d7ce81, 0x01d7cf81, 0x01d7d081, 0x01d7d181,
+    0x01d7d281, 0x01d7d381, 0x01d7d481, 0x01d7d581, 0x01d7d681, 0x01d7d781,
+    0x01d7d881, 0x01d7d981, 0x01d7da81, 0x01d7db81, 0x01d7dc81, 0x01d7dd81,
+    0x01d7de81, 0x01d7df81, 0x01d7e081, 0x01d7e181, 0x01d7e281, 0x01d7e381,
+    0x01d7e481, 0x01d7e581, 0x01d7e681, 0x01d7e781, 0x01d7e881, 0x01d7e981,
+    0x01d7ea81, 0x01d7eb81, 0x01d7ec81, 0x01d7ed81, 0x01d7ee81, 0x01d7ef81,
+    0x01d7f081, 0x01d7f181, 0x01d7f281, 0x01d7f381, 0x01d7f481, 0x01d7f581,
+    0x01d7f681, 0x01d7f781, 0x01d7f881, 0x01d7f981, 0x01d7fa81, 0x01d7fb81,
+    0x01d7fc81, 0x01d7fd81, 0x01d7fe81, 0x01d7ff81, 0x01ee0081, 0x01ee0181,
+    0x01ee0281, 0x01ee0381, 0x01ee0581, 0x01ee0681, 0x01ee0781, 0x01ee0881,
+    0x01ee0981, 0x01ee0a81, 0x01ee0b81, 0x01ee0c81, 0x01ee0d81, 0x01ee0e81,
+    0x01ee0f81, 0x01ee1081, 0x01ee1181, 0x01ee1281, 0x01ee1381, 0x01ee1481,
+    0x01ee1581, 0x01ee1681, 0x01ee1781, 0x01ee1881, 0x01ee1981, 0x01ee1a81,
+    0x01ee1b81, 0x01ee1c81, 0x01ee1d81, 0x01ee1e81, 0x01ee1f81, 0x01ee2181,
+    0x01ee2281, 0x01ee2481, 0x01ee2781, 0x01ee2981, 0x01ee2a81, 0x01ee2b81,
+    0x01ee2c81, 0x01ee2d81, 0x01ee2e81, 0x01ee2f81, 0x01ee3081, 0x01ee3181,
+    0x01ee3281, 0x01ee3481, 0x01ee3581, 0x01ee3681, 0x01ee3781, 0x01ee3981,
+    0x01ee3b81, 0x01ee4281, 0x01ee4781, 0x01ee4981, 0x01ee4b81, 0x01ee4d81,
+    0x01ee4e81, 0x01ee4f81, 0x01ee5181, 0x01ee5281, 0x01ee5481, 0x01ee5781,
+    0x01ee5981, 0x01ee5b81, 0x01ee5d81, 0x01ee5f81, 0x01ee6181, 0x01ee6281,
+    0x01ee6481, 0x01ee6781, 0x01ee6881, 0x01ee6981, 0x01ee6a81, 0x01ee6c81,
+    0x01ee6d81, 0x01ee6e81, 0x01ee6f81, 0x01ee7081, 0x01ee7181, 0x01ee7281,
+    0x01ee7481, 0x01ee7581, 0x01ee7681, 0x01ee7781, 0x01ee7981, 0x01ee7a81,
+    0x01ee7b81, 0x01ee7c81, 0x01ee7e81, 0x01ee8081, 0x01ee8181, 0x01ee8281,
+    0x01ee8381, 0x01ee8481, 0x01ee8581, 0x01ee8681, 0x01ee8781, 0x01ee8881,
+    0x01ee8981, 0x01ee8b81, 0x01ee8c81, 0x01ee8d81, 0x01ee8e81, 0x01ee8f81,
+    0x01ee9081, 0x01ee9181, 0x01ee9281, 0x01ee9381, 0x01ee9481, 0x01ee9581,
+    0x01ee9681, 0x01ee9781, 0x01ee9881, 0x01ee9981, 0x01ee9a81, 0x01ee9b81,
+    0x01eea181, 0x01eea281, 0x01eea381, 0x01eea581, 0x01eea681, 0x01eea781,
+    0x01eea881, 0x01eea981, 0x01eeab81, 0x01eeac81, 0x01eead81, 0x01eeae81,
+    0x01eeaf81, 0x01eeb081, 0x01eeb181, 0x01eeb281, 0x01eeb381, 0x01eeb481,
+    0x01eeb581, 0x01eeb681, 0x01eeb781, 0x01eeb881, 0x01eeb981, 0x01eeba81,
+    0x01eebb81, 0x01f10082, 0x01f10182, 0x01f10282, 0x01f10382, 0x01f10482,
+    0x01f10582, 0x01f10682, 0x01f10782, 0x01f10882, 0x01f10982, 0x01f10a82,
+    0x01f11083, 0x01f11183, 0x01f11283, 0x01f11383, 0x01f11483, 0x01f11583,
+    0x01f11683, 0x01f11783, 0x01f11883, 0x01f11983, 0x01f11a83, 0x01f11b83,
+    0x01f11c83, 0x01f11d83, 0x01f11e83, 0x01f11f83, 0x01f12083, 0x01f12183,
+    0x01f12283, 0x01f12383, 0x01f12483, 0x01f12583, 0x01f12683, 0x01f12783,
+    0x01f12883, 0x01f12983, 0x01f12a83, 0x01f12b81, 0x01f12c81, 0x01f12d82,
+    0x01f12e82, 0x01f13081, 0x01f13181, 0x01f13281, 0x01f13381, 0x01f13481,
+    0x01f13581, 0x01f13681, 0x01f13781, 0x01f13881, 0x01f13981, 0x01f13a81,
+    0x01f13b81, 0x01f13c81, 0x01f13d81, 0x01f13e81, 0x01f13f81, 0x01f14081,
+    0x01f14181, 0x01f14281, 0x01f14381, 0x01f14481, 0x01f14581, 0x01f14681,
+    0x01f14781, 0x01f14881, 0x01f14981, 0x01f14a82, 0x01f14b82, 0x01f14c82,
+    0x01f14d82, 0x01f14e83, 0x01f14f82, 0x01f16a82, 0x01f16b82, 0x01f16c82,
+    0x01f19082, 0x01f20082, 0x01f20182, 0x01f20281, 0x01f21081, 0x01f21181,
+    0x01f21281, 0x01f21381, 0x01f21481, 0x01f21581, 0x01f21681, 0x01f21781,
+    0x01f21881, 0x01f21981, 0x01f21a81, 0x01f21b81, 0x01f21c81, 0x01f21d81,
+    0x01f21e81, 0x01f21f81, 0x01f22081, 0x01f22181, 0x01f22281, 0x01f22381,
+    0x01f22481, 0x01f22581, 0x01f22681, 0x01f22781, 0x01f22881, 0x01f22981,
+    0x01f22a81, 0x01f22b81, 0x01f22c81, 0x01f22d81, 0x01f22e81, 0x01f22f81,
+    0x01f23081, 0x01f23181, 0x01f23281, 0x01f23381, 0x01f23481, 0x01f23581,
+    0x01f23681, 0x01f23781, 0x01f23881, 0x01f23981, 0x01f23a81, 0x01f23b81,
+    0x01f24083, 0x01f24183, 0x01f24283, 0x01f24383, 0x01f24483, 0x01f24583,
+    0x01f24683, 0x01f24783, 0x01f24883, 0x01f25081, 0x01f25181, 0x01fbf081,
+    0x01fbf181, 0x01fbf281, 0x01fbf381, 0x01fbf481, 0x01fbf581, 0x01fbf681,
+    0x01fbf781, 0x01fbf881, 0x01fbf981, 0x02f80001, 0x02f80101, 0x02f80201,
+    0x02f80301, 0x02f80401, 0x02f80501, 0x02f80601, 0x02f80701, 0x02f80801,
+    0x02f80901, 0x02f80a01, 0x02f80b01, 0x02f80c01, 0x02f80d01, 0x02f80e01,
+    0x02f80f01, 0x02f81001, 0x02f81101, 0x02f81201, 0x02f81301, 0x02f81401,
+    0x02f81501, 0x02f81601, 0x02f81701, 0x02f81801, 0x02f81901, 0x02f81a01,
+    0x02f81b01, 0x02f81c01, 0x02f81d01, 0x02f81e01, 0x02f81f01, 0x02f82001,
+    0x02f82101, 0x02f82201, 0x02f82301, 0x02f82401, 0x02f82501, 0x02f82601,
+    0x02f82701, 0x02f82801, 0x02f82901, 0x02f82a01, 0x02f82b01, 0x02f82c01,
+    0x02f82d01, 0x02f82e01, 0x02f82f01, 0x02f83001, 0x02f83101, 0x02f83201,
+    0x02f83301, 0x02f83401, 0x02f83501, 0x02f83601, 0x02f83701, 0x02f83801,
+    0x02f83901, 0x02f83a01, 0x02f83b01, 0x02f83c01, 0x02f83d01, 0x02f83e01,
+    0x02f83f01, 0x02f84001, 0x02f84101, 0x02f84201, 0x02f84301, 0x02f84401,
+    0x02f84501, 0x02f84601, 0x02f84701, 0x02f84801, 0x02f84901, 0x02f84a01,
+    0x02f84b01, 0x02f84c01, 0x02f84d01, 0x02f84e01, 0x02f84f01, 0x02f85001,
+    0x02f85101, 0x02f85201, 0x02f85301, 0x02f85401, 0x02f85501, 0x02f85601,
+    0x02f85701, 0x02f85801, 0x02f85901, 0x02f85a01, 0x02f85b01, 0x02f85c01,
+    0x02f85d01, 0x02f85e01, 0x02f85f01, 0x02f86001, 0x02f86101, 0x02f86201,
+    0x02f86301, 0x02f86401, 0x02f86501, 0x02f86601, 0x02f86701, 0x02f86801,
+    0x02f86901, 0x02f86a01, 0x02f86b01, 0x02f86c01, 0x02f86d01, 0x02f86e01,
+    0x02f86f01, 0x02f87001, 0x02f87101, 0x02f87201, 0x02f87301, 0x02f87401,
+    0x02f87501, 0x02f87601, 0x02f87701, 0x02f87801, 0x02f87901, 0x02f87a01,
+    0x02f87b01, 0x02f87c01, 0x02f87d01, 0x02f87e01, 0x02f87f01, 0x02f88001,
+    0x02f88101, 0x02f88201, 0x02f88301, 0x02f88401, 0x02f88501, 0x02f88601,
+    0x02f88701, 0x02f88801, 0x02f88901, 0x02f88a01, 0x02f88b01, 0x02f88c01,
+    0x02f88d01, 0x02f88e01, 0x02f88f01, 0x02f89001, 0x02f89101, 0x02f89201,
+    0x02f89301, 0x02f89401, 0x02f89501, 0x02f89601, 0x02f89701, 0x02f89801,
+    0x02f89901, 0x02f89a01, 0x02f89b01, 0x02f89c01, 0x02f89d01, 0x02f89e01,
+    0x02f89f01, 0x02f8a001, 0x02f8a101, 0x02f8a201, 0x02f8a301, 0x02f8a401,
+    0x02f8a501, 0x02f8a601, 0x02f8a701, 0x02f8a801, 0x02f8a901, 0x02f8aa01,
+    0x02f8ab01, 0x02f8ac01, 0x02f8ad01, 0x02f8ae01, 0x02f8af01, 0x02f8b001,
+    0x02f8b101, 0x02f8b201, 0x02f8b301, 0x02f8b401, 0x02f8b501, 0x02f8b601,
+    0x02f8b701, 0x02f8b801, 0x02f8b901, 0x02f8ba01, 0x02f8bb01, 0x02f8bc01,
+    0x02f8bd01, 0x02f8be01, 0x02f8bf01, 0x02f8c001, 0x02f8c101, 0x02f8c201,
+    0x02f8c301, 0x02f8c401, 0x02f8c501, 0x02f8c601, 0x02f8c701, 0x02f8c801,
+    0x02f8c901, 0x02f8ca01, 0x02f8cb01, 0x02f8cc01, 0x02f8cd01, 0x02f8ce01,
+    0x02f8cf01, 0x02f8d001, 0x02f8d101, 0x02f8d201, 0x02f8d301, 0x02f8d401,
+    0x02f8d501, 0x02f8d601, 0x02f8d701, 0x02f8d801, 0x02f8d901, 0x02f8da01,
+    0x02f8db01, 0x02f8dc01, 0x02f8dd01, 0x02f8de01, 0x02f8df01, 0x02f8e001,
+    0x02f8e101, 0x02f8e201, 0x02f8e301, 0x02f8e401, 0x02f8e501, 0x02f8e601,
+    0x02f8e701, 0x02f8e801, 0x02f8e901, 0x02f8ea01, 0x02f8eb01, 0x02f8ec01,
+    0x02f8ed01, 0x02f8ee01, 0x02f8ef01, 0x02f8f001, 0x02f8f101, 0x02f8f201,
+    0x02f8f301, 0x02f8f401, 0x02f8f501, 0x02f8f601, 0x02f8f701, 0x02f8f801,
+    0x02f8f901, 0x02f8fa01, 0x02f8fb01, 0x02f8fc01, 0x02f8fd01, 0x02f8fe01,
+    0x02f8ff01, 0x02f90001, 0x02f90101, 0x02f90201, 0x02f90301, 0x02f90401,
+    0x02f90501, 0x02f90601, 0x02f90701, 0x02f90801, 0x02f90901, 0x02f90a01,
+    0x02f90b01, 0x02f90c01, 0x02f90d01, 0x02f90e01, 0x02f90f01, 0x02f91001,
+    0x02f91101, 0x02f91201, 0x02f91301, 0x02f91401, 0x02f91501, 0x02f91601,
+    0x02f91701, 0x02f91801, 0x02f91901, 0x02f91a01, 0x02f91b01, 0x02f91c01,
+    0x02f91d01, 0x02f91e01, 0x02f91f01, 0x02f92001, 0x02f92101, 0x02f92201,
+    0x02f92301, 0x02f92401, 0x02f92501, 0x02f92601, 0x02f92701, 0x02f92801,
+    0x02f92901, 0x02f92a01, 0x02f92b01, 0x02f92c01, 0x02f92d01, 0x02f92e01,
+    0x02f92f01, 0x02f93001, 0x02f93101, 0x02f93201, 0x02f93301, 0x02f93401,
+    0x02f93501, 0x02f93601, 0x02f93701, 0x02f93801, 0x02f93901, 0x02f93a01,
+    0x02f93b01, 0x02f93c01, 0x02f93d01, 0x02f93e01, 0x02f93f01, 0x02f94001,
+    0x02f94101, 0x02f94201, 0x02f94301, 0x02f94401, 0x02f94501, 0x02f94601,
+    0x02f94701, 0x02f94801, 0x02f94901, 0x02f94a01, 0x02f94b01, 0x02f94c01,
+    0x02f94d01, 0x02f94e01, 0x02f94f01, 0x02f95001, 0x02f95101, 0x02f95201,
+    0x02f95301, 0x02f95401, 0x02f95501, 0x02f95601, 0x02f95701, 0x02f95801,
+    0x02f95901, 0x02f95a01, 0x02f95b01, 0x02f95c01, 0x02f95d01, 0x02f95e01,
+    0x02f95f01, 0x02f96001, 0x02f96101, 0x02f96201, 0x02f96301, 0x02f96401,
+    0x02f96501, 0x02f96601, 0x02f96701, 0x02f96801, 0x02f96901, 0x02f96a01,
+    0x02f96b01, 0x02f96c01, 0x02f96d01, 0x02f96e01, 0x02f96f01, 0x02f97001,
+    0x02f97101, 0x02f97201, 0x02f97301, 0x02f97401, 0x02f97501, 0x02f97601,
+    0x02f97701, 0x02f97801, 0x02f97901, 0x02f97a01, 0x02f97b01, 0x02f97c01,
+    0x02f97d01, 0x02f97e01, 0x02f97f01, 0x02f98001, 0x02f98101, 0x02f98201,
+    0x02f98301, 0x02f98401, 0x02f98501, 0x02f98601, 0x02f98701, 0x02f98801,
+    0x02f98901, 0x02f98a01, 0x02f98b01, 0x02f98c01, 0x02f98d01, 0x02f98e01,
+    0x02f98f01, 0x02f99001, 0x02f99101, 0x02f99201, 0x02f99301, 0x02f99401,
+    0x02f99501, 0x02f99601, 0x02f99701, 0x02f99801, 0x02f99901, 0x02f99a01,
+    0x02f99b01, 0x02f99c01, 0x02f99d01, 0x02f99e01, 0x02f99f01, 0x02f9a001,
+    0x02f9a101, 0x02f9a201, 0x02f9a301, 0x02f9a401, 0x02f9a501, 0x02f9a601,
+    0x02f9a701, 0x02f9a801, 0x02f9a901, 0x02f9aa01, 0x02f9ab01, 0x02f9ac01,
+    0x02f9ad01, 0x02f9ae01, 0x02f9af01, 0x02f9b001, 0x02f9b101, 0x02f9b201,
+    0x02f9b301, 0x02f9b401, 0x02f9b501, 0x02f9b601, 0x02f9b701, 0x02f9b801,
+    0x02f9b901, 0x02f9ba01, 0x02f9bb01, 0x02f9bc01, 0x02f9bd01, 0x02f9be01,
+    0x02f9bf01, 0x02f9c001, 0x02f9c101, 0x02f9c201, 0x02f9c301, 0x02f9c401,
+    0x02f9c501, 0x02f9c601, 0x02f9c701, 0x02f9c801, 0x02f9c901, 0x02f9ca01,
+    0x02f9cb01, 0x02f9cc01, 0x02f9cd01, 0x02f9ce01, 0x02f9cf01, 0x02f9d001,
+    0x02f9d101, 0x02f9d201, 0x02f9d301, 0x02f9d401, 0x02f9d501, 0x02f9d601,
+    0x02f9d701, 0x02f9d801, 0x02f9d901, 0x02f9da01, 0x02f9db01, 0x02f9dc01,
+    0x02f9dd01, 0x02f9de01, 0x02f9df01, 0x02f9e001, 0x02f9e101, 0x02f9e201,
+    0x02f9e301, 0x02f9e401, 0x02f9e501, 0x02f9e601, 0x02f9e701, 0x02f9e801,
+    0x02f9e901, 0x02f9ea01, 0x02f9eb01, 0x02f9ec01, 0x02f9ed01, 0x02f9ee01,
+    0x02f9ef01, 0x02f9f001, 0x02f9f101, 0x02f9f201, 0x02f9f301, 0x02f9f401,
+    0x02f9f501, 0x02f9f601, 0x02f9f701, 0x02f9f801, 0x02f9f901, 0x02f9fa01,
+    0x02f9fb01, 0x02f9fc01, 0x02f9fd01, 0x02f9fe01, 0x02f9ff01, 0x02fa0001,
+    0x02fa0101, 0x02fa0201, 0x02fa0301, 0x02fa0401, 0x02fa0501, 0x02fa0601,
+    0x02fa0701, 0x02fa0801, 0x02fa0901, 0x02fa0a01, 0x02fa0b01, 0x02fa0c01,
+    0x02fa0d01, 0x02fa0e01, 0x02fa0f01, 0x02fa1001, 0x02fa1101, 0x02fa1201,
+    0x02fa1301, 0x02fa1401, 0x02fa1501, 0x02fa1601, 0x02fa1701, 0x02fa1801,
+    0x02fa1901, 0x02fa1a01, 0x02fa1b01, 0x02fa1c01, 0x02fa1d01,
+];
+
+pub(super) static DECOMPOSITION_OFFSETS: [u16; 5795] = [
+    0, 1, 3, 4, 6, 7, 8, 10, 11, 13,
+    14, 15, 18, 21, 24, 26, 28, 30, 32, 34,
+    36, 38, 40, 42, 44, 46, 48, 50, 52, 54,
+    56, 58, 60, 62, 64, 66, 68, 70, 72, 74,
+    76, 78, 80, 82, 84, 86, 88, 90, 92, 94,
+    96, 98, 100, 102, 104, 106, 108, 110, 112, 114,
+    116, 118, 120, 122, 124, 126, 128, 130, 132, 134,
+    136, 138, 140, 142, 144, 146, 148, 150, 152, 154,
+    156, 158, 160, 162, 164, 166, 168, 170, 172, 174,
+    176, 178, 180, 182, 184, 186, 188, 190, 192, 194,
+    196, 198, 200, 202, 204, 206, 208, 210, 212, 214,
+    216, 218, 220, 222, 224, 226, 228, 230, 232, 234,
+    236, 238, 240, 242, 244, 246, 248, 250, 252, 254,
+    256, 258, 260, 262, 264, 266, 268, 270, 272, 274,
+    276, 278, 280, 282, 284, 286, 288, 290, 292, 294,
+    296, 298, 300, 302, 304, 306, 308, 310, 312, 314,
+    316, 318, 320, 322, 324, 326, 328, 330, 332, 334,
+    336, 338, 340, 342, 344, 346, 348, 350, 352, 354,
+    356, 357, 359, 361, 363, 365, 367, 369, 371, 373,
+    375, 377, 379, 381, 383, 385, 387, 389, 391, 393,
+    395, 397, 399, 401, 403, 405, 407, 409, 411, 413,
+    415, 417, 419, 421, 423, 425, 427, 429, 431, 433,
+    435, 437, 439, 441, 443, 445, 447, 449, 451, 453,
+    455, 457, 459, 461, 463, 465, 467, 469, 471, 473,
+    475, 477, 479, 481, 483, 485, 487, 489, 491, 493,
+    495, 497, 499, 501, 503, 505, 507, 509, 511, 513,
+    515, 517, 519, 521, 523, 525, 527, 529, 531, 533,
+    535, 537, 539, 541, 543, 545, 547, 549, 551, 553,
+    555, 557, 559, 561, 563, 564, 565, 566, 567, 568,
+    569, 570, 571, 572, 574, 576, 578, 580, 582, 584,
+    585, 586, 587, 588, 589, 590, 591, 592, 594, 595,
+    597, 598, 600, 602, 604, 605, 607, 609, 611, 613,
+    615, 617, 619, 621, 623, 625, 627, 629, 631, 633,
+    635, 637, 639, 641, 643, 644, 645, 646, 648, 650,
+    651, 652, 653, 654, 655, 656, 657, 658, 660, 662,
+    664, 666, 668, 670, 672, 674, 676, 678, 680, 682,
+    684, 686, 688, 690, 692, 694, 696, 698, 700, 702,
+    704, 706, 708, 710, 712, 714, 716, 718, 720, 722,
+    724, 726, 728, 730, 732, 734, 736, 738, 740, 742,
+    744, 746, 748, 750, 752, 754, 756, 758, 760, 762,
+    764, 766, 768, 770, 772, 774, 776, 778, 780, 782,
+    784, 786, 788, 790, 792, 794, 796, 798, 800, 802,
+    804, 806, 808, 810, 812, 814, 816, 818, 820, 822,
+    824, 826, 828, 830, 832, 834, 836, 838, 840, 842,
+    844, 846, 848, 850, 852, 854, 856, 858, 860, 862,
+    864, 866, 868, 870, 872, 874, 876, 878, 880, 882,
+    884, 885, 887, 889, 891, 893, 895, 897, 899, 901,
+    903, 905, 907, 909, 911, 913, 915, 917, 919, 921,
+    923, 925, 926, 928, 930, 932, 934, 936, 938, 940,
+    942, 944, 946, 948, 949, 950, 951, 952, 953, 954,
+    955, 956, 957, 958, 959, 960, 961, 962, 963, 964,
+    965, 966, 967, 968, 969, 970, 971, 972, 973, 974,
+    975, 976, 977, 978, 979, 980, 981, 982, 983, 984,
+    985, 986, 987, 988, 989, 990, 991, 992, 993, 994,
+    995, 996, 997, 998, 999, 1000, 1001, 1002, 1003, 1004,
+    1005, 1006, 1007, 1008, 1009, 1010, 1011, 1012, 1013, 1014,
+    1015, 1016, 1017, 1018, 1019, 1020, 1021, 1022, 1023, 1024,
+    1025, 1026, 1027, 1028, 1029, 1030, 1031, 1032, 1033, 1034,
+    1035, 1036, 1037, 1038, 1039, 1040, 1041, 1042, 1043, 1044,
+    1045, 1046, 1048, 1050, 1052, 1054, 1056, 1058, 1060, 1062,
+    1064, 1066, 1068, 1070, 1072, 1074, 1076, 1078, 1080, 1082,
+    1084, 1086, 1088, 1090, 1092, 1094, 1096, 1098, 1100, 1102,
+    1104, 1106, 1108, 1110, 1112, 1114, 1116, 1118, 1120, 1122,
+    1124, 1126, 1128, 1130, 1132, 1134, 1136, 1138, 1140, 1142,
+    1144, 1146, 1148, 1150, 1152, 1154, 1156, 1158, 1160, 1162,
+    1164, 1166, 1168, 1170, 1172, 1174, 1176, 1178, 1180, 1182,
+    1184, 1186, 1188, 1190, 1192, 1194, 1196, 1198, 1200, 1202,
+    1204, 1206, 1208, 1210, 1212, 1214, 1216, 1218, 1220, 1222,
+    1224, 1226, 1228, 1230, 1232, 1234, 1236, 1238, 1240, 1242,
+    1244, 1246, 1248, 1250, 1252, 1254, 1256, 1258, 1260, 1262,
+    1264, 1266, 1268, 1270, 1272, 1274, 1276, 1278, 1280, 1282,
+    1284, 1286, 1288, 1290, 1292, 1294, 1296, 1298, 1300, 1302,
+    1304, 1306, 1308, 1310, 1312, 1314, 1316, 1318, 1320, 1322,
+    1324, 1326, 1328, 1330, 1332, 1334, 1336, 1338, 1340, 1342,
+    1344, 1346, 1348, 1350, 1352, 1354, 1356, 1358, 1360, 1362,
+    1364, 1366, 1368, 1370, 1372, 1374, 1376, 1378, 1380, 1382,
+    1384, 1386, 1388, 1390, 1392, 1394, 1396, 1398, 1400, 1402,
+    1404, 1406, 1408, 1410, 1412, 1414, 1416, 1418, 1420, 1422,
+    1424, 1426, 1428, 1430, 1432, 1434, 1436, 1438, 1440, 1442,
+    1444, 1446, 1448, 1450, 1452, 1454, 1456, 1458, 1460, 1462,
+    1464, 1466, 1468, 1470, 1472, 1474, 1476, 1478, 1480, 1482,
+    1484, 1486, 1488, 1490, 1492, 1494, 1496, 1498, 1500, 1502,
+    1504, 1506, 1508, 1510, 1512, 1514, 1516, 1518, 1520, 1522,
+    1524, 1526, 1528, 1530, 1532, 1534, 1536, 1538, 1540, 1542,
+    1544, 1546, 1548, 1550, 1552, 1554, 1556, 1558, 1560, 1562,
+    1564, 1566, 1568, 1570, 1572, 1574, 1576, 1578, 1580, 1582,
+    1584, 1586, 1588, 1590, 1592, 1594, 1596, 1598, 1600, 1602,
+    1604, 1606, 1608, 1610, 1612, 1614, 1616, 1618, 1620, 1622,
+    1624, 1626, 1628, 1630, 1632, 1634, 1636, 1638, 1640, 1642,
+    1644, 1646, 1648, 1650, 1652, 1654, 1656, 1658, 1660, 1662,
+    1664, 1666, 1668, 1670, 1672, 1674, 1676, 1678, 1680, 1682,
+    1684, 1686, 1688, 1690, 1692, 1694, 1696, 1698, 1700, 1702,
+    1704, 1706, 1708, 1710, 1712, 1714, 1716, 1718, 1720, 1722,
+    1724, 1726, 1728, 1730, 1732, 1734, 1736, 1738, 1740, 1741,
+    1743, 1744, 1746, 1747, 1749, 1750, 1752, 1753, 1755, 1756,
+    1758, 1759, 1761, 1763, 1765, 1767, 1769, 1771, 1773, 1775,
+    1777, 1779, 1781, 1783, 1785, 1787, 1789, 1791, 1793, 1795,
+    1797, 1799, 1801, 1803, 1805, 1807, 1809, 1811, 1813, 1815,
+    1817, 1819, 1821, 1823, 1825, 1827, 1829, 1831, 1833, 1835,
+    1837, 1839, 1841, 1843, 1845, 1847, 1849, 1851, 1853, 1855,
+    1857, 1859, 1861, 1863, 1865, 1867, 1869, 1871, 1873, 1875,
+    1876, 1878, 1880, 1881, 1883, 1885, 1887, 1889, 1891, 1893,
+    1895, 1897, 1899, 1900, 1902, 1903, 1905, 1907, 1909, 1911,
+    1913, 1915, 1917, 1918, 1920, 1922, 1924, 1926, 1928, 1929,
+    1931, 1933, 1935, 1937, 1939, 1941, 1942, 1944, 1946, 1948,
+    1950, 1952, 1954, 1956, 1957, 1959, 1961, 1962, 1963, 1965,
+    1967, 1969, 1971, 1973, 1975, 1976, 1978, 1979, 1981, 1982,
+    1984, 1985, 1986, 1987, 1988, 1989, 1990, 1991, 1992, 1993,
+    1994, 1995, 1996, 1998, 1999, 2001, 2004, 2005, 2007, 2010,
+    2012, 2015, 2017, 2019, 2021, 2023, 2025, 2029, 2030, 2031,
+    2032, 2033, 2034, 2035, 2036, 2037, 2038, 2039, 2040, 2041,
+    2042, 2043, 2044, 2045, 2046, 2047, 2048, 2049, 2050, 2051,
+    2052, 2053, 2054, 2055, 2056, 2057, 2058, 2059, 2060, 2061,
+    2062, 2063, 2064, 2065, 2066, 2067, 2068, 2069, 2070, 2071,
+    2072, 2074, 2077, 2080, 2081, 2083, 2086, 2089, 2090, 2092,
+    2093, 2094, 2095, 2096, 2097, 2098, 2099, 2100, 2101, 2102,
+    2103, 2105, 2106, 2107, 2108, 2109, 2110, 2112, 2115, 2117,
+    2118, 2119, 2120, 2121, 2122, 2123, 2124, 2125, 2126, 2127,
+    2128, 2129, 2130, 2131, 2132, 2133, 2134, 2137, 2138, 2139,
+    2140, 2141, 2142, 2143, 2144, 2145, 2146, 2147, 2150, 2153,
+    2157, 2160, 2163, 2166, 2169, 2172, 2175, 2178, 2181, 2184,
+    2187, 2190, 2193, 2195, 2196, 2198, 2201, 2203, 2204, 2206,
+    2209, 2213, 2215, 2216, 2218, 2221, 2222, 2223, 2224, 2225,
+    2226, 2228, 2231, 2233, 2234, 2236, 2239, 2243, 2245, 2246,
+    2248, 2251, 2252, 2253, 2254, 2255, 2258, 2260, 2262, 2264,
+    2266, 2268, 2270, 2272, 2274, 2276, 2278, 2280, 2282, 2285,
+    2287, 2290, 2292, 2294, 2296, 2298, 2300, 2302, 2304, 2306,
+    2308, 2310, 2312, 2314, 2316, 2318, 2320, 2322, 2324, 2326,
+    2328, 2330, 2332, 2334, 2336, 2338, 2340, 2342, 2344, 2346,
+    2348, 2350, 2352, 2354, 2356, 2357, 2358, 2359, 2360, 2361,
+    2362, 2363, 2364, 2365, 2366, 2367, 2369, 2371, 2373, 2375,
+    2377, 2379, 2381, 2383, 2385, 2387, 2389, 2392, 2395, 2398,
+    2401, 2404, 2407, 2410, 2413, 2416, 2420, 2424, 2428, 2432,
+    2436, 2440, 2444, 2448, 2452, 2456, 2460, 2462, 2464, 2466,
+    2468, 2470, 2472, 2474, 2476, 2478, 2481, 2484, 2487, 2490,
+    2493, 2496, 2499, 2502, 2505, 2508, 2511, 2514, 2517, 2520,
+    2523, 2526, 2529, 2532, 2535, 2538, 2541, 2544, 2547, 2550,
+    2553, 2556, 2559, 2562, 2565, 2568, 2571, 2574, 2577, 2580,
+    2583, 2586, 2589, 2590, 2591, 2592, 2593, 2594, 2595, 2596,
+    2597, 2598, 2599, 2600, 2601, 2602, 2603, 2604, 2605, 2606,
+    2607, 2608, 2609, 2610, 2611, 2612, 2613, 2614, 2615, 2616,
+    2617, 2618, 2619, 2620, 2621, 2622, 2623, 2624, 2625, 2626,
+    2627, 2628, 2629, 2630, 2631, 2632, 2633, 2634, 2635, 2636,
+    2637, 2638, 2639, 2640, 2641, 2642, 2646, 2649, 2651, 2654,
+    2656, 2657, 2658, 2659, 2660, 2661, 2662, 2663, 2664, 2665,
+    2666, 2667, 2668, 2669, 2670, 2671, 2672, 2673, 2674, 2675,
+    2676, 2677, 2678, 2679, 2680, 2681, 2682, 2683, 2684, 2685,
+    2686, 2687, 2688, 2689, 2690, 2691, 2692, 2693, 2694, 2695,
+    2696, 2697, 2698, 2699, 2700, 2701, 2702, 2703, 2704, 2705,
+    2706, 2707, 2708, 2709, 2710, 2711, 2712, 2713, 2714, 2715,
+    2716, 2717, 2718, 2719, 2720, 2721, 2722, 2723, 2724, 2725,
+    2726, 2727, 2728, 2729, 2730, 2731, 2732, 2733, 2734, 2735,
+    2736, 2737, 2738, 2739, 2740, 2741, 2742, 2743, 2744, 2745,
+    2746, 2747, 2748, 2749, 2750, 2751, 2752, 2753, 2754, 2755,
+    2756, 2757, 2758, 2759, 2760, 2761, 2762, 2763, 2764, 2765,
+    2766, 2767, 2768, 2769, 2770, 2771, 2772, 2773, 2774, 2775,
+    2776, 2777, 2778, 2779, 2780, 2781, 2782, 2783, 2784, 2785,
+    2786, 2787, 2788, 2789, 2790, 2791, 2792, 2793, 2794, 2795,
+    2796, 2797, 2798, 2799, 2800, 2801, 2802, 2803, 2804, 2805,
+    2806, 2807, 2808, 2809, 2810, 2811, 2812, 2813, 2814, 2815,
+    2816, 2817, 2818, 2819, 2820, 2821, 2822, 2823, 2824, 2825,
+    2826, 2827, 2828, 2829, 2830, 2831, 2832, 2833, 2834, 2835,
+    2836, 2837, 2838, 2839, 2840, 2841, 2842, 2843, 2844, 2845,
+    2846, 2847, 2848, 2849, 2850, 2851, 2852, 2853, 2854, 2855,
+    2856, 2857, 2858, 2859, 2860, 2861, 2862, 2863, 2864, 2865,
+    2866, 2867, 2868, 2869, 2870, 2871, 2872, 2873, 2874, 2875,
+    2876, 2877, 2878, 2879, 2880, 2882, 2884, 2886, 2888, 2890,
+    2892, 2894, 2896, 2898, 2900, 2902, 2904, 2906, 2908, 2910,
+    2912, 2914, 2916, 2918, 2920, 2922, 2924, 2926, 2928, 2930,
+    2932, 2934, 2936, 2938, 2940, 2942, 2944, 2946, 2948, 2950,
+    2952, 2954, 2956, 2958, 2960, 2962, 2964, 2966, 2968, 2970,
+    2972, 2974, 2976, 2978, 2980, 2982, 2984, 2986, 2988, 2990,
+    2992, 2994, 2996, 2998, 3000, 3002, 3004, 3005, 3006, 3007,
+    3008, 3009, 3010, 3011, 3012, 3013, 3014, 3015, 3016, 3017,
+    3018, 3019, 3020, 3021, 3022, 3023, 3024, 3025, 3026, 3027,
+    3028, 3029, 3030, 3031, 3032, 3033, 3034, 3035, 3036, 3037,
+    3038, 3039, 3040, 3041, 3042, 3043, 3044, 3045, 3046, 3047,
+    3048, 3049, 3050, 3051, 3052, 3053, 3054, 3055, 3056, 3057,
+    3058, 3059, 3060, 3061, 3062, 3063, 3064, 3065, 3066, 3067,
+    3068, 3069, 3070, 3071, 3072, 3073, 3074, 3075, 3076, 3077,
+    3078, 3079, 3080, 3081, 3082, 3083, 3084, 3085, 3086, 3087,
+    3088, 3089, 3090, 3091, 3092, 3093, 3094, 3095, 3096, 3097,
+    3098, 3099, 3100, 3101, 3102, 3103, 3104, 3105, 3106, 3107,
+    3108, 3109, 3110, 3111, 3112, 3115, 3118, 3121, 3124, 3127,
+    3130, 3133, 3136, 3139, 3142, 3145, 3148, 3151, 3154, 3158,
+    3162, 3166, 3170, 3174, 3178, 3182, 3186, 3190, 3194, 3198,
+    3202, 3206, 3210, 3214, 3221, 3227, 3230, 3233, 3236, 3239,
+    3242, 3245, 3248, 3251, 3254, 3257, 3260, 3263, 3266, 3269,
+    3272, 3275, 3278, 3281, 3284, 3287, 3290, 3293, 3296, 3299,
+    3302, 3305, 3308, 3311, 3314, 3317, 3320, 3323, 3326, 3329,
+    3332, 3335, 3336, 3337, 3338, 3339, 3342, 3344, 3346, 3348,
+    3350, 3352, 3354, 3356, 3358, 3360, 3362, 3364, 3366, 3368,
+    3370, 3372, 3373, 3374, 3375, 3376, 3377, 3378, 3379, 3380,
+    3381, 3382, 3383, 3384, 3385, 3386, 3388, 3390, 3392, 3394,
+    3396, 3398, 3400, 3402, 3404, 3406, 3408, 3410, 3412, 3414,
+    3419, 3423, 3425, 3426, 3427, 3428, 3429, 3430, 3431, 3432,
+    3433, 3434, 3435, 3436, 3437, 3438, 3439, 3440, 3441, 3442,
+    3443, 3444, 3445, 3446, 3447, 3448, 3449, 3450, 3451, 3452,
+    3453, 3454, 3455, 3456, 3457, 3458, 3459, 3460, 3461, 3462,
+    3463, 3464, 3465, 3466, 3467, 3468, 3469, 3470, 3471, 3472,
+    3473, 3474, 3476, 3478, 3480, 3482, 3484, 3486, 3488, 3490,
+    3492, 3494, 3496, 3498, 3500, 3502, 3504, 3506, 3508, 3510,
+    3512, 3514, 3516, 3518, 3520, 3522, 3525, 3528, 3531, 3533,
+    3536, 3538, 3541, 3542, 3543, 3544, 3545, 3546, 3547, 3548,
+    3549, 3550, 3551, 3552, 3553, 3554, 3555, 3556, 3557, 3558,
+    3559, 3560, 3561, 3562, 3563, 3564, 3565, 3566, 3567, 3568,
+    3569, 3570, 3571, 3572, 3573, 3574, 3575, 3576, 3577, 3578,
+    3579, 3580, 3581, 3582, 3583, 3584, 3585, 3586, 3587, 3588,
+    3590, 3594, 3598, 3602, 3605, 3609, 3612, 3615, 3620, 3624,
+    3627, 3630, 3633, 3637, 3641, 3644, 3647, 3649, 3652, 3656,
+    3660, 3662, 3667, 3673, 3678, 3681, 3686, 3691, 3695, 3698,
+    3701, 3704, 3708, 3713, 3717, 3720, 3723, 3726, 3728, 3730,
+    3732, 3734, 3737, 3740, 3745, 3748, 3752, 3757, 3760, 3762,
+    3764, 3769, 3773, 3778, 3781, 3786, 3788, 3791, 3794, 3797,
+    3800, 3803, 3807, 3810, 3812, 3815, 3818, 3821, 3825, 3828,
+    3831, 3834, 3839, 3843, 3845, 3850, 3852, 3856, 3860, 3863,
+    3866, 3869, 3873, 3875, 3878, 3882, 3884, 3889, 3892, 3894,
+    3896, 3898, 3900, 3902, 3904, 3906, 3908, 3910, 3912, 3915,
+    3918, 3921, 3924, 3927, 3930, 3933, 3936, 3939, 3942, 3945,
+    3948, 3951, 3954, 3957, 3960, 3962, 3964, 3967, 3969, 3971,
+    3973, 3976, 3979, 3981, 3983, 3985, 3987, 3989, 3993, 3995,
+    3997, 3999, 4001, 4003, 4005, 4007, 4009, 4012, 4016, 4018,
+    4020, 4022, 4024, 4026, 4028, 4030, 4033, 4036, 4039, 4042,
+    4044, 4046, 4048, 4050, 4052, 4054, 4056, 4058, 4060, 4062,
+    4065, 4068, 4070, 4073, 4076, 4079, 4081, 4084, 4087, 4091,
+    4093, 4096, 4099, 4102, 4105, 4110, 4116, 4118, 4120, 4122,
+    4124, 4126, 4128, 4130, 4132, 4134, 4136, 4138, 4140, 4142,
+    4144, 4146, 4148, 4150, 4152, 4156, 4158, 4160, 4162, 4166,
+    4169, 4171, 4173, 4175, 4177, 4179, 4181, 4183, 4185, 4187,
+    4189, 4192, 4194, 4196, 4199, 4202, 4204, 4208, 4211, 4213,
+    4215, 4217, 4219, 4222, 4225, 4227, 4229, 4231, 4233, 4235,
+    4237, 4239, 4241, 4243, 4246, 4249, 4252, 4255, 4258, 4261,
+    4264, 4267, 4270, 4273, 4276, 4279, 4282, 4285, 4288, 4291,
+    4294, 4297, 4300, 4303, 4306, 4309, 4312, 4313, 4314, 4315,
+    4316, 4317, 4318, 4319, 4320, 4321, 4322, 4323, 4324, 4325,
+    4326, 4327, 4328, 4329, 4330, 4331, 4332, 4333, 4334, 4335,
+    4336, 4337, 4338, 4339, 4340, 4341, 4342, 4343, 4344, 4345,
+    4346, 4347, 4348, 4349, 4350, 4351, 4352, 4353, 4354, 4355,
+    4356, 4357, 4358, 4359, 4360, 4361, 4362, 4363, 4364, 4365,
+    4366, 4367, 4368, 4369, 4370, 4371, 4372, 4373, 4374, 4375,
+    4376, 4377, 4378, 4379, 4380, 4381, 4382, 4383, 4384, 4385,
+    4386, 4387, 4388, 4389, 4390, 4391, 4392, 4393, 4394, 4395,
+    4396, 4397, 4398, 4399, 4400, 4401, 4402, 4403, 4404, 4405,
+    4406, 4407, 4408, 4409, 4410, 4411, 4412, 4413, 4414, 4415,
+    4416, 4417, 4418, 4419, 4420, 4421, 4422, 4423, 4424, 4425,
+    4426, 4427, 4428, 4429, 4430, 4431, 4432, 4433, 4434, 4435,
+    4436, 4437, 4438, 4439, 4440, 4441, 4442, 4443, 4444, 4445,
+    4446, 4447, 4448, 4449, 4450, 4451, 4452, 4453, 4454, 4455,
+    4456, 4457, 4458, 4459, 4460, 4461, 4462, 4463, 4464, 4465,
+    4466, 4467, 4468, 4469, 4470, 4471, 4472, 4473, 4474, 4475,
+    4476, 4477, 4478, 4479, 4480, 4481, 4482, 4483, 4484, 4485,
+    4486, 4487, 4488, 4489, 4490, 4491, 4492, 4493, 4494, 4495,
+    4496, 4497, 4498, 4499, 4500, 4501, 4502, 4503, 4504, 4505,
+    4506, 4507, 4508, 4509, 4510, 4511, 4512, 4513, 4514, 4515,
+    4516, 4517, 4518, 4519, 4520, 4521, 4522, 4523, 4524, 4525,
+    4526, 4527, 4528, 4529, 4530, 4531, 4532, 4533, 4534, 4535,
+    4536, 4537, 4538, 4539, 4540, 4541, 4542, 4543, 4544, 4545,
+    4546, 4547, 4548, 4549, 4550, 4551, 4552, 4553, 4554, 4555,
+    4556, 4557, 4558, 4559, 4560, 4561, 4562, 4563, 4564, 4565,
+    4566, 4567, 4568, 4569, 4570, 4571, 4572, 4573, 4574, 4575,
+    4576, 4577, 4578, 4579, 4580, 4581, 4582, 4583, 4584, 4585,
+    4586, 4587, 4588, 4589, 4590, 4591, 4592, 4593, 4594, 4595,
+    4596, 4597, 4598, 4599, 4600, 4601, 4602, 4603, 4604, 4605,
+    4606, 4607, 4608, 4609, 4610, 4611, 4612, 4613, 4614, 4615,
+    4616, 4617, 4618, 4619, 4620, 4621, 4622, 4623, 4624, 4625,
+    4626, 4627, 4628, 4629, 4630, 4631, 4632, 4633, 4634, 4635,
+    4636, 4637, 4638, 4639, 4640, 4641, 4642, 4643, 4644, 4645,
+    4646, 4647, 4648, 4649, 4650, 4651, 4652, 4653, 4654, 4655,
+    4656, 4657, 4658, 4659, 4660, 4661, 4662, 4663, 4664, 4665,
+    4666, 4667, 4668, 4669, 4670, 4671, 4672, 4673, 4674, 4675,
+    4676, 4677, 4679, 4680, 4681, 4682, 4683, 4684, 4685, 4686,
+    4687, 4688, 4689, 4690, 4691, 4692, 4693, 4694, 4695, 4696,
+    4697, 4698, 4699, 4700, 4701, 4702, 4703, 4704, 4705, 4706,
+    4707, 4708, 4709, 4710, 4711, 4712, 4713, 4714, 4715, 4716,
+    4717, 4718, 4719, 4720, 4721, 4722, 4723, 4724, 4725, 4726,
+    4727, 4728, 4729, 4730, 4731, 4732, 4733, 4734, 4735, 4736,
+    4737, 4738, 4739, 4740, 4741, 4742, 4743, 4744, 4745, 4746,
+    4747, 4748, 4749, 4750, 4751, 4752, 4753, 4754, 4755, 4756,
+    4757, 4758, 4759, 4760, 4761, 4762, 4763, 4764, 4765, 4766,
+    4767, 4768, 4769, 4770, 4771, 4772, 4773, 4774, 4775, 4777,
+    4779, 4781, 4782, 4783, 4784, 4786, 4788, 4790, 4791, 4792,
+    4794, 4796, 4798, 4801, 4804, 4806, 4808, 4810, 4812, 4814,
+    4816, 4818, 4820, 4822, 4823, 4824, 4825, 4826, 4827, 4828,
+    4829, 4830, 4831, 4832, 4834, 4836, 4838, 4840, 4842, 4844,
+    4846, 4848, 4850, 4852, 4854, 4856, 4858, 4860, 4862, 4864,
+    4866, 4868, 4870, 4872, 4874, 4876, 4878, 4880, 4882, 4884,
+    4886, 4888, 4890, 4892, 4894, 4896, 4898, 4899, 4900, 4901,
+    4902, 4903, 4904, 4905, 4906, 4907, 4908, 4909, 4910, 4911,
+    4912, 4913, 4914, 4915, 4916, 4917, 4918, 4919, 4920, 4921,
+    4922, 4923, 4924, 4925, 4926, 4927, 4928, 4929, 4930, 4931,
+    4932, 4933, 4934, 4935, 4936, 4937, 4938, 4939, 4940, 4941,
+    4942, 4943, 4944, 4945, 4946, 4947, 4948, 4949, 4950, 4951,
+    4952, 4953, 4954, 4955, 4956, 4957, 4958, 4959, 4960, 4961,
+    4962, 4963, 4964, 4965, 4966, 4967, 4968, 4969, 4970, 4971,
+    4972, 4973, 4974, 4975, 4976, 4977, 4978, 4979, 4980, 4981,
+    4982, 4983, 4984, 4985, 4986, 4987, 4988, 4989, 4990, 4991,
+    4992, 4993, 4994, 4995, 4996, 4997, 4998, 4999, 5000, 5001,
+    5002, 5003, 5004, 5005, 5006, 5007, 5008, 5009, 5010, 5011,
+    5012, 5013, 5014, 5015, 5016, 5017, 5018, 5019, 5021, 5023,
+    5025, 5027, 5029, 5031, 5033, 5035, 5037, 5039, 5041, 5043,
+    5045, 5047, 5049, 5051, 5053, 5055, 5056, 5057, 5058, 5059,
+    5061, 5063, 5065, 5067, 5069, 5071, 5073, 5075, 5077, 5079,
+    5081, 5083, 5085, 5087, 5089, 5091, 5093, 5095, 5097, 5099,
+    5101, 5103, 5105, 5107, 5109, 5111, 5113, 5115, 5117, 5119,
+    5121, 5123, 5125, 5127, 5129, 5131, 5133, 5135, 5137, 5139,
+    5141, 5143, 5145, 5147, 5149, 5151, 5153, 5155, 5157, 5159,
+    5161, 5163, 5165, 5167, 5169, 5171, 5173, 5175, 5177, 5179,
+    5181, 5183, 5185, 5187, 5189, 5191, 5193, 5195, 5197, 5199,
+    5201, 5203, 5205, 5207, 5209, 5211, 5213, 5215, 5217, 5219,
+    5221, 5223, 5225, 5227, 5229, 5231, 5233, 5235, 5237, 5239,
+    5241, 5243, 5245, 5247, 5250, 5253, 5256, 5259, 5262, 5265,
+    5267, 5269, 5271, 5273, 5275, 5277, 5279, 5281, 5283, 5285,
+    5287, 5289, 5291, 5293, 5295, 5297, 5299, 5301, 5303, 5305,
+    5307, 5309, 5311, 5313, 5315, 5317, 5319, 5321, 5323, 5325,
+    5327, 5329, 5331, 5333, 5335, 5337, 5339, 5341, 5343, 5345,
+    5347, 5349, 5351, 5353, 5355, 5357, 5359, 5361, 5363, 5365,
+    5367, 5369, 5371, 5373, 5375, 5377, 5379, 5381, 5383, 5385,
+    5387, 5389, 5391, 5393, 5395, 5397, 5399, 5401, 5403, 5405,
+    5407, 5409, 5411, 5413, 5415, 5417, 5419, 5421, 5423, 5425,
+    5427, 5429, 5431, 5433, 5435, 5437, 5439, 5441, 5443, 5445,
+    5447, 5449, 5451, 5453, 5455, 5457, 5459, 5461, 5463, 5465,
+    5467, 5469, 5471, 5473, 5475, 5477, 5479, 5481, 5483, 5485,
+    5487, 5489, 5491, 5493, 5495, 5497, 5499, 5501, 5503, 5505,
+    5507, 5509, 5511, 5513, 5515, 5517, 5519, 5521, 5523, 5525,
+    5527, 5529, 5531, 5533, 5535, 5537, 5539, 5541, 5543, 5545,
+    5547, 5549, 5552, 5555, 5558, 5560, 5562, 5564, 5566, 5568,
+    5570, 5572, 5574, 5576, 5578, 5580, 5582, 5584, 5586, 5588,
+    5590, 5592, 5594, 5596, 5598, 5600, 5602, 5604, 5606, 5608,
+    5610, 5612, 5614, 5616, 5618, 5620, 5622, 5624, 5626, 5628,
+    5630, 5632, 5634, 5636, 5638, 5640, 5642, 5644, 5646, 5648,
+    5650, 5652, 5654, 5656, 5658, 5660, 5662, 5664, 5666, 5668,
+    5670, 5672, 5674, 5676, 5678, 5680, 5682, 5684, 5686, 5688,
+    5690, 5692, 5694, 5696, 5698, 5700, 5702, 5704, 5707, 5710,
+    5713, 5716, 5719, 5722, 5725, 5728, 5731, 5734, 5737, 5740,
+    5743, 5746, 5749, 5752, 5755, 5758, 5761, 5764, 5767, 5770,
+    5773, 5776, 5779, 5782, 5785, 5788, 5791, 5794, 5797, 5800,
+    5803, 5806, 5809, 5812, 5815, 5818, 5821, 5824, 5827, 5830,
+    5833, 5836, 5839, 5842, 5845, 5848, 5851, 5854, 5857, 5860,
+    5863, 5866, 5869, 5872, 5875, 5878, 5881, 5884, 5887, 5890,
+    5893, 5896, 5899, 5902, 5905, 5908, 5911, 5914, 5917, 5920,
+    5923, 5926, 5929, 5932, 5935, 5938, 5941, 5944, 5947, 5950,
+    5953, 5956, 5959, 5962, 5965, 5968, 5971, 5974, 5977, 5980,
+    5983, 5986, 5989, 5992, 5995, 5998, 6001, 6004, 6007, 6010,
+    6013, 6016, 6019, 6022, 6025, 6028, 6031, 6034, 6037, 6040,
+    6043, 6046, 6049, 6052, 6055, 6058, 6061, 6064, 6068, 6072,
+    6076, 6080, 6084, 6088, 6092, 6095, 6113, 6121, 6125, 6126,
+    6127, 6128, 6129, 6130, 6131, 6132, 6133, 6134, 6135, 6136,
+    6137, 6138, 6139, 6140, 6141, 6142, 6143, 6144, 6145, 6146,
+    6147, 6148, 6149, 6150, 6151, 6152, 6153, 6154, 6155, 6156,
+    6157, 6158, 6159, 6160, 6161, 6162, 6163, 6164, 6165, 6166,
+    6167, 6168, 6169, 6170, 6171, 6172, 6173, 6174, 6175, 6176,
+    6177, 6178, 6179, 6180, 6181, 6182, 6183, 6184, 6185, 6186,
+    6187, 6188, 6189, 6190, 6191, 6193, 6195, 6197, 6199, 6201,
+    6203, 6205, 6207, 6209, 6211, 6213, 6215, 6217, 6219, 6220,
+    6221, 6222, 6223, 6224, 6225, 6226, 6227, 6228, 6229, 6230,
+    6231, 6232, 6233, 6234, 6235, 6236, 6237, 6238, 6239, 6240,
+    6241, 6242, 6243, 6244, 6245, 6246, 6247, 6248, 6249, 6250,
+    6251, 6252, 6253, 6254, 6255, 6256, 6257, 6258, 6259, 6260,
+    6261, 6262, 6263, 6264, 6265, 6266, 6267, 6268, 6269, 6270,
+    6271, 6272, 6273, 6274, 6275, 6276, 6277, 6278, 6279, 6280,
+    6281, 6282, 6283, 6284, 6285, 6286, 6287, 6288, 6289, 6290,
+    6291, 6292, 6293, 6294, 6295, 6296, 6297, 6298, 6299, 6300,
+    6301, 6302, 6303, 6304, 6305, 6306, 6307, 6308, 6309, 6310,
+    6311, 6312, 6313, 6314, 6315, 6316, 6317, 6318, 6319, 6320,
+    6321, 6322, 6323, 6324, 6325, 6326, 6327, 6328, 6329, 6330,
+    6331, 6332, 6333, 6334, 6335, 6336, 6338, 6340, 6342, 6344,
+    6346, 6348, 6350, 6352, 6353, 6354, 6355, 6356, 6357, 6358,
+    6359, 6360, 6361, 6362, 6363, 6364, 6365, 6366, 6367, 6368,
+    6369, 6370, 6371, 6372, 6373, 6374, 6375, 6376, 6377, 6378,
+    6379, 6380, 6381, 6382, 6383, 6384, 6385, 6386, 6387, 6388,
+    6389, 6390, 6391, 6392, 6393, 6394, 6395, 6396, 6397, 6398,
+    6399, 6400, 6401, 6402, 6403, 6404, 6405, 6406, 6407, 6408,
+    6409, 6410, 6411, 6412, 6413, 6414, 6415, 6416, 6417, 6418,
+    6419, 6420, 6421, 6422, 6423, 6424, 6425, 6426, 6427, 6428,
+    6429, 6430, 6431, 6432, 6433, 6434, 6435, 6436, 6437, 6438,
+    6439, 6440, 6441, 6442, 6443, 6444, 6445, 6446, 6447, 6448,
+    6449, 6450, 6451, 6452, 6453, 6454, 6455, 6456, 6457, 6458,
+    6459, 6460, 6461, 6462, 6463, 6464, 6465, 6466, 6467, 6468,
+    6469, 6470, 6471, 6472, 6473, 6474, 6475, 6476, 6477, 6478,
+    6479, 6480, 6481, 6482, 6483, 6484, 6485, 6486, 6487, 6488,
+    6489, 6490, 6491, 6492, 6493, 6494, 6495, 6496, 6497, 6498,
+    6499, 6500, 6501, 6502, 6503, 6504, 6505, 6506, 6507, 6508,
+    6509, 6510, 6511, 6512, 6513, 6514, 6515, 6516, 6517, 6518,
+    6519, 6520, 6521, 6522, 6523, 6524, 6525, 6526, 6527, 6528,
+    6529, 6530, 6531, 6532, 6533, 6534, 6535, 6536, 6537, 6538,
+    6539, 6540, 6541, 6542, 6543, 6544, 6545, 6546, 6547, 6548,
+    6549, 6550, 6551, 6552, 6553, 6554, 6555, 6556, 6557, 6558,
+    6559, 6560, 6561, 6562, 6563, 6564, 6565, 6566, 6567, 6568,
+    6569, 6570, 6571, 6572, 6573, 6574, 6575, 6576, 6577, 6578,
+    6579, 6580, 6581, 6582, 6583, 6584, 6585, 6586, 6587, 6588,
+    6589, 6590, 6591, 6592, 6593, 6594, 6595, 6596, 6597, 6598,
+    6599, 6600, 6601, 6602, 6603, 6605, 6606, 6607, 6609, 6610,
+    6612, 6613, 6614, 6615, 6616, 6617, 6619, 6620, 6621, 6622,
+    6623, 6624, 6625, 6626, 6627, 6628, 6629, 6630, 6631, 6632,
+    6633, 6634, 6635, 6637, 6639, 6643, 6647, 6651, 6655, 6659,
+    6663, 6667, 6671, 6675, 6679, 6683, 6687, 6691, 6695, 6699,
+    6703, 6707, 6711, 6715, 6719, 6723, 6727, 6731, 6735, 6739,
+    6743, 6744, 6745, 6746, 6747, 6748, 6749, 6750, 6751, 6752,
+    6753, 6754, 6755, 6756, 6757, 6758, 6759, 6760, 6761, 6762,
+    6763, 6764, 6765, 6766, 6767, 6768, 6769, 6770, 6771, 6772,
+    6773, 6774, 6775, 6776, 6777, 6778, 6779, 6780, 6781, 6782,
+    6783, 6784, 6785, 6786, 6787, 6788, 6789, 6790, 6791, 6792,
+    6793, 6794, 6795, 6796, 6797, 6798, 6799, 6800, 6801, 6802,
+    6803, 6804, 6805, 6806, 6807, 6808, 6809, 6810, 6811, 6812,
+    6813, 6814, 6815, 6816, 6817, 6818, 6819, 6820, 6821, 6822,
+    6823, 6824, 6825, 6826, 6827, 6828, 6829, 6830, 6831, 6832,
+    6833, 6834, 6835, 6836, 6837, 6838, 6839, 6840, 6841, 6842,
+    6843, 6844, 6845, 6846, 6847, 6848, 6849, 6850, 6851, 6852,
+    6853, 6854, 6855, 6856, 6857, 6858, 6859, 6860, 6861, 6862,
+    6863, 6864, 6865, 6866, 6867, 6868, 6869, 6870, 6871, 6872,
+    6873, 6874, 6875, 6876, 6877, 6878, 6879, 6880, 6881, 6882,
+    6883, 6884, 6885, 6886, 6887, 6888, 6889, 6890, 6891, 6892,
+    6893, 6894, 6895, 6896, 6897, 6898, 6899, 6900, 6901, 6902,
+    6903, 6904, 6905, 6906, 6907, 6908, 6909, 6910, 6911, 6912,
+    6913, 6914, 6915, 6916, 6917, 6918, 6919, 6920, 6921, 6922,
+    6923, 6924, 6925, 6926, 6927, 6928, 6929, 6930, 6931, 6932,
+    6933, 6934, 6935, 6936, 6937, 6938, 6939, 6940, 6941, 6942,
+    6943, 6944, 6945, 6946, 6947, 6948, 6949, 6950, 6951, 6952,
+    6953, 6954, 6955, 6956, 6957, 6958, 6959, 6960, 6961, 6962,
+    6963, 6964, 6965, 6966, 6967, 6968, 6969, 6970, 6971, 6972,
+    6973, 6974, 6975, 6976, 6977, 6978, 6979, 6980, 6981, 6982,
+    6983, 6984, 6985, 6986, 6987, 6988, 6989, 6990, 6991, 6992,
+    6993, 6994, 6995, 6996, 6997, 6998, 6999, 7000, 7001, 7002,
+    7003, 7004, 7005, 7006, 7007, 7008, 7009, 7010, 7011, 7012,
+    7013, 7014, 7015, 7016, 7017, 7018, 7019, 7020, 7021, 7022,
+    7023, 7024, 7025, 7026, 7027, 7028, 7029, 7030, 7031, 7032,
+    7033, 7034, 7035, 7036, 7037, 7038, 7039, 7040, 7041, 7042,
+    7043, 7044, 7045, 7046, 7047, 7048, 7049, 7050, 7051, 7052,
+    7053, 7054, 7055, 7056, 7057, 7058, 7059, 7060, 7061, 7062,
+    7063, 7064, 7065, 7066, 7067, 7068, 7069, 7070, 7071, 7072,
+    7073, 7074, 7075, 7076, 7077, 7078, 7079, 7080, 7081, 7082,
+    7083, 7084, 7085, 7086, 7087, 7088, 7089, 7090, 7091, 7092,
+    7093, 7094, 7095, 7096, 7097, 7098, 7099, 7100, 7101, 7102,
+    7103, 7104, 7105, 7106, 7107, 7108, 7109, 7110, 7111, 7112,
+    7113, 7114, 7115, 7116, 7117, 7118, 7119, 7120, 7121, 7122,
+    7123, 7124, 7125, 7126, 7127, 7128, 7129, 7130, 7131, 7132,
+    7133, 7134, 7135, 7136, 7137, 7138, 7139, 7140, 7141, 7142,
+    7143, 7144, 7145, 7146, 7147, 7148, 7149, 7150, 7151, 7152,
+    7153, 7154, 7155, 7156, 7157, 7158, 7159, 7160, 7161, 7162,
+    7163, 7164, 7165, 7166, 7167, 7168, 7169, 7170, 7171, 7172,
+    7173, 7174, 7175, 7176, 7177, 7178, 7179, 7180, 7181, 7182,
+    7183, 7184, 7185, 7186, 7187, 7188, 7189, 7190, 7191, 7192,
+    7193, 7194, 7195, 7196, 7197, 7198, 7199, 7200, 7201, 7202,
+    7203, 7204, 7205, 7206, 7207, 7208, 7209, 7210, 7211, 7212,
+    7213, 7214, 7215, 7216, 7217, 7218, 7219, 7220, 7221, 7222,
+    7223, 7224, 7225, 7226, 7227, 7228, 7229, 7230, 7231, 7232,
+    7233, 7234, 7235, 7236, 7237, 7238, 7239, 7240, 7241, 7242,
+    7243, 7244, 7245, 7246, 7247, 7248, 7249, 7250, 7251, 7252,
+    7253, 7254, 7255, 7256, 7257, 7258, 7259, 7260, 7261, 7262,
+    7263, 7264, 7265, 7266, 7267, 7268, 7269, 7270, 7271, 7272,
+    7273, 7274, 7275, 7276, 7277, 7278, 7279, 7280, 7281, 7282,
+    7283, 7284, 7285, 7286, 7287, 7288, 7289, 7290, 7291, 7292,
+    7293, 7294, 7295, 7296, 7297, 7298, 7299, 7300, 7301, 7302,
+    7303, 7304, 7305, 7306, 7307, 7308, 7309, 7310, 7311, 7312,
+    7313, 7314, 7315, 7316, 7317, 7318, 7319, 7320, 7321, 7322,
+    7323, 7324, 7325, 7326, 7327, 7328, 7329, 7330, 7331, 7332,
+    7333, 7334, 7335, 7336, 7337, 7338, 7339, 7340, 7341, 7342,
+    7343, 7344, 7345, 7346, 7347, 7348, 7349, 7350, 7351, 7352,
+    7353, 7354, 7355, 7356, 7357, 7358, 7359, 7360, 7361, 7362,
+    7363, 7364, 7365, 7366, 7367, 7368, 7369, 7370, 7371, 7372,
+    7373, 7374, 7375, 7376, 7377, 7378, 7379, 7380, 7381, 7382,
+    7383, 7384, 7385, 7386, 7387, 7388, 7389, 7390, 7391, 7392,
+    7393, 7394, 7395, 7396, 7397, 7398, 7399, 7400, 7401, 7402,
+    7403, 7404, 7405, 7406, 7407, 7408, 7409, 7410, 7411, 7412,
+    7413, 7414, 7415, 7416, 7417, 7418, 7419, 7420, 7421, 7422,
+    7423, 7424, 7425, 7426, 7427, 7428, 7429, 7430, 7431, 7432,
+    7433, 7434, 7435, 7436, 7437, 7438, 7439, 7440, 7441, 7442,
+    7443, 7444, 7445, 7446, 7447, 7448, 7449, 7450, 7451, 7452,
+    7453, 7454, 7455, 7456, 7457, 7458, 7459, 7460, 7461, 7462,
+    7463, 7464, 7465, 7466, 7467, 7468, 7469, 7470, 7471, 7472,
+    7473, 7474, 7475, 7476, 7477, 7478, 7479, 7480, 7481, 7482,
+    7483, 7484, 7485, 7486, 7487, 7488, 7489, 7490, 7491, 7492,
+    7493, 7494, 7495, 7496, 7497, 7498, 7499, 7500, 7501, 7502,
+    7503, 7504, 7505, 7506, 7507, 7508, 7509, 7510, 7511, 7512,
+    7513, 7514, 7515, 7516, 7517, 7518, 7519, 7520, 7521, 7522,
+    7523, 7524, 7525, 7526, 7527, 7528, 7529, 7530, 7531, 7532,
+    7533, 7534, 7535, 7536, 7537, 7538, 7539, 7540, 7541, 7542,
+    7543, 7544, 7545, 7546, 7547, 7548, 7549, 7550, 7551, 7552,
+    7553, 7554, 7555, 7556, 7557, 7558, 7559, 7560, 7561, 7562,
+    7563, 7564, 7565, 7566, 7567, 7568, 7569, 7570, 7571, 7572,
+    7573, 7574, 7575, 7576, 7577, 7578, 7579, 7580, 7581, 7582,
+    7583, 7584, 7585, 7586, 7587, 7588, 7589, 7590, 7591, 7592,
+    7593, 7594, 7595, 7596, 7597, 7598, 7599, 7600, 7601, 7602,
+    7603, 7604, 7605, 7606, 7607, 7608, 7609, 7610, 7611, 7612,
+    7613, 7614, 7615, 7616, 7617, 7618, 7619, 7620, 7621, 7622,
+    7623, 7624, 7625, 7626, 7627, 7628, 7629, 7630, 7631, 7632,
+    7633, 7634, 7635, 7636, 7637, 7638, 7639, 7640, 7641, 7642,
+    7643, 7644, 7645, 7646, 7647, 7648, 7649, 7650, 7651, 7652,
+    7653, 7654, 7655, 7656, 7657, 7658, 7659, 7660, 7661, 7662,
+    7663, 7664, 7665, 7666, 7667, 7668, 7669, 7670, 7671, 7672,
+    7673, 7674, 7675, 7676, 7677, 7678, 7679, 7680, 7681, 7682,
+    7683, 7684, 7685, 7686, 7687, 7688, 7689, 7690, 7691, 7692,
+    7693, 7694, 7695, 7696, 7697, 7698, 7699, 7700, 7701, 7702,
+    7703, 7704, 7705, 7706, 7707, 7708, 7709, 7710, 7711, 7712,
+    7713, 7714, 7715, 7716, 7717, 7718, 7719, 7720, 7721, 7722,
+    7723, 7724, 7725, 7726, 7727, 7728, 7729, 7730, 7731, 7732,
+    7733, 7734, 7735, 7736, 7737, 7738, 7739, 7740, 7741, 7742,
+    7743, 7744, 7745, 7746, 7747, 7748, 7749, 7750, 7751, 7752,
+    7753, 7754, 7755, 7756, 7757, 7758, 7759, 7760, 7761, 7762,
+    7763, 7764, 7765, 7766, 7767, 7768, 7769, 7770, 7771, 7772,
+    7773, 7774, 7775, 7776, 7777, 7778, 7779, 7780, 7781, 7782,
+    7783, 7784, 7785, 7786, 7787, 7788, 7789, 7790, 7791, 7792,
+    7793, 7794, 7795, 7796, 7797, 7798, 7799, 7800, 7801, 7802,
+    7803, 7804, 7805, 7806, 7807, 7808, 7809, 7810, 7811, 7812,
+    7813, 7814, 7815, 7816, 7817, 7818, 7819, 7820, 7821, 7822,
+    7823, 7824, 7825, 7826, 7827, 7828, 7829, 7830, 7831, 7832,
+    7833, 7834, 7835, 7836, 7837, 7838, 7839, 7840, 7841, 7842,
+    7843, 7844, 7845, 7846, 7847, 7848, 7849, 7850, 7851, 7852,
+    7853, 7854, 7855, 7856, 7857, 7858, 7859, 7860, 7861, 7862,
+    7863, 7864, 7865, 7866, 7867, 7868, 7869, 7870, 7871, 7872,
+    7873, 7874, 7875, 7876, 7877, 7878, 7879, 7880, 7882, 7884,
+    7886, 7888, 7890, 7892, 7894, 7896, 7898, 7900, 7902, 7905,
+    7908, 7911, 7914, 7917, 7920, 7923, 7926, 7929, 7932, 7935,
+    7938, 7941, 7944, 7947, 7950, 7953, 7956, 7959, 7962, 7965,
+    7968, 7971, 7974, 7977, 7980, 7983, 7984, 7985, 7987, 7989,
+    7990, 7991, 7992, 7993, 7994, 7995, 7996, 7997, 7998, 7999,
+    8000, 8001, 8002, 8003, 8004, 8005, 8006, 8007, 8008, 8009,
+    8010, 8011, 8012, 8013, 8014, 8015, 8017, 8019, 8021, 8023,
+    8026, 8028, 8030, 8032, 8034, 8036, 8038, 8040, 8041, 8042,
+    8043, 8044, 8045, 8046, 8047, 8048, 8049, 8050, 8051, 8052,
+    8053, 8054, 8055, 8056, 8057, 8058, 8059, 8060, 8061, 8062,
+    8063, 8064, 8065, 8066, 8067, 8068, 8069, 8070, 8071, 8072,
+    8073, 8074, 8075, 8076, 8077, 8078, 8079, 8080, 8081, 8082,
+    8083, 8084, 8085, 8088, 8091, 8094, 8097, 8100, 8103, 8106,
+    8109, 8112, 8113, 8114, 8115, 8116, 8117, 8118, 8119, 8120,
+    8121, 8122, 8123, 8124, 8125, 8126, 8127, 8129, 8130, 8131,
+    8132, 8133, 8134, 8135, 8136, 8137, 8138, 8140, 8141, 8142,
+    8143, 8144, 8146, 8147, 8148, 8149, 8151, 8152, 8153, 8154,
+    8155, 8156, 8158, 8159, 8160, 8161, 8162, 8163, 8164, 8165,
+    8166, 8167, 8168, 8169, 8170, 8171, 8172, 8173, 8174, 8175,
+    8176, 8177, 8178, 8179, 8180, 8181, 8183, 8184, 8185, 8186,
+    8188, 8189, 8190, 8191, 8192, 8193, 8194, 8195, 8196, 8197,
+    8198, 8199, 8200, 8201, 8202, 8203, 8204, 8205, 8206, 8207,
+    8208, 8209, 8210, 8211, 8212, 8213, 8214, 8215, 8216, 8217,
+    8218, 8219, 8220, 8222, 8223, 8224, 8225, 8226, 8227, 8228,
+    8230, 8232, 8233, 8234, 8235, 8236, 8237, 8238, 8239, 8240,
+    8241, 8242, 8244, 8245, 8246, 8247, 8248, 8250, 8251, 8252,
+    8253, 8254, 8255, 8256, 8257, 8258, 8259, 8261, 8262, 8264,
+    8265, 8266, 8267, 8268, 8269, 8270, 8271, 8272, 8273, 8274,
+    8275, 8277, 8278, 8279, 8280, 8281, 8282, 8284, 8285, 8287,
+    8289, 8290, 8291, 8292, 8293, 8295, 8297, 8298, 8299, 8300,
+    8301, 8302, 8303, 8304, 8305, 8306, 8307, 8308, 8310, 8311,
+    8312, 8313, 8314, 8315, 8316, 8317, 8318, 8319, 8320, 8321,
+    8322, 8323, 8324, 8325, 8326, 8327, 8328, 8329, 8331, 8332,
+    8333, 8334, 8335, 8336, 8338, 8339, 8340, 8341, 8342, 8343,
+    8344, 8345, 8346, 8347, 8348, 8349, 8351, 8352, 8353, 8354,
+    8355, 8356, 8357, 8358, 8359, 8360, 8361, 8362, 8363, 8364,
+    8365, 8366, 8367, 8368, 8369, 8371, 8372, 8373, 8374, 8375,
+    8376, 8378, 8379, 8380, 8381, 8382, 8383, 8384, 8385, 8386,
+    8388, 8389, 8390, 8391, 8393, 8394, 8395, 8396, 8397, 8398,
+    8399, 8401, 8403, 8405, 8406, 8408, 8409, 8410, 8411, 8412,
+    8413, 8414, 8415, 8416, 8417, 8418, 8420, 8421, 8422, 8423,
+    8424, 8425, 8426, 8428, 8429, 8430, 8432, 8434, 8435, 8436,
+    8437, 8438, 8439, 8440, 8441, 8442, 8443, 8445, 8446, 8448,
+    8449, 8451, 8452, 8453, 8454, 8456, 8457, 8458, 8460, 8462,
+    8463, 8464, 8465, 8466, 8467, 8468, 8469, 8470, 8471, 8472,
+    8473, 8474, 8475, 8477, 8478, 8480, 8481, 8483, 8484, 8486,
+    8488, 8490, 8491, 8492, 8493, 8495, 8497, 8499, 8501, 8502,
+    8503, 8504, 8505, 8506, 8507, 8508, 8509, 8511, 8512, 8513,
+    8514, 8515, 8517, 8518, 8520, 8522, 8523, 8524, 8525, 8526,
+    8527, 8528, 8530, 8532, 8534, 8535, 8536, 8538, 8539, 8540,
+    8541, 8543, 8544, 8545, 8546, 8547, 8548, 8550, 8551, 8552,
+    8553, 8554, 8555, 8556, 8558, 8560, 8561, 8563, 8564, 8566,
+    8567, 8568, 8569, 8571, 8573, 8574, 8576, 8577, 8579, 8580,
+    8581, 8582, 8583, 8584, 8585, 8587, 8589, 8591, 8593, 8594,
+    8595, 8596, 8597, 8598, 8599, 8600, 8601, 8602, 8603, 8604,
+    8605, 8607, 8608, 8609, 8610, 8611, 8612, 8613, 8614, 8615,
+    8616, 8617, 8618, 8619, 8621, 8623, 8625, 8626, 8627, 8628,
+    8629, 8631, 8632, 8634, 8635, 8636, 8638, 8640, 8641, 8642,
+    8643, 8644, 8645, 8646, 8647, 8648, 8649, 8650, 8651, 8652,
+    8653, 8654, 8655, 8656, 8657, 8658, 8659, 8661, 8662, 8663,
+    8664, 8665, 8666, 8668, 8670, 8671, 8672, 8673, 8674, 8675,
+    8676, 8678, 8679, 8680, 8681, 8682, 8684, 8686, 8687, 8688,
+    8689, 8691, 8692, 8693, 8695, 8697, 8698, 8699, 8700, 8702,
+    8703, 8704, 8705, 8706, 8707, 8708, 8709, 8711, 8712, 8713,
+    8714, 8716, 8717, 8718, 8719, 8720, 8722, 8724, 8725, 8726,
+    8727, 8729, 8730, 8732, 8733, 8734, 8735, 8737, 8738, 8739,
+    8740, 8741, 8742, 8743, 8744, 8746, 8747, 8748, 8749, 8750,
+    8751, 8752, 8754, 8755, 8757, 8759, 8761, 8762, 8763, 8764,
+    8765, 8766, 8767, 8768, 8769,
+];
+
+pub(super) static DECOMPOSITION_CHARS: [u16; 8771] = [
+    0x0020, 0x0020, 0x0308, 0x0061, 0x0020, 0x0304, 0x0032, 0x0033,
+    0x0020, 0x0301, 0x03bc, 0x0020, 0x0327, 0x0031, 0x006f, 0x0031,
+    0x2044, 0x0034, 0x0031, 0x2044, 0x0032, 0x0033, 0x2044, 0x0034,
+    0x0041, 0x0300, 0x0041, 0x0301, 0x0041, 0x0302, 0x0041, 0x0303,
+    0x0041, 0x0308, 0x0041, 0x030a, 0x0043, 0x0327, 0x0045, 0x0300,
+    0x0045, 0x0301, 0x0045, 0x0302, 0x0045, 0x0308, 0x0049, 0x0300,
+    0x0049, 0x0301, 0x0049, 0x0302, 0x0049, 0x0308, 0x004e, 0x0303,
+    0x004f, 0x0300, 0x004f, 0x0301, 0x004f, 0x0302, 0x004f, 0x0303,
+    0x004f, 0x0308, 0x0055, 0x0300, 0x0055, 0x0301, 0x0055, 0x0302,
+    0x0055, 0x0308, 0x0059, 0x0301, 0x0061, 0x0300, 0x0061, 0x0301,
+    0x0061, 0x0302, 0x0061, 0x0303, 0x0061, 0x0308, 0x0061, 0x030a,
+    0x0063, 0x0327, 0x0065, 0x0300, 0x0065, 0x0301, 0x0065, 0x0302,
+    0x0065, 0x0308, 0x0069, 0x0300, 0x0069, 0x0301, 0x0069, 0x0302,
+    0x0069, 0x0308, 0x006e, 0x0303, 0x006f, 0x0300, 0x006f, 0x0301,
+    0x006f, 0x0302, 0x006f, 0x0303, 0x006f, 0x0308, 0x0075, 0x0300,
+    0x0075, 0x0301, 0x0075, 0x0302, 0x0075, 0x0308, 0x0079, 0x0301,
+    0x0079, 0x0308, 0x0041, 0x0304, 0x0061, 0x0304, 0x0041, 0x0306,
+    0x0061, 0x0306, 0x0041, 0x0328, 0x0061, 0x0328, 0x0043, 0x0301,
+    0x0063, 0x0301, 0x0043, 0x0302, 0x0063, 0x0302, 0x0043, 0x0307,
+    0x0063, 0x0307, 0x0043, 0x030c, 0x0063, 0x030c, 0x0044, 0x030c,
+    0x0064, 0x030c, 0x0045, 0x0304, 0x0065, 0x0304, 0x0045, 0x0306,
+    0x0065, 0x0306, 0x0045, 0x0307, 0x0065, 0x0307, 0x0045, 0x0328,
+    0x0065, 0x0328, 0x0045, 0x030c, 0x0065, 0x030c, 0x0047, 0x0302,
+    0x0067, 0x0302, 0x0047, 0x0306, 0x0067, 0x0306, 0x0047, 0x0307,
+    0x0067, 0x0307, 0x0047, 0x0327, 0x0067, 0x0327, 0x0048, 0x0302,
+    0x0068, 0x0302, 0x0049, 0x0303, 0x0069, 0x0303, 0x0049, 0x0304,
+    0x0069, 0x0304, 0x0049, 0x0306, 0x0069, 0x0306, 0x0049, 0x0328,
+    0x0069, 0x0328, 0x0049, 0x0307, 0x0049, 0x004a, 0x0069, 0x006a,
+    0x004a, 0x0302, 0x006a, 0x0302, 0x004b, 0x0327, 0x006b, 0x0327,
+    0x004c, 0x0301, 0x006c, 0x0301, 0x004c, 0x0327, 0x006c, 0x0327,
+    0x004c, 0x030c, 0x006c, 0x030c, 0x004c, 0x00b7, 0x006c, 0x00b7,
+    0x004e, 0x0301, 0x006e, 0x0301, 0x004e, 0x0327, 0x006e, 0x0327,
+    0x004e, 0x030c, 0x006e, 0x030c, 0x02bc, 0x006e, 0x004f, 0x0304,
+    0x006f, 0x0304, 0x004f, 0x0306, 0x006f, 0x0306, 0x004f, 0x030b,
+    0x006f, 0x030b, 0x0052, 0x0301, 0x0072, 0x0301, 0x0052, 0x0327,
+    0x0072, 0x0327, 0x0052, 0x030c, 0x0072, 0x030c, 0x0053, 0x0301,
+    0x0073, 0x0301, 0x0053, 0x0302, 0x0073, 0x0302, 0x0053, 0x0327,
+    0x0073, 0x0327, 0x0053, 0x030c, 0x0073, 0x030c, 0x0054, 0x0327,
+    0x0074, 0x0327, 0x0054, 0x030c, 0x0074, 0x030c, 0x0055, 0x0303,
+    0x0075, 0x0303, 0x0055, 0x0304, 0x0075, 0x0304, 0x0055, 0x0306,
+    0x0075, 0x0306, 0x0055, 0x030a, 0x0075, 0x030a, 0x0055, 0x030b,
+    0x0075, 0x030b, 0x0055, 0x0328, 0x0075, 0x0328, 0x0057, 0x0302,
+    0x0077, 0x0302, 0x0059, 0x0302, 0x0079, 0x0302, 0x0059, 0x0308,
+    0x005a, 0x0301, 0x007a, 0x0301, 0x005a, 0x0307, 0x007a, 0x0307,
+    0x005a, 0x030c, 0x007a, 0x030c, 0x0073, 0x004f, 0x031b, 0x006f,
+    0x031b, 0x0055, 0x031b, 0x0075, 0x031b, 0x0044, 0x017d, 0x0044,
+    0x017e, 0x0064, 0x017e, 0x004c, 0x004a, 0x004c, 0x006a, 0x006c,
+    0x006a, 0x004e, 0x004a, 0x004e, 0x006a, 0x006e, 0x006a, 0x0041,
+    0x030c, 0x0061, 0x030c, 0x0049, 0x030c, 0x0069, 0x030c, 0x004f,
+    0x030c, 0x006f, 0x030c, 0x0055, 0x030c, 0x0075, 0x030c, 0x00dc,
+    0x0304, 0x00fc, 0x0304, 0x00dc, 0x0301, 0x00fc, 0x0301, 0x00dc,
+    0x030c, 0x00fc, 0x030c, 0x00dc, 0x0300, 0x00fc, 0x0300, 0x00c4,
+    0x0304, 0x00e4, 0x0304, 0x0226, 0x0304, 0x0227, 0x0304, 0x00c6,
+    0x0304, 0x00e6, 0x0304, 0x0047, 0x030c, 0x0067, 0x030c, 0x004b,
+    0x030c, 0x006b, 0x030c, 0x004f, 0x0328, 0x006f, 0x0328, 0x01ea,
+    0x0304, 0x01eb, 0x0304, 0x01b7, 0x030c, 0x0292, 0x030c, 0x006a,
+    0x030c, 0x0044, 0x005a, 0x0044, 0x007a, 0x0064, 0x007a, 0x0047,
+    0x0301, 0x0067, 0x0301, 0x004e, 0x0300, 0x006e, 0x0300, 0x00c5,
+    0x0301, 0x00e5, 0x0301, 0x00c6, 0x0301, 0x00e6, 0x0301, 0x00d8,
+    0x0301, 0x00f8, 0x0301, 0x0041, 0x030f, 0x0061, 0x030f, 0x0041,
+    0x0311, 0x0061, 0x0311, 0x0045, 0x030f, 0x0065, 0x030f, 0x0045,
+    0x0311, 0x0065, 0x0311, 0x0049, 0x030f, 0x0069, 0x030f, 0x0049,
+    0x0311, 0x0069, 0x0311, 0x004f, 0x030f, 0x006f, 0x030f, 0x004f,
+    0x0311, 0x006f, 0x0311, 0x0052, 0x030f, 0x0072, 0x030f, 0x0052,
+    0x0311, 0x0072, 0x0311, 0x0055, 0x030f, 0x0075, 0x030f, 0x0055,
+    0x0311, 0x0075, 0x0311, 0x0053, 0x0326, 0x0073, 0x0326, 0x0054,
+    0x0326, 0x0074, 0x0326, 0x0048, 0x030c, 0x0068, 0x030c, 0x0041,
+    0x0307, 0x0061, 0x0307, 0x0045, 0x0327, 0x0065, 0x0327, 0x00d6,
+    0x0304, 0x00f6, 0x0304, 0x00d5, 0x0304, 0x00f5, 0x0304, 0x004f,
+    0x0307, 0x006f, 0x0307, 0x022e, 0x0304, 0x022f, 0x0304, 0x0059,
+    0x0304, 0x0079, 0x0304, 0x0068, 0x0266, 0x006a, 0x0072, 0x0279,
+    0x027b, 0x0281, 0x0077, 0x0079, 0x0020, 0x0306, 0x0020, 0x0307,
+    0x0020, 0x030a, 0x0020, 0x0328, 0x0020, 0x0303, 0x0020, 0x030b,
+    0x0263, 0x006c, 0x0073, 0x0078, 0x0295, 0x0300, 0x0301, 0x0313,
+    0x0308, 0x0301, 0x02b9, 0x0020, 0x0345, 0x003b, 0x0020, 0x0301,
+    0x00a8, 0x0301, 0x0391, 0x0301, 0x00b7, 0x0395, 0x0301, 0x0397,
+    0x0301, 0x0399, 0x0301, 0x039f, 0x0301, 0x03a5, 0x0301, 0x03a9,
+    0x0301, 0x03ca, 0x0301, 0x0399, 0x0308, 0x03a5, 0x0308, 0x03b1,
+    0x0301, 0x03b5, 0x0301, 0x03b7, 0x0301, 0x03b9, 0x0301, 0x03cb,
+    0x0301, 0x03b9, 0x0308, 0x03c5, 0x0308, 0x03bf, 0x0301, 0x03c5,
+    0x0301, 0x03c9, 0x0301, 0x03b2, 0x03b8, 0x03a5, 0x03d2, 0x0301,
+    0x03d2, 0x0308, 0x03c6, 0x03c0, 0x03ba, 0x03c1, 0x03c2, 0x0398,
+    0x03b5, 0x03a3, 0x0415, 0x0300, 0x0415, 0x0308, 0x0413, 0x0301,
+    0x0406, 0x0308, 0x041a, 0x0301, 0x0418, 0x0300, 0x0423, 0x0306,
+    0x0418, 0x0306, 0x0438, 0x0306, 0x0435, 0x0300, 0x0435, 0x0308,
+    0x0433, 0x0301, 0x0456, 0x0308, 0x043a, 0x0301, 0x0438, 0x0300,
+    0x0443, 0x0306, 0x0474, 0x030f, 0x0475, 0x030f, 0x0416, 0x0306,
+    0x0436, 0x0306, 0x0410, 0x0306, 0x0430, 0x0306, 0x0410, 0x0308,
+    0x0430, 0x0308, 0x0415, 0x0306, 0x0435, 0x0306, 0x04d8, 0x0308,
+    0x04d9, 0x0308, 0x0416, 0x0308, 0x0436, 0x0308, 0x0417, 0x0308,
+    0x0437, 0x0308, 0x0418, 0x0304, 0x0438, 0x0304, 0x0418, 0x0308,
+    0x0438, 0x0308, 0x041e, 0x0308, 0x043e, 0x0308, 0x04e8, 0x0308,
+    0x04e9, 0x0308, 0x042d, 0x0308, 0x044d, 0x0308, 0x0423, 0x0304,
+    0x0443, 0x0304, 0x0423, 0x0308, 0x0443, 0x0308, 0x0423, 0x030b,
+    0x0443, 0x030b, 0x0427, 0x0308, 0x0447, 0x0308, 0x042b, 0x0308,
+    0x044b, 0x0308, 0x0565, 0x0582, 0x0627, 0x0653, 0x0627, 0x0654,
+    0x0648, 0x0654, 0x0627, 0x0655, 0x064a, 0x0654, 0x0627, 0x0674,
+    0x0648, 0x0674, 0x06c7, 0x0674, 0x064a, 0x0674, 0x06d5, 0x0654,
+    0x06c1, 0x0654, 0x06d2, 0x0654, 0x0928, 0x093c, 0x0930, 0x093c,
+    0x0933, 0x093c, 0x0915, 0x093c, 0x0916, 0x093c, 0x0917, 0x093c,
+    0x091c, 0x093c, 0x0921, 0x093c, 0x0922, 0x093c, 0x092b, 0x093c,
+    0x092f, 0x093c, 0x09c7, 0x09be, 0x09c7, 0x09d7, 0x09a1, 0x09bc,
+    0x09a2, 0x09bc, 0x09af, 0x09bc, 0x0a32, 0x0a3c, 0x0a38, 0x0a3c,
+    0x0a16, 0x0a3c, 0x0a17, 0x0a3c, 0x0a1c, 0x0a3c, 0x0a2b, 0x0a3c,
+    0x0b47, 0x0b56, 0x0b47, 0x0b3e, 0x0b47, 0x0b57, 0x0b21, 0x0b3c,
+    0x0b22, 0x0b3c, 0x0b92, 0x0bd7, 0x0bc6, 0x0bbe, 0x0bc7, 0x0bbe,
+    0x0bc6, 0x0bd7, 0x0c46, 0x0c56, 0x0cbf, 0x0cd5, 0x0cc6, 0x0cd5,
+    0x0cc6, 0x0cd6, 0x0cc6, 0x0cc2, 0x0cca, 0x0cd5, 0x0d46, 0x0d3e,
+    0x0d47, 0x0d3e, 0x0d46, 0x0d57, 0x0dd9, 0x0dca, 0x0dd9, 0x0dcf,
+    0x0ddc, 0x0dca, 0x0dd9, 0x0ddf, 0x0e4d, 0x0e32, 0x0ecd, 0x0eb2,
+    0x0eab, 0x0e99, 0x0eab, 0x0ea1, 0x0f0b, 0x0f42, 0x0fb7, 0x0f4c,
+    0x0fb7, 0x0f51, 0x0fb7, 0x0f56, 0x0fb7, 0x0f5b, 0x0fb7, 0x0f40,
+    0x0fb5, 0x0f71, 0x0f72, 0x0f71, 0x0f74, 0x0fb2, 0x0f80, 0x0fb2,
+    0x0f81, 0x0fb3, 0x0f80, 0x0fb3, 0x0f81, 0x0f71, 0x0f80, 0x0f92,
+    0x0fb7, 0x0f9c, 0x0fb7, 0x0fa1, 0x0fb7, 0x0fa6, 0x0fb7, 0x0fab,
+    0x0fb7, 0x0f90, 0x0fb5, 0x1025, 0x102e, 0x10dc, 0x1b05, 0x1b35,
+    0x1b07, 0x1b35, 0x1b09, 0x1b35, 0x1b0b, 0x1b35, 0x1b0d, 0x1b35,
+    0x1b11, 0x1b35, 0x1b3a, 0x1b35, 0x1b3c, 0x1b35, 0x1b3e, 0x1b35,
+    0x1b3f, 0x1b35, 0x1b42, 0x1b35, 0x0041, 0x00c6, 0x0042, 0x0044,
+    0x0045, 0x018e, 0x0047, 0x0048, 0x0049, 0x004a, 0x004b, 0x004c,
+    0x004d, 0x004e, 0x004f, 0x0222, 0x0050, 0x0052, 0x0054, 0x0055,
+    0x0057, 0x0061, 0x0250, 0x0251, 0x1d02, 0x0062, 0x0064, 0x0065,
+    0x0259, 0x025b, 0x025c, 0x0067, 0x006b, 0x006d, 0x014b, 0x006f,
+    0x0254, 0x1d16, 0x1d17, 0x0070, 0x0074, 0x0075, 0x1d1d, 0x026f,
+    0x0076, 0x1d25, 0x03b2, 0x03b3, 0x03b4, 0x03c6, 0x03c7, 0x0069,
+    0x0072, 0x0075, 0x0076, 0x03b2, 0x03b3, 0x03c1, 0x03c6, 0x03c7,
+    0x043d, 0x0252, 0x0063, 0x0255, 0x00f0, 0x025c, 0x0066, 0x025f,
+    0x0261, 0x0265, 0x0268, 0x0269, 0x026a, 0x1d7b, 0x029d, 0x026d,
+    0x1d85, 0x029f, 0x0271, 0x0270, 0x0272, 0x0273, 0x0274, 0x0275,
+    0x0278, 0x0282, 0x0283, 0x01ab, 0x0289, 0x028a, 0x1d1c, 0x028b,
+    0x028c, 0x007a, 0x0290, 0x0291, 0x0292, 0x03b8, 0x0041, 0x0325,
+    0x0061, 0x0325, 0x0042, 0x0307, 0x0062, 0x0307, 0x0042, 0x0323,
+    0x0062, 0x0323, 0x0042, 0x0331, 0x0062, 0x0331, 0x00c7, 0x0301,
+    0x00e7, 0x0301, 0x0044, 0x0307, 0x0064, 0x0307, 0x0044, 0x0323,
+    0x0064, 0x0323, 0x0044, 0x0331, 0x0064, 0x0331, 0x0044, 0x0327,
+    0x0064, 0x0327, 0x0044, 0x032d, 0x0064, 0x032d, 0x0112, 0x0300,
+    0x0113, 0x0300, 0x0112, 0x0301, 0x0113, 0x0301, 0x0045, 0x032d,
+    0x0065, 0x032d, 0x0045, 0x0330, 0x0065, 0x0330, 0x0228, 0x0306,
+    0x0229, 0x0306, 0x0046, 0x0307, 0x0066, 0x0307, 0x0047, 0x0304,
+    0x0067, 0x0304, 0x0048, 0x0307, 0x0068, 0x0307, 0x0048, 0x0323,
+    0x0068, 0x0323, 0x0048, 0x0308, 0x0068, 0x0308, 0x0048, 0x0327,
+    0x0068, 0x0327, 0x0048, 0x032e, 0x0068, 0x032e, 0x0049, 0x0330,
+    0x0069, 0x0330, 0x00cf, 0x0301, 0x00ef, 0x0301, 0x004b, 0x0301,
+    0x006b, 0x0301, 0x004b, 0x0323, 0x006b, 0x0323, 0x004b, 0x0331,
+    0x006b, 0x0331, 0x004c, 0x0323, 0x006c, 0x0323, 0x1e36, 0x0304,
+    0x1e37, 0x0304, 0x004c, 0x0331, 0x006c, 0x0331, 0x004c, 0x032d,
+    0x006c, 0x032d, 0x004d, 0x0301, 0x006d, 0x0301, 0x004d, 0x0307,
+    0x006d, 0x0307, 0x004d, 0x0323, 0x006d, 0x0323, 0x004e, 0x0307,
+    0x006e, 0x0307, 0x004e, 0x0323, 0x006e, 0x0323, 0x004e, 0x0331,
+    0x006e, 0x0331, 0x004e, 0x032d, 0x006e, 0x032d, 0x00d5, 0x0301,
+    0x00f5, 0x0301, 0x00d5, 0x0308, 0x00f5, 0x0308, 0x014c, 0x0300,
+    0x014d, 0x0300, 0x014c, 0x0301, 0x014d, 0x0301, 0x0050, 0x0301,
+    0x0070, 0x0301, 0x0050, 0x0307, 0x0070, 0x0307, 0x0052, 0x0307,
+    0x0072, 0x0307, 0x0052, 0x0323, 0x0072, 0x0323, 0x1e5a, 0x0304,
+    0x1e5b, 0x0304, 0x0052, 0x0331, 0x0072, 0x0331, 0x0053, 0x0307,
+    0x0073, 0x0307, 0x0053, 0x0323, 0x0073, 0x0323, 0x015a, 0x0307,
+    0x015b, 0x0307, 0x0160, 0x0307, 0x0161, 0x0307, 0x1e62, 0x0307,
+    0x1e63, 0x0307, 0x0054, 0x0307, 0x0074, 0x0307, 0x0054, 0x0323,
+    0x0074, 0x0323, 0x0054, 0x0331, 0x0074, 0x0331, 0x0054, 0x032d,
+    0x0074, 0x032d, 0x0055, 0x0324, 0x0075, 0x0324, 0x0055, 0x0330,
+    0x0075, 0x0330, 0x0055, 0x032d, 0x0075, 0x032d, 0x0168, 0x0301,
+    0x0169, 0x0301, 0x016a, 0x0308, 0x016b, 0x0308, 0x0056, 0x0303,
+    0x0076, 0x0303, 0x0056, 0x0323, 0x0076, 0x0323, 0x0057, 0x0300,
+    0x0077, 0x0300, 0x0057, 0x0301, 0x0077, 0x0301, 0x0057, 0x0308,
+    0x0077, 0x0308, 0x0057, 0x0307, 0x0077, 0x0307, 0x0057, 0x0323,
+    0x0077, 0x0323, 0x0058, 0x0307, 0x0078, 0x0307, 0x0058, 0x0308,
+    0x0078, 0x0308, 0x0059, 0x0307, 0x0079, 0x0307, 0x005a, 0x0302,
+    0x007a, 0x0302, 0x005a, 0x0323, 0x007a, 0x0323, 0x005a, 0x0331,
+    0x007a, 0x0331, 0x0068, 0x0331, 0x0074, 0x0308, 0x0077, 0x030a,
+    0x0079, 0x030a, 0x0061, 0x02be, 0x017f, 0x0307, 0x0041, 0x0323,
+    0x0061, 0x0323, 0x0041, 0x0309, 0x0061, 0x0309, 0x00c2, 0x0301,
+    0x00e2, 0x0301, 0x00c2, 0x0300, 0x00e2, 0x0300, 0x00c2, 0x0309,
+    0x00e2, 0x0309, 0x00c2, 0x0303, 0x00e2, 0x0303, 0x1ea0, 0x0302,
+    0x1ea1, 0x0302, 0x0102, 0x0301, 0x0103, 0x0301, 0x0102, 0x0300,
+    0x0103, 0x0300, 0x0102, 0x0309, 0x0103, 0x0309, 0x0102, 0x0303,
+    0x0103, 0x0303, 0x1ea0, 0x0306, 0x1ea1, 0x0306, 0x0045, 0x0323,
+    0x0065, 0x0323, 0x0045, 0x0309, 0x0065, 0x0309, 0x0045, 0x0303,
+    0x0065, 0x0303, 0x00ca, 0x0301, 0x00ea, 0x0301, 0x00ca, 0x0300,
+    0x00ea, 0x0300, 0x00ca, 0x0309, 0x00ea, 0x0309, 0x00ca, 0x0303,
+    0x00ea, 0x0303, 0x1eb8, 0x0302, 0x1eb9, 0x0302, 0x0049, 0x0309,
+    0x0069, 0x0309, 0x0049, 0x0323, 0x0069, 0x0323, 0x004f, 0x0323,
+    0x006f, 0x0323, 0x004f, 0x0309, 0x006f, 0x0309, 0x00d4, 0x0301,
+    0x00f4, 0x0301, 0x00d4, 0x0300, 0x00f4, 0x0300, 0x00d4, 0x0309,
+    0x00f4, 0x0309, 0x00d4, 0x0303, 0x00f4, 0x0303, 0x1ecc, 0x0302,
+    0x1ecd, 0x0302, 0x01a0, 0x0301, 0x01a1, 0x0301, 0x01a0, 0x0300,
+    0x01a1, 0x0300, 0x01a0, 0x0309, 0x01a1, 0x0309, 0x01a0, 0x0303,
+    0x01a1, 0x0303, 0x01a0, 0x0323, 0x01a1, 0x0323, 0x0055, 0x0323,
+    0x0075, 0x0323, 0x0055, 0x0309, 0x0075, 0x0309, 0x01af, 0x0301,
+    0x01b0, 0x0301, 0x01af, 0x0300, 0x01b0, 0x0300, 0x01af, 0x0309,
+    0x01b0, 0x0309, 0x01af, 0x0303, 0x01b0, 0x0303, 0x01af, 0x0323,
+    0x01b0, 0x0323, 0x0059, 0x0300, 0x0079, 0x0300, 0x0059, 0x0323,
+    0x0079, 0x0323, 0x0059, 0x0309, 0x0079, 0x0309, 0x0059, 0x0303,
+    0x0079, 0x0303, 0x03b1, 0x0313, 0x03b1, 0x0314, 0x1f00, 0x0300,
+    0x1f01, 0x0300, 0x1f00, 0x0301, 0x1f01, 0x0301, 0x1f00, 0x0342,
+    0x1f01, 0x0342, 0x0391, 0x0313, 0x0391, 0x0314, 0x1f08, 0x0300,
+    0x1f09, 0x0300, 0x1f08, 0x0301, 0x1f09, 0x0301, 0x1f08, 0x0342,
+    0x1f09, 0x0342, 0x03b5, 0x0313, 0x03b5, 0x0314, 0x1f10, 0x0300,
+    0x1f11, 0x0300, 0x1f10, 0x0301, 0x1f11, 0x0301, 0x0395, 0x0313,
+    0x0395, 0x0314, 0x1f18, 0x0300, 0x1f19, 0x0300, 0x1f18, 0x0301,
+    0x1f19, 0x0301, 0x03b7, 0x0313, 0x03b7, 0x0314, 0x1f20, 0x0300,
+    0x1f21, 0x0300, 0x1f20, 0x0301, 0x1f21, 0x0301, 0x1f20, 0x0342,
+    0x1f21, 0x0342, 0x0397, 0x0313, 0x0397, 0x0314, 0x1f28, 0x0300,
+    0x1f29, 0x0300, 0x1f28, 0x0301, 0x1f29, 0x0301, 0x1f28, 0x0342,
+    0x1f29, 0x0342, 0x03b9, 0x0313, 0x03b9, 0x0314, 0x1f30, 0x0300,
+    0x1f31, 0x0300, 0x1f30, 0x0301, 0x1f31, 0x0301, 0x1f30, 0x0342,
+    0x1f31, 0x0342, 0x0399, 0x0313, 0x0399, 0x0314, 0x1f38, 0x0300,
+    0x1f39, 0x0300, 0x1f38, 0x0301, 0x1f39, 0x0301, 0x1f38, 0x0342,
+    0x1f39, 0x0342, 0x03bf, 0x0313, 0x03bf, 0x0314, 0x1f40, 0x0300,
+    0x1f41, 0x0300, 0x1f40, 0x0301, 0x1f41, 0x0301, 0x039f, 0x0313,
+    0x039f, 0x0314, 0x1f48, 0x0300, 0x1f49, 0x0300, 0x1f48, 0x0301,
+    0x1f49, 0x0301, 0x03c5, 0x0313, 0x03c5, 0x0314, 0x1f50, 0x0300,
+    0x1f51, 0x0300, 0x1f50, 0x0301, 0x1f51, 0x0301, 0x1f50, 0x0342,
+    0x1f51, 0x0342, 0x03a5, 0x0314, 0x1f59, 0x0300, 0x1f59, 0x0301,
+    0x1f59, 0x0342, 0x03c9, 0x0313, 0x03c9, 0x0314, 0x1f60, 0x0300,
+    0x1f61, 0x0300, 0x1f60, 0x0301, 0x1f61, 0x0301, 0x1f60, 0x0342,
+    0x1f61, 0x0342, 0x03a9, 0x0313, 0x03a9, 0x0314, 0x1f68, 0x0300,
+    0x1f69, 0x0300, 0x1f68, 0x0301, 0x1f69, 0x0301, 0x1f68, 0x0342,
+    0x1f69, 0x0342, 0x03b1, 0x0300, 0x03ac, 0x03b5, 0x0300, 0x03ad,
+    0x03b7, 0x0300, 0x03ae, 0x03b9, 0x0300, 0x03af, 0x03bf, 0x0300,
+    0x03cc, 0x03c5, 0x0300, 0x03cd, 0x03c9, 0x0300, 0x03ce, 0x1f00,
+    0x0345, 0x1f01, 0x0345, 0x1f02, 0x0345, 0x1f03, 0x0345, 0x1f04,
+    0x0345, 0x1f05, 0x0345, 0x1f06, 0x0345, 0x1f07, 0x0345, 0x1f08,
+    0x0345, 0x1f09, 0x0345, 0x1f0a, 0x0345, 0x1f0b, 0x0345, 0x1f0c,
+    0x0345, 0x1f0d, 0x0345, 0x1f0e, 0x0345, 0x1f0f, 0x0345, 0x1f20,
+    0x0345, 0x1f21, 0x0345, 0x1f22, 0x0345, 0x1f23, 0x0345, 0x1f24,
+    0x0345, 0x1f25, 0x0345, 0x1f26, 0x0345, 0x1f27, 0x0345, 0x1f28,
+    0x0345, 0x1f29, 0x0345, 0x1f2a, 0x0345, 0x1f2b, 0x0345, 0x1f2c,
+    0x0345, 0x1f2d, 0x0345, 0x1f2e, 0x0345, 0x1f2f, 0x0345, 0x1f60,
+    0x0345, 0x1f61, 0x0345, 0x1f62, 0x0345, 0x1f63, 0x0345, 0x1f64,
+    0x0345, 0x1f65, 0x0345, 0x1f66, 0x0345, 0x1f67, 0x0345, 0x1f68,
+    0x0345, 0x1f69, 0x0345, 0x1f6a, 0x0345, 0x1f6b, 0x0345, 0x1f6c,
+    0x0345, 0x1f6d, 0x0345, 0x1f6e, 0x0345, 0x1f6f, 0x0345, 0x03b1,
+    0x0306, 0x03b1, 0x0304, 0x1f70, 0x0345, 0x03b1, 0x0345, 0x03ac,
+    0x0345, 0x03b1, 0x0342, 0x1fb6, 0x0345, 0x0391, 0x0306, 0x0391,
+    0x0304, 0x0391, 0x0300, 0x0386, 0x0391, 0x0345, 0x0020, 0x0313,
+    0x03b9, 0x0020, 0x0313, 0x0020, 0x0342, 0x00a8, 0x0342, 0x1f74,
+    0x0345, 0x03b7, 0x0345, 0x03ae, 0x0345, 0x03b7, 0x0342, 0x1fc6,
+    0x0345, 0x0395, 0x0300, 0x0388, 0x0397, 0x0300, 0x0389, 0x0397,
+    0x0345, 0x1fbf, 0x0300, 0x1fbf, 0x0301, 0x1fbf, 0x0342, 0x03b9,
+    0x0306, 0x03b9, 0x0304, 0x03ca, 0x0300, 0x0390, 0x03b9, 0x0342,
+    0x03ca, 0x0342, 0x0399, 0x0306, 0x0399, 0x0304, 0x0399, 0x0300,
+    0x038a, 0x1ffe, 0x0300, 0x1ffe, 0x0301, 0x1ffe, 0x0342, 0x03c5,
+    0x0306, 0x03c5, 0x0304, 0x03cb, 0x0300, 0x03b0, 0x03c1, 0x0313,
+    0x03c1, 0x0314, 0x03c5, 0x0342, 0x03cb, 0x0342, 0x03a5, 0x0306,
+    0x03a5, 0x0304, 0x03a5, 0x0300, 0x038e, 0x03a1, 0x0314, 0x00a8,
+    0x0300, 0x0385, 0x0060, 0x1f7c, 0x0345, 0x03c9, 0x0345, 0x03ce,
+    0x0345, 0x03c9, 0x0342, 0x1ff6, 0x0345, 0x039f, 0x0300, 0x038c,
+    0x03a9, 0x0300, 0x038f, 0x03a9, 0x0345, 0x00b4, 0x0020, 0x0314,
+    0x2002, 0x2003, 0x0020, 0x0020, 0x0020, 0x0020, 0x0020, 0x0020,
+    0x0020, 0x0020, 0x0020, 0x2010, 0x0020, 0x0333, 0x002e, 0x002e,
+    0x002e, 0x002e, 0x002e, 0x002e, 0x0020, 0x2032, 0x2032, 0x2032,
+    0x2032, 0x2032, 0x2035, 0x2035, 0x2035, 0x2035, 0x2035, 0x0021,
+    0x0021, 0x0020, 0x0305, 0x003f, 0x003f, 0x003f, 0x0021, 0x0021,
+    0x003f, 0x2032, 0x2032, 0x2032, 0x2032, 0x0020, 0x0030, 0x0069,
+    0x0034, 0x0035, 0x0036, 0x0037, 0x0038, 0x0039, 0x002b, 0x2212,
+    0x003d, 0x0028, 0x0029, 0x006e, 0x0030, 0x0031, 0x0032, 0x0033,
+    0x0034, 0x0035, 0x0036, 0x0037, 0x0038, 0x0039, 0x002b, 0x2212,
+    0x003d, 0x0028, 0x0029, 0x0061, 0x0065, 0x006f, 0x0078, 0x0259,
+    0x0068, 0x006b, 0x006c, 0x006d, 0x006e, 0x0070, 0x0073, 0x0074,
+    0x0052, 0x0073, 0x0061, 0x002f, 0x0063, 0x0061, 0x002f, 0x0073,
+    0x0043, 0x00b0, 0x0043, 0x0063, 0x002f, 0x006f, 0x0063, 0x002f,
+    0x0075, 0x0190, 0x00b0, 0x0046, 0x0067, 0x0048, 0x0048, 0x0048,
+    0x0068, 0x0127, 0x0049, 0x0049, 0x004c, 0x006c, 0x004e, 0x004e,
+    0x006f, 0x0050, 0x0051, 0x0052, 0x0052, 0x0052, 0x0053, 0x004d,
+    0x0054, 0x0045, 0x004c, 0x0054, 0x004d, 0x005a, 0x03a9, 0x005a,
+    0x004b, 0x00c5, 0x0042, 0x0043, 0x0065, 0x0045, 0x0046, 0x004d,
+    0x006f, 0x05d0, 0x05d1, 0x05d2, 0x05d3, 0x0069, 0x0046, 0x0041,
+    0x0058, 0x03c0, 0x03b3, 0x0393, 0x03a0, 0x2211, 0x0044, 0x0064,
+    0x0065, 0x0069, 0x006a, 0x0031, 0x2044, 0x0037, 0x0031, 0x2044,
+    0x0039, 0x0031, 0x2044, 0x0031, 0x0030, 0x0031, 0x2044, 0x0033,
+    0x0032, 0x2044, 0x0033, 0x0031, 0x2044, 0x0035, 0x0032, 0x2044,
+    0x0035, 0x0033, 0x2044, 0x0035, 0x0034, 0x2044, 0x0035, 0x0031,
+    0x2044, 0x0036, 0x0035, 0x2044, 0x0036, 0x0031, 0x2044, 0x0038,
+    0x0033, 0x2044, 0x0038, 0x0035, 0x2044, 0x0038, 0x0037, 0x2044,
+    0x0038, 0x0031, 0x2044, 0x0049, 0x0049, 0x0049, 0x0049, 0x0049,
+    0x0049, 0x0049, 0x0056, 0x0056, 0x0056, 0x0049, 0x0056, 0x0049,
+    0x0049, 0x0056, 0x0049, 0x0049, 0x0049, 0x0049, 0x0058, 0x0058,
+    0x0058, 0x0049, 0x0058, 0x0049, 0x0049, 0x004c, 0x0043, 0x0044,
+    0x004d, 0x0069, 0x0069, 0x0069, 0x0069, 0x0069, 0x0069, 0x0069,
+    0x0076, 0x0076, 0x0076, 0x0069, 0x0076, 0x0069, 0x0069, 0x0076,
+    0x0069, 0x0069, 0x0069, 0x0069, 0x0078, 0x0078, 0x0078, 0x0069,
+    0x0078, 0x0069, 0x0069, 0x006c, 0x0063, 0x0064, 0x006d, 0x0030,
+    0x2044, 0x0033, 0x2190, 0x0338, 0x2192, 0x0338, 0x2194, 0x0338,
+    0x21d0, 0x0338, 0x21d4, 0x0338, 0x21d2, 0x0338, 0x2203, 0x0338,
+    0x2208, 0x0338, 0x220b, 0x0338, 0x2223, 0x0338, 0x2225, 0x0338,
+    0x222b, 0x222b, 0x222b, 0x222b, 0x222b, 0x222e, 0x222e, 0x222e,
+    0x222e, 0x222e, 0x223c, 0x0338, 0x2243, 0x0338, 0x2245, 0x0338,
+    0x2248, 0x0338, 0x003d, 0x0338, 0x2261, 0x0338, 0x224d, 0x0338,
+    0x003c, 0x0338, 0x003e, 0x0338, 0x2264, 0x0338, 0x2265, 0x0338,
+    0x2272, 0x0338, 0x2273, 0x0338, 0x2276, 0x0338, 0x2277, 0x0338,
+    0x227a, 0x0338, 0x227b, 0x0338, 0x2282, 0x0338, 0x2283, 0x0338,
+    0x2286, 0x0338, 0x2287, 0x0338, 0x22a2, 0x0338, 0x22a8, 0x0338,
+    0x22a9, 0x0338, 0x22ab, 0x0338, 0x227c, 0x0338, 0x227d, 0x0338,
+    0x2291, 0x0338, 0x2292, 0x0338, 0x22b2, 0x0338, 0x22b3, 0x0338,
+    0x22b4, 0x0338, 0x22b5, 0x0338, 0x3008, 0x3009, 0x0031, 0x0032,
+    0x0033, 0x0034, 0x0035, 0x0036, 0x0037, 0x0038, 0x0039, 0x0031,
+    0x0030, 0x0031, 0x0031, 0x0031, 0x0032, 0x0031, 0x0033, 0x0031,
+    0x0034, 0x0031, 0x0035, 0x0031, 0x0036, 0x0031, 0x0037, 0x0031,
+    0x0038, 0x0031, 0x0039, 0x0032, 0x0030, 0x0028, 0x0031, 0x0029,
+    0x0028, 0x0032, 0x0029, 0x0028, 0x0033, 0x0029, 0x0028, 0x0034,
+    0x0029, 0x0028, 0x0035, 0x0029, 0x0028, 0x0036, 0x0029, 0x0028,
+    0x0037, 0x0029, 0x0028, 0x0038, 0x0029, 0x0028, 0x0039, 0x0029,
+    0x0028, 0x0031, 0x0030, 0x0029, 0x0028, 0x0031, 0x0031, 0x0029,
+    0x0028, 0x0031, 0x0032, 0x0029, 0x0028, 0x0031, 0x0033, 0x0029,
+    0x0028, 0x0031, 0x0034, 0x0029, 0x0028, 0x0031, 0x0035, 0x0029,
+    0x0028, 0x0031, 0x0036, 0x0029, 0x0028, 0x0031, 0x0037, 0x0029,
+    0x0028, 0x0031, 0x0038, 0x0029, 0x0028, 0x0031, 0x0039, 0x0029,
+    0x0028, 0x0032, 0x0030, 0x0029, 0x0031, 0x002e, 0x0032, 0x002e,
+    0x0033, 0x002e, 0x0034, 0x002e, 0x0035, 0x002e, 0x0036, 0x002e,
+    0x0037, 0x002e, 0x0038, 0x002e, 0x0039, 0x002e, 0x0031, 0x0030,
+    0x002e, 0x0031, 0x0031, 0x002e, 0x0031, 0x0032, 0x002e, 0x0031,
+    0x0033, 0x002e, 0x0031, 0x0034, 0x002e, 0x0031, 0x0035, 0x002e,
+    0x0031, 0x0036, 0x002e, 0x0031, 0x0037, 0x002e, 0x0031, 0x0038,
+    0x002e, 0x0031, 0x0039, 0x002e, 0x0032, 0x0030, 0x002e, 0x0028,
+    0x0061, 0x0029, 0x0028, 0x0062, 0x0029, 0x0028, 0x0063, 0x0029,
+    0x0028, 0x0064, 0x0029, 0x0028, 0x0065, 0x0029, 0x0028, 0x0066,
+    0x0029, 0x0028, 0x0067, 0x0029, 0x0028, 0x0068, 0x0029, 0x0028,
+    0x0069, 0x0029, 0x0028, 0x006a, 0x0029, 0x0028, 0x006b, 0x0029,
+    0x0028, 0x006c, 0x0029, 0x0028, 0x006d, 0x0029, 0x0028, 0x006e,
+    0x0029, 0x0028, 0x006f, 0x0029, 0x0028, 0x0070, 0x0029, 0x0028,
+    0x0071, 0x0029, 0x0028, 0x0072, 0x0029, 0x0028, 0x0073, 0x0029,
+    0x0028, 0x0074, 0x0029, 0x0028, 0x0075, 0x0029, 0x0028, 0x0076,
+    0x0029, 0x0028, 0x0077, 0x0029, 0x0028, 0x0078, 0x0029, 0x0028,
+    0x0079, 0x0029, 0x0028, 0x007a, 0x0029, 0x0041, 0x0042, 0x0043,
+    0x0044, 0x0045, 0x0046, 0x0047, 0x0048, 0x0049, 0x004a, 0x004b,
+    0x004c, 0x004d, 0x004e, 0x004f, 0x0050, 0x0051, 0x0052, 0x0053,
+    0x0054, 0x0055, 0x0056, 0x0057, 0x0058, 0x0059, 0x005a, 0x0061,
+    0x0062, 0x0063, 0x0064, 0x0065, 0x0066, 0x0067, 0x0068, 0x0069,
+    0x006a, 0x006b, 0x006c, 0x006d, 0x006e, 0x006f, 0x0070, 0x0071,
+    0x0072, 0x0073, 0x0074, 0x0075, 0x0076, 0x0077, 0x0078, 0x0079,
+    0x007a, 0x0030, 0x222b, 0x222b, 0x222b, 0x222b, 0x003a, 0x003a,
+    0x003d, 0x003d, 0x003d, 0x003d, 0x003d, 0x003d, 0x2add, 0x0338,
+    0x006a, 0x0056, 0x2d61, 0x6bcd, 0x9f9f, 0x4e00, 0x4e28, 0x4e36,
+    0x4e3f, 0x4e59, 0x4e85, 0x4e8c, 0x4ea0, 0x4eba, 0x513f, 0x5165,
+    0x516b, 0x5182, 0x5196, 0x51ab, 0x51e0, 0x51f5, 0x5200, 0x529b,
+    0x52f9, 0x5315, 0x531a, 0x5338, 0x5341, 0x535c, 0x5369, 0x5382,
+    0x53b6, 0x53c8, 0x53e3, 0x56d7, 0x571f, 0x58eb, 0x5902, 0x590a,
+    0x5915, 0x5927, 0x5973, 0x5b50, 0x5b80, 0x5bf8, 0x5c0f, 0x5c22,
+    0x5c38, 0x5c6e, 0x5c71, 0x5ddb, 0x5de5, 0x5df1, 0x5dfe, 0x5e72,
+    0x5e7a, 0x5e7f, 0x5ef4, 0x5efe, 0x5f0b, 0x5f13, 0x5f50, 0x5f61,
+    0x5f73, 0x5fc3, 0x6208, 0x6236, 0x624b, 0x652f, 0x6534, 0x6587,
+    0x6597, 0x65a4, 0x65b9, 0x65e0, 0x65e5, 0x66f0, 0x6708, 0x6728,
+    0x6b20, 0x6b62, 0x6b79, 0x6bb3, 0x6bcb, 0x6bd4, 0x6bdb, 0x6c0f,
+    0x6c14, 0x6c34, 0x706b, 0x722a, 0x7236, 0x723b, 0x723f, 0x7247,
+    0x7259, 0x725b, 0x72ac, 0x7384, 0x7389, 0x74dc, 0x74e6, 0x7518,
+    0x751f, 0x7528, 0x7530, 0x758b, 0x7592, 0x7676, 0x767d, 0x76ae,
+    0x76bf, 0x76ee, 0x77db, 0x77e2, 0x77f3, 0x793a, 0x79b8, 0x79be,
+    0x7a74, 0x7acb, 0x7af9, 0x7c73, 0x7cf8, 0x7f36, 0x7f51, 0x7f8a,
+    0x7fbd, 0x8001, 0x800c, 0x8012, 0x8033, 0x807f, 0x8089, 0x81e3,
+    0x81ea, 0x81f3, 0x81fc, 0x820c, 0x821b, 0x821f, 0x826e, 0x8272,
+    0x8278, 0x864d, 0x866b, 0x8840, 0x884c, 0x8863, 0x897e, 0x898b,
+    0x89d2, 0x8a00, 0x8c37, 0x8c46, 0x8c55, 0x8c78, 0x8c9d, 0x8d64,
+    0x8d70, 0x8db3, 0x8eab, 0x8eca, 0x8f9b, 0x8fb0, 0x8fb5, 0x9091,
+    0x9149, 0x91c6, 0x91cc, 0x91d1, 0x9577, 0x9580, 0x961c, 0x96b6,
+    0x96b9, 0x96e8, 0x9751, 0x975e, 0x9762, 0x9769, 0x97cb, 0x97ed,
+    0x97f3, 0x9801, 0x98a8, 0x98db, 0x98df, 0x9996, 0x9999, 0x99ac,
+    0x9aa8, 0x9ad8, 0x9adf, 0x9b25, 0x9b2f, 0x9b32, 0x9b3c, 0x9b5a,
+    0x9ce5, 0x9e75, 0x9e7f, 0x9ea5, 0x9ebb, 0x9ec3, 0x9ecd, 0x9ed1,
+    0x9ef9, 0x9efd, 0x9f0e, 0x9f13, 0x9f20, 0x9f3b, 0x9f4a, 0x9f52,
+    0x9f8d, 0x9f9c, 0x9fa0, 0x0020, 0x3012, 0x5341, 0x5344, 0x5345,
+    0x304b, 0x3099, 0x304d, 0x3099, 0x304f, 0x3099, 0x3051, 0x3099,
+    0x3053, 0x3099, 0x3055, 0x3099, 0x3057, 0x3099, 0x3059, 0x3099,
+    0x305b, 0x3099, 0x305d, 0x3099, 0x305f, 0x3099, 0x3061, 0x3099,
+    0x3064, 0x3099, 0x3066, 0x3099, 0x3068, 0x3099, 0x306f, 0x3099,
+    0x306f, 0x309a, 0x3072, 0x3099, 0x3072, 0x309a, 0x3075, 0x3099,
+    0x3075, 0x309a, 0x3078, 0x3099, 0x3078, 0x309a, 0x307b, 0x3099,
+    0x307b, 0x309a, 0x3046, 0x3099, 0x0020, 0x3099, 0x0020, 0x309a,
+    0x309d, 0x3099, 0x3088, 0x308a, 0x30ab, 0x3099, 0x30ad, 0x3099,
+    0x30af, 0x3099, 0x30b1, 0x3099, 0x30b3, 0x3099, 0x30b5, 0x3099,
+    0x30b7, 0x3099, 0x30b9, 0x3099, 0x30bb, 0x3099, 0x30bd, 0x3099,
+    0x30bf, 0x3099, 0x30c1, 0x3099, 0x30c4, 0x3099, 0x30c6, 0x3099,
+    0x30c8, 0x3099, 0x30cf, 0x3099, 0x30cf, 0x309a, 0x30d2, 0x3099,
+    0x30d2, 0x309a, 0x30d5, 0x3099, 0x30d5, 0x309a, 0x30d8, 0x3099,
+    0x30d8, 0x309a, 0x30db, 0x3099, 0x30db, 0x309a, 0x30a6, 0x3099,
+    0x30ef, 0x3099, 0x30f0, 0x3099, 0x30f1, 0x3099, 0x30f2, 0x3099,
+    0x30fd, 0x3099, 0x30b3, 0x30c8, 0x1100, 0x1101, 0x11aa, 0x1102,
+    0x11ac, 0x11ad, 0x1103, 0x1104, 0x1105, 0x11b0, 0x11b1, 0x11b2,
+    0x11b3, 0x11b4, 0x11b5, 0x111a, 0x1106, 0x1107, 0x1108, 0x1121,
+    0x1109, 0x110a, 0x110b, 0x110c, 0x110d, 0x110e, 0x110f, 0x1110,
+    0x1111, 0x1112, 0x1161, 0x1162, 0x1163, 0x1164, 0x1165, 0x1166,
+    0x1167, 0x1168, 0x1169, 0x116a, 0x116b, 0x116c, 0x116d, 0x116e,
+    0x116f, 0x1170, 0x1171, 0x1172, 0x1173, 0x1174, 0x1175, 0x1160,
+    0x1114, 0x1115, 0x11c7, 0x11c8, 0x11cc, 0x11ce, 0x11d3, 0x11d7,
+    0x11d9, 0x111c, 0x11dd, 0x11df, 0x111d, 0x111e, 0x1120, 0x1122,
+    0x1123, 0x1127, 0x1129, 0x112b, 0x112c, 0x112d, 0x112e, 0x112f,
+    0x1132, 0x1136, 0x1140, 0x1147, 0x114c, 0x11f1, 0x11f2, 0x1157,
+    0x1158, 0x1159, 0x1184, 0x1185, 0x1188, 0x1191, 0x1192, 0x1194,
+    0x119e, 0x11a1, 0x4e00, 0x4e8c, 0x4e09, 0x56db, 0x4e0a, 0x4e2d,
+    0x4e0b, 0x7532, 0x4e59, 0x4e19, 0x4e01, 0x5929, 0x5730, 0x4eba,
+    0x0028, 0x1100, 0x0029, 0x0028, 0x1102, 0x0029, 0x0028, 0x1103,
+    0x0029, 0x0028, 0x1105, 0x0029, 0x0028, 0x1106, 0x0029, 0x0028,
+    0x1107, 0x0029, 0x0028, 0x1109, 0x0029, 0x0028, 0x110b, 0x0029,
+    0x0028, 0x110c, 0x0029, 0x0028, 0x110e, 0x0029, 0x0028, 0x110f,
+    0x0029, 0x0028, 0x1110, 0x0029, 0x0028, 0x1111, 0x0029, 0x0028,
+    0x1112, 0x0029, 0x0028, 0x1100, 0x1161, 0x0029, 0x0028, 0x1102,
+    0x1161, 0x0029, 0x0028, 0x1103, 0x1161, 0x0029, 0x0028, 0x1105,
+    0x1161, 0x0029, 0x0028, 0x1106, 0x1161, 0x0029, 0x0028, 0x1107,
+    0x1161, 0x0029, 0x0028, 0x1109, 0x1161, 0x0029, 0x0028, 0x110b,
+    0x1161, 0x0029, 0x0028, 0x110c, 0x1161, 0x0029, 0x0028, 0x110e,
+    0x1161, 0x0029, 0x0028, 0x110f, 0x1161, 0x0029, 0x0028, 0x1110,
+    0x1161, 0x0029, 0x0028, 0x1111, 0x1161, 0x0029, 0x0028, 0x1112,
+    0x1161, 0x0029, 0x0028, 0x110c, 0x116e, 0x0029, 0x0028, 0x110b,
+    0x1169, 0x110c, 0x1165, 0x11ab, 0x0029, 0x0028, 0x110b, 0x1169,
+    0x1112, 0x116e, 0x0029, 0x0028, 0x4e00, 0x0029, 0x0028, 0x4e8c,
+    0x0029, 0x0028, 0x4e09, 0x0029, 0x0028, 0x56db, 0x0029, 0x0028,
+    0x4e94, 0x0029, 0x0028, 0x516d, 0x0029, 0x0028, 0x4e03, 0x0029,
+    0x0028, 0x516b, 0x0029, 0x0028, 0x4e5d, 0x0029, 0x0028, 0x5341,
+    0x0029, 0x0028, 0x6708, 0x0029, 0x0028, 0x706b, 0x0029, 0x0028,
+    0x6c34, 0x0029, 0x0028, 0x6728, 0x0029, 0x0028, 0x91d1, 0x0029,
+    0x0028, 0x571f, 0x0029, 0x0028, 0x65e5, 0x0029, 0x0028, 0x682a,
+    0x0029, 0x0028, 0x6709, 0x0029, 0x0028, 0x793e, 0x0029, 0x0028,
+    0x540d, 0x0029, 0x0028, 0x7279, 0x0029, 0x0028, 0x8ca1, 0x0029,
+    0x0028, 0x795d, 0x0029, 0x0028, 0x52b4, 0x0029, 0x0028, 0x4ee3,
+    0x0029, 0x0028, 0x547c, 0x0029, 0x0028, 0x5b66, 0x0029, 0x0028,
+    0x76e3, 0x0029, 0x0028, 0x4f01, 0x0029, 0x0028, 0x8cc7, 0x0029,
+    0x0028, 0x5354, 0x0029, 0x0028, 0x796d, 0x0029, 0x0028, 0x4f11,
+    0x0029, 0x0028, 0x81ea, 0x0029, 0x0028, 0x81f3, 0x0029, 0x554f,
+    0x5e7c, 0x6587, 0x7b8f, 0x0050, 0x0054, 0x0045, 0x0032, 0x0031,
+    0x0032, 0x0032, 0x0032, 0x0033, 0x0032, 0x0034, 0x0032, 0x0035,
+    0x0032, 0x0036, 0x0032, 0x0037, 0x0032, 0x0038, 0x0032, 0x0039,
+    0x0033, 0x0030, 0x0033, 0x0031, 0x0033, 0x0032, 0x0033, 0x0033,
+    0x0033, 0x0034, 0x0033, 0x0035, 0x1100, 0x1102, 0x1103, 0x1105,
+    0x1106, 0x1107, 0x1109, 0x110b, 0x110c, 0x110e, 0x110f, 0x1110,
+    0x1111, 0x1112, 0x1100, 0x1161, 0x1102, 0x1161, 0x1103, 0x1161,
+    0x1105, 0x1161, 0x1106, 0x1161, 0x1107, 0x1161, 0x1109, 0x1161,
+    0x110b, 0x1161, 0x110c, 0x1161, 0x110e, 0x1161, 0x110f, 0x1161,
+    0x1110, 0x1161, 0x1111, 0x1161, 0x1112, 0x1161, 0x110e, 0x1161,
+    0x11b7, 0x1100, 0x1169, 0x110c, 0x116e, 0x110b, 0x1174, 0x110b,
+    0x116e, 0x4e00, 0x4e8c, 0x4e09, 0x56db, 0x4e94, 0x516d, 0x4e03,
+    0x516b, 0x4e5d, 0x5341, 0x6708, 0x706b, 0x6c34, 0x6728, 0x91d1,
+    0x571f, 0x65e5, 0x682a, 0x6709, 0x793e, 0x540d, 0x7279, 0x8ca1,
+    0x795d, 0x52b4, 0x79d8, 0x7537, 0x5973, 0x9069, 0x512a, 0x5370,
+    0x6ce8, 0x9805, 0x4f11, 0x5199, 0x6b63, 0x4e0a, 0x4e2d, 0x4e0b,
+    0x5de6, 0x53f3, 0x533b, 0x5b97, 0x5b66, 0x76e3, 0x4f01, 0x8cc7,
+    0x5354, 0x591c, 0x0033, 0x0036, 0x0033, 0x0037, 0x0033, 0x0038,
+    0x0033, 0x0039, 0x0034, 0x0030, 0x0034, 0x0031, 0x0034, 0x0032,
+    0x0034, 0x0033, 0x0034, 0x0034, 0x0034, 0x0035, 0x0034, 0x0036,
+    0x0034, 0x0037, 0x0034, 0x0038, 0x0034, 0x0039, 0x0035, 0x0030,
+    0x0031, 0x6708, 0x0032, 0x6708, 0x0033, 0x6708, 0x0034, 0x6708,
+    0x0035, 0x6708, 0x0036, 0x6708, 0x0037, 0x6708, 0x0038, 0x6708,
+    0x0039, 0x6708, 0x0031, 0x0030, 0x6708, 0x0031, 0x0031, 0x6708,
+    0x0031, 0x0032, 0x6708, 0x0048, 0x0067, 0x0065, 0x0072, 0x0067,
+    0x0065, 0x0056, 0x004c, 0x0054, 0x0044, 0x30a2, 0x30a4, 0x30a6,
+    0x30a8, 0x30aa, 0x30ab, 0x30ad, 0x30af, 0x30b1, 0x30b3, 0x30b5,
+    0x30b7, 0x30b9, 0x30bb, 0x30bd, 0x30bf, 0x30c1, 0x30c4, 0x30c6,
+    0x30c8, 0x30ca, 0x30cb, 0x30cc, 0x30cd, 0x30ce, 0x30cf, 0x30d2,
+    0x30d5, 0x30d8, 0x30db, 0x30de, 0x30df, 0x30e0, 0x30e1, 0x30e2,
+    0x30e4, 0x30e6, 0x30e8, 0x30e9, 0x30ea, 0x30eb, 0x30ec, 0x30ed,
+    0x30ef, 0x30f0, 0x30f1, 0x30f2, 0x4ee4, 0x548c, 0x30a2, 0x30d1,
+    0x30fc, 0x30c8, 0x30a2, 0x30eb, 0x30d5, 0x30a1, 0x30a2, 0x30f3,
+    0x30da, 0x30a2, 0x30a2, 0x30fc, 0x30eb, 0x30a4, 0x30cb, 0x30f3,
+    0x30b0, 0x30a4, 0x30f3, 0x30c1, 0x30a6, 0x30a9, 0x30f3, 0x30a8,
+    0x30b9, 0x30af, 0x30fc, 0x30c9, 0x30a8, 0x30fc, 0x30ab, 0x30fc,
+    0x30aa, 0x30f3, 0x30b9, 0x30aa, 0x30fc, 0x30e0, 0x30ab, 0x30a4,
+    0x30ea, 0x30ab, 0x30e9, 0x30c3, 0x30c8, 0x30ab, 0x30ed, 0x30ea,
+    0x30fc, 0x30ac, 0x30ed, 0x30f3, 0x30ac, 0x30f3, 0x30de, 0x30ae,
+    0x30ac, 0x30ae, 0x30cb, 0x30fc, 0x30ad, 0x30e5, 0x30ea, 0x30fc,
+    0x30ae, 0x30eb, 0x30c0, 0x30fc, 0x30ad, 0x30ed, 0x30ad, 0x30ed,
+    0x30b0, 0x30e9, 0x30e0, 0x30ad, 0x30ed, 0x30e1, 0x30fc, 0x30c8,
+    0x30eb, 0x30ad, 0x30ed, 0x30ef, 0x30c3, 0x30c8, 0x30b0, 0x30e9,
+    0x30e0, 0x30b0, 0x30e9, 0x30e0, 0x30c8, 0x30f3, 0x30af, 0x30eb,
+    0x30bc, 0x30a4, 0x30ed, 0x30af, 0x30ed, 0x30fc, 0x30cd, 0x30b1,
+    0x30fc, 0x30b9, 0x30b3, 0x30eb, 0x30ca, 0x30b3, 0x30fc, 0x30dd,
+    0x30b5, 0x30a4, 0x30af, 0x30eb, 0x30b5, 0x30f3, 0x30c1, 0x30fc,
+    0x30e0, 0x30b7, 0x30ea, 0x30f3, 0x30b0, 0x30bb, 0x30f3, 0x30c1,
+    0x30bb, 0x30f3, 0x30c8, 0x30c0, 0x30fc, 0x30b9, 0x30c7, 0x30b7,
+    0x30c9, 0x30eb, 0x30c8, 0x30f3, 0x30ca, 0x30ce, 0x30ce, 0x30c3,
+    0x30c8, 0x30cf, 0x30a4, 0x30c4, 0x30d1, 0x30fc, 0x30bb, 0x30f3,
+    0x30c8, 0x30d1, 0x30fc, 0x30c4, 0x30d0, 0x30fc, 0x30ec, 0x30eb,
+    0x30d4, 0x30a2, 0x30b9, 0x30c8, 0x30eb, 0x30d4, 0x30af, 0x30eb,
+    0x30d4, 0x30b3, 0x30d3, 0x30eb, 0x30d5, 0x30a1, 0x30e9, 0x30c3,
+    0x30c9, 0x30d5, 0x30a3, 0x30fc, 0x30c8, 0x30d6, 0x30c3, 0x30b7,
+    0x30a7, 0x30eb, 0x30d5, 0x30e9, 0x30f3, 0x30d8, 0x30af, 0x30bf,
+    0x30fc, 0x30eb, 0x30da, 0x30bd, 0x30da, 0x30cb, 0x30d2, 0x30d8,
+    0x30eb, 0x30c4, 0x30da, 0x30f3, 0x30b9, 0x30da, 0x30fc, 0x30b8,
+    0x30d9, 0x30fc, 0x30bf, 0x30dd, 0x30a4, 0x30f3, 0x30c8, 0x30dc,
+    0x30eb, 0x30c8, 0x30db, 0x30f3, 0x30dd, 0x30f3, 0x30c9, 0x30db,
+    0x30fc, 0x30eb, 0x30db, 0x30fc, 0x30f3, 0x30de, 0x30a4, 0x30af,
+    0x30ed, 0x30de, 0x30a4, 0x30eb, 0x30de, 0x30c3, 0x30cf, 0x30de,
+    0x30eb, 0x30af, 0x30de, 0x30f3, 0x30b7, 0x30e7, 0x30f3, 0x30df,
+    0x30af, 0x30ed, 0x30f3, 0x30df, 0x30ea, 0x30df, 0x30ea, 0x30d0,
+    0x30fc, 0x30eb, 0x30e1, 0x30ac, 0x30e1, 0x30ac, 0x30c8, 0x30f3,
+    0x30e1, 0x30fc, 0x30c8, 0x30eb, 0x30e4, 0x30fc, 0x30c9, 0x30e4,
+    0x30fc, 0x30eb, 0x30e6, 0x30a2, 0x30f3, 0x30ea, 0x30c3, 0x30c8,
+    0x30eb, 0x30ea, 0x30e9, 0x30eb, 0x30d4, 0x30fc, 0x30eb, 0x30fc,
+    0x30d6, 0x30eb, 0x30ec, 0x30e0, 0x30ec, 0x30f3, 0x30c8, 0x30b2,
+    0x30f3, 0x30ef, 0x30c3, 0x30c8, 0x0030, 0x70b9, 0x0031, 0x70b9,
+    0x0032, 0x70b9, 0x0033, 0x70b9, 0x0034, 0x70b9, 0x0035, 0x70b9,
+    0x0036, 0x70b9, 0x0037, 0x70b9, 0x0038, 0x70b9, 0x0039, 0x70b9,
+    0x0031, 0x0030, 0x70b9, 0x0031, 0x0031, 0x70b9, 0x0031, 0x0032,
+    0x70b9, 0x0031, 0x0033, 0x70b9, 0x0031, 0x0034, 0x70b9, 0x0031,
+    0x0035, 0x70b9, 0x0031, 0x0036, 0x70b9, 0x0031, 0x0037, 0x70b9,
+    0x0031, 0x0038, 0x70b9, 0x0031, 0x0039, 0x70b9, 0x0032, 0x0030,
+    0x70b9, 0x0032, 0x0031, 0x70b9, 0x0032, 0x0032, 0x70b9, 0x0032,
+    0x0033, 0x70b9, 0x0032, 0x0034, 0x70b9, 0x0068, 0x0050, 0x0061,
+    0x0064, 0x0061, 0x0041, 0x0055, 0x0062, 0x0061, 0x0072, 0x006f,
+    0x0056, 0x0070, 0x0063, 0x0064, 0x006d, 0x0064, 0x006d, 0x00b2,
+    0x0064, 0x006d, 0x00b3, 0x0049, 0x0055, 0x5e73, 0x6210, 0x662d,
+    0x548c, 0x5927, 0x6b63, 0x660e, 0x6cbb, 0x682a, 0x5f0f, 0x4f1a,
+    0x793e, 0x0070, 0x0041, 0x006e, 0x0041, 0x03bc, 0x0041, 0x006d,
+    0x0041, 0x006b, 0x0041, 0x004b, 0x0042, 0x004d, 0x0042, 0x0047,
+    0x0042, 0x0063, 0x0061, 0x006c, 0x006b, 0x0063, 0x0061, 0x006c,
+    0x0070, 0x0046, 0x006e, 0x0046, 0x03bc, 0x0046, 0x03bc, 0x0067,
+    0x006d, 0x0067, 0x006b, 0x0067, 0x0048, 0x007a, 0x006b, 0x0048,
+    0x007a, 0x004d, 0x0048, 0x007a, 0x0047, 0x0048, 0x007a, 0x0054,
+    0x0048, 0x007a, 0x03bc, 0x2113, 0x006d, 0x2113, 0x0064, 0x2113,
+    0x006b, 0x2113, 0x0066, 0x006d, 0x006e, 0x006d, 0x03bc, 0x006d,
+    0x006d, 0x006d, 0x0063, 0x006d, 0x006b, 0x006d, 0x006d, 0x006d,
+    0x00b2, 0x0063, 0x006d, 0x00b2, 0x006d, 0x00b2, 0x006b, 0x006d,
+    0x00b2, 0x006d, 0x006d, 0x00b3, 0x0063, 0x006d, 0x00b3, 0x006d,
+    0x00b3, 0x006b, 0x006d, 0x00b3, 0x006d, 0x2215, 0x0073, 0x006d,
+    0x2215, 0x0073, 0x00b2, 0x0050, 0x0061, 0x006b, 0x0050, 0x0061,
+    0x004d, 0x0050, 0x0061, 0x0047, 0x0050, 0x0061, 0x0072, 0x0061,
+    0x0064, 0x0072, 0x0061, 0x0064, 0x2215, 0x0073, 0x0072, 0x0061,
+    0x0064, 0x2215, 0x0073, 0x00b2, 0x0070, 0x0073, 0x006e, 0x0073,
+    0x03bc, 0x0073, 0x006d, 0x0073, 0x0070, 0x0056, 0x006e, 0x0056,
+    0x03bc, 0x0056, 0x006d, 0x0056, 0x006b, 0x0056, 0x004d, 0x0056,
+    0x0070, 0x0057, 0x006e, 0x0057, 0x03bc, 0x0057, 0x006d, 0x0057,
+    0x006b, 0x0057, 0x004d, 0x0057, 0x006b, 0x03a9, 0x004d, 0x03a9,
+    0x0061, 0x002e, 0x006d, 0x002e, 0x0042, 0x0071, 0x0063, 0x0063,
+    0x0063, 0x0064, 0x0043, 0x2215, 0x006b, 0x0067, 0x0043, 0x006f,
+    0x002e, 0x0064, 0x0042, 0x0047, 0x0079, 0x0068, 0x0061, 0x0048,
+    0x0050, 0x0069, 0x006e, 0x004b, 0x004b, 0x004b, 0x004d, 0x006b,
+    0x0074, 0x006c, 0x006d, 0x006c, 0x006e, 0x006c, 0x006f, 0x0067,
+    0x006c, 0x0078, 0x006d, 0x0062, 0x006d, 0x0069, 0x006c, 0x006d,
+    0x006f, 0x006c, 0x0050, 0x0048, 0x0070, 0x002e, 0x006d, 0x002e,
+    0x0050, 0x0050, 0x004d, 0x0050, 0x0052, 0x0073, 0x0072, 0x0053,
+    0x0076, 0x0057, 0x0062, 0x0056, 0x2215, 0x006d, 0x0041, 0x2215,
+    0x006d, 0x0031, 0x65e5, 0x0032, 0x65e5, 0x0033, 0x65e5, 0x0034,
+    0x65e5, 0x0035, 0x65e5, 0x0036, 0x65e5, 0x0037, 0x65e5, 0x0038,
+    0x65e5, 0x0039, 0x65e5, 0x0031, 0x0030, 0x65e5, 0x0031, 0x0031,
+    0x65e5, 0x0031, 0x0032, 0x65e5, 0x0031, 0x0033, 0x65e5, 0x0031,
+    0x0034, 0x65e5, 0x0031, 0x0035, 0x65e5, 0x0031, 0x0036, 0x65e5,
+    0x0031, 0x0037, 0x65e5, 0x0031, 0x0038, 0x65e5, 0x0031, 0x0039,
+    0x65e5, 0x0032, 0x0030, 0x65e5, 0x0032, 0x0031, 0x65e5, 0x0032,
+    0x0032, 0x65e5, 0x0032, 0x0033, 0x65e5, 0x0032, 0x0034, 0x65e5,
+    0x0032, 0x0035, 0x65e5, 0x0032, 0x0036, 0x65e5, 0x0032, 0x0037,
+    0x65e5, 0x0032, 0x0038, 0x65e5, 0x0032, 0x0039, 0x65e5, 0x0033,
+    0x0030, 0x65e5, 0x0033, 0x0031, 0x65e5, 0x0067, 0x0061, 0x006c,
+    0x044a, 0x044c, 0xa76f, 0x0043, 0x0046, 0x0051, 0x0126, 0x0153,
+    0xa727, 0xab37, 0x026b, 0xab52, 0x028d, 0x8c48, 0x66f4, 0x8eca,
+    0x8cc8, 0x6ed1, 0x4e32, 0x53e5, 0x9f9c, 0x9f9c, 0x5951, 0x91d1,
+    0x5587, 0x5948, 0x61f6, 0x7669, 0x7f85, 0x863f, 0x87ba, 0x88f8,
+    0x908f, 0x6a02, 0x6d1b, 0x70d9, 0x73de, 0x843d, 0x916a, 0x99f1,
+    0x4e82, 0x5375, 0x6b04, 0x721b, 0x862d, 0x9e1e, 0x5d50, 0x6feb,
+    0x85cd, 0x8964, 0x62c9, 0x81d8, 0x881f, 0x5eca, 0x6717, 0x6d6a,
+    0x72fc, 0x90ce, 0x4f86, 0x51b7, 0x52de, 0x64c4, 0x6ad3, 0x7210,
+    0x76e7, 0x8001, 0x8606, 0x865c, 0x8def, 0x9732, 0x9b6f, 0x9dfa,
+    0x788c, 0x797f, 0x7da0, 0x83c9, 0x9304, 0x9e7f, 0x8ad6, 0x58df,
+    0x5f04, 0x7c60, 0x807e, 0x7262, 0x78ca, 0x8cc2, 0x96f7, 0x58d8,
+    0x5c62, 0x6a13, 0x6dda, 0x6f0f, 0x7d2f, 0x7e37, 0x964b, 0x52d2,
+    0x808b, 0x51dc, 0x51cc, 0x7a1c, 0x7dbe, 0x83f1, 0x9675, 0x8b80,
+    0x62cf, 0x6a02, 0x8afe, 0x4e39, 0x5be7, 0x6012, 0x7387, 0x7570,
+    0x5317, 0x78fb, 0x4fbf, 0x5fa9, 0x4e0d, 0x6ccc, 0x6578, 0x7d22,
+    0x53c3, 0x585e, 0x7701, 0x8449, 0x8aaa, 0x6bba, 0x8fb0, 0x6c88,
+    0x62fe, 0x82e5, 0x63a0, 0x7565, 0x4eae, 0x5169, 0x51c9, 0x6881,
+    0x7ce7, 0x826f, 0x8ad2, 0x91cf, 0x52f5, 0x5442, 0x5973, 0x5eec,
+    0x65c5, 0x6ffe, 0x792a, 0x95ad, 0x9a6a, 0x9e97, 0x9ece, 0x529b,
+    0x66c6, 0x6b77, 0x8f62, 0x5e74, 0x6190, 0x6200, 0x649a, 0x6f23,
+    0x7149, 0x7489, 0x79ca, 0x7df4, 0x806f, 0x8f26, 0x84ee, 0x9023,
+    0x934a, 0x5217, 0x52a3, 0x54bd, 0x70c8, 0x88c2, 0x8aaa, 0x5ec9,
+    0x5ff5, 0x637b, 0x6bae, 0x7c3e, 0x7375, 0x4ee4, 0x56f9, 0x5be7,
+    0x5dba, 0x601c, 0x73b2, 0x7469, 0x7f9a, 0x8046, 0x9234, 0x96f6,
+    0x9748, 0x9818, 0x4f8b, 0x79ae, 0x91b4, 0x96b8, 0x60e1, 0x4e86,
+    0x50da, 0x5bee, 0x5c3f, 0x6599, 0x6a02, 0x71ce, 0x7642, 0x84fc,
+    0x907c, 0x9f8d, 0x6688, 0x962e, 0x5289, 0x677b, 0x67f3, 0x6d41,
+    0x6e9c, 0x7409, 0x7559, 0x786b, 0x7d10, 0x985e, 0x516d, 0x622e,
+    0x9678, 0x502b, 0x5d19, 0x6dea, 0x8f2a, 0x5f8b, 0x6144, 0x6817,
+    0x7387, 0x9686, 0x5229, 0x540f, 0x5c65, 0x6613, 0x674e, 0x68a8,
+    0x6ce5, 0x7406, 0x75e2, 0x7f79, 0x88cf, 0x88e1, 0x91cc, 0x96e2,
+    0x533f, 0x6eba, 0x541d, 0x71d0, 0x7498, 0x85fa, 0x96a3, 0x9c57,
+    0x9e9f, 0x6797, 0x6dcb, 0x81e8, 0x7acb, 0x7b20, 0x7c92, 0x72c0,
+    0x7099, 0x8b58, 0x4ec0, 0x8336, 0x523a, 0x5207, 0x5ea6, 0x62d3,
+    0x7cd6, 0x5b85, 0x6d1e, 0x66b4, 0x8f3b, 0x884c, 0x964d, 0x898b,
+    0x5ed3, 0x5140, 0x55c0, 0x585a, 0x6674, 0x51de, 0x732a, 0x76ca,
+    0x793c, 0x795e, 0x7965, 0x798f, 0x9756, 0x7cbe, 0x7fbd, 0x8612,
+    0x8af8, 0x9038, 0x90fd, 0x98ef, 0x98fc, 0x9928, 0x9db4, 0x90de,
+    0x96b7, 0x4fae, 0x50e7, 0x514d, 0x52c9, 0x52e4, 0x5351, 0x559d,
+    0x5606, 0x5668, 0x5840, 0x58a8, 0x5c64, 0x5c6e, 0x6094, 0x6168,
+    0x618e, 0x61f2, 0x654f, 0x65e2, 0x6691, 0x6885, 0x6d77, 0x6e1a,
+    0x6f22, 0x716e, 0x722b, 0x7422, 0x7891, 0x793e, 0x7949, 0x7948,
+    0x7950, 0x7956, 0x795d, 0x798d, 0x798e, 0x7a40, 0x7a81, 0x7bc0,
+    0x7df4, 0x7e09, 0x7e41, 0x7f72, 0x8005, 0x81ed, 0x8279, 0x8279,
+    0x8457, 0x8910, 0x8996, 0x8b01, 0x8b39, 0x8cd3, 0x8d08, 0x8fb6,
+    0x9038, 0x96e3, 0x97ff, 0x983b, 0x6075, 0xd850, 0xdeee, 0x8218,
+    0x4e26, 0x51b5, 0x5168, 0x4f80, 0x5145, 0x5180, 0x52c7, 0x52fa,
+    0x559d, 0x5555, 0x5599, 0x55e2, 0x585a, 0x58b3, 0x5944, 0x5954,
+    0x5a62, 0x5b28, 0x5ed2, 0x5ed9, 0x5f69, 0x5fad, 0x60d8, 0x614e,
+    0x6108, 0x618e, 0x6160, 0x61f2, 0x6234, 0x63c4, 0x641c, 0x6452,
+    0x6556, 0x6674, 0x6717, 0x671b, 0x6756, 0x6b79, 0x6bba, 0x6d41,
+    0x6edb, 0x6ecb, 0x6f22, 0x701e, 0x716e, 0x77a7, 0x7235, 0x72af,
+    0x732a, 0x7471, 0x7506, 0x753b, 0x761d, 0x761f, 0x76ca, 0x76db,
+    0x76f4, 0x774a, 0x7740, 0x78cc, 0x7ab1, 0x7bc0, 0x7c7b, 0x7d5b,
+    0x7df4, 0x7f3e, 0x8005, 0x8352, 0x83ef, 0x8779, 0x8941, 0x8986,
+    0x8996, 0x8abf, 0x8af8, 0x8acb, 0x8b01, 0x8afe, 0x8aed, 0x8b39,
+    0x8b8a, 0x8d08, 0x8f38, 0x9072, 0x9199, 0x9276, 0x967c, 0x96e3,
+    0x9756, 0x97db, 0x97ff, 0x980b, 0x983b, 0x9b12, 0x9f9c, 0xd84a,
+    0xdc4a, 0xd84a, 0xdc44, 0xd84c, 0xdfd5, 0x3b9d, 0x4018, 0x4039,
+    0xd854, 0xde49, 0xd857, 0xdcd0, 0xd85f, 0xded3, 0x9f43, 0x9f8e,
+    0x0066, 0x0066, 0x0066, 0x0069, 0x0066, 0x006c, 0x0066, 0x0066,
+    0x0069, 0x0066, 0x0066, 0x006c, 0x017f, 0x0074, 0x0073, 0x0074,
+    0x0574, 0x0576, 0x0574, 0x0565, 0x0574, 0x056b, 0x057e, 0x0576,
+    0x0574, 0x056d, 0x05d9, 0x05b4, 0x05f2, 0x05b7, 0x05e2, 0x05d0,
+    0x05d3, 0x05d4, 0x05db, 0x05dc, 0x05dd, 0x05e8, 0x05ea, 0x002b,
+    0x05e9, 0x05c1, 0x05e9, 0x05c2, 0xfb49, 0x05c1, 0xfb49, 0x05c2,
+    0x05d0, 0x05b7, 0x05d0, 0x05b8, 0x05d0, 0x05bc, 0x05d1, 0x05bc,
+    0x05d2, 0x05bc, 0x05d3, 0x05bc, 0x05d4, 0x05bc, 0x05d5, 0x05bc,
+    0x05d6, 0x05bc, 0x05d8, 0x05bc, 0x05d9, 0x05bc, 0x05da, 0x05bc,
+    0x05db, 0x05bc, 0x05dc, 0x05bc, 0x05de, 0x05bc, 0x05e0, 0x05bc,
+    0x05e1, 0x05bc, 0x05e3, 0x05bc, 0x05e4, 0x05bc, 0x05e6, 0x05bc,
+    0x05e7, 0x05bc, 0x05e8, 0x05bc, 0x05e9, 0x05bc, 0x05ea, 0x05bc,
+    0x05d5, 0x05b9, 0x05d1, 0x05bf, 0x05db, 0x05bf, 0x05e4, 0x05bf,
+    0x05d0, 0x05dc, 0x0671, 0x0671, 0x067b, 0x067b, 0x067b, 0x067b,
+    0x067e, 0x067e, 0x067e, 0x067e, 0x0680, 0x0680, 0x0680, 0x0680,
+    0x067a, 0x067a, 0x067a, 0x067a, 0x067f, 0x067f, 0x067f, 0x067f,
+    0x0679, 0x0679, 0x0679, 0x0679, 0x06a4, 0x06a4, 0x06a4, 0x06a4,
+    0x06a6, 0x06a6, 0x06a6, 0x06a6, 0x0684, 0x0684, 0x0684, 0x0684,
+    0x0683, 0x0683, 0x0683, 0x0683, 0x0686, 0x0686, 0x0686, 0x0686,
+    0x0687, 0x0687, 0x0687, 0x0687, 0x068d, 0x068d, 0x068c, 0x068c,
+    0x068e, 0x068e, 0x0688, 0x0688, 0x0698, 0x0698, 0x0691, 0x0691,
+    0x06a9, 0x06a9, 0x06a9, 0x06a9, 0x06af, 0x06af, 0x06af, 0x06af,
+    0x06b3, 0x06b3, 0x06b3, 0x06b3, 0x06b1, 0x06b1, 0x06b1, 0x06b1,
+    0x06ba, 0x06ba, 0x06bb, 0x06bb, 0x06bb, 0x06bb, 0x06c0, 0x06c0,
+    0x06c1, 0x06c1, 0x06c1, 0x06c1, 0x06be, 0x06be, 0x06be, 0x06be,
+    0x06d2, 0x06d2, 0x06d3, 0x06d3, 0x06ad, 0x06ad, 0x06ad, 0x06ad,
+    0x06c7, 0x06c7, 0x06c6, 0x06c6, 0x06c8, 0x06c8, 0x0677, 0x06cb,
+    0x06cb, 0x06c5, 0x06c5, 0x06c9, 0x06c9, 0x06d0, 0x06d0, 0x06d0,
+    0x06d0, 0x0649, 0x0649, 0x0626, 0x0627, 0x0626, 0x0627, 0x0626,
+    0x06d5, 0x0626, 0x06d5, 0x0626, 0x0648, 0x0626, 0x0648, 0x0626,
+    0x06c7, 0x0626, 0x06c7, 0x0626, 0x06c6, 0x0626, 0x06c6, 0x0626,
+    0x06c8, 0x0626, 0x06c8, 0x0626, 0x06d0, 0x0626, 0x06d0, 0x0626,
+    0x06d0, 0x0626, 0x0649, 0x0626, 0x0649, 0x0626, 0x0649, 0x06cc,
+    0x06cc, 0x06cc, 0x06cc, 0x0626, 0x062c, 0x0626, 0x062d, 0x0626,
+    0x0645, 0x0626, 0x0649, 0x0626, 0x064a, 0x0628, 0x062c, 0x0628,
+    0x062d, 0x0628, 0x062e, 0x0628, 0x0645, 0x0628, 0x0649, 0x0628,
+    0x064a, 0x062a, 0x062c, 0x062a, 0x062d, 0x062a, 0x062e, 0x062a,
+    0x0645, 0x062a, 0x0649, 0x062a, 0x064a, 0x062b, 0x062c, 0x062b,
+    0x0645, 0x062b, 0x0649, 0x062b, 0x064a, 0x062c, 0x062d, 0x062c,
+    0x0645, 0x062d, 0x062c, 0x062d, 0x0645, 0x062e, 0x062c, 0x062e,
+    0x062d, 0x062e, 0x0645, 0x0633, 0x062c, 0x0633, 0x062d, 0x0633,
+    0x062e, 0x0633, 0x0645, 0x0635, 0x062d, 0x0635, 0x0645, 0x0636,
+    0x062c, 0x0636, 0x062d, 0x0636, 0x062e, 0x0636, 0x0645, 0x0637,
+    0x062d, 0x0637, 0x0645, 0x0638, 0x0645, 0x0639, 0x062c, 0x0639,
+    0x0645, 0x063a, 0x062c, 0x063a, 0x0645, 0x0641, 0x062c, 0x0641,
+    0x062d, 0x0641, 0x062e, 0x0641, 0x0645, 0x0641, 0x0649, 0x0641,
+    0x064a, 0x0642, 0x062d, 0x0642, 0x0645, 0x0642, 0x0649, 0x0642,
+    0x064a, 0x0643, 0x0627, 0x0643, 0x062c, 0x0643, 0x062d, 0x0643,
+    0x062e, 0x0643, 0x0644, 0x0643, 0x0645, 0x0643, 0x0649, 0x0643,
+    0x064a, 0x0644, 0x062c, 0x0644, 0x062d, 0x0644, 0x062e, 0x0644,
+    0x0645, 0x0644, 0x0649, 0x0644, 0x064a, 0x0645, 0x062c, 0x0645,
+    0x062d, 0x0645, 0x062e, 0x0645, 0x0645, 0x0645, 0x0649, 0x0645,
+    0x064a, 0x0646, 0x062c, 0x0646, 0x062d, 0x0646, 0x062e, 0x0646,
+    0x0645, 0x0646, 0x0649, 0x0646, 0x064a, 0x0647, 0x062c, 0x0647,
+    0x0645, 0x0647, 0x0649, 0x0647, 0x064a, 0x064a, 0x062c, 0x064a,
+    0x062d, 0x064a, 0x062e, 0x064a, 0x0645, 0x064a, 0x0649, 0x064a,
+    0x064a, 0x0630, 0x0670, 0x0631, 0x0670, 0x0649, 0x0670, 0x0020,
+    0x064c, 0x0651, 0x0020, 0x064d, 0x0651, 0x0020, 0x064e, 0x0651,
+    0x0020, 0x064f, 0x0651, 0x0020, 0x0650, 0x0651, 0x0020, 0x0651,
+    0x0670, 0x0626, 0x0631, 0x0626, 0x0632, 0x0626, 0x0645, 0x0626,
+    0x0646, 0x0626, 0x0649, 0x0626, 0x064a, 0x0628, 0x0631, 0x0628,
+    0x0632, 0x0628, 0x0645, 0x0628, 0x0646, 0x0628, 0x0649, 0x0628,
+    0x064a, 0x062a, 0x0631, 0x062a, 0x0632, 0x062a, 0x0645, 0x062a,
+    0x0646, 0x062a, 0x0649, 0x062a, 0x064a, 0x062b, 0x0631, 0x062b,
+    0x0632, 0x062b, 0x0645, 0x062b, 0x0646, 0x062b, 0x0649, 0x062b,
+    0x064a, 0x0641, 0x0649, 0x0641, 0x064a, 0x0642, 0x0649, 0x0642,
+    0x064a, 0x0643, 0x0627, 0x0643, 0x0644, 0x0643, 0x0645, 0x0643,
+    0x0649, 0x0643, 0x064a, 0x0644, 0x0645, 0x0644, 0x0649, 0x0644,
+    0x064a, 0x0645, 0x0627, 0x0645, 0x0645, 0x0646, 0x0631, 0x0646,
+    0x0632, 0x0646, 0x0645, 0x0646, 0x0646, 0x0646, 0x0649, 0x0646,
+    0x064a, 0x0649, 0x0670, 0x064a, 0x0631, 0x064a, 0x0632, 0x064a,
+    0x0645, 0x064a, 0x0646, 0x064a, 0x0649, 0x064a, 0x064a, 0x0626,
+    0x062c, 0x0626, 0x062d, 0x0626, 0x062e, 0x0626, 0x0645, 0x0626,
+    0x0647, 0x0628, 0x062c, 0x0628, 0x062d, 0x0628, 0x062e, 0x0628,
+    0x0645, 0x0628, 0x0647, 0x062a, 0x062c, 0x062a, 0x062d, 0x062a,
+    0x062e, 0x062a, 0x0645, 0x062a, 0x0647, 0x062b, 0x0645, 0x062c,
+    0x062d, 0x062c, 0x0645, 0x062d, 0x062c, 0x062d, 0x0645, 0x062e,
+    0x062c, 0x062e, 0x0645, 0x0633, 0x062c, 0x0633, 0x062d, 0x0633,
+    0x062e, 0x0633, 0x0645, 0x0635, 0x062d, 0x0635, 0x062e, 0x0635,
+    0x0645, 0x0636, 0x062c, 0x0636, 0x062d, 0x0636, 0x062e, 0x0636,
+    0x0645, 0x0637, 0x062d, 0x0638, 0x0645, 0x0639, 0x062c, 0x0639,
+    0x0645, 0x063a, 0x062c, 0x063a, 0x0645, 0x0641, 0x062c, 0x0641,
+    0x062d, 0x0641, 0x062e, 0x0641, 0x0645, 0x0642, 0x062d, 0x0642,
+    0x0645, 0x0643, 0x062c, 0x0643, 0x062d, 0x0643, 0x062e, 0x0643,
+    0x0644, 0x0643, 0x0645, 0x0644, 0x062c, 0x0644, 0x062d, 0x0644,
+    0x062e, 0x0644, 0x0645, 0x0644, 0x0647, 0x0645, 0x062c, 0x0645,
+    0x062d, 0x0645, 0x062e, 0x0645, 0x0645, 0x0646, 0x062c, 0x0646,
+    0x062d, 0x0646, 0x062e, 0x0646, 0x0645, 0x0646, 0x0647, 0x0647,
+    0x062c, 0x0647, 0x0645, 0x0647, 0x0670, 0x064a, 0x062c, 0x064a,
+    0x062d, 0x064a, 0x062e, 0x064a, 0x0645, 0x064a, 0x0647, 0x0626,
+    0x0645, 0x0626, 0x0647, 0x0628, 0x0645, 0x0628, 0x0647, 0x062a,
+    0x0645, 0x062a, 0x0647, 0x062b, 0x0645, 0x062b, 0x0647, 0x0633,
+    0x0645, 0x0633, 0x0647, 0x0634, 0x0645, 0x0634, 0x0647, 0x0643,
+    0x0644, 0x0643, 0x0645, 0x0644, 0x0645, 0x0646, 0x0645, 0x0646,
+    0x0647, 0x064a, 0x0645, 0x064a, 0x0647, 0x0640, 0x064e, 0x0651,
+    0x0640, 0x064f, 0x0651, 0x0640, 0x0650, 0x0651, 0x0637, 0x0649,
+    0x0637, 0x064a, 0x0639, 0x0649, 0x0639, 0x064a, 0x063a, 0x0649,
+    0x063a, 0x064a, 0x0633, 0x0649, 0x0633, 0x064a, 0x0634, 0x0649,
+    0x0634, 0x064a, 0x062d, 0x0649, 0x062d, 0x064a, 0x062c, 0x0649,
+    0x062c, 0x064a, 0x062e, 0x0649, 0x062e, 0x064a, 0x0635, 0x0649,
+    0x0635, 0x064a, 0x0636, 0x0649, 0x0636, 0x064a, 0x0634, 0x062c,
+    0x0634, 0x062d, 0x0634, 0x062e, 0x0634, 0x0645, 0x0634, 0x0631,
+    0x0633, 0x0631, 0x0635, 0x0631, 0x0636, 0x0631, 0x0637, 0x0649,
+    0x0637, 0x064a, 0x0639, 0x0649, 0x0639, 0x064a, 0x063a, 0x0649,
+    0x063a, 0x064a, 0x0633, 0x0649, 0x0633, 0x064a, 0x0634, 0x0649,
+    0x0634, 0x064a, 0x062d, 0x0649, 0x062d, 0x064a, 0x062c, 0x0649,
+    0x062c, 0x064a, 0x062e, 0x0649, 0x062e, 0x064a, 0x0635, 0x0649,
+    0x0635, 0x064a, 0x0636, 0x0649, 0x0636, 0x064a, 0x0634, 0x062c,
+    0x0634, 0x062d, 0x0634, 0x062e, 0x0634, 0x0645, 0x0634, 0x0631,
+    0x0633, 0x0631, 0x0635, 0x0631, 0x0636, 0x0631, 0x0634, 0x062c,
+    0x0634, 0x062d, 0x0634, 0x062e, 0x0634, 0x0645, 0x0633, 0x0647,
+    0x0634, 0x0647, 0x0637, 0x0645, 0x0633, 0x062c, 0x0633, 0x062d,
+    0x0633, 0x062e, 0x0634, 0x062c, 0x0634, 0x062d, 0x0634, 0x062e,
+    0x0637, 0x0645, 0x0638, 0x0645, 0x0627, 0x064b, 0x0627, 0x064b,
+    0x062a, 0x062c, 0x0645, 0x062a, 0x062d, 0x062c, 0x062a, 0x062d,
+    0x062c, 0x062a, 0x062d, 0x0645, 0x062a, 0x062e, 0x0645, 0x062a,
+    0x0645, 0x062c, 0x062a, 0x0645, 0x062d, 0x062a, 0x0645, 0x062e,
+    0x062c, 0x0645, 0x062d, 0x062c, 0x0645, 0x062d, 0x062d, 0x0645,
+    0x064a, 0x062d, 0x0645, 0x0649, 0x0633, 0x062d, 0x062c, 0x0633,
+    0x062c, 0x062d, 0x0633, 0x062c, 0x0649, 0x0633, 0x0645, 0x062d,
+    0x0633, 0x0645, 0x062d, 0x0633, 0x0645, 0x062c, 0x0633, 0x0645,
+    0x0645, 0x0633, 0x0645, 0x0645, 0x0635, 0x062d, 0x062d, 0x0635,
+    0x062d, 0x062d, 0x0635, 0x0645, 0x0645, 0x0634, 0x062d, 0x0645,
+    0x0634, 0x062d, 0x0645, 0x0634, 0x062c, 0x064a, 0x0634, 0x0645,
+    0x062e, 0x0634, 0x0645, 0x062e, 0x0634, 0x0645, 0x0645, 0x0634,
+    0x0645, 0x0645, 0x0636, 0x062d, 0x0649, 0x0636, 0x062e, 0x0645,
+    0x0636, 0x062e, 0x0645, 0x0637, 0x0645, 0x062d, 0x0637, 0x0645,
+    0x062d, 0x0637, 0x0645, 0x0645, 0x0637, 0x0645, 0x064a, 0x0639,
+    0x062c, 0x0645, 0x0639, 0x0645, 0x0645, 0x0639, 0x0645, 0x0645,
+    0x0639, 0x0645, 0x0649, 0x063a, 0x0645, 0x0645, 0x063a, 0x0645,
+    0x064a, 0x063a, 0x0645, 0x0649, 0x0641, 0x062e, 0x0645, 0x0641,
+    0x062e, 0x0645, 0x0642, 0x0645, 0x062d, 0x0642, 0x0645, 0x0645,
+    0x0644, 0x062d, 0x0645, 0x0644, 0x062d, 0x064a, 0x0644, 0x062d,
+    0x0649, 0x0644, 0x062c, 0x062c, 0x0644, 0x062c, 0x062c, 0x0644,
+    0x062e, 0x0645, 0x0644, 0x062e, 0x0645, 0x0644, 0x0645, 0x062d,
+    0x0644, 0x0645, 0x062d, 0x0645, 0x062d, 0x062c, 0x0645, 0x062d,
+    0x0645, 0x0645, 0x062d, 0x064a, 0x0645, 0x062c, 0x062d, 0x0645,
+    0x062c, 0x0645, 0x0645, 0x062e, 0x062c, 0x0645, 0x062e, 0x0645,
+    0x0645, 0x062c, 0x062e, 0x0647, 0x0645, 0x062c, 0x0647, 0x0645,
+    0x0645, 0x0646, 0x062d, 0x0645, 0x0646, 0x062d, 0x0649, 0x0646,
+    0x062c, 0x0645, 0x0646, 0x062c, 0x0645, 0x0646, 0x062c, 0x0649,
+    0x0646, 0x0645, 0x064a, 0x0646, 0x0645, 0x0649, 0x064a, 0x0645,
+    0x0645, 0x064a, 0x0645, 0x0645, 0x0628, 0x062e, 0x064a, 0x062a,
+    0x062c, 0x064a, 0x062a, 0x062c, 0x0649, 0x062a, 0x062e, 0x064a,
+    0x062a, 0x062e, 0x0649, 0x062a, 0x0645, 0x064a, 0x062a, 0x0645,
+    0x0649, 0x062c, 0x0645, 0x064a, 0x062c, 0x062d, 0x0649, 0x062c,
+    0x0645, 0x0649, 0x0633, 0x062e, 0x0649, 0x0635, 0x062d, 0x064a,
+    0x0634, 0x062d, 0x064a, 0x0636, 0x062d, 0x064a, 0x0644, 0x062c,
+    0x064a, 0x0644, 0x0645, 0x064a, 0x064a, 0x062d, 0x064a, 0x064a,
+    0x062c, 0x064a, 0x064a, 0x0645, 0x064a, 0x0645, 0x0645, 0x064a,
+    0x0642, 0x0645, 0x064a, 0x0646, 0x062d, 0x064a, 0x0642, 0x0645,
+    0x062d, 0x0644, 0x062d, 0x0645, 0x0639, 0x0645, 0x064a, 0x0643,
+    0x0645, 0x064a, 0x0646, 0x062c, 0x062d, 0x0645, 0x062e, 0x064a,
+    0x0644, 0x062c, 0x0645, 0x0643, 0x0645, 0x0645, 0x0644, 0x062c,
+    0x0645, 0x0646, 0x062c, 0x062d, 0x062c, 0x062d, 0x064a, 0x062d,
+    0x062c, 0x064a, 0x0645, 0x062c, 0x064a, 0x0641, 0x0645, 0x064a,
+    0x0628, 0x062d, 0x064a, 0x0643, 0x0645, 0x0645, 0x0639, 0x062c,
+    0x0645, 0x0635, 0x0645, 0x0645, 0x0633, 0x062e, 0x064a, 0x0646,
+    0x062c, 0x064a, 0x0635, 0x0644, 0x06d2, 0x0642, 0x0644, 0x06d2,
+    0x0627, 0x0644, 0x0644, 0x0647, 0x0627, 0x0643, 0x0628, 0x0631,
+    0x0645, 0x062d, 0x0645, 0x062f, 0x0635, 0x0644, 0x0639, 0x0645,
+    0x0631, 0x0633, 0x0648, 0x0644, 0x0639, 0x0644, 0x064a, 0x0647,
+    0x0648, 0x0633, 0x0644, 0x0645, 0x0635, 0x0644, 0x0649, 0x0635,
+    0x0644, 0x0649, 0x0020, 0x0627, 0x0644, 0x0644, 0x0647, 0x0020,
+    0x0639, 0x0644, 0x064a, 0x0647, 0x0020, 0x0648, 0x0633, 0x0644,
+    0x0645, 0x062c, 0x0644, 0x0020, 0x062c, 0x0644, 0x0627, 0x0644,
+    0x0647, 0x0631, 0x06cc, 0x0627, 0x0644, 0x002c, 0x3001, 0x3002,
+    0x003a, 0x003b, 0x0021, 0x003f, 0x3016, 0x3017, 0x2026, 0x2025,
+    0x2014, 0x2013, 0x005f, 0x005f, 0x0028, 0x0029, 0x007b, 0x007d,
+    0x3014, 0x3015, 0x3010, 0x3011, 0x300a, 0x300b, 0x3008, 0x3009,
+    0x300c, 0x300d, 0x300e, 0x300f, 0x005b, 0x005d, 0x203e, 0x203e,
+    0x203e, 0x203e, 0x005f, 0x005f, 0x005f, 0x002c, 0x3001, 0x002e,
+    0x003b, 0x003a, 0x003f, 0x0021, 0x2014, 0x0028, 0x0029, 0x007b,
+    0x007d, 0x3014, 0x3015, 0x0023, 0x0026, 0x002a, 0x002b, 0x002d,
+    0x003c, 0x003e, 0x003d, 0x005c, 0x0024, 0x0025, 0x0040, 0x0020,
+    0x064b, 0x0640, 0x064b, 0x0020, 0x064c, 0x0020, 0x064d, 0x0020,
+    0x064e, 0x0640, 0x064e, 0x0020, 0x064f, 0x0640, 0x064f, 0x0020,
+    0x0650, 0x0640, 0x0650, 0x0020, 0x0651, 0x0640, 0x0651, 0x0020,
+    0x0652, 0x0640, 0x0652, 0x0621, 0x0622, 0x0622, 0x0623, 0x0623,
+    0x0624, 0x0624, 0x0625, 0x0625, 0x0626, 0x0626, 0x0626, 0x0626,
+    0x0627, 0x0627, 0x0628, 0x0628, 0x0628, 0x0628, 0x0629, 0x0629,
+    0x062a, 0x062a, 0x062a, 0x062a, 0x062b, 0x062b, 0x062b, 0x062b,
+    0x062c, 0x062c, 0x062c, 0x062c, 0x062d, 0x062d, 0x062d, 0x062d,
+    0x062e, 0x062e, 0x062e, 0x062e, 0x062f, 0x062f, 0x0630, 0x0630,
+    0x0631, 0x0631, 0x0632, 0x0632, 0x0633, 0x0633, 0x0633, 0x0633,
+    0x0634, 0x0634, 0x0634, 0x0634, 0x0635, 0x0635, 0x0635, 0x0635,
+    0x0636, 0x0636, 0x0636, 0x0636, 0x0637, 0x0637, 0x0637, 0x0637,
+    0x0638, 0x0638, 0x0638, 0x0638, 0x0639, 0x0639, 0x0639, 0x0639,
+    0x063a, 0x063a, 0x063a, 0x063a, 0x0641, 0x0641, 0x0641, 0x0641,
+    0x0642, 0x0642, 0x0642, 0x0642, 0x0643, 0x0643, 0x0643, 0x0643,
+    0x0644, 0x0644, 0x0644, 0x0644, 0x0645, 0x0645, 0x0645, 0x0645,
+    0x0646, 0x0646, 0x0646, 0x0646, 0x0647, 0x0647, 0x0647, 0x0647,
+    0x0648, 0x0648, 0x0649, 0x0649, 0x064a, 0x064a, 0x064a, 0x064a,
+    0x0644, 0x0622, 0x0644, 0x0622, 0x0644, 0x0623, 0x0644, 0x0623,
+    0x0644, 0x0625, 0x0644, 0x0625, 0x0644, 0x0627, 0x0644, 0x0627,
+    0x0021, 0x0022, 0x0023, 0x0024, 0x0025, 0x0026, 0x0027, 0x0028,
+    0x0029, 0x002a, 0x002b, 0x002c, 0x002d, 0x002e, 0x002f, 0x0030,
+    0x0031, 0x0032, 0x0033, 0x0034, 0x0035, 0x0036, 0x0037, 0x0038,
+    0x0039, 0x003a, 0x003b, 0x003c, 0x003d, 0x003e, 0x003f, 0x0040,
+    0x0041, 0x0042, 0x0043, 0x0044, 0x0045, 0x0046, 0x0047, 0x0048,
+    0x0049, 0x004a, 0x004b, 0x004c, 0x004d, 0x004e, 0x004f, 0x0050,
+    0x0051, 0x0052, 0x0053, 0x0054, 0x0055, 0x0056, 0x0057, 0x0058,
+    0x0059, 0x005a, 0x005b, 0x005c, 0x005d, 0x005e, 0x005f, 0x0060,
+    0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066, 0x0067, 0x0068,
+    0x0069, 0x006a, 0x006b, 0x006c, 0x006d, 0x006e, 0x006f, 0x0070,
+    0x0071, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076, 0x0077, 0x0078,
+    0x0079, 0x007a, 0x007b, 0x007c, 0x007d, 0x007e, 0x2985, 0x2986,
+    0x3002, 0x300c, 0x300d, 0x3001, 0x30fb, 0x30f2, 0x30a1, 0x30a3,
+    0x30a5, 0x30a7, 0x30a9, 0x30e3, 0x30e5, 0x30e7, 0x30c3, 0x30fc,
+    0x30a2, 0x30a4, 0x30a6, 0x30a8, 0x30aa, 0x30ab, 0x30ad, 0x30af,
+    0x30b1, 0x30b3, 0x30b5, 0x30b7, 0x30b9, 0x30bb, 0x30bd, 0x30bf,
+    0x30c1, 0x30c4, 0x30c6, 0x30c8, 0x30ca, 0x30cb, 0x30cc, 0x30cd,
+    0x30ce, 0x30cf, 0x30d2, 0x30d5, 0x30d8, 0x30db, 0x30de, 0x30df,
+    0x30e0, 0x30e1, 0x30e2, 0x30e4, 0x30e6, 0x30e8, 0x30e9, 0x30ea,
+    0x30eb, 0x30ec, 0x30ed, 0x30ef, 0x30f3, 0x3099, 0x309a, 0x3164,
+    0x3131, 0x3132, 0x3133, 0x3134, 0x3135, 0x3136, 0x3137, 0x3138,
+    0x3139, 0x313a, 0x313b, 0x313c, 0x313d, 0x313e, 0x313f, 0x3140,
+    0x3141, 0x3142, 0x3143, 0x3144, 0x3145, 0x3146, 0x3147, 0x3148,
+    0x3149, 0x314a, 0x314b, 0x314c, 0x314d, 0x314e, 0x314f, 0x3150,
+    0x3151, 0x3152, 0x3153, 0x3154, 0x3155, 0x3156, 0x3157, 0x3158,
+    0x3159, 0x315a, 0x315b, 0x315c, 0x315d, 0x315e, 0x315f, 0x3160,
+    0x3161, 0x3162, 0x3163, 0x00a2, 0x00a3, 0x00ac, 0x00af, 0x00a6,
+    0x00a5, 0x20a9, 0x2502, 0x2190, 0x2191, 0x2192, 0x2193, 0x25a0,
+    0x25cb, 0x02d0, 0x02d1, 0x00e6, 0x0299, 0x0253, 0x02a3, 0xab66,
+    0x02a5, 0x02a4, 0x0256, 0x0257, 0x1d91, 0x0258, 0x025e, 0x02a9,
+    0x0264, 0x0262, 0x0260, 0x029b, 0x0127, 0x029c, 0x0267, 0x0284,
+    0x02aa, 0x02ab, 0x026c, 0xd837, 0xdf04, 0xa78e, 0x026e, 0xd837,
+    0xdf05, 0x028e, 0xd837, 0xdf06, 0x00f8, 0x0276, 0x0277, 0x0071,
+    0x027a, 0xd837, 0xdf08, 0x027d, 0x027e, 0x0280, 0x02a8, 0x02a6,
+    0xab67, 0x02a7, 0x0288, 0x2c71, 0x028f, 0x02a1, 0x02a2, 0x0298,
+    0x01c0, 0x01c1, 0x01c2, 0xd837, 0xdf0a, 0xd837, 0xdf1e, 0xd804,
+    0xdc99, 0xd804, 0xdcba, 0xd804, 0xdc9b, 0xd804, 0xdcba, 0xd804,
+    0xdca5, 0xd804, 0xdcba, 0xd804, 0xdd31, 0xd804, 0xdd27, 0xd804,
+    0xdd32, 0xd804, 0xdd27, 0xd804, 0xdf47, 0xd804, 0xdf3e, 0xd804,
+    0xdf47, 0xd804, 0xdf57, 0xd805, 0xdcb9, 0xd805, 0xdcba, 0xd805,
+    0xdcb9, 0xd805, 0xdcb0, 0xd805, 0xdcb9, 0xd805, 0xdcbd, 0xd805,
+    0xddb8, 0xd805, 0xddaf, 0xd805, 0xddb9, 0xd805, 0xddaf, 0xd806,
+    0xdd35, 0xd806, 0xdd30, 0xd834, 0xdd57, 0xd834, 0xdd65, 0xd834,
+    0xdd58, 0xd834, 0xdd65, 0xd834, 0xdd5f, 0xd834, 0xdd6e, 0xd834,
+    0xdd5f, 0xd834, 0xdd6f, 0xd834, 0xdd5f, 0xd834, 0xdd70, 0xd834,
+    0xdd5f, 0xd834, 0xdd71, 0xd834, 0xdd5f, 0xd834, 0xdd72, 0xd834,
+    0xddb9, 0xd834, 0xdd65, 0xd834, 0xddba, 0xd834, 0xdd65, 0xd834,
+    0xddbb, 0xd834, 0xdd6e, 0xd834, 0xddbc, 0xd834, 0xdd6e, 0xd834,
+    0xddbb, 0xd834, 0xdd6f, 0xd834, 0xddbc, 0xd834, 0xdd6f, 0x0041,
+    0x0042, 0x0043, 0x0044, 0x0045, 0x0046, 0x0047, 0x0048, 0x0049,
+    0x004a, 0x004b, 0x004c, 0x004d, 0x004e, 0x004f, 0x0050, 0x0051,
+    0x0052, 0x0053, 0x0054, 0x0055, 0x0056, 0x0057, 0x0058, 0x0059,
+    0x005a, 0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066, 0x0067,
+    0x0068, 0x0069, 0x006a, 0x006b, 0x006c, 0x006d, 0x006e, 0x006f,
+    0x0070, 0x0071, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076, 0x0077,
+    0x0078, 0x0079, 0x007a, 0x0041, 0x0042, 0x0043, 0x0044, 0x0045,
+    0x0046, 0x0047, 0x0048, 0x0049, 0x004a, 0x004b, 0x004c, 0x004d,
+    0x004e, 0x004f, 0x0050, 0x0051, 0x0052, 0x0053, 0x0054, 0x0055,
+    0x0056, 0x0057, 0x0058, 0x0059, 0x005a, 0x0061, 0x0062, 0x0063,
+    0x0064, 0x0065, 0x0066, 0x0067, 0x0069, 0x006a, 0x006b, 0x006c,
+    0x006d, 0x006e, 0x006f, 0x0070, 0x0071, 0x0072, 0x0073, 0x0074,
+    0x0075, 0x0076, 0x0077, 0x0078, 0x0079, 0x007a, 0x0041, 0x0042,
+    0x0043, 0x0044, 0x0045, 0x0046, 0x0047, 0x0048, 0x0049, 0x004a,
+    0x004b, 0x004c, 0x004d, 0x004e, 0x004f, 0x0050, 0x0051, 0x0052,
+    0x0053, 0x0054, 0x0055, 0x0056, 0x0057, 0x0058, 0x0059, 0x005a,
+    0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066, 0x0067, 0x0068,
+    0x0069, 0x006a, 0x006b, 0x006c, 0x006d, 0x006e, 0x006f, 0x0070,
+    0x0071, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076, 0x0077, 0x0078,
+    0x0079, 0x007a, 0x0041, 0x0043, 0x0044, 0x0047, 0x004a, 0x004b,
+    0x004e, 0x004f, 0x0050, 0x0051, 0x0053, 0x0054, 0x0055, 0x0056,
+    0x0057, 0x0058, 0x0059, 0x005a, 0x0061, 0x0062, 0x0063, 0x0064,
+    0x0066, 0x0068, 0x0069, 0x006a, 0x006b, 0x006c, 0x006d, 0x006e,
+    0x0070, 0x0071, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076, 0x0077,
+    0x0078, 0x0079, 0x007a, 0x0041, 0x0042, 0x0043, 0x0044, 0x0045,
+    0x0046, 0x0047, 0x0048, 0x0049, 0x004a, 0x004b, 0x004c, 0x004d,
+    0x004e, 0x004f, 0x0050, 0x0051, 0x0052, 0x0053, 0x0054, 0x0055,
+    0x0056, 0x0057, 0x0058, 0x0059, 0x005a, 0x0061, 0x0062, 0x0063,
+    0x0064, 0x0065, 0x0066, 0x0067, 0x0068, 0x0069, 0x006a, 0x006b,
+    0x006c, 0x006d, 0x006e, 0x006f, 0x0070, 0x0071, 0x0072, 0x0073,
+    0x0074, 0x0075, 0x0076, 0x0077, 0x0078, 0x0079, 0x007a, 0x0041,
+    0x0042, 0x0044, 0x0045, 0x0046, 0x0047, 0x004a, 0x004b, 0x004c,
+    0x004d, 0x004e, 0x004f, 0x0050, 0x0051, 0x0053, 0x0054, 0x0055,
+    0x0056, 0x0057, 0x0058, 0x0059, 0x0061, 0x0062, 0x0063, 0x0064,
+    0x0065, 0x0066, 0x0067, 0x0068, 0x0069, 0x006a, 0x006b, 0x006c,
+    0x006d, 0x006e, 0x006f, 0x0070, 0x0071, 0x0072, 0x0073, 0x0074,
+    0x0075, 0x0076, 0x0077, 0x0078, 0x0079, 0x007a, 0x0041, 0x0042,
+    0x0044, 0x0045, 0x0046, 0x0047, 0x0049, 0x004a, 0x004b, 0x004c,
+    0x004d, 0x004f, 0x0053, 0x0054, 0x0055, 0x0056, 0x0057, 0x0058,
+    0x0059, 0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066, 0x0067,
+    0x0068, 0x0069, 0x006a, 0x006b, 0x006c, 0x006d, 0x006e, 0x006f,
+    0x0070, 0x0071, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076, 0x0077,
+    0x0078, 0x0079, 0x007a, 0x0041, 0x0042, 0x0043, 0x0044, 0x0045,
+    0x0046, 0x0047, 0x0048, 0x0049, 0x004a, 0x004b, 0x004c, 0x004d,
+    0x004e, 0x004f, 0x0050, 0x0051, 0x0052, 0x0053, 0x0054, 0x0055,
+    0x0056, 0x0057, 0x0058, 0x0059, 0x005a, 0x0061, 0x0062, 0x0063,
+    0x0064, 0x0065, 0x0066, 0x0067, 0x0068, 0x0069, 0x006a, 0x006b,
+    0x006c, 0x006d, 0x006e, 0x006f, 0x0070, 0x0071, 0x0072, 0x0073,
+    0x0074, 0x0075, 0x0076, 0x0077, 0x0078, 0x0079, 0x007a, 0x0041,
+    0x0042, 0x0043, 0x0044, 0x0045, 0x0046, 0x0047, 0x0048, 0x0049,
+    0x004a, 0x004b, 0x004c, 0x004d, 0x004e, 0x004f, 0x0050, 0x0051,
+    0x0052, 0x0053, 0x0054, 0x0055, 0x0056, 0x0057, 0x0058, 0x0059,
+    0x005a, 0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066, 0x0067,
+    0x0068, 0x0069, 0x006a, 0x006b, 0x006c, 0x006d, 0x006e, 0x006f,
+    0x0070, 0x0071, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076, 0x0077,
+    0x0078, 0x0079, 0x007a, 0x0041, 0x0042, 0x0043, 0x0044, 0x0045,
+    0x0046, 0x0047, 0x0048, 0x0049, 0x004a, 0x004b, 0x004c, 0x004d,
+    0x004e, 0x004f, 0x0050, 0x0051, 0x0052, 0x0053, 0x0054, 0x0055,
+    0x0056, 0x0057, 0x0058, 0x0059, 0x005a, 0x0061, 0x0062, 0x0063,
+    0x0064, 0x0065, 0x0066, 0x0067, 0x0068, 0x0069, 0x006a, 0x006b,
+    0x006c, 0x006d, 0x006e, 0x006f, 0x0070, 0x0071, 0x0072, 0x0073,
+    0x0074, 0x0075, 0x0076, 0x0077, 0x0078, 0x0079, 0x007a, 0x0041,
+    0x0042, 0x0043, 0x0044, 0x0045, 0x0046, 0x0047, 0x0048, 0x0049,
+    0x004a, 0x004b, 0x004c, 0x004d, 0x004e, 0x004f, 0x0050, 0x0051,
+    0x0052, 0x0053, 0x0054, 0x0055, 0x0056, 0x0057, 0x0058, 0x0059,
+    0x005a, 0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066, 0x0067,
+    0x0068, 0x0069, 0x006a, 0x006b, 0x006c, 0x006d, 0x006e, 0x006f,
+    0x0070, 0x0071, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076, 0x0077,
+    0x0078, 0x0079, 0x007a, 0x0041, 0x0042, 0x0043, 0x0044, 0x0045,
+    0x0046, 0x0047, 0x0048, 0x0049, 0x004a, 0x004b, 0x004c, 0x004d,
+    0x004e, 0x004f, 0x0050, 0x0051, 0x0052, 0x0053, 0x0054, 0x0055,
+    0x0056, 0x0057, 0x0058, 0x0059, 0x005a, 0x0061, 0x0062, 0x0063,
+    0x0064, 0x0065, 0x0066, 0x0067, 0x0068, 0x0069, 0x006a, 0x006b,
+    0x006c, 0x006d, 0x006e, 0x006f, 0x0070, 0x0071, 0x0072, 0x0073,
+    0x0074, 0x0075, 0x0076, 0x0077, 0x0078, 0x0079, 0x007a, 0x0041,
+    0x0042, 0x0043, 0x0044, 0x0045, 0x0046, 0x0047, 0x0048, 0x0049,
+    0x004a, 0x004b, 0x004c, 0x004d, 0x004e, 0x004f, 0x0050, 0x0051,
+    0x0052, 0x0053, 0x0054, 0x0055, 0x0056, 0x0057, 0x0058, 0x0059,
+    0x005a, 0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066, 0x0067,
+    0x0068, 0x0069, 0x006a, 0x006b, 0x006c, 0x006d, 0x006e, 0x006f,
+    0x0070, 0x0071, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076, 0x0077,
+    0x0078, 0x0079, 0x007a, 0x0131, 0x0237, 0x0391, 0x0392, 0x0393,
+    0x0394, 0x0395, 0x0396, 0x0397, 0x0398, 0x0399, 0x039a, 0x039b,
+    0x039c, 0x039d, 0x039e, 0x039f, 0x03a0, 0x03a1, 0x03f4, 0x03a3,
+    0x03a4, 0x03a5, 0x03a6, 0x03a7, 0x03a8, 0x03a9, 0x2207, 0x03b1,
+    0x03b2, 0x03b3, 0x03b4, 0x03b5, 0x03b6, 0x03b7, 0x03b8, 0x03b9,
+    0x03ba, 0x03bb, 0x03bc, 0x03bd, 0x03be, 0x03bf, 0x03c0, 0x03c1,
+    0x03c2, 0x03c3, 0x03c4, 0x03c5, 0x03c6, 0x03c7, 0x03c8, 0x03c9,
+    0x2202, 0x03f5, 0x03d1, 0x03f0, 0x03d5, 0x03f1, 0x03d6, 0x0391,
+    0x0392, 0x0393, 0x0394, 0x0395, 0x0396, 0x0397, 0x0398, 0x0399,
+    0x039a, 0x039b, 0x039c, 0x039d, 0x039e, 0x039f, 0x03a0, 0x03a1,
+    0x03f4, 0x03a3, 0x03a4, 0x03a5, 0x03a6, 0x03a7, 0x03a8, 0x03a9,
+    0x2207, 0x03b1, 0x03b2, 0x03b3, 0x03b4, 0x03b5, 0x03b6, 0x03b7,
+    0x03b8, 0x03b9, 0x03ba, 0x03bb, 0x03bc, 0x03bd, 0x03be, 0x03bf,
+    0x03c0, 0x03c1, 0x03c2, 0x03c3, 0x03c4, 0x03c5, 0x03c6, 0x03c7,
+    0x03c8, 0x03c9, 0x2202, 0x03f5, 0x03d1, 0x03f0, 0x03d5, 0x03f1,
+    0x03d6, 0x0391, 0x0392, 0x0393, 0x0394, 0x0395, 0x0396, 0x0397,
+    0x0398, 0x0399, 0x039a, 0x039b, 0x039c, 0x039d, 0x039e, 0x039f,
+    0x03a0, 0x03a1, 0x03f4, 0x03a3, 0x03a4, 0x03a5, 0x03a6, 0x03a7,
+    0x03a8, 0x03a9, 0x2207, 0x03b1, 0x03b2, 0x03b3, 0x03b4, 0x03b5,
+    0x03b6, 0x03b7, 0x03b8, 0x03b9, 0x03ba, 0x03bb, 0x03bc, 0x03bd,
+    0x03be, 0x03bf, 0x03c0, 0x03c1, 0x03c2, 0x03c3, 0x03c4, 0x03c5,
+    0x03c6, 0x03c7, 0x03c8, 0x03c9, 0x2202, 0x03f5, 0x03d1, 0x03f0,
+    0x03d5, 0x03f1, 0x03d6, 0x0391, 0x0392, 0x0393, 0x0394, 0x0395,
+    0x0396, 0x0397, 0x0398, 0x0399, 0x039a, 0x039b, 0x039c, 0x039d,
+    0x039e, 0x039f, 0x03a0, 0x03a1, 0x03f4, 0x03a3, 0x03a4, 0x03a5,
+    0x03a6, 0x03a7, 0x03a8, 0x03a9, 0x2207, 0x03b1, 0x03b2, 0x03b3,
+    0x03b4, 0x03b5, 0x03b6, 0x03b7, 0x03b8, 0x03b9, 0x03ba, 0x03bb,
+    0x03bc, 0x03bd, 0x03be, 0x03bf, 0x03c0, 0x03c1, 0x03c2, 0x03c3,
+    0x03c4, 0x03c5, 0x03c6, 0x03c7, 0x03c8, 0x03c9, 0x2202, 0x03f5,
+    0x03d1, 0x03f0, 0x03d5, 0x03f1, 0x03d6, 0x0391, 0x0392, 0x0393,
+    0x0394, 0x0395, 0x0396, 0x0397, 0x0398, 0x0399, 0x039a, 0x039b,
+    0x039c, 0x039d, 0x039e, 0x039f, 0x03a0, 0x03a1, 0x03f4, 0x03a3,
+    0x03a4, 0x03a5, 0x03a6, 0x03a7, 0x03a8, 0x03a9, 0x2207, 0x03b1,
+    0x03b2, 0x03b3, 0x03b4, 0x03b5, 0x03b6, 0x03b7, 0x03b8, 0x03b9,
+    0x03ba, 0x03bb, 0x03bc, 0x03bd, 0x03be, 0x03bf, 0x03c0, 0x03c1,
+    0x03c2, 0x03c3, 0x03c4, 0x03c5, 0x03c6, 0x03c7, 0x03c8, 0x03c9,
+    0x2202, 0x03f5, 0x03d1, 0x03f0, 0x03d5, 0x03f1, 0x03d6, 0x03dc,
+    0x03dd, 0x0030, 0x0031, 0x0032, 0x0033, 0x0034, 0x0035, 0x0036,
+    0x0037, 0x0038, 0x0039, 0x0030, 0x0031, 0x0032, 0x0033, 0x0034,
+    0x0035, 0x0036, 0x0037, 0x0038, 0x0039, 0x0030, 0x0031, 0x0032,
+    0x0033, 0x0034, 0x0035, 0x0036, 0x0037, 0x0038, 0x0039, 0x0030,
+    0x0031, 0x0032, 0x0033, 0x0034, 0x0035, 0x0036, 0x0037, 0x0038,
+    0x0039, 0x0030, 0x0031, 0x0032, 0x0033, 0x0034, 0x0035, 0x0036,
+    0x0037, 0x0038, 0x0039, 0x0627, 0x0628, 0x062c, 0x062f, 0x0648,
+    0x0632, 0x062d, 0x0637, 0x064a, 0x0643, 0x0644, 0x0645, 0x0646,
+    0x0633, 0x0639, 0x0641, 0x0635, 0x0642, 0x0631, 0x0634, 0x062a,
+    0x062b, 0x062e, 0x0630, 0x0636, 0x0638, 0x063a, 0x066e, 0x06ba,
+    0x06a1, 0x066f, 0x0628, 0x062c, 0x0647, 0x062d, 0x064a, 0x0643,
+    0x0644, 0x0645, 0x0646, 0x0633, 0x0639, 0x0641, 0x0635, 0x0642,
+    0x0634, 0x062a, 0x062b, 0x062e, 0x0636, 0x063a, 0x062c, 0x062d,
+    0x064a, 0x0644, 0x0646, 0x0633, 0x0639, 0x0635, 0x0642, 0x0634,
+    0x062e, 0x0636, 0x063a, 0x06ba, 0x066f, 0x0628, 0x062c, 0x0647,
+    0x062d, 0x0637, 0x064a, 0x0643, 0x0645, 0x0646, 0x0633, 0x0639,
+    0x0641, 0x0635, 0x0642, 0x0634, 0x062a, 0x062b, 0x062e, 0x0636,
+    0x0638, 0x063a, 0x066e, 0x06a1, 0x0627, 0x0628, 0x062c, 0x062f,
+    0x0647, 0x0648, 0x0632, 0x062d, 0x0637, 0x064a, 0x0644, 0x0645,
+    0x0646, 0x0633, 0x0639, 0x0641, 0x0635, 0x0642, 0x0631, 0x0634,
+    0x062a, 0x062b, 0x062e, 0x0630, 0x0636, 0x0638, 0x063a, 0x0628,
+    0x062c, 0x062f, 0x0648, 0x0632, 0x062d, 0x0637, 0x064a, 0x0644,
+    0x0645, 0x0646, 0x0633, 0x0639, 0x0641, 0x0635, 0x0642, 0x0631,
+    0x0634, 0x062a, 0x062b, 0x062e, 0x0630, 0x0636, 0x0638, 0x063a,
+    0x0030, 0x002e, 0x0030, 0x002c, 0x0031, 0x002c, 0x0032, 0x002c,
+    0x0033, 0x002c, 0x0034, 0x002c, 0x0035, 0x002c, 0x0036, 0x002c,
+    0x0037, 0x002c, 0x0038, 0x002c, 0x0039, 0x002c, 0x0028, 0x0041,
+    0x0029, 0x0028, 0x0042, 0x0029, 0x0028, 0x0043, 0x0029, 0x0028,
+    0x0044, 0x0029, 0x0028, 0x0045, 0x0029, 0x0028, 0x0046, 0x0029,
+    0x0028, 0x0047, 0x0029, 0x0028, 0x0048, 0x0029, 0x0028, 0x0049,
+    0x0029, 0x0028, 0x004a, 0x0029, 0x0028, 0x004b, 0x0029, 0x0028,
+    0x004c, 0x0029, 0x0028, 0x004d, 0x0029, 0x0028, 0x004e, 0x0029,
+    0x0028, 0x004f, 0x0029, 0x0028, 0x0050, 0x0029, 0x0028, 0x0051,
+    0x0029, 0x0028, 0x0052, 0x0029, 0x0028, 0x0053, 0x0029, 0x0028,
+    0x0054, 0x0029, 0x0028, 0x0055, 0x0029, 0x0028, 0x0056, 0x0029,
+    0x0028, 0x0057, 0x0029, 0x0028, 0x0058, 0x0029, 0x0028, 0x0059,
+    0x0029, 0x0028, 0x005a, 0x0029, 0x3014, 0x0053, 0x3015, 0x0043,
+    0x0052, 0x0043, 0x0044, 0x0057, 0x005a, 0x0041, 0x0042, 0x0043,
+    0x0044, 0x0045, 0x0046, 0x0047, 0x0048, 0x0049, 0x004a, 0x004b,
+    0x004c, 0x004d, 0x004e, 0x004f, 0x0050, 0x0051, 0x0052, 0x0053,
+    0x0054, 0x0055, 0x0056, 0x0057, 0x0058, 0x0059, 0x005a, 0x0048,
+    0x0056, 0x004d, 0x0056, 0x0053, 0x0044, 0x0053, 0x0053, 0x0050,
+    0x0050, 0x0056, 0x0057, 0x0043, 0x004d, 0x0043, 0x004d, 0x0044,
+    0x004d, 0x0052, 0x0044, 0x004a, 0x307b, 0x304b, 0x30b3, 0x30b3,
+    0x30b5, 0x624b, 0x5b57, 0x53cc, 0x30c7, 0x4e8c, 0x591a, 0x89e3,
+    0x5929, 0x4ea4, 0x6620, 0x7121, 0x6599, 0x524d, 0x5f8c, 0x518d,
+    0x65b0, 0x521d, 0x7d42, 0x751f, 0x8ca9, 0x58f0, 0x5439, 0x6f14,
+    0x6295, 0x6355, 0x4e00, 0x4e09, 0x904a, 0x5de6, 0x4e2d, 0x53f3,
+    0x6307, 0x8d70, 0x6253, 0x7981, 0x7a7a, 0x5408, 0x6e80, 0x6709,
+    0x6708, 0x7533, 0x5272, 0x55b6, 0x914d, 0x3014, 0x672c, 0x3015,
+    0x3014, 0x4e09, 0x3015, 0x3014, 0x4e8c, 0x3015, 0x3014, 0x5b89,
+    0x3015, 0x3014, 0x70b9, 0x3015, 0x3014, 0x6253, 0x3015, 0x3014,
+    0x76d7, 0x3015, 0x3014, 0x52dd, 0x3015, 0x3014, 0x6557, 0x3015,
+    0x5f97, 0x53ef, 0x0030, 0x0031, 0x0032, 0x0033, 0x0034, 0x0035,
+    0x0036, 0x0037, 0x0038, 0x0039, 0x4e3d, 0x4e38, 0x4e41, 0xd840,
+    0xdd22, 0x4f60, 0x4fae, 0x4fbb, 0x5002, 0x507a, 0x5099, 0x50e7,
+    0x50cf, 0x349e, 0xd841, 0xde3a, 0x514d, 0x5154, 0x5164, 0x5177,
+    0xd841, 0xdd1c, 0x34b9, 0x5167, 0x518d, 0xd841, 0xdd4b, 0x5197,
+    0x51a4, 0x4ecc, 0x51ac, 0x51b5, 0xd864, 0xdddf, 0x51f5, 0x5203,
+    0x34df, 0x523b, 0x5246, 0x5272, 0x5277, 0x3515, 0x52c7, 0x52c9,
+    0x52e4, 0x52fa, 0x5305, 0x5306, 0x5317, 0x5349, 0x5351, 0x535a,
+    0x5373, 0x537d, 0x537f, 0x537f, 0x537f, 0xd842, 0xde2c, 0x7070,
+    0x53ca, 0x53df, 0xd842, 0xdf63, 0x53eb, 0x53f1, 0x5406, 0x549e,
+    0x5438, 0x5448, 0x5468, 0x54a2, 0x54f6, 0x5510, 0x5553, 0x5563,
+    0x5584, 0x5584, 0x5599, 0x55ab, 0x55b3, 0x55c2, 0x5716, 0x5606,
+    0x5717, 0x5651, 0x5674, 0x5207, 0x58ee, 0x57ce, 0x57f4, 0x580d,
+    0x578b, 0x5832, 0x5831, 0x58ac, 0xd845, 0xdce4, 0x58f2, 0x58f7,
+    0x5906, 0x591a, 0x5922, 0x5962, 0xd845, 0xdea8, 0xd845, 0xdeea,
+    0x59ec, 0x5a1b, 0x5a27, 0x59d8, 0x5a66, 0x36ee, 0x36fc, 0x5b08,
+    0x5b3e, 0x5b3e, 0xd846, 0xddc8, 0x5bc3, 0x5bd8, 0x5be7, 0x5bf3,
+    0xd846, 0xdf18, 0x5bff, 0x5c06, 0x5f53, 0x5c22, 0x3781, 0x5c60,
+    0x5c6e, 0x5cc0, 0x5c8d, 0xd847, 0xdde4, 0x5d43, 0xd847, 0xdde6,
+    0x5d6e, 0x5d6b, 0x5d7c, 0x5de1, 0x5de2, 0x382f, 0x5dfd, 0x5e28,
+    0x5e3d, 0x5e69, 0x3862, 0xd848, 0xdd83, 0x387c, 0x5eb0, 0x5eb3,
+    0x5eb6, 0x5eca, 0xd868, 0xdf92, 0x5efe, 0xd848, 0xdf31, 0xd848,
+    0xdf31, 0x8201, 0x5f22, 0x5f22, 0x38c7, 0xd84c, 0xdeb8, 0xd858,
+    0xddda, 0x5f62, 0x5f6b, 0x38e3, 0x5f9a, 0x5fcd, 0x5fd7, 0x5ff9,
+    0x6081, 0x393a, 0x391c, 0x6094, 0xd849, 0xded4, 0x60c7, 0x6148,
+    0x614c, 0x614e, 0x614c, 0x617a, 0x618e, 0x61b2, 0x61a4, 0x61af,
+    0x61de, 0x61f2, 0x61f6, 0x6210, 0x621b, 0x625d, 0x62b1, 0x62d4,
+    0x6350, 0xd84a, 0xdf0c, 0x633d, 0x62fc, 0x6368, 0x6383, 0x63e4,
+    0xd84a, 0xdff1, 0x6422, 0x63c5, 0x63a9, 0x3a2e, 0x6469, 0x647e,
+    0x649d, 0x6477, 0x3a6c, 0x654f, 0x656c, 0xd84c, 0xdc0a, 0x65e3,
+    0x66f8, 0x6649, 0x3b19, 0x6691, 0x3b08, 0x3ae4, 0x5192, 0x5195,
+    0x6700, 0x669c, 0x80ad, 0x43d9, 0x6717, 0x671b, 0x6721, 0x675e,
+    0x6753, 0xd84c, 0xdfc3, 0x3b49, 0x67fa, 0x6785, 0x6852, 0x6885,
+    0xd84d, 0xdc6d, 0x688e, 0x681f, 0x6914, 0x3b9d, 0x6942, 0x69a3,
+    0x69ea, 0x6aa8, 0xd84d, 0xdea3, 0x6adb, 0x3c18, 0x6b21, 0xd84e,
+    0xdca7, 0x6b54, 0x3c4e, 0x6b72, 0x6b9f, 0x6bba, 0x6bbb, 0xd84e,
+    0xde8d, 0xd847, 0xdd0b, 0xd84e, 0xdefa, 0x6c4e, 0xd84f, 0xdcbc,
+    0x6cbf, 0x6ccd, 0x6c67, 0x6d16, 0x6d3e, 0x6d77, 0x6d41, 0x6d69,
+    0x6d78, 0x6d85, 0xd84f, 0xdd1e, 0x6d34, 0x6e2f, 0x6e6e, 0x3d33,
+    0x6ecb, 0x6ec7, 0xd84f, 0xded1, 0x6df9, 0x6f6e, 0xd84f, 0xdf5e,
+    0xd84f, 0xdf8e, 0x6fc6, 0x7039, 0x701e, 0x701b, 0x3d96, 0x704a,
+    0x707d, 0x7077, 0x70ad, 0xd841, 0xdd25, 0x7145, 0xd850, 0xde63,
+    0x719c, 0xd850, 0xdfab, 0x7228, 0x7235, 0x7250, 0xd851, 0xde08,
+    0x7280, 0x7295, 0xd851, 0xdf35, 0xd852, 0xdc14, 0x737a, 0x738b,
+    0x3eac, 0x73a5, 0x3eb8, 0x3eb8, 0x7447, 0x745c, 0x7471, 0x7485,
+    0x74ca, 0x3f1b, 0x7524, 0xd853, 0xdc36, 0x753e, 0xd853, 0xdc92,
+    0x7570, 0xd848, 0xdd9f, 0x7610, 0xd853, 0xdfa1, 0xd853, 0xdfb8,
+    0xd854, 0xdc44, 0x3ffc, 0x4008, 0x76f4, 0xd854, 0xdcf3, 0xd854,
+    0xdcf2, 0xd854, 0xdd19, 0xd854, 0xdd33, 0x771e, 0x771f, 0x771f,
+    0x774a, 0x4039, 0x778b, 0x4046, 0x4096, 0xd855, 0xdc1d, 0x784e,
+    0x788c, 0x78cc, 0x40e3, 0xd855, 0xde26, 0x7956, 0xd855, 0xde9a,
+    0xd855, 0xdec5, 0x798f, 0x79eb, 0x412f, 0x7a40, 0x7a4a, 0x7a4f,
+    0xd856, 0xdd7c, 0xd856, 0xdea7, 0xd856, 0xdea7, 0x7aee, 0x4202,
+    0xd856, 0xdfab, 0x7bc6, 0x7bc9, 0x4227, 0xd857, 0xdc80, 0x7cd2,
+    0x42a0, 0x7ce8, 0x7ce3, 0x7d00, 0xd857, 0xdf86, 0x7d63, 0x4301,
+    0x7dc7, 0x7e02, 0x7e45, 0x4334, 0xd858, 0xde28, 0xd858, 0xde47,
+    0x4359, 0xd858, 0xded9, 0x7f7a, 0xd858, 0xdf3e, 0x7f95, 0x7ffa,
+    0x8005, 0xd859, 0xdcda, 0xd859, 0xdd23, 0x8060, 0xd859, 0xdda8,
+    0x8070, 0xd84c, 0xdf5f, 0x43d5, 0x80b2, 0x8103, 0x440b, 0x813e,
+    0x5ab5, 0xd859, 0xdfa7, 0xd859, 0xdfb5, 0xd84c, 0xdf93, 0xd84c,
+    0xdf9c, 0x8201, 0x8204, 0x8f9e, 0x446b, 0x8291, 0x828b, 0x829d,
+    0x52b3, 0x82b1, 0x82b3, 0x82bd, 0x82e6, 0xd85a, 0xdf3c, 0x82e5,
+    0x831d, 0x8363, 0x83ad, 0x8323, 0x83bd, 0x83e7, 0x8457, 0x8353,
+    0x83ca, 0x83cc, 0x83dc, 0xd85b, 0xdc36, 0xd85b, 0xdd6b, 0xd85b,
+    0xdcd5, 0x452b, 0x84f1, 0x84f3, 0x8516, 0xd85c, 0xdfca, 0x8564,
+    0xd85b, 0xdf2c, 0x455d, 0x4561, 0xd85b, 0xdfb1, 0xd85c, 0xdcd2,
+    0x456b, 0x8650, 0x865c, 0x8667, 0x8669, 0x86a9, 0x8688, 0x870e,
+    0x86e2, 0x8779, 0x8728, 0x876b, 0x8786, 0x45d7, 0x87e1, 0x8801,
+    0x45f9, 0x8860, 0x8863, 0xd85d, 0xde67, 0x88d7, 0x88de, 0x4635,
+    0x88fa, 0x34bb, 0xd85e, 0xdcae, 0xd85e, 0xdd66, 0x46be, 0x46c7,
+    0x8aa0, 0x8aed, 0x8b8a, 0x8c55, 0xd85f, 0xdca8, 0x8cab, 0x8cc1,
+    0x8d1b, 0x8d77, 0xd85f, 0xdf2f, 0xd842, 0xdc04, 0x8dcb, 0x8dbc,
+    0x8df0, 0xd842, 0xdcde, 0x8ed4, 0x8f38, 0xd861, 0xddd2, 0xd861,
+    0xdded, 0x9094, 0x90f1, 0x9111, 0xd861, 0xdf2e, 0x911b, 0x9238,
+    0x92d7, 0x92d8, 0x927c, 0x93f9, 0x9415, 0xd862, 0xdffa, 0x958b,
+    0x4995, 0x95b7, 0xd863, 0xdd77, 0x49e6, 0x96c3, 0x5db2, 0x9723,
+    0xd864, 0xdd45, 0xd864, 0xde1a, 0x4a6e, 0x4a76, 0x97e0, 0xd865,
+    0xdc0a, 0x4ab2, 0xd865, 0xdc96, 0x980b, 0x980b, 0x9829, 0xd865,
+    0xddb6, 0x98e2, 0x4b33, 0x9929, 0x99a7, 0x99c2, 0x99fe, 0x4bce,
+    0xd866, 0xdf30, 0x9b12, 0x9c40, 0x9cfd, 0x4cce, 0x4ced, 0x9d67,
+    0xd868, 0xdcce, 0x4cf8, 0xd868, 0xdd05, 0xd868, 0xde0e, 0xd868,
+    0xde91, 0x9ebb, 0x4d56, 0x9ef9, 0x9efe, 0x9f05, 0x9f0f, 0x9f16,
+    0x9f3b, 0xd869, 0xde00,
+];
+
+pub(super) static COMPOSITIONS: [u16; 941] = [
+    1268, 1265, 1269, 14, 15, 16, 17, 67, 69, 270,
+    18, 749, 19, 194, 240, 242, 747, 591, 71, 593,
+    595, 597, 73, 75, 77, 79, 20, 601, 81, 603,
+    607, 609, 605, 21, 22, 23, 775, 83, 85, 87,
+    24, 773, 91, 244, 246, 771, 272, 89, 615, 617,
+    621, 230, 93, 623, 95, 97, 216, 99, 101, 625,
+    629, 268, 627, 631, 633, 25, 26, 27, 103, 105,
+    107, 111, 28, 787, 196, 248, 250, 789, 109, 635,
+    114, 639, 218, 641, 116, 643, 118, 122, 645, 120,
+    651, 649, 653, 655, 657, 232, 126, 29, 659, 130,
+    661, 128, 665, 663, 30, 31, 32, 33, 133, 135,
+    278, 34, 793, 137, 198, 252, 254, 181, 791, 220,
+    675, 677, 139, 679, 143, 256, 258, 681, 141, 685,
+    145, 147, 687, 151, 689, 264, 149, 697, 155, 699,
+    266, 153, 703, 701, 35, 36, 37, 157, 159, 161,
+    38, 817, 163, 165, 200, 260, 262, 183, 815, 705,
+    167, 709, 707, 715, 717, 719, 721, 169, 725, 723,
+    727, 729, 731, 829, 39, 171, 835, 282, 733, 173,
+    833, 831, 174, 735, 176, 178, 737, 739, 40, 41,
+    42, 43, 68, 70, 271, 44, 750, 45, 195, 241,
+    243, 748, 592, 72, 594, 596, 598, 74, 76, 78,
+    80, 46, 602, 82, 604, 608, 610, 606, 47, 48,
+    49, 776, 84, 86, 88, 50, 774, 92, 245, 247,
+    772, 273, 90, 616, 618, 622, 231, 94, 624, 96,
+    98, 217, 100, 102, 626, 630, 269, 628, 632, 634,
+    741, 51, 52, 53, 104, 106, 108, 54, 788, 197,
+    249, 251, 790, 110, 636, 115, 226, 640, 219, 642,
+    117, 644, 119, 123, 646, 121, 652, 650, 654, 656,
+    658, 233, 127, 55, 660, 131, 662, 129, 666, 664,
+    56, 57, 58, 59, 134, 136, 279, 60, 794, 138,
+    199, 253, 255, 182, 792, 221, 676, 678, 140, 680,
+    144, 257, 259, 682, 142, 686, 146, 148, 688, 152,
+    690, 265, 150, 698, 742, 156, 700, 267, 154, 704,
+    702, 61, 62, 63, 158, 160, 162, 64, 818, 164,
+    166, 201, 261, 263, 184, 816, 706, 168, 710, 708,
+    716, 718, 720, 722, 170, 726, 724, 743, 728, 730,
+    732, 830, 65, 172, 836, 283, 734, 66, 834, 744,
+    832, 175, 736, 177, 179, 738, 740, 1055, 312, 1015,
+    753, 751, 757, 755, 210, 234, 236, 214, 599, 779,
+    777, 783, 781, 637, 797, 795, 801, 799, 667, 276,
+    669, 274, 238, 208, 204, 202, 206, 754, 752, 758,
+    756, 211, 235, 237, 215, 600, 780, 778, 784, 782,
+    638, 798, 796, 802, 800, 668, 277, 670, 275, 239,
+    209, 205, 203, 207, 763, 761, 767, 765, 764, 762,
+    768, 766, 611, 613, 612, 614, 671, 673, 672, 674,
+    691, 692, 693, 694, 711, 712, 713, 714, 746, 807,
+    805, 811, 809, 813, 808, 806, 812, 810, 814, 821,
+    819, 825, 823, 827, 822, 820, 826, 824, 828, 224,
+    222, 223, 212, 213, 619, 620, 280, 281, 225, 1008,
+    313, 1007, 1006, 845, 846, 1010, 1021, 315, 859, 860,
+    1023, 316, 873, 874, 1025, 1037, 317, 1036, 1035, 322,
+    889, 890, 1063, 318, 903, 904, 1054, 1052, 319, 1051,
+    1050, 323, 917, 1065, 320, 929, 930, 1067, 1003, 1018,
+    937, 324, 1000, 999, 837, 838, 1004, 1002, 939, 325,
+    853, 854, 941, 326, 865, 866, 1019, 1017, 943, 327,
+    1030, 1029, 329, 881, 882, 1033, 945, 331, 897, 898,
+    1046, 1047, 947, 332, 1043, 1042, 330, 909, 910, 1048,
+    949, 333, 921, 922, 1061, 1059, 1031, 321, 1034, 1044,
+    328, 1049, 1060, 337, 338, 350, 367, 369, 349, 347,
+    371, 348, 365, 375, 377, 352, 379, 354, 381, 351,
+    383, 389, 353, 391, 393, 395, 397, 387, 368, 370,
+    358, 356, 372, 357, 366, 376, 378, 361, 380, 355,
+    382, 360, 384, 390, 362, 392, 394, 396, 398, 388,
+    359, 363, 364, 373, 374, 385, 386, 400, 401, 403,
+    402, 404, 410, 411, 409, 412, 413, 414, 423, 424,
+    435, 434, 436, 439, 440, 442, 441, 443, 444, 447,
+    445, 446, 448, 449, 451, 450, 452, 453, 455, 454,
+    480, 482, 483, 484, 485, 486, 487, 488, 489, 490,
+    491, 492, 647, 648, 683, 684, 695, 696, 759, 769,
+    760, 770, 785, 786, 803, 804, 839, 841, 843, 951,
+    840, 842, 844, 952, 953, 954, 955, 956, 957, 958,
+    847, 849, 851, 959, 848, 850, 852, 960, 961, 962,
+    963, 964, 965, 966, 855, 857, 856, 858, 861, 863,
+    862, 864, 867, 869, 871, 967, 868, 870, 872, 968,
+    969, 970, 971, 972, 973, 974, 875, 877, 879, 975,
+    876, 878, 880, 976, 977, 978, 979, 980, 981, 982,
+    883, 885, 887, 884, 886, 888, 891, 893, 895, 892,
+    894, 896, 899, 901, 900, 902, 905, 907, 906, 908,
+    911, 913, 915, 912, 914, 916, 918, 919, 920, 923,
+    925, 927, 983, 924, 926, 928, 984, 985, 986, 987,
+    988, 989, 990, 931, 933, 935, 991, 932, 934, 936,
+    992, 993, 994, 995, 996, 997, 998, 1001, 1016, 1058,
+    1005, 1026, 1027, 1028, 1020, 1062, 1039, 1040, 1041, 1246,
+    1247, 1248, 1249, 1251, 1250, 1252, 1253, 1254, 1255, 1256,
+    1261, 1262, 1263, 1264, 1267, 1266, 1270, 1271, 1272, 1273,
+    1274, 1275, 1276, 1277, 1286, 1287, 1278, 1279, 1280, 1281,
+    1288, 1289, 1282, 1283, 1284, 1285, 1290, 1291, 1292, 1293,
+    1689, 1664, 1665, 1666, 1667, 1668, 1669, 1670, 1671, 1672,
+    1673, 1674, 1675, 1676, 1677, 1678, 1679, 1680, 1681, 1682,
+    1683, 1684, 1685, 1686, 1687, 1688, 1692, 1719, 1694, 1695,
+    1696, 1697, 1698, 1699, 1700, 1701, 1702, 1703, 1704, 1705,
+    1706, 1707, 1708, 1709, 1710, 1711, 1712, 1713, 1714, 1715,
+    1716, 1717, 1718, 1720, 1721, 1722, 1723, 1724, 3944, 3945,
+    3946, 3947, 3948, 3949, 3950, 3952, 3951, 3953, 3954, 3955,
+    3956,
+];
+
+pub(super) static GRAPHEME_BREAK_RANGES: [u32; 636] = [
+    0x00000009, 0x00005000, 0x00005801, 0x00006800, 0x00007011, 0x0003f820,
+    0x00054800, 0x00056800, 0x00057000, 0x0018006f, 0x00241806, 0x002c882c,
+    0x002df800, 0x002e0801, 0x002e2001, 0x002e3800, 0x00300005, 0x0030800a,
+    0x0030e000, 0x00325814, 0x00338000, 0x0036b006, 0x0036e800, 0x0036f805,
+    0x00373801, 0x00375003, 0x00387800, 0x00388800, 0x0039801a, 0x003d300a,
+    0x003f5808, 0x003fe800, 0x0040b003, 0x0040d808, 0x00412802, 0x00414804,
+    0x0042c802, 0x00448001, 0x0044c007, 0x00465017, 0x00471000, 0x0047181f,
+    0x00481800, 0x0049d000, 0x0049d800, 0x0049e000, 0x0049f002, 0x004a0807,
+    0x004a4803, 0x004a6800, 0x004a7001, 0x004a8806, 0x004b1001, 0x004c0800,
+    0x004c1001, 0x004de000, 0x004df000, 0x004df801, 0x004e0803, 0x004e3801,
+    0x004e5801, 0x004e6800, 0x004eb800, 0x004f1001, 0x004ff000, 0x00500801,
+    0x00501800, 0x0051e000, 0x0051f002, 0x00520801, 0x00523801, 0x00525802,
+    0x00528800, 0x00538001, 0x0053a800, 0x00540801, 0x00541800, 0x0055e000,
+    0x0055f002, 0x00560804, 0x00563801, 0x00564800, 0x00565801, 0x00566800,
+    0x00571001, 0x0057d005, 0x00580800, 0x00581001, 0x0059e000, 0x0059f001,
+    0x005a0000, 0x005a0803, 0x005a3801, 0x005a5801, 0x005a6800, 0x005aa802,
+    0x005b1001, 0x005c1000, 0x005df000, 0x005df800, 0x005e0000, 0x005e0801,
+    0x005e3002, 0x005e5002, 0x005e6800, 0x005eb800, 0x00600000, 0x00600802,
+    0x00602000, 0x0061e000, 0x0061f002, 0x00620803, 0x00623002, 0x00625003,
+    0x0062a801, 0x00631001, 0x00640800, 0x00641001, 0x0065e000, 0x0065f000,
+    0x0065f800, 0x00660001, 0x00661000, 0x00661801, 0x00663000, 0x00663801,
+    0x00665001, 0x00666001, 0x0066a801, 0x00671001, 0x00680001, 0x00681001,
+    0x0069d801, 0x0069f000, 0x0069f801, 0x006a0803, 0x006a3002, 0x006a5002,
+    0x006a6800, 0x006a7000, 0x006ab800, 0x006b1001, 0x006c0800, 0x006c1001,
+    0x006e5000, 0x006e7800, 0x006e8001, 0x006e9002, 0x006eb000, 0x006ec006,
+    0x006ef800, 0x006f9001, 0x00718800, 0x00719800, 0x0071a006, 0x00723807,
+    0x00758800, 0x00759800, 0x0075a008, 0x00764005, 0x0078c001, 0x0079a800,
+    0x0079b800, 0x0079c800, 0x0079f001, 0x007b880d, 0x007bf800, 0x007c0004,
+    0x007c3001, 0x007c680a, 0x007cc823, 0x007e3000, 0x00816803, 0x00818800,
+    0x00819005, 0x0081c801, 0x0081d801, 0x0081e801, 0x0082b001, 0x0082c001,
+    0x0082f002, 0x00838803, 0x00841000, 0x00842000, 0x00842801, 0x00846800,
+    0x0084e800, 0x0088005f, 0x008b0047, 0x008d4057, 0x009ae802, 0x00b89002,
+    0x00b8a800, 0x00b99001, 0x00b9a000, 0x00ba9001, 0x00bb9001, 0x00bda001,
+    0x00bdb000, 0x00bdb806, 0x00bdf007, 0x00be3000, 0x00be3801, 0x00be480a,
+    0x00bee800, 0x00c05802, 0x00c07000, 0x00c07800, 0x00c42801, 0x00c54800,
+    0x00c90002, 0x00c91803, 0x00c93801, 0x00c94802, 0x00c98001, 0x00c99000,
+    0x00c99805, 0x00c9c802, 0x00d0b801, 0x00d0c801, 0x00d0d800, 0x00d2a800,
+    0x00d2b000, 0x00d2b800, 0x00d2c006, 0x00d30000, 0x00d31000, 0x00d32807,
+    0x00d36805, 0x00d39809, 0x00d3f800, 0x00d5801e, 0x00d80003, 0x00d82000,
+    0x00d9a006, 0x00d9d800, 0x00d9e000, 0x00d9e804, 0x00da1000, 0x00da1801,
+    0x00db5808, 0x00dc0001, 0x00dc1000, 0x00dd0800, 0x00dd1003, 0x00dd3001,
+    0x00dd4001, 0x00dd5000, 0x00dd5802, 0x00df3000, 0x00df3800, 0x00df4001,
+    0x00df5002, 0x00df6800, 0x00df7000, 0x00df7802, 0x00df9001, 0x00e12007,
+    0x00e16007, 0x00e1a001, 0x00e1b001, 0x00e68002, 0x00e6a00c, 0x00e70800,
+    0x00e71006, 0x00e76800, 0x00e7a000, 0x00e7b800, 0x00e7c001, 0x00ee003f,
+    0x01005800, 0x01006000, 0x01006800, 0x01007001, 0x01014006, 0x0101e000,
+    0x01024800, 0x0103000f, 0x01068020, 0x01091000, 0x0109c800, 0x010ca005,
+    0x010d4801, 0x0118d001, 0x01194000, 0x011c4000, 0x011e7800, 0x011f480a,
+    0x011fc002, 0x01261000, 0x012d5001, 0x012db000, 0x012e0000, 0x012fd803,
+    0x01300005, 0x0130380b, 0x0130a071, 0x01348075, 0x0138400a, 0x0138a000,
+    0x0138b000, 0x0138e800, 0x01390800, 0x01394000, 0x01399801, 0x013a2000,
+    0x013a3800, 0x013a6000, 0x013a7000, 0x013a9802, 0x013ab800, 0x013b1804,
+    0x013ca802, 0x013d0800, 0x013d8000, 0x013df800, 0x0149a001, 0x01582802,
+    0x0158d801, 0x015a8000, 0x015aa800, 0x01677802, 0x016bf800, 0x016f001f,
+    0x01815005, 0x01818000, 0x0181e800, 0x0184c801, 0x0194b800, 0x0194c800,
+    0x05337803, 0x0533a009, 0x0534f001, 0x05378001, 0x05401000, 0x05403000,
+    0x05405800, 0x05411801, 0x05412801, 0x05413800, 0x05416000, 0x05440001,
+    0x0545a00f, 0x05462001, 0x05470011, 0x0547f800, 0x05493007, 0x054a380a,
+    0x054a9001, 0x054b001c, 0x054c0002, 0x054c1800, 0x054d9800, 0x054da001,
+    0x054db003, 0x054dd001, 0x054de001, 0x054df002, 0x054f2800, 0x05514805,
+    0x05517801, 0x05518801, 0x05519801, 0x0551a801, 0x05521800, 0x05526000,
+    0x05526800, 0x0553e000, 0x05558000, 0x05559002, 0x0555b801, 0x0555f001,
+    0x05560800, 0x05575800, 0x05576001, 0x05577001, 0x0557a800, 0x0557b000,
+    0x055f1801, 0x055f2800, 0x055f3001, 0x055f4000, 0x055f4801, 0x055f6000,
+    0x055f6800, 0x06bd8016, 0x06be5830, 0x07d8f000, 0x07f0000f, 0x07f1000f,
+    0x07f7f800, 0x07fcf001, 0x07ff800b, 0x080fe800, 0x08170000, 0x081bb004,
+    0x08500802, 0x08502801, 0x08506003, 0x0851c002, 0x0851f800, 0x08572801,
+    0x08692003, 0x08755801, 0x087a300a, 0x087c1003, 0x08800000, 0x08800800,
+    0x08801000, 0x0881c00e, 0x08838000, 0x08839801, 0x0883f802, 0x08841000,
+    0x08858002, 0x08859803, 0x0885b801, 0x0885c801, 0x0885e800, 0x08861000,
+    0x08866800, 0x08880002, 0x08893804, 0x08896000, 0x08896807, 0x088a2801,
+    0x088b9800, 0x088c0001, 0x088c1000, 0x088d9802, 0x088db008, 0x088df801,
+    0x088e1001, 0x088e4803, 0x088e7000, 0x088e7800, 0x08916002, 0x08917802,
+    0x08919001, 0x0891a000, 0x0891a800, 0x0891b001, 0x0891f000, 0x0896f800,
+    0x08970002, 0x08971807, 0x08980001, 0x08981001, 0x0899d801, 0x0899f000,
+    0x0899f800, 0x089a0000, 0x089a0803, 0x089a3801, 0x089a5802, 0x089ab800,
+    0x089b1001, 0x089b3006, 0x089b8004, 0x08a1a802, 0x08a1c007, 0x08a20001,
+    0x08a21002, 0x08a22800, 0x08a23000, 0x08a2f000, 0x08a58000, 0x08a58801,
+    0x08a59805, 0x08a5c800, 0x08a5d000, 0x08a5d801, 0x08a5e800, 0x08a5f000,
+    0x08a5f801, 0x08a60800, 0x08a61001, 0x08ad7800, 0x08ad8001, 0x08ad9003,
+    0x08adc003, 0x08ade001, 0x08adf000, 0x08adf801, 0x08aee001, 0x08b18002,
+    0x08b19807, 0x08b1d801, 0x08b1e800, 0x08b1f000, 0x08b1f801, 0x08b55800,
+    0x08b56000, 0x08b56800, 0x08b57001, 0x08b58005, 0x08b5b000, 0x08b5b800,
+    0x08b8e802, 0x08b91003, 0x08b93000, 0x08b93804, 0x08c16002, 0x08c17808,
+    0x08c1c000, 0x08c1c801, 0x08c98000, 0x08c98804, 0x08c9b801, 0x08c9d801,
+    0x08c9e800, 0x08c9f000, 0x08c9f800, 0x08ca0000, 0x08ca0800, 0x08ca1000,
+    0x08ca1800, 0x08ce8802, 0x08cea003, 0x08ced001, 0x08cee003, 0x08cf0000,
+    0x08cf2000, 0x08d00809, 0x08d19805, 0x08d1c800, 0x08d1d000, 0x08d1d803,
+    0x08d23800, 0x08d28805, 0x08d2b801, 0x08d2c802, 0x08d42005, 0x08d4500c,
+    0x08d4b800, 0x08d4c001, 0x08e17800, 0x08e18006, 0x08e1c005, 0x08e1f000,
+    0x08e1f800, 0x08e49015, 0x08e54800, 0x08e55006, 0x08e58800, 0x08e59001,
+    0x08e5a000, 0x08e5a801, 0x08e98805, 0x08e9d000, 0x08e9e001, 0x08e9f806,
+    0x08ea3000, 0x08ea3800, 0x08ec5004, 0x08ec8001, 0x08ec9801, 0x08eca800,
+    0x08ecb000, 0x08ecb800, 0x08f79801, 0x08f7a801, 0x09a18008, 0x0b578004,
+    0x0b598006, 0x0b7a7800, 0x0b7a8836, 0x0b7c7803, 0x0b7f2000, 0x0b7f8001,
+    0x0de4e801, 0x0de50003, 0x0e78002d, 0x0e798016, 0x0e8b2800, 0x0e8b3000,
+    0x0e8b3802, 0x0e8b6800, 0x0e8b7004, 0x0e8b9807, 0x0e8bd807, 0x0e8c2806,
+    0x0e8d5003, 0x0e921002, 0x0ed00036, 0x0ed1d831, 0x0ed3a800, 0x0ed42000,
+    0x0ed4d804, 0x0ed5080e, 0x0f000006, 0x0f004010, 0x0f00d806, 0x0f011801,
+    0x0f013004, 0x0f098006, 0x0f157000, 0x0f176003, 0x0f468006, 0x0f4a2006,
+    0x0f8000ff, 0x0f886802, 0x0f897800, 0x0f8b6005, 0x0f8bf001, 0x0f8c7000,
+    0x0f8c8809, 0x0f8d6838, 0x0f8f3019, 0x0f90080e, 0x0f90d000, 0x0f917800,
+    0x0f919008, 0x0f91e003, 0x0f9249b1, 0x0f9fd804, 0x0fa0013d, 0x0faa3109,
+    0x0fb4007f, 0x0fbba00b, 0x0fbea82a, 0x0fc06003, 0x0fc24007, 0x0fc2d005,
+    0x0fc44007, 0x0fc57051, 0x0fc8602e, 0x0fc9e009, 0x0fca39b8, 0x0fe003fd,
+    0x7000001f, 0x7001005f, 0x7004007f, 0x700800ef, 0x700f87ff, 0x704f860f,
+];
+
+pub(super) static GRAPHEME_BREAKS: [u8; 636] = [
+    3, 2, 3, 1, 3, 3, 14, 3, 14, 4, 4, 4, 4, 4, 4, 4,
+    7, 4, 3, 4, 4, 4, 7, 4, 4, 4, 7, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 7, 4, 4, 7, 4, 8, 4, 8, 4, 8, 4,
+    8, 4, 8, 4, 4, 4, 8, 4, 4, 8, 4, 8, 8, 4, 4, 4,
+    4, 4, 8, 4, 8, 4, 4, 4, 4, 4, 4, 4, 8, 4, 8, 4,
+    4, 8, 8, 4, 4, 4, 4, 8, 4, 4, 8, 4, 8, 8, 4, 4,
+    4, 4, 4, 8, 4, 8, 8, 8, 4, 4, 4, 8, 4, 4, 4, 8,
+    4, 4, 4, 4, 4, 8, 4, 8, 4, 8, 4, 8, 4, 8, 8, 4,
+    4, 4, 4, 8, 4, 4, 8, 4, 8, 8, 4, 7, 4, 4, 4, 8,
+    4, 4, 8, 4, 4, 8, 4, 8, 4, 8, 4, 4, 4, 8, 4, 4,
+    4, 4, 4, 4, 8, 4, 8, 4, 4, 4, 4, 4, 4, 8, 4, 4,
+    8, 4, 8, 4, 4, 4, 4, 8, 4, 4, 4, 9, 10, 11, 4, 4,
+    8, 4, 8, 4, 4, 4, 8, 4, 8, 4, 8, 4, 4, 4, 3, 4,
+    4, 4, 4, 8, 4, 8, 8, 4, 8, 4, 4, 8, 4, 8, 4, 8,
+    4, 4, 4, 4, 8, 4, 4, 4, 4, 8, 4, 8, 4, 8, 4, 8,
+    4, 4, 8, 8, 4, 8, 4, 8, 4, 4, 8, 4, 8, 4, 8, 4,
+    8, 8, 4, 8, 4, 4, 4, 8, 4, 4, 4, 8, 4, 4, 3, 4,
+    5, 3, 3, 14, 14, 3, 4, 14, 14, 14, 14, 14, 14, 14, 14, 14,
+    14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14,
+    14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14, 14,
+    14, 4, 4, 4, 4, 14, 14, 4, 14, 14, 4, 4, 4, 4, 4, 4,
+    4, 8, 4, 8, 4, 8, 8, 4, 4, 4, 4, 4, 8, 9, 4, 8,
+    4, 8, 4, 8, 4, 8, 4, 4, 8, 4, 8, 4, 4, 4, 8, 4,
+    4, 4, 4, 4, 4, 8, 4, 8, 8, 4, 8, 4, 8, 4, 8, 8,
+    4, 10, 11, 4, 4, 4, 3, 4, 3, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 8, 4, 8, 4, 4, 4, 4, 8, 8, 4,
+    8, 4, 7, 4, 7, 4, 4, 8, 4, 8, 4, 4, 8, 8, 4, 8,
+    7, 4, 8, 4, 8, 4, 8, 4, 8, 4, 4, 4, 8, 4, 4, 8,
+    4, 4, 8, 4, 8, 8, 8, 4, 8, 4, 4, 8, 4, 8, 4, 8,
+    4, 4, 4, 8, 4, 8, 4, 8, 4, 8, 4, 8, 4, 4, 8, 4,
+    8, 4, 8, 4, 4, 8, 4, 8, 4, 8, 4, 4, 8, 4, 8, 4,
+    8, 4, 4, 4, 8, 4, 8, 4, 8, 4, 4, 8, 8, 4, 8, 4,
+    7, 8, 7, 8, 4, 8, 4, 4, 8, 4, 8, 4, 4, 8, 7, 4,
+    4, 4, 8, 4, 7, 4, 8, 4, 8, 4, 4, 8, 4, 4, 8, 4,
+    8, 4, 8, 4, 4, 4, 4, 4, 7, 4, 8, 4, 8, 4, 8, 4,
+    4, 8, 3, 4, 4, 4, 8, 4, 4, 8, 4, 3, 4, 4, 4, 8,
+    4, 8, 4, 3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+    4, 4, 4, 4, 4, 4, 4, 4, 14, 14, 14, 14, 14, 14, 14, 14,
+    6, 14, 14, 14, 14, 14, 14, 4, 14, 14, 14, 14, 14, 14, 14, 14,
+    14, 14, 14, 14, 14, 14, 3, 4, 3, 4, 3, 3,
+];
+
+pub(super) static CASE_PROPERTY_RANGES: [u32; 588] = [
+    0x00013800, 0x00017000, 0x0001d000, 0x00020819, 0x0002f000, 0x00030000,
+    0x00030819, 0x00054000, 0x00055000, 0x00056800, 0x00057800, 0x0005a000,
+    0x0005a800, 0x0005b801, 0x0005d000, 0x00060016, 0x0006c01e, 0x0007c0c2,
+    0x000de003, 0x000e20cf, 0x0014a81a, 0x00158008, 0x0015c806, 0x00160001,
+    0x0016101d, 0x00170004, 0x0017285f, 0x001a2800, 0x001a3029, 0x001b8003,
+    0x001ba001, 0x001bb001, 0x001bd000, 0x001bd802, 0x001bf800, 0x001c2001,
+    0x001c3000, 0x001c3800, 0x001c4002, 0x001c6000, 0x001c7013, 0x001d1852,
+    0x001fb88a, 0x00241806, 0x002450a5, 0x00298825, 0x002ac800, 0x002af800,
+    0x002b0028, 0x002c882c, 0x002df800, 0x002e0801, 0x002e2001, 0x002e3800,
+    0x002fa000, 0x00300005, 0x0030800a, 0x0030e000, 0x00320000, 0x00325814,
+    0x00338000, 0x0036b007, 0x0036f809, 0x00375003, 0x00387800, 0x00388800,
+    0x0039801a, 0x003d300a, 0x003f580a, 0x003fd000, 0x003fe800, 0x0040b017,
+    0x0042c802, 0x00444000, 0x00448001, 0x0044c007, 0x00464839, 0x0049d000,
+    0x0049e000, 0x004a0807, 0x004a6800, 0x004a8806, 0x004b1001, 0x004b8800,
+    0x004c0800, 0x004de000, 0x004e0803, 0x004e6800, 0x004f1001, 0x004ff000,
+    0x00500801, 0x0051e000, 0x00520801, 0x00523801, 0x00525802, 0x00528800,
+    0x00538001, 0x0053a800, 0x00540801, 0x0055e000, 0x00560804, 0x00563801,
+    0x00566800, 0x00571001, 0x0057d005, 0x00580800, 0x0059e000, 0x0059f800,
+    0x005a0803, 0x005a6800, 0x005aa801, 0x005b1001, 0x005c1000, 0x005e0000,
+    0x005e6800, 0x00600000, 0x00602000, 0x0061e000, 0x0061f002, 0x00623002,
+    0x00625003, 0x0062a801, 0x00631001, 0x00640800, 0x0065e000, 0x0065f800,
+    0x00663000, 0x00666001, 0x00671001, 0x00680001, 0x0069d801, 0x006a0803,
+    0x006a6800, 0x006b1001, 0x006c0800, 0x006e5000, 0x006e9002, 0x006eb000,
+    0x00718800, 0x0071a006, 0x00723008, 0x00758800, 0x0075a008, 0x00763000,
+    0x00764005, 0x0078c001, 0x0079a800, 0x0079b800, 0x0079c800, 0x007b880d,
+    0x007c0004, 0x007c3001, 0x007c680a, 0x007cc823, 0x007e3000, 0x00816803,
+    0x00819005, 0x0081c801, 0x0081e801, 0x0082c001, 0x0082f002, 0x00838803,
+    0x00841000, 0x00842801, 0x00846800, 0x0084e800, 0x00850025, 0x00863800,
+    0x00866800, 0x0086802a, 0x0087e000, 0x0087e802, 0x009ae802, 0x009d0055,
+    0x009fc005, 0x00b89002, 0x00b99001, 0x00ba9001, 0x00bb9001, 0x00bda001,
+    0x00bdb806, 0x00be3000, 0x00be480a, 0x00beb800, 0x00bee800, 0x00c05804,
+    0x00c21800, 0x00c42801, 0x00c54800, 0x00c90002, 0x00c93801, 0x00c99000,
+    0x00c9c802, 0x00d0b801, 0x00d0d800, 0x00d2b000, 0x00d2c006, 0x00d30000,
+    0x00d31000, 0x00d32807, 0x00d39809, 0x00d3f800, 0x00d53800, 0x00d5801e,
+    0x00d80003, 0x00d9a000, 0x00d9b004, 0x00d9e000, 0x00da1000, 0x00db5808,
+    0x00dc0001, 0x00dd1003, 0x00dd4001, 0x00dd5802, 0x00df3000, 0x00df4001,
+    0x00df6800, 0x00df7802, 0x00e16007, 0x00e1b001, 0x00e3c005, 0x00e40008,
+    0x00e4802a, 0x00e5e802, 0x00e68002, 0x00e6a00c, 0x00e71006, 0x00e76800,
+    0x00e7a000, 0x00e7c001, 0x00e8002b, 0x00e9603e, 0x00eb580c, 0x00ebc000,
+    0x00ebc821, 0x00ecd824, 0x00ee003f, 0x00f00115, 0x00f8c005, 0x00f90025,
+    0x00fa4005, 0x00fa8007, 0x00fac800, 0x00fad800, 0x00fae800, 0x00faf81e,
+    0x00fc0034, 0x00fdb006, 0x00fde800, 0x00fdf000, 0x00fdf802, 0x00fe1002,
+    0x00fe3006, 0x00fe6802, 0x00fe8003, 0x00feb005, 0x00fee802, 0x00ff000c,
+    0x00ff6802, 0x00ff9002, 0x00ffb006, 0x00ffe801, 0x01005804, 0x0100c001,
+    0x01012000, 0x01013800, 0x01015004, 0x01030004, 0x01033009, 0x01038800,
+    0x0103f800, 0x0104800c, 0x01068020, 0x01081000, 0x01083800, 0x01085009,
+    0x0108a800, 0x0108c804, 0x01092000, 0x01093000, 0x01094000, 0x01095003,
+    0x01097805, 0x0109c800, 0x0109e003, 0x010a2804, 0x010a7000, 0x010b001f,
+    0x010c1801, 0x0125b033, 0x0160007b, 0x0163e001, 0x0163f066, 0x01675803,
+    0x01677802, 0x01679001, 0x01680025, 0x01693800, 0x01696800, 0x016b7800,
+    0x016bf800, 0x016f001f, 0x01717800, 0x01802800, 0x01815003, 0x01818804,
+    0x0181d800, 0x0184c805, 0x0187e002, 0x0500a800, 0x0527c005, 0x05306000,
+    0x0532002d, 0x05337803, 0x0533a009, 0x0533f800, 0x0534001b, 0x0534e001,
+    0x0534f001, 0x05378001, 0x05380021, 0x0539104d, 0x053b8000, 0x053b8816,
+    0x053c4002, 0x053c5803, 0x053c803a, 0x053e8001, 0x053e9800, 0x053ea804,
+    0x053f9002, 0x053fa801, 0x053fc001, 0x053fd000, 0x05401000, 0x05403000,
+    0x05405800, 0x05412801, 0x05416000, 0x05462001, 0x05470011, 0x0547f800,
+    0x05493007, 0x054a380a, 0x054c0002, 0x054d9800, 0x054db003, 0x054de001,
+    0x054e7800, 0x054f2801, 0x05514805, 0x05518801, 0x0551a801, 0x05521800,
+    0x05526000, 0x05538000, 0x0553e000, 0x05558000, 0x05559002, 0x0555b801,
+    0x0555f001, 0x05560800, 0x0556e800, 0x05576001, 0x05579801, 0x0557b000,
+    0x0559802a, 0x055ad800, 0x055ae003, 0x055b0008, 0x055b4802, 0x055b804f,
+    0x055f2800, 0x055f4000, 0x055f6800, 0x07d80006, 0x07d89804, 0x07d8f000,
+    0x07dd9010, 0x07f0000f, 0x07f09800, 0x07f1000f, 0x07f29000, 0x07f2a800,
+    0x07f7f800, 0x07f83800, 0x07f87000, 0x07f8d000, 0x07f90819, 0x07f9f000,
+    0x07fa0000, 0x07fa0819, 0x07fb8000, 0x07fcf001, 0x07ff1800, 0x07ffc802,
+    0x080fe800, 0x08170000, 0x081bb004, 0x0820004f, 0x08258023, 0x0826c023,
+    0x082b800a, 0x082be00e, 0x082c6006, 0x082ca001, 0x082cb80a, 0x082d180e,
+    0x082d9806, 0x082dd801, 0x083c0000, 0x083c0801, 0x083c1802, 0x083c3829,
+    0x083d9008, 0x08500802, 0x08502801, 0x08506003, 0x0851c002, 0x0851f800,
+    0x08572801, 0x08640032, 0x08660032, 0x08692003, 0x08755801, 0x087a300a,
+    0x087c1003, 0x08800800, 0x0881c00e, 0x08838000, 0x08839801, 0x0883f802,
+    0x08859803, 0x0885c801, 0x0885e800, 0x08861000, 0x08866800, 0x08880002,
+    0x08893804, 0x08896807, 0x088b9800, 0x088c0001, 0x088db008, 0x088e4803,
+    0x088e7800, 0x08917802, 0x0891a000, 0x0891b001, 0x0891f000, 0x0896f800,
+    0x08971807, 0x08980001, 0x0899d801, 0x089a0000, 0x089b3006, 0x089b8004,
+    0x08a1c007, 0x08a21002, 0x08a23000, 0x08a2f000, 0x08a59805, 0x08a5d000,
+    0x08a5f801, 0x08a61001, 0x08ad9003, 0x08ade001, 0x08adf801, 0x08aee001,
+    0x08b19807, 0x08b1e800, 0x08b1f801, 0x08b55800, 0x08b56800, 0x08b58005,
+    0x08b5b800, 0x08b8e802, 0x08b91003, 0x08b93804, 0x08c17808, 0x08c1c801,
+    0x08c5003f, 0x08c9d801, 0x08c9f000, 0x08ca1800, 0x08cea003, 0x08ced001,
+    0x08cf0000, 0x08d00809, 0x08d19805, 0x08d1d803, 0x08d23800, 0x08d28805,
+    0x08d2c802, 0x08d4500c, 0x08d4c001, 0x08e18006, 0x08e1c005, 0x08e1f800,
+    0x08e49015, 0x08e55006, 0x08e59001, 0x08e5a801, 0x08e98805, 0x08e9d000,
+    0x08e9e001, 0x08e9f806, 0x08ea3800, 0x08ec8001, 0x08eca800, 0x08ecb800,
+    0x08f79801, 0x09a18008, 0x0b578004, 0x0b598006, 0x0b5a0003, 0x0b72003f,
+    0x0b7a7800, 0x0b7c7810, 0x0b7f0001, 0x0b7f1801, 0x0d7f8003, 0x0d7fa806,
+    0x0d7fe801, 0x0de4e801, 0x0de50003, 0x0e78002d, 0x0e798016, 0x0e8b3802,
+    0x0e8b980f, 0x0e8c2806, 0x0e8d5003, 0x0e921002, 0x0ea00054, 0x0ea2b046,
+    0x0ea4f001, 0x0ea51000, 0x0ea52801, 0x0ea54803, 0x0ea5700b, 0x0ea5d800,
+    0x0ea5e806, 0x0ea62840, 0x0ea83803, 0x0ea86807, 0x0ea8b006, 0x0ea8f01b,
+    0x0ea9d803, 0x0eaa0004, 0x0eaa3000, 0x0eaa5006, 0x0eaa9153, 0x0eb54018,
+    0x0eb61018, 0x0eb6e01e, 0x0eb7e018, 0x0eb8b01e, 0x0eb9b018, 0x0eba801e,
+    0x0ebb8018, 0x0ebc501e, 0x0ebd5018, 0x0ebe2007, 0x0ed00036, 0x0ed1d831,
+    0x0ed3a800, 0x0ed42000, 0x0ed4d804, 0x0ed5080e, 0x0ef80009, 0x0ef85813,
+    0x0f000006, 0x0f004010, 0x0f00d806, 0x0f011801, 0x0f013004, 0x0f09800d,
+    0x0f157000, 0x0f176003, 0x0f468006, 0x0f480043, 0x0f4a2007, 0x0f898019,
+    0x0f8a8019, 0x0f8b8019, 0x0f9fd804, 0x70000800, 0x7001005f, 0x700800ef,
+];
+
+pub(super) static CASE_PROPERTIES: [u8; 588] = [
+    2, 2, 2, 1, 2, 2, 1, 2, 1, 2, 2, 2, 1, 2, 1, 1,
+    1, 1, 1, 1, 1, 3, 2, 3, 2, 3, 2, 3, 2, 1, 2, 1,
+    3, 1, 1, 2, 1, 2, 1, 1, 1, 1, 1, 2, 1, 1, 2, 2,
+    1, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 1, 1, 1, 1, 2, 1, 2, 1, 1, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 1, 1, 1,
+    2, 2, 2, 2, 2, 2, 1, 3, 1, 3, 1, 3, 2, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 2, 1, 2, 1, 1, 2, 1, 1,
+    2, 1, 2, 1, 1, 2, 2, 2, 2, 2, 2, 2, 2, 3, 3, 3,
+    2, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 3, 1, 1, 2, 1, 1, 1, 1, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 1, 2, 2, 2, 1, 3, 2, 2,
+    2, 1, 3, 1, 2, 1, 1, 1, 1, 1, 2, 1, 3, 1, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 1, 2,
+    3, 1, 2, 1, 2, 2, 2, 1, 1, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 1, 2, 2, 1, 2, 2, 2, 2, 2, 2, 2, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 3, 2, 3, 3, 3, 2,
+    2, 2, 2, 2, 2, 1, 1, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    1, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 1, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 2, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 2, 2, 2, 2, 2, 2, 1, 1, 2, 2, 2, 2, 2, 2,
+    2, 2, 2, 1, 2, 1, 1, 1, 2, 2, 2, 2,
+];
+
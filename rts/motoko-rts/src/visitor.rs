@@ -116,6 +116,10 @@ pub unsafe fn visit_pointer_fields<C, F, G>(
             // These don't have pointers, skip
         }
 
+        TAG_WEAK_REF => {
+            // Weak references don't keep their targets alive, see `weak_ref.rs`
+        }
+
         TAG_NULL => {
             rts_trap_with("encountered NULL object tag in visit_pointer_fields");
         }
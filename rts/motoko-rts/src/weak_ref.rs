@@ -0,0 +1,56 @@
+//! Weak references: objects that point to another object without keeping it alive.
+//!
+//! The collectors don't follow the target of a weak reference when marking (see
+//! `visit_pointer_fields`). Once all live objects are marked (or evacuated), each collector
+//! updates the weak references whose targets are live, and clears the others by setting the target
+//! to `WEAK_REF_CLEARED`. Targets outside of the heap being collected (static objects, and the old
+//! generation in a young generation collection) are not touched.
+//!
+//! The incremental GC needs a read barrier: the target of a weak reference read while marking is
+//! marked, as the mutator may store it in an object that is not scanned in the current cycle.
+//!
+//! Weak references are internal to the RTS, there are no Motoko primitives for them: the compiler
+//! doesn't know the `WeakRef` object type, so they can't be stored in Motoko values (e.g. they
+//! can't be serialized). They are used by the intern table in `intern.rs`.
+
+use crate::memory::Memory;
+use crate::rts_trap_with;
+use crate::types::*;
+
+use motoko_rts_macros::ic_mem_fn;
+
+/// Target of a cleared weak reference
+pub const WEAK_REF_CLEARED: Value = Value::from_scalar(0);
+
+/// Allocates a weak reference to the given object
+#[ic_mem_fn]
+pub unsafe fn weak_ref_new<M: Memory>(mem: &mut M, target: Value) -> Value {
+    if !target.is_ptr() {
+        rts_trap_with("weak_ref_new: target is not an object");
+    }
+
+    let ptr = mem.alloc_words(size_of::<WeakRef>());
+    let weak_ref = ptr.get_ptr() as *mut WeakRef;
+    (*weak_ref).header.tag = TAG_WEAK_REF;
+    (*weak_ref).target = target;
+    ptr
+}
+
+/// Returns whether the target of the weak reference is still alive
+#[no_mangle]
+pub unsafe extern "C" fn weak_ref_is_live(weak_ref: Value) -> bool {
+    (*weak_ref.as_weak_ref()).target.is_ptr()
+}
+
+/// Returns the target of the weak reference, or `WEAK_REF_CLEARED` when the target died
+#[ic_mem_fn]
+pub unsafe fn weak_ref_get<M: Memory>(mem: &mut M, weak_ref: Value) -> Value {
+    let target = (*weak_ref.as_weak_ref()).target;
+    crate::gc::incremental::weak_ref_read_barrier(mem, target);
+    target
+}
+
+/// Clears the weak reference
+pub(crate) unsafe fn clear(weak_ref: *mut WeakRef) {
+    (*weak_ref).target = WEAK_REF_CLEARED;
+}
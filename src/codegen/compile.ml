@@ -566,13 +566,28 @@ module E = struct
     Int32.(add (div (get_end_of_static_memory env) page_size) 1l)
 
   let collect_garbage env =
-    (* GC function name = "schedule_"? ("compacting" | "copying") "_gc" *)
+    (* GC function name = "schedule_"? ("compacting" | "copying" | "generational" | "incremental" | "marksweep") "_gc" *)
     let gc_fn = match !Flags.gc_strategy with
     | Mo_config.Flags.MarkCompact -> "compacting"
     | Mo_config.Flags.Copying -> "copying"
+    | Mo_config.Flags.Generational -> "generational"
+    | Mo_config.Flags.Incremental -> "incremental"
+    | Mo_config.Flags.MarkSweep -> "marksweep"
     in
-    let gc_fn = if !Flags.force_gc then gc_fn else "schedule_" ^ gc_fn in
-    call_import env "rts" (gc_fn ^ "_gc")
+    let scheduled_fn = if !Flags.force_gc then gc_fn else "schedule_" ^ gc_fn in
+    (* Allocations of the collector are not sampled, see `--alloc-sampling` *)
+    (match !Flags.alloc_sampling with
+     | None -> G.nop
+     | Some _ ->
+       G.i (Const (nr (Wasm.Values.I32 (-1l)))) ^^
+       call_import env "rts" "set_alloc_site") ^^
+    (* When an allocation crossed the `--heap-limit`, do a full collection, and
+       let the RTS call the low-memory hook if that was not enough *)
+    call_import env "rts" "heap_limit_exceeded" ^^
+    G.if0
+      (call_import env "rts" (gc_fn ^ "_gc") ^^
+       call_import env "rts" "check_heap_limit")
+      (call_import env "rts" (scheduled_fn ^ "_gc"))
 end
 
 
@@ -884,10 +899,21 @@ module RTS = struct
     E.add_func_import env "rts" "text_iter_done" [I32Type] [I32Type];
     E.add_func_import env "rts" "text_iter" [I32Type] [I32Type];
     E.add_func_import env "rts" "text_iter_next" [I32Type] [I32Type];
+    E.add_func_import env "rts" "text_iter_next_grapheme" [I32Type] [I32Type];
     E.add_func_import env "rts" "text_len" [I32Type] [I32Type];
     E.add_func_import env "rts" "text_of_ptr_size" [I32Type; I32Type] [I32Type];
     E.add_func_import env "rts" "text_singleton" [I32Type] [I32Type];
     E.add_func_import env "rts" "text_size" [I32Type] [I32Type];
+    E.add_func_import env "rts" "text_slice" [I32Type; I32Type; I32Type] [I32Type];
+    E.add_func_import env "rts" "text_char_at" [I32Type; I32Type] [I32Type];
+    E.add_func_import env "rts" "text_find" [I32Type; I32Type] [I32Type];
+    E.add_func_import env "rts" "text_split" [I32Type; I32Type] [I32Type];
+    E.add_func_import env "rts" "text_replace" [I32Type; I32Type; I32Type] [I32Type];
+    E.add_func_import env "rts" "text_to_upper" [I32Type] [I32Type];
+    E.add_func_import env "rts" "text_to_lower" [I32Type] [I32Type];
+    E.add_func_import env "rts" "text_nfc" [I32Type] [I32Type];
+    E.add_func_import env "rts" "text_nfd" [I32Type] [I32Type];
+    E.add_func_import env "rts" "text_nfkc" [I32Type] [I32Type];
     E.add_func_import env "rts" "text_to_buf" [I32Type; I32Type] [];
     E.add_func_import env "rts" "blob_of_principal" [I32Type] [I32Type];
     E.add_func_import env "rts" "principal_of_blob" [I32Type] [I32Type];
@@ -919,6 +945,36 @@ module RTS = struct
     E.add_func_import env "rts" "compacting_gc" [] [];
     E.add_func_import env "rts" "schedule_copying_gc" [] [];
     E.add_func_import env "rts" "schedule_compacting_gc" [] [];
+    E.add_func_import env "rts" "generational_gc" [] [];
+    E.add_func_import env "rts" "schedule_generational_gc" [] [];
+    E.add_func_import env "rts" "post_write_barrier" [I32Type] [];
+    E.add_func_import env "rts" "incremental_gc" [] [];
+    E.add_func_import env "rts" "schedule_incremental_gc" [] [];
+    E.add_func_import env "rts" "marksweep_gc" [] [];
+    E.add_func_import env "rts" "schedule_marksweep_gc" [] [];
+    E.add_func_import env "rts" "pre_write_barrier" [I32Type] [];
+    E.add_func_import env "rts" "set_gc_policy" [I32Type; I32Type] [];
+    E.add_func_import env "rts" "set_heap_limit" [I32Type] [];
+    E.add_func_import env "rts" "heap_limit_exceeded" [] [I32Type];
+    E.add_func_import env "rts" "check_heap_limit" [] [];
+    E.add_func_import env "rts" "set_alloc_sampling" [I32Type] [];
+    E.add_func_import env "rts" "set_alloc_site" [I32Type] [];
+    E.add_func_import env "rts" "alloc_profile" [] [I32Type];
+    E.add_func_import env "rts" "blob_intern" [I32Type] [I32Type];
+    E.add_func_import env "rts" "text_intern" [I32Type] [I32Type];
+    E.add_func_import env "rts" "request_blob_dedup" [] [];
+    E.add_func_import env "rts" "gc_stats_objects_marked" [] [I32Type];
+    E.add_func_import env "rts" "gc_stats_bytes_moved" [] [I32Type];
+    E.add_func_import env "rts" "gc_stats_bytes_freed" [I32Type] [I32Type];
+    E.add_func_import env "rts" "gc_stats_mark_stack_high_water_mark" [] [I32Type];
+    E.add_func_import env "rts" "gc_stats_bitmap_size" [] [I32Type];
+    E.add_func_import env "rts" "gc_stats_bytes_deduplicated" [] [I32Type];
+    E.add_func_import env "rts" "gc_stats_instructions" [I32Type] [I64Type];
+    E.add_func_import env "rts" "set_gc_stats_freed_by_tag" [I32Type] [];
+    E.add_func_import env "rts" "heap_census" [] [I32Type];
+    E.add_func_import env "rts" "check_heap" [] [I32Type];
+    E.add_func_import env "rts" "heap_snapshot" [] [I32Type];
+    E.add_func_import env "rts" "retainer_path" [I32Type] [I32Type];
     E.add_func_import env "rts" "alloc_words" [I32Type] [I32Type];
     E.add_func_import env "rts" "get_total_allocations" [] [I64Type];
     E.add_func_import env "rts" "get_heap_size" [] [I32Type];
@@ -979,6 +1035,41 @@ module GC = struct
     E.collect_garbage env ^^
     record_collector_instructions env
 
+  (* Sets the GC scheduling policy given with the `--gc-*` flags, if any.
+     Policy codes need to be in sync with `rts/motoko-rts/src/gc/policy.rs` *)
+  let set_policy env =
+    let set code parameter =
+      compile_unboxed_const code ^^
+      compile_unboxed_const (Int32.of_int parameter) ^^
+      E.call_import env "rts" "set_gc_policy" in
+    match !Flags.gc_policy with
+    | None -> G.nop
+    | Some (Flags.GrowthFactor percent) -> set 0l percent
+    | Some (Flags.AllocationBudget bytes) -> set 1l bytes
+
+  (* Sets the soft heap limit given with `--heap-limit`, if any *)
+  let set_heap_limit env =
+    match !Flags.heap_limit with
+    | None -> G.nop
+    | Some bytes ->
+      compile_unboxed_const (Int32.of_int bytes) ^^
+      E.call_import env "rts" "set_heap_limit"
+
+  (* Enables allocation sampling when `--alloc-sampling` is given *)
+  let set_alloc_sampling env =
+    match !Flags.alloc_sampling with
+    | None -> G.nop
+    | Some bytes ->
+      compile_unboxed_const (Int32.of_int bytes) ^^
+      E.call_import env "rts" "set_alloc_sampling"
+
+  (* Enables the per-tag freed bytes in the GC stats when `--gc-stats-freed-by-tag` is given *)
+  let set_stats_freed_by_tag env =
+    if !Flags.gc_stats_freed_by_tag then
+      compile_unboxed_const 1l ^^
+      E.call_import env "rts" "set_gc_stats_freed_by_tag"
+    else G.nop
+
 end (* GC *)
 
 module Heap = struct
@@ -1031,6 +1122,32 @@ module Heap = struct
     let offset = Int32.(add (mul word_size i) ptr_unskew) in
     G.i (Store {ty = I32Type; align = 2; offset; sz = None})
 
+  (* Pointer stores into objects that may have survived a GC (mutable
+     variables, array elements and mutable object fields) need to be reported to
+     the generational GC, so that it can find old-to-young pointers, and to the
+     incremental GC, so that it can mark the overwritten value.
+     Expects the skewed location (as `store_ptr` does) and the value on the stack. *)
+  let has_write_barrier () =
+    match !Flags.gc_strategy with
+    | Flags.Generational | Flags.Incremental -> true
+    | Flags.Copying | Flags.MarkCompact | Flags.MarkSweep -> false
+
+  let store_ptr_with_barrier env =
+    match !Flags.gc_strategy with
+    | Flags.Generational ->
+      Func.share_code2 env "store_ptr_with_barrier" (("loc", I32Type), ("value", I32Type)) [] (fun env get_loc get_value ->
+        get_loc ^^ get_value ^^ store_ptr ^^
+        get_loc ^^ compile_add_const ptr_unskew ^^
+        E.call_import env "rts" "post_write_barrier"
+      )
+    | Flags.Incremental ->
+      Func.share_code2 env "store_ptr_with_barrier" (("loc", I32Type), ("value", I32Type)) [] (fun env get_loc get_value ->
+        get_loc ^^ compile_add_const ptr_unskew ^^
+        E.call_import env "rts" "pre_write_barrier" ^^
+        get_loc ^^ get_value ^^ store_ptr
+      )
+    | Flags.Copying | Flags.MarkCompact | Flags.MarkSweep -> store_ptr
+
   (* Although we occasionally want to treat two consecutive
      32 bit fields as one 64 bit number *)
 
@@ -1305,6 +1422,7 @@ module Tagged = struct
     | CoercionFailure (* Used in the Candid decoder. Static singleton! *)
     | OneWordFiller (* Only used by the RTS *)
     | FreeSpace (* Only used by the RTS *)
+    | WeakRef (* Only used by the RTS *)
 
   (* Tags needs to have the lowest bit set, to allow distinguishing object
      headers from heap locations (object or field addresses).
@@ -1328,6 +1446,7 @@ module Tagged = struct
     | Null -> 27l
     | OneWordFiller -> 29l
     | FreeSpace -> 31l
+    | WeakRef -> 33l
     (* Next two tags won't be seen by the GC, so no need to set the lowest bit
        for `CoercionFailure` and `StableSeen` *)
     | CoercionFailure -> 0xfffffffel
@@ -3304,9 +3423,12 @@ module Text = struct
 
   (* The layout of a concatenation node is
 
-     ┌─────┬─────────┬───────┬───────┐
-     │ tag │ n_bytes │ text1 │ text2 │
-     └─────┴─────────┴───────┴───────┘
+     ┌─────┬─────────┬───────┬───────┬───────┬─────────┐
+     │ tag │ n_bytes │ text1 │ text2 │ depth │ n_chars │
+     └─────┴─────────┴───────┴───────┴───────┴─────────┘
+
+    where `depth` keeps concatenation trees balanced and `n_chars` caches the
+    number of characters (see rts/motoko-rts/src/text.rs).
 
     This is internal to rts/text.c, with the exception of GC-related code.
   *)
@@ -3342,6 +3464,38 @@ module Text = struct
     E.call_import env "rts" "text_iter_next" ^^
     TaggedSmallWord.tag_codepoint
 
+  let index env =
+    Blob.lit env "Text index out of bounds" ^^
+    BigNum.to_word32_with env
+
+  let slice env =
+    Func.share_code3 env "Text.slice" (("text", I32Type), ("start", I32Type), ("len", I32Type)) [I32Type] (fun env get_text get_start get_len ->
+      get_text ^^
+      get_start ^^ index env ^^
+      get_len ^^ index env ^^
+      E.call_import env "rts" "text_slice"
+    )
+
+  let char_at env =
+    Func.share_code2 env "Text.char_at" (("text", I32Type), ("idx", I32Type)) [I32Type] (fun env get_text get_idx ->
+      get_text ^^
+      get_idx ^^ index env ^^
+      E.call_import env "rts" "text_char_at" ^^
+      TaggedSmallWord.tag_codepoint
+    )
+
+  let find env =
+    Func.share_code2 env "Text.find" (("text", I32Type), ("pattern", I32Type)) [I32Type] (fun env get_text get_pattern ->
+      let (set_idx, get_idx) = new_local env "idx" in
+      get_text ^^ get_pattern ^^
+      E.call_import env "rts" "text_find" ^^
+      set_idx ^^
+      get_idx ^^ compile_eq_const (-1l) ^^
+      G.if1 I32Type
+        (Opt.null_lit env)
+        (Opt.inject_noop env (get_idx ^^ BigNum.from_word32 env))
+    )
+
   let compare env op =
     let open Operator in
     let name = match op with
@@ -4561,6 +4715,54 @@ module RTS_Exports = struct
     E.add_export env (nr {
       name = Wasm.Utf8.decode "stable64_write_moc";
       edesc = nr (FuncExport (nr stable64_write_moc_fi))
+    });
+
+    (* Used by the RTS to count the instructions of the GC phases *)
+    let performance_counter_moc_fi =
+      match E.mode env with
+      | Flags.(ICMode | RefMode) -> E.reuse_import env "ic0" "performance_counter"
+      | _ ->
+        E.add_fun env "performance_counter_moc" (
+            Func.of_body env ["counter_type", I32Type] [I64Type]
+              (fun env -> compile_const_64 0L)
+          ) in
+    E.add_export env (nr {
+      name = Wasm.Utf8.decode "performance_counter_moc";
+      edesc = nr (FuncExport (nr performance_counter_moc_fi))
+    });
+
+    (* Called by the RTS when the heap is larger than the `--heap-limit` after
+       a full collection. Runs the hook registered with
+       `rts_set_low_memory_hook`, if any. The hook is kept in a static MutBox,
+       whose address is in the `__low_memory_hook` global. *)
+    let hook_box = MutBox.static env in
+    E.add_global32 env "__low_memory_hook" Immutable hook_box;
+    let low_memory_moc_fi = E.add_fun env "low_memory_moc" (
+      Func.of_body env [] [] (fun env ->
+        let (set_hook, get_hook) = new_local env "hook" in
+        compile_unboxed_const hook_box ^^ Heap.load_field MutBox.field ^^ set_hook ^^
+        get_hook ^^ compile_eq_const 0l ^^
+        G.if0
+          G.nop
+          (get_hook ^^ get_hook ^^ Closure.call_closure env 0 0)
+      )
+    ) in
+    E.add_export env (nr {
+      name = Wasm.Utf8.decode "low_memory_moc";
+      edesc = nr (FuncExport (nr low_memory_moc_fi))
+    });
+
+    (* The intern table of `blob_intern` is kept in a static MutBox, so that
+       it's a GC root. The RTS gets the MutBox with `get_intern_table`. *)
+    let intern_table_box = MutBox.static env in
+    let get_intern_table_fi = E.add_fun env "get_intern_table" (
+      Func.of_body env [] [I32Type] (fun env ->
+        compile_unboxed_const intern_table_box
+      )
+    ) in
+    E.add_export env (nr {
+      name = Wasm.Utf8.decode "get_intern_table";
+      edesc = nr (FuncExport (nr get_intern_table_fi))
     })
 
 end (* RTS_Exports *)
@@ -6758,6 +6960,10 @@ module Var = struct
       G.nop,
       sr,
       G.i (LocalSet (nr i))
+    | Some (HeapInd i) when Heap.has_write_barrier () ->
+      G.i (LocalGet (nr i)) ^^ compile_add_const (Int32.mul MutBox.field Heap.word_size),
+      SR.Vanilla,
+      Heap.store_ptr_with_barrier env
     | Some (HeapInd i) ->
       G.i (LocalGet (nr i)),
       SR.Vanilla,
@@ -6900,7 +7106,18 @@ module FuncDec = struct
       closure_codeW (mk_body env ae2)
     ))
 
-  let message_start env sort = match sort with
+  (* With `--alloc-sampling`, allocations are attributed to the message being
+     run, identified by the hash of its name *)
+  let set_alloc_site env name =
+    match !Flags.alloc_sampling with
+    | None -> G.nop
+    | Some _ ->
+      compile_unboxed_const (Mo_types.Hash.hash name) ^^
+      E.call_import env "rts" "set_alloc_site"
+
+  let message_start env name sort =
+    set_alloc_site env name ^^
+    match sort with
       | Type.Shared Type.Write ->
         Lifecycle.trans env Lifecycle.InUpdate
       | Type.Shared Type.Query ->
@@ -6915,10 +7132,10 @@ module FuncDec = struct
         Lifecycle.trans env Lifecycle.PostQuery
       | _ -> assert false
 
-  let compile_const_message outer_env outer_ae sort control args mk_body ret_tys at : E.func_with_names =
+  let compile_const_message outer_env outer_ae name sort control args mk_body ret_tys at : E.func_with_names =
     let ae0 = VarEnv.mk_fun_ae outer_ae in
     Func.of_body outer_env [] [] (fun env -> G.with_region at (
-      message_start env sort ^^
+      message_start env name sort ^^
       (* cycles *)
       Internals.reset_cycles env outer_ae ^^
       Internals.reset_refund env outer_ae ^^
@@ -6945,7 +7162,7 @@ module FuncDec = struct
     then begin
       let (fi, fill) = E.reserve_fun pre_env name in
       ( Const.t_of_v (Const.Message fi), fun env ae ->
-        fill (compile_const_message env ae sort control args mk_body ret_tys at)
+        fill (compile_const_message env ae name sort control args mk_body ret_tys at)
       )
     end else begin
       assert (control = Type.Returns);
@@ -7070,7 +7287,7 @@ module FuncDec = struct
            (fun env -> compile_unboxed_const 0l)))
     in
     Func.define_built_in env reply_name ["env", I32Type] [] (fun env ->
-        message_start env (Type.Shared Type.Write) ^^
+        message_start env reply_name (Type.Shared Type.Write) ^^
         (* Look up continuation *)
         let (set_closure, get_closure) = new_local env "closure" in
         G.i (LocalGet (nr 0l)) ^^
@@ -7090,7 +7307,7 @@ module FuncDec = struct
 
     let reject_name = "@reject_callback" in
     Func.define_built_in env reject_name ["env", I32Type] [] (fun env ->
-        message_start env (Type.Shared Type.Write) ^^
+        message_start env reject_name (Type.Shared Type.Write) ^^
         (* Look up continuation *)
         let (set_closure, get_closure) = new_local env "closure" in
         G.i (LocalGet (nr 0l)) ^^
@@ -7249,7 +7466,7 @@ module FuncDec = struct
       Func.define_built_in env name [] [] (fun env ->
         let (set_closure, get_closure) = new_local env "closure" in
 
-        message_start env (Type.Shared Type.Write) ^^
+        message_start env name (Type.Shared Type.Write) ^^
 
         (* Check that we are calling this *)
         IC.assert_caller_self env ^^
@@ -8171,13 +8388,13 @@ let rec compile_lexp (env : E.t) ae lexp =
      compile_exp_vanilla env ae e2 ^^ (* idx *)
      Arr.idx_bigint env,
      SR.Vanilla,
-     store_ptr
+     Heap.store_ptr_with_barrier env
   | DotLE (e, n) ->
      compile_exp_vanilla env ae e ^^
      (* Only real objects have mutable fields, no need to branch on the tag *)
      Object.idx env e.note.Note.typ n,
      SR.Vanilla,
-     store_ptr
+     Heap.store_ptr_with_barrier env
 
 and compile_prim_invocation (env : E.t) ae p es at =
   (* for more concise code when all arguments and result use the same sr *)
@@ -8594,12 +8811,45 @@ and compile_prim_invocation (env : E.t) ae p es at =
     SR.bool, compile_exp_vanilla env ae e ^^ Text.iter_done env
   | OtherPrim "text_iter_next", [e] ->
     SR.Vanilla, compile_exp_vanilla env ae e ^^ Text.iter_next env
+  | OtherPrim "text_iter_next_grapheme", [e] ->
+    SR.Vanilla, compile_exp_vanilla env ae e ^^ E.call_import env "rts" "text_iter_next_grapheme"
   | OtherPrim "text_compare", [e1; e2] ->
     SR.Vanilla,
     compile_exp_vanilla env ae e1 ^^
     compile_exp_vanilla env ae e2 ^^
     E.call_import env "rts" "text_compare" ^^
     TaggedSmallWord.msb_adjust Type.Int8
+  | OtherPrim "text_slice", [e1; e2; e3] ->
+    SR.Vanilla,
+    compile_exp_vanilla env ae e1 ^^
+    compile_exp_vanilla env ae e2 ^^
+    compile_exp_vanilla env ae e3 ^^
+    Text.slice env
+  | OtherPrim "text_char_at", [e1; e2] ->
+    SR.Vanilla,
+    compile_exp_vanilla env ae e1 ^^
+    compile_exp_vanilla env ae e2 ^^
+    Text.char_at env
+  | OtherPrim "text_find", [e1; e2] ->
+    SR.Vanilla,
+    compile_exp_vanilla env ae e1 ^^
+    compile_exp_vanilla env ae e2 ^^
+    Text.find env
+  | OtherPrim "text_split", [e1; e2] ->
+    SR.Vanilla,
+    compile_exp_vanilla env ae e1 ^^
+    compile_exp_vanilla env ae e2 ^^
+    E.call_import env "rts" "text_split"
+  | OtherPrim "text_replace", [e1; e2; e3] ->
+    SR.Vanilla,
+    compile_exp_vanilla env ae e1 ^^
+    compile_exp_vanilla env ae e2 ^^
+    compile_exp_vanilla env ae e3 ^^
+    E.call_import env "rts" "text_replace"
+  | OtherPrim (("text_to_upper"|"text_to_lower"|"text_nfc"|"text_nfd"|"text_nfkc") as name), [e] ->
+    SR.Vanilla,
+    compile_exp_vanilla env ae e ^^
+    E.call_import env "rts" name
 
   | OtherPrim "blob_size", [e] ->
     SR.Vanilla, compile_exp_vanilla env ae e ^^ Blob.len env ^^ BigNum.from_word32 env
@@ -8781,6 +9031,88 @@ and compile_prim_invocation (env : E.t) ae p es at =
     SR.Vanilla,
     GC.get_collector_instructions env ^^ BigNum.from_word64 env
 
+  | OtherPrim "rts_gc_objects_marked", [] ->
+    SR.Vanilla,
+    E.call_import env "rts" "gc_stats_objects_marked" ^^ BigNum.from_word32 env
+
+  | OtherPrim "rts_gc_bytes_moved", [] ->
+    SR.Vanilla,
+    E.call_import env "rts" "gc_stats_bytes_moved" ^^ BigNum.from_word32 env
+
+  | OtherPrim "rts_gc_mark_stack_high_water_mark", [] ->
+    SR.Vanilla,
+    E.call_import env "rts" "gc_stats_mark_stack_high_water_mark" ^^ BigNum.from_word32 env
+
+  | OtherPrim "rts_gc_bitmap_size", [] ->
+    SR.Vanilla,
+    E.call_import env "rts" "gc_stats_bitmap_size" ^^ BigNum.from_word32 env
+
+  | OtherPrim "rts_gc_bytes_deduplicated", [] ->
+    SR.Vanilla,
+    E.call_import env "rts" "gc_stats_bytes_deduplicated" ^^ BigNum.from_word32 env
+
+  (* Phase numbers need to be in sync with `rts/motoko-rts/src/gc/stats.rs` *)
+  | OtherPrim "rts_gc_mark_instructions", [] ->
+    SR.Vanilla,
+    compile_unboxed_const 0l ^^
+    E.call_import env "rts" "gc_stats_instructions" ^^ BigNum.from_word64 env
+
+  | OtherPrim "rts_gc_thread_instructions", [] ->
+    SR.Vanilla,
+    compile_unboxed_const 1l ^^
+    E.call_import env "rts" "gc_stats_instructions" ^^ BigNum.from_word64 env
+
+  | OtherPrim "rts_gc_update_instructions", [] ->
+    SR.Vanilla,
+    compile_unboxed_const 2l ^^
+    E.call_import env "rts" "gc_stats_instructions" ^^ BigNum.from_word64 env
+
+  | OtherPrim "rts_heap_census", [] ->
+    SR.Vanilla,
+    E.call_import env "rts" "heap_census"
+
+  | OtherPrim "rts_heap_snapshot", [] ->
+    SR.Vanilla,
+    E.call_import env "rts" "heap_snapshot"
+
+  | OtherPrim "rts_retainer_path", [e] ->
+    SR.Vanilla,
+    compile_exp_vanilla env ae e ^^
+    E.call_import env "rts" "retainer_path"
+
+  | OtherPrim "rts_set_low_memory_hook", [e] ->
+    SR.unit,
+    G.i (GlobalGet (nr (E.get_global env "__low_memory_hook"))) ^^
+    compile_exp_vanilla env ae e ^^
+    Heap.store_field MutBox.field
+
+  | OtherPrim "rts_alloc_profile", [] ->
+    SR.Vanilla,
+    E.call_import env "rts" "alloc_profile"
+
+  | OtherPrim "rts_blob_intern", [e] ->
+    SR.Vanilla,
+    compile_exp_vanilla env ae e ^^
+    E.call_import env "rts" "blob_intern"
+
+  | OtherPrim "rts_text_intern", [e] ->
+    SR.Vanilla,
+    compile_exp_vanilla env ae e ^^
+    E.call_import env "rts" "text_intern"
+
+  | OtherPrim "rts_request_blob_dedup", [] ->
+    SR.unit,
+    E.call_import env "rts" "request_blob_dedup"
+
+  | OtherPrim "rts_check_heap", [] ->
+    SR.Vanilla,
+    E.call_import env "rts" "check_heap" ^^ Prim.prim_word32toNat env
+
+  | OtherPrim "rts_gc_bytes_freed", [e] ->
+    SR.Vanilla,
+    compile_exp_as env ae SR.UnboxedWord32 e ^^
+    E.call_import env "rts" "gc_stats_bytes_freed" ^^ BigNum.from_word32 env
+
   | OtherPrim "crc32Hash", [e] ->
     SR.UnboxedWord32,
     compile_exp_vanilla env ae e ^^
@@ -9839,8 +10171,12 @@ and conclude_module env start_fi_o =
 
   (* Wrap the start function with the RTS initialization *)
   let rts_start_fi = E.add_fun env "rts_start" (Func.of_body env [] [] (fun env1 ->
-    Bool.lit (!Flags.gc_strategy = Mo_config.Flags.MarkCompact) ^^
+    Bool.lit (!Flags.gc_strategy <> Mo_config.Flags.Copying) ^^
     E.call_import env "rts" "init" ^^
+    GC.set_policy env ^^
+    GC.set_heap_limit env ^^
+    GC.set_alloc_sampling env ^^
+    GC.set_stats_freed_by_tag env ^^
     match start_fi_o with
     | Some fi ->
       G.i (Call fi)
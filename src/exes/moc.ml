@@ -30,6 +30,11 @@ let explain_code = ref ""
 let stable_types = ref false
 let idl = ref false
 
+(* Byte counts passed to the RTS as 32-bit unsigned integers *)
+let check_u32_bytes flag n =
+  if n <= 0 || n >= 1 lsl 32 then
+    raise (Arg.Bad (flag ^ " must be between 1 and 4294967295"))
+
 let valid_metadata_names =
     ["candid:args";
      "candid:service";
@@ -146,10 +151,52 @@ let argspec = [
   Arg.Unit (fun () -> Flags.gc_strategy := Mo_config.Flags.Copying),
   " use copying GC (default)";
 
+  "--generational-gc",
+  Arg.Unit (fun () -> Flags.gc_strategy := Mo_config.Flags.Generational),
+  " use generational GC";
+
+  "--incremental-gc",
+  Arg.Unit (fun () -> Flags.gc_strategy := Mo_config.Flags.Incremental),
+  " use incremental compacting GC";
+
+  "--marksweep-gc",
+  Arg.Unit (fun () -> Flags.gc_strategy := Mo_config.Flags.MarkSweep),
+  " use non-moving mark-sweep GC";
+
   "--force-gc",
   Arg.Unit (fun () -> Flags.force_gc := true),
   " disable GC scheduling, always do GC after an update message (for testing)";
 
+  "--gc-growth-factor",
+  Arg.Int (fun n ->
+    if n < 100 then raise (Arg.Bad "--gc-growth-factor must be at least 100");
+    Flags.gc_policy := Some (Mo_config.Flags.GrowthFactor n)),
+  "<n>  schedule GC when the heap reaches <n> percent (at least 100) of the heap size after the last GC (default 150)";
+
+  "--gc-allocation-budget",
+  Arg.Int (fun n ->
+    check_u32_bytes "--gc-allocation-budget" n;
+    Flags.gc_policy := Some (Mo_config.Flags.AllocationBudget n)),
+  "<n>  schedule GC when <n> bytes were allocated since the last GC";
+
+  "--gc-always",
+  Arg.Unit (fun () -> Flags.force_gc := true),
+  " same as --force-gc";
+
+  "--heap-limit",
+  Arg.Int (fun n ->
+    check_u32_bytes "--heap-limit" n;
+    Flags.heap_limit := Some n),
+  "<n>  force a full GC when the heap grows over <n> bytes, and call the low-memory hook if the heap is still larger after the GC";
+
+  "--alloc-sampling",
+  Arg.Int (fun n -> Flags.alloc_sampling := Some n),
+  "<n>  sample an allocation every <n> bytes allocated, for `Prim.rts_alloc_profile` (for profiling)";
+
+  "--gc-stats-freed-by-tag",
+  Arg.Set Flags.gc_stats_freed_by_tag,
+  " record the bytes freed per object tag in `Prim.rts_gc_stats` (costs a walk over the dead objects in each GC)";
+
   "--max-stable-pages",
   Arg.Set_int Flags.max_stable_pages,
   "<n>  set maximum number of pages available for library `ExperimentalStableMemory.mo` (default " ^ (Int.to_string Flags.max_stable_pages_default) ^ ")";
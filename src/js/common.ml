@@ -157,4 +157,7 @@ let gc_flags option =
   | "scheduling" -> Flags.force_gc := false
   | "copying" -> Flags.gc_strategy := Mo_config.Flags.Copying
   | "marking" -> Flags.gc_strategy := Mo_config.Flags.MarkCompact
+  | "generational" -> Flags.gc_strategy := Mo_config.Flags.Generational
+  | "incremental" -> Flags.gc_strategy := Mo_config.Flags.Incremental
+  | "marksweep" -> Flags.gc_strategy := Mo_config.Flags.MarkSweep
   | _ -> raise (Invalid_argument "gc_flags: Unexpected flag")
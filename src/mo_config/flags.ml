@@ -4,7 +4,9 @@ module M = Map.Make(String)
 
 type compile_mode = WasmMode | ICMode | RefMode | WASIMode
 
-type gc_strategy = MarkCompact | Copying
+type gc_strategy = MarkCompact | Copying | Generational | Incremental | MarkSweep
+
+type gc_policy = GrowthFactor of int | AllocationBudget of int
 
 let trace = ref false
 let verbose = ref false
@@ -40,4 +42,8 @@ let error_detail = ref 2
 let sanity = ref false
 let gc_strategy = ref Copying
 let force_gc = ref false
+let gc_policy : gc_policy option ref = ref None
+let heap_limit : int option ref = ref None
+let alloc_sampling : int option ref = ref None
+let gc_stats_freed_by_tag = ref false
 let experimental_field_aliasing = ref false
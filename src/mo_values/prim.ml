@@ -75,6 +75,79 @@ for suitable types t1 and t2
 let num_conv_wrap_prim t1 t2 =
   fun v -> of_big_int_wrap t2 (as_big_int t1 v)
 
+(* Byte offset of the first occurrence of [p] in [s] at or after [i] *)
+let rec text_find_from s p i =
+  if i + String.length p > String.length s then None
+  else if String.sub s i (String.length p) = p then Some i
+  else text_find_from s p (i + 1)
+
+(* Parts of [s] between the occurrences of [p], from byte offset [i] on *)
+let rec text_split_from s p i =
+  match if p = "" then None else text_find_from s p i with
+  | None -> [String.sub s i (String.length s - i)]
+  | Some j -> String.sub s i (j - i) :: text_split_from s p (j + String.length p)
+
+(* Full case mapping of a text, with the final sigma rule of lower case *)
+let text_map_case to_upper s =
+  let cs = Array.of_list (Wasm.Utf8.decode s) in
+  let is_cased i = Uucp.Case.is_cased (Uchar.of_int cs.(i)) in
+  let is_ignorable i = Uucp.Case.is_case_ignorable (Uchar.of_int cs.(i)) in
+  let rec cased_before i =
+    i >= 0 && (if is_ignorable i then cased_before (i - 1) else is_cased i) in
+  let rec cased_after i =
+    i < Array.length cs && (if is_ignorable i then cased_after (i + 1) else is_cased i) in
+  let map i c =
+    if not to_upper && c = 0x3A3 && cased_before (i - 1) && not (cased_after (i + 1))
+    then [0x3C2]
+    else
+      let map = if to_upper then Uucp.Case.Map.to_upper else Uucp.Case.Map.to_lower in
+      match map (Uchar.of_int c) with
+      | `Self -> [c]
+      | `Uchars us -> List.map Uchar.to_int us
+  in
+  Wasm.Utf8.encode (List.concat (List.mapi map (Array.to_list cs)))
+
+(* Normalization of a text to the given normalization form *)
+let text_normalize form s =
+  let n = Uunf.create form in
+  let out = ref [] in
+  let rec add v =
+    match Uunf.add n v with
+    | `Uchar u -> out := Uchar.to_int u :: !out; add `Await
+    | `Await | `End -> ()
+  in
+  List.iter (fun c -> add (`Uchar (Uchar.of_int c))) (Wasm.Utf8.decode s);
+  add `End;
+  Wasm.Utf8.encode (List.rev !out)
+
+(* Extended grapheme cluster boundaries, as found by `GraphemeBreaks` in the RTS. The state is the
+   property of the last character, and whether rules GB11 and GB12/GB13 apply after it. *)
+let grapheme_prop c =
+  let u = Uchar.of_int c in
+  if Uucp.Emoji.is_extended_pictographic u then `ExtPict
+  else (Uucp.Break.grapheme_cluster u :> [Uucp.Break.grapheme_cluster | `ExtPict])
+
+let grapheme_start c =
+  let prop = grapheme_prop c in
+  (prop, prop = `ExtPict, prop = `RI)
+
+let grapheme_break (prev, ext_pict, ri_odd) c =
+  let next = grapheme_prop c in
+  let is_break = match prev, next with
+    | `CR, `LF -> false
+    | (`CR | `LF | `CN), _ | _, (`CR | `LF | `CN) -> true
+    | `L, (`L | `V | `LV | `LVT) | (`LV | `V), (`V | `T) | (`LVT | `T), `T -> false
+    | _, (`EX | `ZWJ | `SM) | `PP, _ -> false
+    | `ZWJ, `ExtPict -> not ext_pict
+    | `RI, `RI -> not ri_odd
+    | _ -> true in
+  let ext_pict = match next with
+    | `ExtPict -> true
+    | `EX | `ZWJ -> ext_pict && prev <> `ZWJ
+    | _ -> false in
+  let ri_odd = next = `RI && not (prev = `RI && ri_odd) in
+  is_break, (next, ext_pict, ri_odd)
+
 let prim =
   let via_float f v = Float.(Float (of_float (f (to_float (as_float v))))) in
   let via_float2 f v w = Float.(Float (of_float (f (to_float (as_float v)) (to_float (as_float w))))) in
@@ -204,8 +277,22 @@ let prim =
      | "rts_callback_table_count"
      | "rts_callback_table_size"
      | "rts_mutator_instructions"
-     | "rts_collector_instructions") ->
+     | "rts_collector_instructions"
+     | "rts_gc_objects_marked"
+     | "rts_gc_bytes_moved"
+     | "rts_gc_mark_stack_high_water_mark"
+     | "rts_gc_bitmap_size"
+     | "rts_gc_bytes_deduplicated"
+     | "rts_gc_mark_instructions"
+     | "rts_gc_thread_instructions"
+     | "rts_gc_update_instructions"
+     | "rts_check_heap") ->
         fun _ v k -> as_unit v; k (Int (Int.of_int 0))
+  | "rts_gc_bytes_freed" -> fun _ v k -> ignore (as_nat32 v); k (Int (Int.of_int 0))
+  | ("rts_heap_census" | "rts_heap_snapshot" | "rts_alloc_profile") -> fun _ v k -> as_unit v; k (Blob "")
+  | "rts_retainer_path" -> fun _ v k -> k (Array [||])
+  | ("rts_set_low_memory_hook" | "rts_request_blob_dedup") -> fun _ v k -> k unit
+  | ("rts_blob_intern" | "rts_text_intern") -> fun _ v k -> k v
   | "time" -> fun _ v k -> as_unit v; k (Value.Nat64 (Numerics.Nat64.of_int 42))
   | "idlHash" -> fun _ v k ->
     let s = as_text v in
@@ -230,6 +317,21 @@ let prim =
     | Seq.Nil -> assert false
     | Seq.Cons (v, vs) -> i := vs; k v
     end
+  | "text_iter_next_grapheme" -> fun _ v k ->
+    let i = Value.as_iter v in
+    let code = function Char c -> c | _ -> assert false in
+    let rec cluster state acc cs =
+      match cs () with
+      | Seq.Cons (c, cs') ->
+        let is_break, state' = grapheme_break state (code c) in
+        if is_break then acc, cs else cluster state' (code c :: acc) cs'
+      | Seq.Nil -> acc, cs in
+    begin match !i () with
+    | Seq.Nil -> assert false
+    | Seq.Cons (c, cs) ->
+      let acc, cs = cluster (grapheme_start (code c)) [code c] cs in
+      i := cs; k (Text (Wasm.Utf8.encode (List.rev acc)))
+    end
   | "text_len" -> fun _ v k ->
     k (Int (Nat.of_int (List.length (Wasm.Utf8.decode (Value.as_text v)))))
   | "text_compare" -> fun _ v k ->
@@ -238,6 +340,46 @@ let prim =
                             (let a, b = Value.as_text a, Value.as_text b in
                              if a = b then 0 else if a < b then -1 else 1)))
      | _ -> assert false)
+  | "text_slice" -> fun _ v k ->
+    (match Value.as_tup v with
+     | [t; start; len] ->
+       let s = Wasm.Utf8.decode (Value.as_text t) in
+       let start, len = Int.to_int (as_int start), Int.to_int (as_int len) in
+       if start + len > List.length s then raise (Invalid_argument "Text index out of bounds");
+       k (Text (Wasm.Utf8.encode (Lib.List.take len (Lib.List.drop start s))))
+     | _ -> assert false)
+  | "text_char_at" -> fun _ v k ->
+    (match Value.as_tup v with
+     | [t; i] ->
+       let s = Wasm.Utf8.decode (Value.as_text t) in
+       let i = Int.to_int (as_int i) in
+       if i >= List.length s then raise (Invalid_argument "Text index out of bounds");
+       k (Char (List.nth s i))
+     | _ -> assert false)
+  | "text_find" -> fun _ v k ->
+    (match Value.as_tup v with
+     | [t; p] ->
+       let s = Value.as_text t in
+       (match text_find_from s (Value.as_text p) 0 with
+        | None -> k Null
+        | Some i -> k (Opt (Int (Nat.of_int (List.length (Wasm.Utf8.decode (String.sub s 0 i)))))))
+     | _ -> assert false)
+  | "text_split" -> fun _ v k ->
+    (match Value.as_tup v with
+     | [t; p] ->
+       let parts = text_split_from (Value.as_text t) (Value.as_text p) 0 in
+       k (Array (Array.of_list (List.map (fun s -> Text s) parts)))
+     | _ -> assert false)
+  | "text_replace" -> fun _ v k ->
+    (match Value.as_tup v with
+     | [t; p; r] ->
+       k (Text (String.concat (Value.as_text r) (text_split_from (Value.as_text t) (Value.as_text p) 0)))
+     | _ -> assert false)
+  | "text_to_upper" -> fun _ v k -> k (Text (text_map_case true (Value.as_text v)))
+  | "text_to_lower" -> fun _ v k -> k (Text (text_map_case false (Value.as_text v)))
+  | "text_nfc" -> fun _ v k -> k (Text (text_normalize `NFC (Value.as_text v)))
+  | "text_nfd" -> fun _ v k -> k (Text (text_normalize `NFD (Value.as_text v)))
+  | "text_nfkc" -> fun _ v k -> k (Text (text_normalize `NFKC (Value.as_text v)))
   | "text_iter" -> fun _ v k ->
     let s = Wasm.Utf8.decode (Value.as_text v) in
     let i = Seq.map (fun c -> Char c) (List.to_seq s) in